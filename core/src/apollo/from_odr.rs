@@ -0,0 +1,1110 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use crate::apollo::generated::{
+    common::PointEnu,
+    hdmap::{
+        self, Curve, CurveSegment, Id, LaneBoundary, LaneBoundaryType, LaneOverlapInfo,
+        LaneSampleAssociation, LineSegment, ObjectOverlapInfo, Overlap, Polygon,
+        lane::{LaneDirection, LaneTurn, LaneType},
+        lane_boundary_type,
+        object_overlap_info::OverlapInfo,
+    },
+};
+use crate::math::{polyline, vec3::Vec3};
+use crate::odr::models::{
+    enums::{OdrOrientation, OdrRoadMarkColor},
+    lane::{
+        OdrLane,
+        lane_road_mark::{OdrRoadMark, OdrRoadMarkType},
+        lane_section::OdrLaneSection,
+        lane_speed::OdrLaneSpeed,
+    },
+    opendrive::{LaneKey, OpenDrive},
+    road::OdrRoad,
+    signal::OdrSignal,
+};
+
+/// OpenDRIVE → Apollo 转换选项
+#[derive(Debug, Clone, Copy)]
+pub struct OdrToApolloOptions {
+    /// 沿车道采样中心线和边界的步长（米）
+    pub sample_step: f64,
+}
+
+impl Default for OdrToApolloOptions {
+    fn default() -> Self {
+        Self { sample_step: 1.0 }
+    }
+}
+
+/// 采样得到的车道几何
+struct LaneSamples {
+    center: Vec<Vec3>,
+    left: Vec<Vec3>,
+    right: Vec<Vec3>,
+    /// 中心线到左/右边界的距离
+    half_widths: Vec<f64>,
+    /// 中心线到道路左/右边缘的距离（按行驶方向）
+    road_left: Vec<f64>,
+    road_right: Vec<f64>,
+}
+
+/// 将 OpenDRIVE 地图转换为 Apollo 高精地图
+///
+/// 每条 OpenDRIVE 车道（中心车道除外）按车道段采样为一条 Apollo Lane，
+/// 左侧车道按行驶方向反转采样顺序。道路/车道连接与 junction connection
+/// 转换为前驱/后继关系，junction 多边形取其内部车道边界的凸包，
+/// 并计算车道与信号灯、停车标志、人行横道、junction 以及路口内车道之间的 Overlap。
+pub fn opendrive_to_apollo(odr: &OpenDrive, options: &OdrToApolloOptions) -> Result<hdmap::Map> {
+    if options.sample_step <= 0.0 {
+        return Err(anyhow!("sample_step 必须为正数"));
+    }
+
+    let road_index: HashMap<&str, usize> = odr
+        .roads
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (r.id.as_str(), i))
+        .collect();
+
+    let mut map = hdmap::Map {
+        header: Some(build_header(odr)),
+        ..Default::default()
+    };
+
+    // 1. 车道几何
+    let mut lane_index: HashMap<LaneKey, usize> = HashMap::new();
+    let mut centers: Vec<Vec<Vec3>> = Vec::new();
+
+    for (road_idx, road) in odr.roads.iter().enumerate() {
+        let junction_id = road_junction(road);
+        let mut apollo_road = hdmap::Road {
+            id: Some(id(&road.id)),
+            junction_id: junction_id.map(id),
+            r#type: road
+                .road_types
+                .first()
+                .map(|t| road_type_from_odr(&t.road_type) as i32),
+            ..Default::default()
+        };
+
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            if s_end - s_start < 1e-6 {
+                continue;
+            }
+
+            let mut section_lane_ids = Vec::new();
+            for lane in section.left.iter().chain(section.right.iter()) {
+                let samples = sample_lane(road, section, lane, s_start, s_end, options.sample_step);
+                if samples.half_widths.iter().all(|w| *w < 5e-4) {
+                    continue;
+                }
+
+                let lane_id = lane_id(road, section_idx, lane.id);
+                let apollo_lane =
+                    build_lane(&lane_id, road, section, lane, s_end - s_start, &samples);
+                section_lane_ids.push(Id { id: Some(lane_id) });
+
+                lane_index.insert((road_idx, section_idx, lane.id), map.lane.len());
+                centers.push(samples.center);
+                map.lane.push(apollo_lane);
+            }
+
+            apollo_road.section.push(hdmap::RoadSection {
+                id: Some(id(&format!("{}_{}", road.id, section_idx))),
+                lane_id: section_lane_ids,
+                boundary: None,
+            });
+        }
+
+        map.road.push(apollo_road);
+    }
+
+    // 2. 相邻车道
+    for (&(road_idx, section_idx, odr_id), &idx) in &lane_index {
+        let neighbor = |other: i32| {
+            lane_index
+                .get(&(road_idx, section_idx, other))
+                .map(|&i| map.lane[i].id.clone().unwrap_or_default())
+        };
+        let step = odr_id.signum();
+
+        // 两侧车道的驾驶员左侧都指向参考线
+        let inner = if odr_id.abs() > 1 {
+            neighbor(odr_id - step)
+        } else {
+            None
+        };
+        let outer = neighbor(odr_id + step);
+        let opposite = if odr_id.abs() == 1 {
+            neighbor(-odr_id)
+        } else {
+            None
+        };
+
+        let lane = &mut map.lane[idx];
+        lane.left_neighbor_forward_lane_id.extend(inner);
+        lane.right_neighbor_forward_lane_id.extend(outer);
+        lane.left_neighbor_reverse_lane_id.extend(opposite);
+    }
+
+    // 3. 前驱/后继
+    for (from, to) in odr.lane_flows(&road_index, &lane_index) {
+        let from_id = map.lane[from].id.clone().unwrap_or_default();
+        let to_id = map.lane[to].id.clone().unwrap_or_default();
+        map.lane[from].successor_id.push(to_id);
+        map.lane[to].predecessor_id.push(from_id);
+    }
+
+    // 4. Junction
+    let mut overlaps = OverlapBuilder::default();
+    for junction in &odr.junctions {
+        let mut lanes: Vec<usize> = lane_index
+            .iter()
+            .filter(|((road_idx, _, _), _)| {
+                road_junction(&odr.roads[*road_idx]) == Some(junction.id.as_str())
+            })
+            .map(|(_, &idx)| idx)
+            .collect();
+        lanes.sort_unstable();
+
+        let boundary_points: Vec<Vec3> = lanes
+            .iter()
+            .flat_map(|&idx| boundary_points(&map.lane[idx]))
+            .collect();
+        let hull = polyline::convex_hull(&boundary_points);
+
+        map.junction.push(hdmap::Junction {
+            id: Some(id(&junction.id)),
+            polygon: (hull.len() >= 3).then(|| to_polygon(&hull)),
+            ..Default::default()
+        });
+        let junction_idx = map.junction.len() - 1;
+
+        for &lane in &lanes {
+            let length = map.lane[lane].length.unwrap_or(0.0);
+            let overlap = overlaps.add(
+                &mut map,
+                lane,
+                0.0,
+                length,
+                false,
+                Element::Junction(junction_idx),
+            );
+            map.junction[junction_idx].overlap_id.push(overlap);
+        }
+
+        add_lane_crossings(&mut map, &mut overlaps, &lanes, &centers);
+    }
+
+    // 5. 人行横道
+    for road in &odr.roads {
+        for object in &road.objects {
+            if object.object_type.as_deref() != Some("crosswalk") {
+                continue;
+            }
+            let Some(outline) = object
+                .outline_points(road)
+                .into_iter()
+                .find(|o| o.len() >= 3)
+            else {
+                continue;
+            };
+
+            map.crosswalk.push(hdmap::Crosswalk {
+                id: Some(id(&object.id)),
+                polygon: Some(to_polygon(&outline)),
+                overlap_id: Vec::new(),
+            });
+            let crosswalk_idx = map.crosswalk.len() - 1;
+
+            for (lane, center) in centers.iter().enumerate() {
+                if let Some((start, end)) = polygon_s_range(center, &outline) {
+                    overlaps.add(
+                        &mut map,
+                        lane,
+                        start,
+                        end,
+                        false,
+                        Element::Crosswalk(crosswalk_idx),
+                    );
+                }
+            }
+        }
+    }
+
+    // 6. 信号灯与停车标志
+    for road in &odr.roads {
+        for signal in &road.signals {
+            let stop_line = stop_line_for(road, signal);
+            let stop_line_curve = (stop_line.len() >= 2).then(|| to_curve(&stop_line));
+
+            let element = if signal.dynamic {
+                map.signal.push(hdmap::Signal {
+                    id: Some(id(&signal.id)),
+                    boundary: Some(to_polygon(&signal_boundary(road, signal))),
+                    r#type: Some(signal_type_from_odr(signal) as i32),
+                    stop_line: stop_line_curve.into_iter().collect(),
+                    ..Default::default()
+                });
+                Element::Signal(map.signal.len() - 1)
+            } else if is_stop_sign(signal) {
+                map.stop_sign.push(hdmap::StopSign {
+                    id: Some(id(&signal.id)),
+                    stop_line: stop_line_curve.into_iter().collect(),
+                    ..Default::default()
+                });
+                Element::StopSign(map.stop_sign.len() - 1)
+            } else {
+                continue;
+            };
+
+            for (lane, center) in centers.iter().enumerate() {
+                if let Some(&(s, _)) = polyline::intersections(center, &stop_line).first() {
+                    overlaps.add(&mut map, lane, s, s, false, element);
+                }
+            }
+        }
+    }
+
+    map.overlap = overlaps.overlaps;
+    Ok(map)
+}
+
+fn road_junction(road: &OdrRoad) -> Option<&str> {
+    (road.junction != "-1" && !road.junction.is_empty()).then_some(road.junction.as_str())
+}
+
+fn lane_id(road: &OdrRoad, section_idx: usize, odr_id: i32) -> String {
+    format!("{}_{}_{}", road.id, section_idx, odr_id)
+}
+
+/// 沿车道采样中心线和边界，左侧车道按行驶方向（-s）排列
+fn sample_lane(
+    road: &OdrRoad,
+    section: &OdrLaneSection,
+    lane: &OdrLane,
+    s_start: f64,
+    s_end: f64,
+    step: f64,
+) -> LaneSamples {
+    let num_samples = (((s_end - s_start) / step).ceil() as usize).max(1) + 1;
+    let point = |s: f64, t: f64| road.sth_to_xyz(s, t, road.eval_shape(s, t));
+
+    // 道路左右边缘：最外侧车道的外边界
+    let edge = |lanes: &[OdrLane], s: f64| {
+        lanes
+            .iter()
+            .max_by_key(|l| l.id.abs())
+            .map(|l| road.lane_t_bounds(section, l, s).1)
+            .unwrap_or_else(|| road.eval_lane_offset(s))
+    };
+
+    let mut samples = LaneSamples {
+        center: Vec::with_capacity(num_samples),
+        left: Vec::with_capacity(num_samples),
+        right: Vec::with_capacity(num_samples),
+        half_widths: Vec::with_capacity(num_samples),
+        road_left: Vec::with_capacity(num_samples),
+        road_right: Vec::with_capacity(num_samples),
+    };
+
+    for i in 0..num_samples {
+        let ratio = i as f64 / (num_samples - 1) as f64;
+        let s = if lane.id > 0 {
+            s_end - ratio * (s_end - s_start)
+        } else {
+            s_start + ratio * (s_end - s_start)
+        };
+
+        let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+        let t_center = (t_inner + t_outer) / 2.0;
+        let left_edge = edge(&section.left, s);
+        let right_edge = edge(&section.right, s);
+
+        // 驾驶员左侧始终是内边界（靠近参考线）
+        samples.left.push(point(s, t_inner));
+        samples.right.push(point(s, t_outer));
+        samples.center.push(point(s, t_center));
+        samples.half_widths.push((t_outer - t_inner).abs() / 2.0);
+
+        if lane.id < 0 {
+            samples.road_left.push(left_edge - t_center);
+            samples.road_right.push(t_center - right_edge);
+        } else {
+            samples.road_left.push(t_center - right_edge);
+            samples.road_right.push(left_edge - t_center);
+        }
+    }
+
+    samples
+}
+
+/// 构建 Apollo Lane（不含拓扑关系）
+fn build_lane(
+    lane_id: &str,
+    road: &OdrRoad,
+    section: &OdrLaneSection,
+    lane: &OdrLane,
+    section_length: f64,
+    samples: &LaneSamples,
+) -> hdmap::Lane {
+    let reversed = lane.id > 0;
+    let center_lengths = polyline::cumulative_lengths(&samples.center);
+    let length = center_lengths.last().copied().unwrap_or(0.0);
+
+    let associations = |values: &[f64]| {
+        center_lengths
+            .iter()
+            .zip(values)
+            .map(|(s, w)| LaneSampleAssociation {
+                s: Some(*s),
+                width: Some(*w),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // 内边界标线属于更靠近参考线的车道
+    let inner_marks = if lane.id.abs() == 1 {
+        &section.center.road_marks
+    } else {
+        let inner_id = lane.id - lane.id.signum();
+        section
+            .left
+            .iter()
+            .chain(section.right.iter())
+            .find(|l| l.id == inner_id)
+            .map(|l| &l.road_marks)
+            .unwrap_or(&section.center.road_marks)
+    };
+
+    let boundary = |points: &[Vec3], marks: &[OdrRoadMark]| LaneBoundary {
+        curve: Some(to_curve(points)),
+        length: Some(polyline::length(points)),
+        r#virtual: Some(
+            marks
+                .iter()
+                .all(|m| matches!(m.mark_type, OdrRoadMarkType::None)),
+        ),
+        boundary_type: boundary_types(marks, section_length, reversed),
+    };
+
+    let lane_type = lane_type_from_odr(&lane.lane_type);
+    let direction = if lane.lane_type == "bidirectional" {
+        LaneDirection::Bidirection
+    } else {
+        LaneDirection::Forward
+    };
+    let turn = if road_junction(road).is_some() {
+        lane_turn(&samples.center)
+    } else {
+        LaneTurn::NoTurn
+    };
+
+    hdmap::Lane {
+        id: Some(Id {
+            id: Some(lane_id.to_string()),
+        }),
+        central_curve: Some(to_curve(&samples.center)),
+        left_boundary: Some(boundary(&samples.left, inner_marks)),
+        right_boundary: Some(boundary(&samples.right, &lane.road_marks)),
+        length: Some(length),
+        speed_limit: lane.speed.first().map(OdrLaneSpeed::max_mps),
+        r#type: Some(lane_type as i32),
+        turn: Some(turn as i32),
+        junction_id: road_junction(road).map(id),
+        left_sample: associations(&samples.half_widths),
+        right_sample: associations(&samples.half_widths),
+        direction: Some(direction as i32),
+        left_road_sample: associations(&samples.road_left),
+        right_road_sample: associations(&samples.road_right),
+        ..Default::default()
+    }
+}
+
+/// 将车道段内的道路标线转换为 Apollo 边界类型
+///
+/// # 参数
+/// - `section_length`: 车道段长度
+/// - `reversed`: 车道行驶方向与参考线相反
+fn boundary_types(
+    marks: &[OdrRoadMark],
+    section_length: f64,
+    reversed: bool,
+) -> Vec<LaneBoundaryType> {
+    if marks.is_empty() {
+        return vec![LaneBoundaryType {
+            s: Some(0.0),
+            types: vec![lane_boundary_type::Type::Unknown as i32],
+        }];
+    }
+
+    let mut entries: Vec<(f64, lane_boundary_type::Type)> = marks
+        .iter()
+        .map(|m| {
+            (
+                m.s_offset.clamp(0.0, section_length),
+                boundary_type_from_road_mark(&m.mark_type, &m.color),
+            )
+        })
+        .collect();
+
+    if reversed {
+        // [s_k, s_k+1) 映射为 [L - s_k+1, L - s_k)
+        let ends: Vec<f64> = entries.iter().skip(1).map(|e| e.0).collect();
+        entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, kind))| {
+                let end = ends.get(i).copied().unwrap_or(section_length);
+                (section_length - end, kind)
+            })
+            .rev()
+            .collect();
+    }
+
+    entries
+        .into_iter()
+        .map(|(s, kind)| LaneBoundaryType {
+            s: Some(s),
+            types: vec![kind as i32],
+        })
+        .collect()
+}
+
+/// OpenDRIVE 标线类型和颜色到 Apollo `LaneBoundaryType` 的映射
+pub fn boundary_type_from_road_mark(
+    mark_type: &OdrRoadMarkType,
+    color: &OdrRoadMarkColor,
+) -> lane_boundary_type::Type {
+    use lane_boundary_type::Type;
+    let yellow = matches!(color, OdrRoadMarkColor::Yellow | OdrRoadMarkColor::Orange);
+
+    match mark_type {
+        OdrRoadMarkType::Broken | OdrRoadMarkType::BottsDots | OdrRoadMarkType::BrokenBroken => {
+            if yellow {
+                Type::DottedYellow
+            } else {
+                Type::DottedWhite
+            }
+        }
+        OdrRoadMarkType::Solid => {
+            if yellow {
+                Type::SolidYellow
+            } else {
+                Type::SolidWhite
+            }
+        }
+        OdrRoadMarkType::SolidSolid
+        | OdrRoadMarkType::SolidBroken
+        | OdrRoadMarkType::BrokenSolid => {
+            if yellow {
+                Type::DoubleYellow
+            } else {
+                Type::SolidWhite
+            }
+        }
+        OdrRoadMarkType::Curb => Type::Curb,
+        _ => Type::Unknown,
+    }
+}
+
+fn lane_type_from_odr(lane_type: &str) -> LaneType {
+    match lane_type {
+        "driving" | "entry" | "exit" | "onRamp" | "offRamp" | "connectingRamp" | "slipLane" => {
+            LaneType::CityDriving
+        }
+        "biking" => LaneType::Biking,
+        "sidewalk" | "walking" => LaneType::Sidewalk,
+        "parking" => LaneType::Parking,
+        "shoulder" => LaneType::Shoulder,
+        "bidirectional" | "shared" => LaneType::Shared,
+        _ => LaneType::None,
+    }
+}
+
+fn road_type_from_odr(road_type: &str) -> hdmap::road::Type {
+    match road_type {
+        "motorway" => hdmap::road::Type::Highway,
+        "town" | "townArterial" | "townCollector" | "townLocal" | "rural" | "trunk" => {
+            hdmap::road::Type::CityRoad
+        }
+        "lowSpeed" | "townPrivate" | "townPlayStreet" | "pedestrian" | "bicycle" => {
+            hdmap::road::Type::Park
+        }
+        _ => hdmap::road::Type::Unknown,
+    }
+}
+
+/// 根据中心线首尾航向变化判断路口内车道的转向
+fn lane_turn(center: &[Vec3]) -> LaneTurn {
+    let mut total = 0.0;
+    let mut prev: Option<f64> = None;
+    for seg in center.windows(2) {
+        let dx = seg[1].x - seg[0].x;
+        let dy = seg[1].y - seg[0].y;
+        if dx.abs() < 1e-9 && dy.abs() < 1e-9 {
+            continue;
+        }
+        let hdg = dy.atan2(dx);
+        if let Some(p) = prev {
+            let mut d = hdg - p;
+            while d > std::f64::consts::PI {
+                d -= std::f64::consts::TAU;
+            }
+            while d < -std::f64::consts::PI {
+                d += std::f64::consts::TAU;
+            }
+            total += d;
+        }
+        prev = Some(hdg);
+    }
+
+    let degrees = total.to_degrees();
+    if degrees.abs() > 150.0 {
+        LaneTurn::UTurn
+    } else if degrees > 45.0 {
+        LaneTurn::LeftTurn
+    } else if degrees < -45.0 {
+        LaneTurn::RightTurn
+    } else {
+        LaneTurn::NoTurn
+    }
+}
+
+/// Overlap 中与车道相对的另一方
+#[derive(Debug, Clone, Copy)]
+enum Element {
+    Lane(usize, f64, f64),
+    Junction(usize),
+    Crosswalk(usize),
+    Signal(usize),
+    StopSign(usize),
+}
+
+#[derive(Default)]
+struct OverlapBuilder {
+    overlaps: Vec<Overlap>,
+}
+
+impl OverlapBuilder {
+    /// 创建车道与另一元素之间的 Overlap，并写回双方的 overlap_id
+    fn add(
+        &mut self,
+        map: &mut hdmap::Map,
+        lane: usize,
+        start_s: f64,
+        end_s: f64,
+        is_merge: bool,
+        element: Element,
+    ) -> Id {
+        let lane_info = |idx: usize, start_s: f64, end_s: f64| ObjectOverlapInfo {
+            id: map.lane[idx].id.clone(),
+            overlap_info: Some(OverlapInfo::LaneOverlapInfo(LaneOverlapInfo {
+                start_s: Some(start_s),
+                end_s: Some(end_s),
+                is_merge: Some(is_merge),
+                region_overlap_id: None,
+            })),
+        };
+
+        let other = match element {
+            Element::Lane(idx, s0, s1) => lane_info(idx, s0, s1),
+            Element::Junction(idx) => ObjectOverlapInfo {
+                id: map.junction[idx].id.clone(),
+                overlap_info: Some(OverlapInfo::JunctionOverlapInfo(Default::default())),
+            },
+            Element::Crosswalk(idx) => ObjectOverlapInfo {
+                id: map.crosswalk[idx].id.clone(),
+                overlap_info: Some(OverlapInfo::CrosswalkOverlapInfo(Default::default())),
+            },
+            Element::Signal(idx) => ObjectOverlapInfo {
+                id: map.signal[idx].id.clone(),
+                overlap_info: Some(OverlapInfo::SignalOverlapInfo(Default::default())),
+            },
+            Element::StopSign(idx) => ObjectOverlapInfo {
+                id: map.stop_sign[idx].id.clone(),
+                overlap_info: Some(OverlapInfo::StopSignOverlapInfo(Default::default())),
+            },
+        };
+
+        let overlap_id = Id {
+            id: Some(format!(
+                "overlap_{}_{}",
+                id_str(&map.lane[lane].id),
+                id_str(&other.id)
+            )),
+        };
+        let objects = vec![lane_info(lane, start_s, end_s), other];
+
+        map.lane[lane].overlap_id.push(overlap_id.clone());
+        match element {
+            Element::Lane(idx, _, _) => map.lane[idx].overlap_id.push(overlap_id.clone()),
+            // junction 由调用方在构建完成后写入
+            Element::Junction(_) => {}
+            Element::Crosswalk(idx) => map.crosswalk[idx].overlap_id.push(overlap_id.clone()),
+            Element::Signal(idx) => map.signal[idx].overlap_id.push(overlap_id.clone()),
+            Element::StopSign(idx) => map.stop_sign[idx].overlap_id.push(overlap_id.clone()),
+        }
+
+        self.overlaps.push(Overlap {
+            id: Some(overlap_id.clone()),
+            object: objects,
+            region_overlap: Vec::new(),
+        });
+        overlap_id
+    }
+}
+
+/// 计算路口内车道之间的交叉 Overlap
+///
+/// 共同起点的分流车道不计入；共同终点的汇流车道标记为 is_merge。
+fn add_lane_crossings(
+    map: &mut hdmap::Map,
+    overlaps: &mut OverlapBuilder,
+    lanes: &[usize],
+    centers: &[Vec<Vec3>],
+) {
+    const ENDPOINT_EPS: f64 = 0.5;
+
+    for (i, &a) in lanes.iter().enumerate() {
+        for &b in &lanes[i + 1..] {
+            let Some(&(s_a, s_b)) = polyline::intersections(&centers[a], &centers[b]).first()
+            else {
+                continue;
+            };
+            if s_a < ENDPOINT_EPS && s_b < ENDPOINT_EPS {
+                continue;
+            }
+
+            let len_a = map.lane[a].length.unwrap_or(0.0);
+            let len_b = map.lane[b].length.unwrap_or(0.0);
+            let is_merge = len_a - s_a < ENDPOINT_EPS && len_b - s_b < ENDPOINT_EPS;
+
+            // 交叉区域长度近似为另一车道的宽度
+            let half_width = |idx: usize| {
+                map.lane[idx]
+                    .left_sample
+                    .first()
+                    .and_then(|s| s.width)
+                    .unwrap_or(1.75)
+            };
+            let (hw_a, hw_b) = (half_width(a), half_width(b));
+
+            overlaps.add(
+                map,
+                a,
+                (s_a - hw_b).max(0.0),
+                (s_a + hw_b).min(len_a),
+                is_merge,
+                Element::Lane(b, (s_b - hw_a).max(0.0), (s_b + hw_a).min(len_b)),
+            );
+        }
+    }
+}
+
+/// 中心线位于多边形内部部分的 s 范围
+fn polygon_s_range(center: &[Vec3], polygon: &[Vec3]) -> Option<(f64, f64)> {
+    let mut closed = polygon.to_vec();
+    closed.push(polygon[0]);
+
+    let mut values: Vec<f64> = polyline::intersections(center, &closed)
+        .into_iter()
+        .map(|(s, _)| s)
+        .collect();
+
+    let first = center.first()?;
+    let last = center.last()?;
+    if polyline::point_in_polygon(polygon, first.x, first.y) {
+        values.push(0.0);
+    }
+    if polyline::point_in_polygon(polygon, last.x, last.y) {
+        values.push(polyline::length(center));
+    }
+
+    let min = values.iter().copied().reduce(f64::min)?;
+    let max = values.iter().copied().reduce(f64::max)?;
+    Some((min, max))
+}
+
+/// 信号灯外框：位于信号位置、垂直于道路方向的竖直矩形
+fn signal_boundary(road: &OdrRoad, signal: &OdrSignal) -> Vec<Vec3> {
+    let width = signal.width.unwrap_or(0.3);
+    let height = signal.height.unwrap_or(1.0);
+    let base = road.sth_to_xyz(signal.s, signal.t, signal.z_offset);
+    let hdg = road.eval_reference_line(signal.s).hdg + signal.h_offset.unwrap_or(0.0);
+    let (nx, ny) = (-hdg.sin() * width / 2.0, hdg.cos() * width / 2.0);
+
+    vec![
+        Vec3::new(base.x - nx, base.y - ny, base.z),
+        Vec3::new(base.x + nx, base.y + ny, base.z),
+        Vec3::new(base.x + nx, base.y + ny, base.z + height),
+        Vec3::new(base.x - nx, base.y - ny, base.z + height),
+    ]
+}
+
+fn signal_type_from_odr(signal: &OdrSignal) -> hdmap::signal::Type {
+    signal
+        .name
+        .as_deref()
+        .and_then(hdmap::signal::Type::from_str_name)
+        .unwrap_or(if signal.signal_type == "1000001" {
+            hdmap::signal::Type::Mix3Vertical
+        } else {
+            hdmap::signal::Type::Unknown
+        })
+}
+
+fn is_stop_sign(signal: &OdrSignal) -> bool {
+    matches!(signal.signal_type.as_str(), "206" | "R1-1")
+}
+
+/// 信号控制的停止线
+///
+/// 优先使用同一道路上、信号前方 50 米内的 stopLine 路面标记，
+/// 否则在信号所在 s 处横跨受控一侧的全部车道生成停止线。
+fn stop_line_for(road: &OdrRoad, signal: &OdrSignal) -> Vec<Vec3> {
+    const SEARCH_DISTANCE: f64 = 50.0;
+    let positive = !matches!(signal.orientation, OdrOrientation::Negative);
+    let negative = !matches!(signal.orientation, OdrOrientation::Positive);
+
+    let existing = road
+        .objects
+        .iter()
+        .filter(|o| {
+            o.subtype.as_deref() == Some("stopLine") || o.name.as_deref() == Some("stopLine")
+        })
+        .filter(|o| (o.s - signal.s).abs() <= SEARCH_DISTANCE)
+        .filter(|o| (o.t <= 0.0 && positive) || (o.t >= 0.0 && negative))
+        .min_by(|a, b| (a.s - signal.s).abs().total_cmp(&(b.s - signal.s).abs()));
+
+    if let Some(points) = existing
+        .and_then(|o| o.outline_points(road).into_iter().next())
+        .filter(|p| p.len() >= 2)
+    {
+        return points;
+    }
+
+    let s = signal.s.clamp(0.0, road.length);
+    let Some(section_idx) = road.lanes.iter().rposition(|sec| sec.s <= s + 1e-9) else {
+        return Vec::new();
+    };
+    let section = &road.lanes[section_idx];
+    let outer = |lanes: &[OdrLane]| {
+        lanes
+            .iter()
+            .max_by_key(|l| l.id.abs())
+            .map(|l| road.lane_t_bounds(section, l, s).1)
+    };
+
+    let offset = road.eval_lane_offset(s);
+    let t_min = if positive {
+        outer(&section.right).unwrap_or(offset)
+    } else {
+        offset
+    };
+    let t_max = if negative {
+        outer(&section.left).unwrap_or(offset)
+    } else {
+        offset
+    };
+    if t_max - t_min < 1e-6 {
+        return Vec::new();
+    }
+
+    vec![
+        road.sth_to_xyz(s, t_min, 0.0),
+        road.sth_to_xyz(s, t_max, 0.0),
+    ]
+}
+
+fn build_header(odr: &OpenDrive) -> hdmap::Header {
+    let header = &odr.header;
+    let bytes = |s: &Option<String>| s.as_ref().map(|s| s.as_bytes().to_vec());
+
+    hdmap::Header {
+        version: bytes(&header.version),
+        date: bytes(&header.date),
+        projection: header.geo_reference.as_ref().map(|proj| hdmap::Projection {
+            proj: Some(proj.trim().to_string()),
+        }),
+        district: bytes(&header.name),
+        generation: None,
+        rev_major: Some(header.rev_major.to_string().into_bytes()),
+        rev_minor: Some(header.rev_minor.to_string().into_bytes()),
+        left: header.west,
+        top: header.north,
+        right: header.east,
+        bottom: header.south,
+        vendor: bytes(&header.vendor),
+    }
+}
+
+fn boundary_points(lane: &hdmap::Lane) -> Vec<Vec3> {
+    [&lane.left_boundary, &lane.right_boundary]
+        .into_iter()
+        .flatten()
+        .flat_map(|b| b.curve.iter())
+        .flat_map(|c| c.segment.iter())
+        .flat_map(|seg| match &seg.curve_type {
+            Some(hdmap::curve_segment::CurveType::LineSegment(line)) => line.point.as_slice(),
+            None => &[],
+        })
+        .map(|p| Vec3::new(p.x.unwrap_or(0.0), p.y.unwrap_or(0.0), p.z.unwrap_or(0.0)))
+        .collect()
+}
+
+fn id(s: &str) -> Id {
+    Id {
+        id: Some(s.to_string()),
+    }
+}
+
+fn id_str(id: &Option<Id>) -> &str {
+    id.as_ref().and_then(|i| i.id.as_deref()).unwrap_or("")
+}
+
+fn point_enu(p: &Vec3) -> PointEnu {
+    PointEnu {
+        x: Some(p.x),
+        y: Some(p.y),
+        z: Some(p.z),
+    }
+}
+
+fn to_polygon(points: &[Vec3]) -> Polygon {
+    Polygon {
+        point: points.iter().map(point_enu).collect(),
+    }
+}
+
+fn to_curve(points: &[Vec3]) -> Curve {
+    let heading = match points {
+        [a, b, ..] => Some((b.y - a.y).atan2(b.x - a.x)),
+        _ => None,
+    };
+
+    Curve {
+        segment: vec![CurveSegment {
+            curve_type: Some(hdmap::curve_segment::CurveType::LineSegment(LineSegment {
+                point: points.iter().map(point_enu).collect(),
+            })),
+            s: Some(0.0),
+            start_position: points.first().map(point_enu),
+            heading,
+            length: Some(polyline::length(points)),
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::apollo::to_odr::{ApolloToOdrOptions, apollo_to_opendrive};
+    use crate::odr::{
+        models::{
+            enums::{OdrContactPoint, OdrOrientation, OdrSpeedUnit},
+            junction::{
+                OdrJunction, OdrJunctionType, connection::OdrConnection,
+                lane_link::OdrJunctionLaneLink,
+            },
+            object::OdrObject,
+            road::road_link::{OdrRoadLink, OdrRoadLinkElementType},
+        },
+        test_support::{self, header, line_road, road_link},
+    };
+
+    fn lane(id: i32, width: f64, mark: OdrRoadMarkType) -> OdrLane {
+        OdrLane {
+            speed: vec![OdrLaneSpeed::new(0.0, 36.0, Some(OdrSpeedUnit::KMH))],
+            ..test_support::lane(id, width, mark)
+        }
+    }
+
+    fn straight_road(id: &str, x: f64, length: f64, junction: &str) -> OdrRoad {
+        let center = lane(0, 0.0, OdrRoadMarkType::Solid);
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, 3.5, OdrRoadMarkType::Solid)],
+            vec![
+                lane(-1, 3.5, OdrRoadMarkType::Broken),
+                lane(-2, 3.0, OdrRoadMarkType::Solid),
+            ],
+            center,
+            None,
+        );
+        OdrRoad {
+            junction: junction.to_string(),
+            ..line_road(id, x, 0.0, length, vec![section])
+        }
+    }
+
+    fn two_roads() -> OpenDrive {
+        let mut r1 = straight_road("1", 0.0, 50.0, "-1");
+        let mut r2 = straight_road("2", 50.0, 30.0, "-1");
+        r1.successor = road_link("2", OdrContactPoint::Start);
+        r2.predecessor = road_link("1", OdrContactPoint::End);
+        let section = &mut r1.lanes[0];
+        for lane in section.right.iter_mut().chain(section.left.iter_mut()) {
+            lane.link.successor = Some(lane.id);
+        }
+
+        OpenDrive::new(header(), vec![r1, r2], Vec::new())
+    }
+
+    fn find<'a>(map: &'a hdmap::Map, lane_id: &str) -> &'a hdmap::Lane {
+        map.lane.iter().find(|l| id_str(&l.id) == lane_id).unwrap()
+    }
+
+    fn ids(list: &[Id]) -> Vec<&str> {
+        list.iter().map(|i| i.id.as_deref().unwrap_or("")).collect()
+    }
+
+    #[test]
+    fn test_export_lanes() {
+        let map = opendrive_to_apollo(&two_roads(), &OdrToApolloOptions::default()).unwrap();
+        assert_eq!(map.lane.len(), 6);
+        assert_eq!(map.road.len(), 2);
+
+        let lane = find(&map, "1_0_-1");
+        assert!((lane.length.unwrap() - 50.0).abs() < 1e-6);
+        assert!((lane.speed_limit.unwrap() - 10.0).abs() < 1e-9);
+        assert!((lane.left_sample[0].width.unwrap() - 1.75).abs() < 1e-9);
+        assert_eq!(ids(&lane.right_neighbor_forward_lane_id), vec!["1_0_-2"]);
+        assert_eq!(ids(&lane.left_neighbor_reverse_lane_id), vec!["1_0_1"]);
+
+        // 左侧车道沿 -s 方向
+        let left = find(&map, "1_0_1");
+        let points = boundary_points(left);
+        assert!(points[0].x > points[points.len() / 2 - 1].x);
+    }
+
+    #[test]
+    fn test_export_boundary_types() {
+        let map = opendrive_to_apollo(&two_roads(), &OdrToApolloOptions::default()).unwrap();
+        let lane = find(&map, "1_0_-2");
+
+        let left = &lane.left_boundary.as_ref().unwrap().boundary_type[0];
+        let right = &lane.right_boundary.as_ref().unwrap().boundary_type[0];
+        assert_eq!(
+            left.types,
+            vec![lane_boundary_type::Type::DottedWhite as i32]
+        );
+        assert_eq!(
+            right.types,
+            vec![lane_boundary_type::Type::SolidWhite as i32]
+        );
+    }
+
+    #[test]
+    fn test_export_successors() {
+        let map = opendrive_to_apollo(&two_roads(), &OdrToApolloOptions::default()).unwrap();
+
+        assert_eq!(ids(&find(&map, "1_0_-1").successor_id), vec!["2_0_-1"]);
+        assert_eq!(ids(&find(&map, "2_0_-1").predecessor_id), vec!["1_0_-1"]);
+        // 左侧车道车流方向相反
+        assert_eq!(ids(&find(&map, "2_0_1").successor_id), vec!["1_0_1"]);
+    }
+
+    #[test]
+    fn test_export_junction() {
+        let mut odr = two_roads();
+        odr.roads[1].junction = "j1".to_string();
+        odr.roads[0].successor = Some(OdrRoadLink {
+            element_id: "j1".to_string(),
+            element_type: OdrRoadLinkElementType::Junction,
+            contact_point: None,
+            element_dir: None,
+            element_s: None,
+        });
+        let mut junction = OdrJunction::new("j1".to_string(), None, OdrJunctionType::Default);
+        let mut connection = OdrConnection::new(
+            "0".to_string(),
+            Some("1".to_string()),
+            Some("2".to_string()),
+            None,
+            Some("start".to_string()),
+        );
+        connection.add_lane_link(OdrJunctionLaneLink::new(-1, -1, None));
+        junction.add_connection(connection);
+        odr.junctions.push(junction);
+
+        let map = opendrive_to_apollo(&odr, &OdrToApolloOptions::default()).unwrap();
+        assert_eq!(map.junction.len(), 1);
+        assert!(map.junction[0].polygon.as_ref().unwrap().point.len() >= 4);
+        assert_eq!(ids(&find(&map, "1_0_-1").successor_id), vec!["2_0_-1"]);
+        assert_eq!(
+            find(&map, "2_0_-1")
+                .junction_id
+                .as_ref()
+                .unwrap()
+                .id
+                .as_deref(),
+            Some("j1")
+        );
+        // 路口内每条车道都有 junction overlap
+        assert_eq!(map.junction[0].overlap_id.len(), 3);
+    }
+
+    #[test]
+    fn test_export_signal_overlaps() {
+        let mut odr = two_roads();
+        let mut signal = OdrSignal::new(
+            "s1".to_string(),
+            45.0,
+            -8.0,
+            true,
+            OdrOrientation::Positive,
+            "1000001".to_string(),
+            "-1".to_string(),
+        );
+        signal.z_offset = 5.0;
+        odr.roads[0].signals.push(signal);
+
+        let mut crosswalk = OdrObject::new("c1".to_string(), 10.0, 0.0, 0.0);
+        crosswalk.object_type = Some("crosswalk".to_string());
+        crosswalk.length = Some(4.0);
+        crosswalk.width = Some(14.0);
+        odr.roads[1].objects.push(crosswalk);
+
+        let map = opendrive_to_apollo(&odr, &OdrToApolloOptions::default()).unwrap();
+        assert_eq!(map.signal.len(), 1);
+        assert_eq!(map.crosswalk.len(), 1);
+
+        // 信号只控制正向车道
+        assert_eq!(map.signal[0].overlap_id.len(), 2);
+        assert_eq!(map.crosswalk[0].overlap_id.len(), 3);
+
+        let lane = find(&map, "1_0_-1");
+        let overlap = map
+            .overlap
+            .iter()
+            .find(|o| lane.overlap_id.contains(o.id.as_ref().unwrap()))
+            .unwrap();
+        match &overlap.object[0].overlap_info {
+            Some(OverlapInfo::LaneOverlapInfo(info)) => {
+                assert!((info.start_s.unwrap() - 45.0).abs() < 1e-6)
+            }
+            _ => panic!("expected lane overlap info"),
+        }
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let odr = two_roads();
+        let map = opendrive_to_apollo(&odr, &OdrToApolloOptions::default()).unwrap();
+        let decoded = hdmap::Map::decode(map.encode_to_vec().as_slice()).unwrap();
+
+        let back = apollo_to_opendrive(&decoded, &ApolloToOdrOptions::default()).unwrap();
+        assert_eq!(back.roads.len(), 2);
+        let section = &back.roads[0].lanes[0];
+        assert_eq!(section.right.len(), 2);
+        assert_eq!(section.left.len(), 1);
+        assert!((section.right[1].eval_width(25.0) - 3.0).abs() < 1e-3);
+    }
+}
@@ -1,6 +1,11 @@
 use prost::Message;
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use crate::odr::models::opendrive::OpenDrive;
+
+pub mod from_odr;
+pub mod to_odr;
+
 pub mod generated {
     /// Apollo common proto 包（包含基础几何类型）
     pub mod common {
@@ -30,3 +35,30 @@ pub fn parse_apollo_map(buf: &[u8]) -> Result<ApolloMap, String> {
     let map = parse_apollo_map_internal(buf).map_err(|e| e.to_string())?;
     Ok(ApolloMap { map })
 }
+
+#[wasm_bindgen]
+impl ApolloMap {
+    /// 转换为 OpenDRIVE 地图
+    #[wasm_bindgen(js_name = toOpenDrive)]
+    pub fn to_opendrive(&self) -> Result<OpenDrive, String> {
+        to_odr::apollo_to_opendrive(&self.map, &to_odr::ApolloToOdrOptions::default())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[wasm_bindgen]
+impl ApolloMap {
+    /// 由 OpenDRIVE 地图生成 Apollo 地图
+    #[wasm_bindgen(js_name = fromOpenDrive)]
+    pub fn from_opendrive(odr: &OpenDrive) -> Result<ApolloMap, String> {
+        let map = from_odr::opendrive_to_apollo(odr, &from_odr::OdrToApolloOptions::default())
+            .map_err(|e| e.to_string())?;
+        Ok(ApolloMap { map })
+    }
+
+    /// 编码为 Apollo base_map.bin 格式
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.map.encode_to_vec()
+    }
+}
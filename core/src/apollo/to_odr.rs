@@ -0,0 +1,1335 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, anyhow};
+
+use crate::apollo::generated::{
+    common::PointEnu,
+    hdmap::{self, lane::LaneType, lane_boundary_type},
+};
+use crate::math::{fitting::fit_cubic_segments, polyline, vec3::Vec3};
+use crate::odr::models::{
+    enums::{OdrContactPoint, OdrOrientation, OdrRoadMarkColor, OdrSpeedUnit},
+    header::OdrHeader,
+    junction::{
+        OdrJunction, OdrJunctionType, connection::OdrConnection, lane_link::OdrJunctionLaneLink,
+    },
+    lane::{
+        OdrLane,
+        lane_geometry::OdrLaneWidth,
+        lane_link::OdrLaneLink,
+        lane_road_mark::{OdrRoadMark, OdrRoadMarkType},
+        lane_section::OdrLaneSection,
+        lane_speed::OdrLaneSpeed,
+    },
+    object::{OdrCornerRoad, OdrObject, OdrOutline},
+    opendrive::OpenDrive,
+    road::{
+        OdrRoad,
+        road_elevation::OdrRoadElevation,
+        road_geometry::OdrRoadGeometry,
+        road_link::{OdrRoadLink, OdrRoadLinkElementType},
+        road_type::OdrRoadType,
+    },
+    signal::OdrSignal,
+};
+
+/// Apollo → OpenDRIVE 转换选项
+#[derive(Debug, Clone, Copy)]
+pub struct ApolloToOdrOptions {
+    /// 参考线几何拟合允许的最大横向误差（米）
+    pub geometry_tolerance: f64,
+    /// 车道宽度和高程多项式拟合允许的最大误差（米）
+    pub profile_tolerance: f64,
+    /// 沿参考线采样车道宽度的步长（米）
+    pub sample_step: f64,
+}
+
+impl Default for ApolloToOdrOptions {
+    fn default() -> Self {
+        Self {
+            geometry_tolerance: 0.05,
+            profile_tolerance: 0.02,
+            sample_step: 1.0,
+        }
+    }
+}
+
+/// 一组并行车道，对应一条 OpenDRIVE 道路
+///
+/// 参考线取最左侧正向车道的左边界，正向车道位于参考线右侧（负 ID），
+/// 反向车道位于参考线左侧（正 ID）。
+struct LaneGroup<'a> {
+    road_id: String,
+    junction_id: Option<String>,
+    road_type: Option<hdmap::road::Type>,
+    /// 参考线左侧（反向）车道，由内向外
+    left: Vec<&'a hdmap::Lane>,
+    /// 参考线右侧（正向）车道，由内向外
+    right: Vec<&'a hdmap::Lane>,
+}
+
+/// 车道在转换结果中的位置
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LaneRef {
+    road: usize,
+    lane_id: i32,
+}
+
+impl LaneRef {
+    /// 车道行驶方向是否与道路参考线方向一致
+    fn is_forward(&self) -> bool {
+        self.lane_id < 0
+    }
+}
+
+/// 将 Apollo 高精地图转换为 OpenDRIVE 地图
+///
+/// 每个 Apollo RoadSection（没有所属道路的车道按相邻关系分组）转换为一条
+/// OpenDRIVE 道路：参考线由 line/arc/paramPoly3 拟合，车道宽度由边界距离
+/// 拟合为三次多项式，车道前驱/后继关系转换为道路连接和 junction connection，
+/// 信号灯和停车标志转换为 signal，停止线转换为 object。
+pub fn apollo_to_opendrive(map: &hdmap::Map, options: &ApolloToOdrOptions) -> Result<OpenDrive> {
+    if options.sample_step <= 0.0 {
+        return Err(anyhow!("sample_step 必须为正数"));
+    }
+
+    let junction_polygons: Vec<(String, Vec<Vec3>)> = map
+        .junction
+        .iter()
+        .map(|j| (id_of(&j.id).to_string(), polygon_points(&j.polygon)))
+        .collect();
+
+    let groups = build_lane_groups(map, &junction_polygons);
+
+    // 构建道路几何与车道
+    let mut roads = Vec::new();
+    let mut road_groups = Vec::new();
+    for group in &groups {
+        if let Some(road) = build_road(group, options) {
+            roads.push(road);
+            road_groups.push(group);
+        }
+    }
+
+    // 建立 Apollo 车道到 OpenDRIVE 车道的索引
+    let mut lane_index: HashMap<&str, LaneRef> = HashMap::new();
+    for (road_idx, group) in road_groups.iter().enumerate() {
+        for (i, lane) in group.right.iter().enumerate() {
+            lane_index.insert(
+                id_of(&lane.id),
+                LaneRef {
+                    road: road_idx,
+                    lane_id: -(i as i32 + 1),
+                },
+            );
+        }
+        for (i, lane) in group.left.iter().enumerate() {
+            lane_index.insert(
+                id_of(&lane.id),
+                LaneRef {
+                    road: road_idx,
+                    lane_id: i as i32 + 1,
+                },
+            );
+        }
+    }
+
+    let mut junctions = build_junctions(map, &road_groups);
+    link_roads(&mut roads, &road_groups, &lane_index, &mut junctions);
+
+    attach_signals(map, &mut roads);
+
+    Ok(OpenDrive::new(build_header(map), roads, junctions))
+}
+
+/// 将 Apollo 车道划分为并行车道组
+fn build_lane_groups<'a>(
+    map: &'a hdmap::Map,
+    junction_polygons: &[(String, Vec<Vec3>)],
+) -> Vec<LaneGroup<'a>> {
+    let lanes_by_id: HashMap<&str, &hdmap::Lane> =
+        map.lane.iter().map(|l| (id_of(&l.id), l)).collect();
+
+    let mut groups = Vec::new();
+    let mut assigned: HashSet<&str> = HashSet::new();
+
+    for road in &map.road {
+        let road_id = id_of(&road.id);
+        let mut road_groups = Vec::new();
+
+        for section in &road.section {
+            let lanes: Vec<&hdmap::Lane> = section
+                .lane_id
+                .iter()
+                .filter_map(|id| lanes_by_id.get(id.id.as_deref().unwrap_or("")).copied())
+                .filter(|l| !assigned.contains(id_of(&l.id)))
+                .collect();
+
+            for (left, right) in split_parallel_lanes(lanes) {
+                for lane in left.iter().chain(right.iter()) {
+                    assigned.insert(id_of(&lane.id));
+                }
+                road_groups.push((left, right));
+            }
+        }
+
+        let single = road_groups.len() == 1;
+        for (i, (left, right)) in road_groups.into_iter().enumerate() {
+            let junction_id = road
+                .junction_id
+                .as_ref()
+                .and_then(|id| id.id.clone())
+                .or_else(|| lanes_junction(&left, &right, junction_polygons));
+
+            groups.push(LaneGroup {
+                road_id: if single {
+                    road_id.to_string()
+                } else {
+                    format!("{}_{}", road_id, i)
+                },
+                junction_id,
+                road_type: hdmap::road::Type::try_from(road.r#type.unwrap_or(0)).ok(),
+                left,
+                right,
+            });
+        }
+    }
+
+    // 不属于任何 Road 的车道按相邻关系分组
+    let orphans: Vec<&hdmap::Lane> = map
+        .lane
+        .iter()
+        .filter(|l| !assigned.contains(id_of(&l.id)))
+        .collect();
+
+    for (left, right) in split_parallel_lanes(orphans) {
+        let seed = right.first().or(left.first()).copied();
+        let Some(seed) = seed else { continue };
+
+        groups.push(LaneGroup {
+            road_id: format!("lane_{}", id_of(&seed.id)),
+            junction_id: lanes_junction(&left, &right, junction_polygons),
+            road_type: None,
+            left,
+            right,
+        });
+    }
+
+    groups
+}
+
+/// 按相邻车道关系拆分为若干组 (左侧反向车道, 右侧正向车道)
+fn split_parallel_lanes(lanes: Vec<&hdmap::Lane>) -> Vec<(Vec<&hdmap::Lane>, Vec<&hdmap::Lane>)> {
+    let mut remaining = lanes;
+    let mut result = Vec::new();
+
+    while !remaining.is_empty() {
+        let ids: HashSet<&str> = remaining.iter().map(|l| id_of(&l.id)).collect();
+        let find = |id: &str| remaining.iter().find(|l| id_of(&l.id) == id).copied();
+
+        // 同向相邻车道链的长度（含自身）
+        let chain_len = |lane: &hdmap::Lane| {
+            let mut len = 1;
+            let mut cur = lane;
+            while let Some(next) = cur
+                .right_neighbor_forward_lane_id
+                .iter()
+                .filter_map(|id| id.id.as_deref())
+                .find_map(find)
+                && len < remaining.len()
+            {
+                len += 1;
+                cur = next;
+            }
+            len
+        };
+
+        // 最左侧正向车道：没有位于同组内的左侧同向相邻车道。
+        // 双向道路两侧都满足条件，取同向车道更多的一侧，便于与 OpenDRIVE 的右侧车道对应
+        let seed = remaining
+            .iter()
+            .filter(|l| {
+                !l.left_neighbor_forward_lane_id
+                    .iter()
+                    .any(|id| ids.contains(id.id.as_deref().unwrap_or("")))
+            })
+            .min_by_key(|l| std::cmp::Reverse(chain_len(l)))
+            .copied()
+            .unwrap_or(remaining[0]);
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        visited.insert(id_of(&seed.id));
+
+        // 向外遍历的下一个车道（对反向车道而言，其右侧即远离参考线的一侧）
+        let next_outer = |lane: &hdmap::Lane, visited: &HashSet<&str>| {
+            lane.right_neighbor_forward_lane_id
+                .iter()
+                .filter_map(|id| id.id.as_deref())
+                .find(|id| !visited.contains(id))
+                .and_then(find)
+        };
+
+        let mut right = vec![seed];
+        while let Some(next) = next_outer(right[right.len() - 1], &visited) {
+            visited.insert(id_of(&next.id));
+            right.push(next);
+        }
+
+        let mut left = Vec::new();
+        let first_reverse = seed
+            .left_neighbor_reverse_lane_id
+            .iter()
+            .filter_map(|id| id.id.as_deref())
+            .find(|id| !visited.contains(id))
+            .and_then(find);
+        if let Some(first) = first_reverse {
+            visited.insert(id_of(&first.id));
+            left.push(first);
+            while let Some(next) = next_outer(left[left.len() - 1], &visited) {
+                visited.insert(id_of(&next.id));
+                left.push(next);
+            }
+        }
+
+        remaining.retain(|l| !visited.contains(id_of(&l.id)));
+        result.push((left, right));
+    }
+
+    result
+}
+
+/// 根据车道的 junction_id 或中心线中点是否位于 junction 多边形内确定所属 junction
+fn lanes_junction(
+    left: &[&hdmap::Lane],
+    right: &[&hdmap::Lane],
+    junction_polygons: &[(String, Vec<Vec3>)],
+) -> Option<String> {
+    let lanes = || left.iter().chain(right.iter());
+
+    if let Some(id) = lanes().find_map(|l| l.junction_id.as_ref().and_then(|id| id.id.clone())) {
+        return Some(id);
+    }
+
+    lanes().find_map(|lane| {
+        let points = curve_points(&lane.central_curve);
+        let lengths = polyline::cumulative_lengths(&points);
+        let mid = polyline::interpolate(&points, &lengths, lengths.last()? / 2.0);
+        junction_polygons
+            .iter()
+            .find(|(_, polygon)| polyline::point_in_polygon(polygon, mid.x, mid.y))
+            .map(|(id, _)| id.clone())
+    })
+}
+
+/// 构建单条道路的几何、高程和车道
+fn build_road(group: &LaneGroup, options: &ApolloToOdrOptions) -> Option<OdrRoad> {
+    let seed = group.right.first().or(group.left.first())?;
+    let reference = reference_points(seed);
+    if reference.len() < 2 {
+        return None;
+    }
+
+    let plan_view = OdrRoadGeometry::fit_polyline(&reference, options.geometry_tolerance);
+    let length: f64 = plan_view.iter().map(|g| g.length).sum();
+    if plan_view.is_empty() || length <= 0.0 {
+        return None;
+    }
+
+    // 高程：参考线顶点的 z 按弧长比例映射到道路 s
+    let ref_lengths = polyline::cumulative_lengths(&reference);
+    let scale = length / ref_lengths.last().copied().unwrap_or(length).max(1e-9);
+    let elevations = if reference.iter().any(|p| p.z.abs() > 1e-9) {
+        let samples: Vec<(f64, f64)> = dedup_samples(
+            reference
+                .iter()
+                .zip(&ref_lengths)
+                .map(|(p, l)| (l * scale, p.z))
+                .collect(),
+        );
+        fit_cubic_segments(&samples, options.profile_tolerance)
+            .into_iter()
+            .map(|seg| OdrRoadElevation::new(seg.s, seg.a, seg.b, seg.c, seg.d))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut road = OdrRoad::new(
+        group.road_id.clone(),
+        length,
+        group
+            .junction_id
+            .clone()
+            .unwrap_or_else(|| "-1".to_string()),
+        None,
+        None,
+        group
+            .road_type
+            .map(|t| vec![OdrRoadType::new(0.0, road_type_name(t).to_string(), None)]),
+        Some(plan_view),
+        Some(elevations),
+        None,
+        None,
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    );
+
+    // 沿参考线采样 s
+    let num_samples = ((length / options.sample_step).ceil() as usize).max(1) + 1;
+    let sample_s: Vec<f64> = (0..num_samples)
+        .map(|i| length * i as f64 / (num_samples - 1) as f64)
+        .collect();
+
+    let right = build_side_lanes(&road, &group.right, &sample_s, -1, options);
+    let mut left = build_side_lanes(&road, &group.left, &sample_s, 1, options);
+    // 与 XML 中的顺序一致：左侧车道由外向内排列
+    left.reverse();
+
+    let mut center = new_lane(0, "none".to_string(), Vec::new(), Vec::new(), Vec::new());
+    if let Some(boundary) = &seed.left_boundary {
+        center.road_marks = road_marks_from_boundary(boundary, length, false);
+    }
+
+    road.lanes = vec![OdrLaneSection::new(0.0, left, right, center, None)];
+    Some(road)
+}
+
+/// 构建参考线一侧的车道
+///
+/// # 参数
+/// - `sign`: -1 表示右侧（正向）车道，1 表示左侧（反向）车道
+fn build_side_lanes(
+    road: &OdrRoad,
+    lanes: &[&hdmap::Lane],
+    sample_s: &[f64],
+    sign: i32,
+    options: &ApolloToOdrOptions,
+) -> Vec<OdrLane> {
+    let reversed = sign > 0;
+    let outer_boundaries: Vec<Vec<Vec3>> = lanes
+        .iter()
+        .map(|l| {
+            l.right_boundary
+                .as_ref()
+                .map(|b| curve_points(&b.curve))
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // 每个采样点处各车道外边界的 t 坐标
+    let boundary_t: Vec<Vec<Option<f64>>> = sample_s
+        .iter()
+        .map(|&s| {
+            let pos = road.eval_reference_line(s);
+            let (nx, ny) = (-pos.hdg.sin(), pos.hdg.cos());
+            outer_boundaries
+                .iter()
+                .map(|points| boundary_offset(points, pos.x, pos.y, nx, ny))
+                .collect()
+        })
+        .collect();
+
+    lanes
+        .iter()
+        .enumerate()
+        .map(|(i, lane)| {
+            let width_samples: Vec<(f64, f64)> = sample_s
+                .iter()
+                .zip(&boundary_t)
+                .map(|(&s, ts)| {
+                    let inner = if i == 0 { Some(0.0) } else { ts[i - 1] };
+                    let width = match (inner, ts[i]) {
+                        (Some(inner), Some(outer)) => (outer - inner).abs(),
+                        _ => lane_sample_width(lane),
+                    };
+                    (s, width)
+                })
+                .collect();
+
+            let widths = fit_cubic_segments(&width_samples, options.profile_tolerance)
+                .into_iter()
+                .map(|seg| OdrLaneWidth::new(seg.s, seg.a, seg.b, seg.c, seg.d))
+                .collect();
+
+            let road_marks = lane
+                .right_boundary
+                .as_ref()
+                .map(|b| road_marks_from_boundary(b, road.length, reversed))
+                .unwrap_or_default();
+
+            let speed = lane
+                .speed_limit
+                .filter(|v| *v > 0.0)
+                .map(|v| vec![OdrLaneSpeed::new(0.0, v, Some(OdrSpeedUnit::MPS))])
+                .unwrap_or_default();
+
+            new_lane(
+                sign * (i as i32 + 1),
+                lane_type_name(lane.r#type()).to_string(),
+                widths,
+                speed,
+                road_marks,
+            )
+        })
+        .collect()
+}
+
+/// 计算边界折线相对于参考线点的横向偏移
+///
+/// 优先使用法线与边界的交点，无交点时退化为最近点在法线上的投影。
+fn boundary_offset(points: &[Vec3], x: f64, y: f64, nx: f64, ny: f64) -> Option<f64> {
+    if let Some((t, _)) = polyline::intersect_line(points, x, y, nx, ny) {
+        return Some(t);
+    }
+    polyline::closest_point(points, x, y).map(|(q, _, _)| (q.x - x) * nx + (q.y - y) * ny)
+}
+
+/// 无法由边界计算宽度时使用 Apollo 的采样宽度
+fn lane_sample_width(lane: &hdmap::Lane) -> f64 {
+    let left = lane.left_sample.first().and_then(|s| s.width);
+    let right = lane.right_sample.first().and_then(|s| s.width);
+    match (left, right) {
+        (Some(l), Some(r)) => l + r,
+        _ => 3.5,
+    }
+}
+
+/// 参考线：最左侧正向车道的左边界，缺失时由中心线向左偏移半个车道宽度
+fn reference_points(seed: &hdmap::Lane) -> Vec<Vec3> {
+    let boundary = seed
+        .left_boundary
+        .as_ref()
+        .map(|b| curve_points(&b.curve))
+        .unwrap_or_default();
+    if boundary.len() >= 2 {
+        return boundary;
+    }
+
+    let center = curve_points(&seed.central_curve);
+    let half_width = seed
+        .left_sample
+        .first()
+        .and_then(|s| s.width)
+        .unwrap_or(lane_sample_width(seed) / 2.0);
+
+    let n = center.len();
+    (0..n)
+        .map(|i| {
+            let a = center[i.saturating_sub(1)];
+            let b = center[(i + 1).min(n - 1)];
+            let hdg = (b.y - a.y).atan2(b.x - a.x);
+            let p = center[i];
+            Vec3::new(
+                p.x - half_width * hdg.sin(),
+                p.y + half_width * hdg.cos(),
+                p.z,
+            )
+        })
+        .collect()
+}
+
+/// 将 Apollo 车道边界类型转换为 OpenDRIVE 道路标线
+///
+/// # 参数
+/// - `length`: 道路长度
+/// - `reversed`: 边界方向与参考线方向相反（反向车道）
+fn road_marks_from_boundary(
+    boundary: &hdmap::LaneBoundary,
+    length: f64,
+    reversed: bool,
+) -> Vec<OdrRoadMark> {
+    let mut entries: Vec<(f64, OdrRoadMarkType, OdrRoadMarkColor)> = boundary
+        .boundary_type
+        .iter()
+        .map(|bt| {
+            let kind = bt
+                .types
+                .iter()
+                .filter_map(|t| lane_boundary_type::Type::try_from(*t).ok())
+                .find(|t| *t != lane_boundary_type::Type::Unknown)
+                .unwrap_or(lane_boundary_type::Type::Unknown);
+            let (mark_type, color) = road_mark_from_boundary_type(kind);
+            (bt.s.unwrap_or(0.0).max(0.0), mark_type, color)
+        })
+        .collect();
+    entries.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    if reversed {
+        // 反向车道的边界 s 从道路终点开始计算，[s_k, s_k+1) 映射为 [L - s_k+1, L - s_k)
+        let ends: Vec<f64> = entries.iter().skip(1).map(|e| e.0).collect();
+        entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, t, c))| {
+                let end = ends.get(i).copied().unwrap_or(length);
+                ((length - end).max(0.0), t, c)
+            })
+            .rev()
+            .collect();
+    }
+
+    entries
+        .into_iter()
+        .map(|(s_offset, mark_type, color)| OdrRoadMark {
+            s_offset,
+            mark_type,
+            color,
+            width: None,
+            height: None,
+            material: None,
+            weight: None,
+            lane_change: None,
+            type_detail: None,
+            explicit: None,
+            sways: Vec::new(),
+        })
+        .collect()
+}
+
+/// Apollo `LaneBoundaryType` 到 OpenDRIVE 标线类型和颜色的映射
+pub fn road_mark_from_boundary_type(
+    kind: lane_boundary_type::Type,
+) -> (OdrRoadMarkType, OdrRoadMarkColor) {
+    use lane_boundary_type::Type;
+    match kind {
+        Type::DottedYellow => (OdrRoadMarkType::Broken, OdrRoadMarkColor::Yellow),
+        Type::DottedWhite => (OdrRoadMarkType::Broken, OdrRoadMarkColor::White),
+        Type::SolidYellow => (OdrRoadMarkType::Solid, OdrRoadMarkColor::Yellow),
+        Type::SolidWhite => (OdrRoadMarkType::Solid, OdrRoadMarkColor::White),
+        Type::DoubleYellow => (OdrRoadMarkType::SolidSolid, OdrRoadMarkColor::Yellow),
+        Type::Curb => (OdrRoadMarkType::Curb, OdrRoadMarkColor::Standard),
+        Type::Unknown => (OdrRoadMarkType::None, OdrRoadMarkColor::Standard),
+    }
+}
+
+fn lane_type_name(lane_type: LaneType) -> &'static str {
+    match lane_type {
+        LaneType::None => "none",
+        LaneType::CityDriving => "driving",
+        LaneType::Biking => "biking",
+        LaneType::Sidewalk => "sidewalk",
+        LaneType::Parking => "parking",
+        LaneType::Shoulder => "shoulder",
+        LaneType::Shared => "shared",
+    }
+}
+
+fn road_type_name(road_type: hdmap::road::Type) -> &'static str {
+    match road_type {
+        hdmap::road::Type::Unknown => "unknown",
+        hdmap::road::Type::Highway => "motorway",
+        hdmap::road::Type::CityRoad => "town",
+        hdmap::road::Type::Park => "lowSpeed",
+    }
+}
+
+/// 创建 Junction，包含 Apollo 中定义的和道路引用的所有 junction
+fn build_junctions(map: &hdmap::Map, groups: &[&LaneGroup]) -> Vec<OdrJunction> {
+    let mut ids: Vec<String> = map
+        .junction
+        .iter()
+        .map(|j| id_of(&j.id).to_string())
+        .collect();
+    for group in groups {
+        if let Some(id) = &group.junction_id
+            && !ids.contains(id)
+        {
+            ids.push(id.clone());
+        }
+    }
+
+    ids.into_iter()
+        .map(|id| OdrJunction::new(id, None, OdrJunctionType::Default))
+        .collect()
+}
+
+/// 根据车道前驱/后继关系建立道路连接、车道连接和 junction connection
+fn link_roads(
+    roads: &mut [OdrRoad],
+    groups: &[&LaneGroup],
+    lane_index: &HashMap<&str, LaneRef>,
+    junctions: &mut [OdrJunction],
+) {
+    // (道路, 车道, 是否位于道路终点一侧, 目标车道)
+    let mut links: Vec<(usize, i32, bool, LaneRef)> = Vec::new();
+
+    // 车流方向 (上游车道, 下游车道)，同时收集前驱和后继，避免只有单侧声明时丢失连接
+    let mut flows: Vec<(LaneRef, LaneRef)> = Vec::new();
+    for group in groups {
+        for lane in group.left.iter().chain(group.right.iter()) {
+            let Some(own) = lane_index.get(id_of(&lane.id)) else {
+                continue;
+            };
+            let pairs = lane
+                .successor_id
+                .iter()
+                .filter_map(|id| lane_index.get(id.id.as_deref().unwrap_or("")))
+                .map(|succ| (*own, *succ))
+                .chain(
+                    lane.predecessor_id
+                        .iter()
+                        .filter_map(|id| lane_index.get(id.id.as_deref().unwrap_or("")))
+                        .map(|pred| (*pred, *own)),
+                );
+            for (from, to) in pairs {
+                if !flows.contains(&(from, to)) {
+                    flows.push((from, to));
+                }
+            }
+        }
+    }
+
+    for (from, to) in flows {
+        // 正向车道的后继位于道路终点、前驱位于起点；反向车道相反
+        links.push((from.road, from.lane_id, from.is_forward(), to));
+        links.push((to.road, to.lane_id, !to.is_forward(), from));
+    }
+
+    let junction_of = |road: usize| groups[road].junction_id.clone();
+
+    for (road_idx, road) in roads.iter_mut().enumerate() {
+        for at_end in [true, false] {
+            let side: Vec<&(usize, i32, bool, LaneRef)> = links
+                .iter()
+                .filter(|(r, _, e, _)| *r == road_idx && *e == at_end)
+                .collect();
+            let Some(first) = side.first() else { continue };
+            let target_road = first.3.road;
+
+            let link = match (junction_of(road_idx), junction_of(target_road)) {
+                (None, Some(junction_id)) => OdrRoadLink {
+                    element_id: junction_id,
+                    element_type: OdrRoadLinkElementType::Junction,
+                    contact_point: None,
+                    element_dir: None,
+                    element_s: None,
+                },
+                _ => {
+                    // 行驶方向一致时，终点连接目标起点、起点连接目标终点
+                    let own_forward = first.1 < 0;
+                    let same_dir = own_forward == first.3.is_forward();
+                    let contact = if same_dir == at_end {
+                        OdrContactPoint::Start
+                    } else {
+                        OdrContactPoint::End
+                    };
+
+                    for (_, lane_id, _, target) in side.iter().filter(|l| l.3.road == target_road) {
+                        if let Some(lane) = find_lane_mut(road, *lane_id) {
+                            if at_end {
+                                lane.link.successor = Some(target.lane_id);
+                            } else {
+                                lane.link.predecessor = Some(target.lane_id);
+                            }
+                        }
+                    }
+
+                    OdrRoadLink {
+                        element_id: groups[target_road].road_id.clone(),
+                        element_type: OdrRoadLinkElementType::Road,
+                        contact_point: Some(contact),
+                        element_dir: None,
+                        element_s: None,
+                    }
+                }
+            };
+
+            if at_end {
+                road.successor = Some(link);
+            } else {
+                road.predecessor = Some(link);
+            }
+        }
+    }
+
+    // junction connection：车流从 incoming road 进入 connecting road
+    for junction in junctions.iter_mut() {
+        let mut connections: Vec<(String, String, &'static str, Vec<OdrJunctionLaneLink>)> =
+            Vec::new();
+
+        for (road_idx, group) in groups.iter().enumerate() {
+            if group.junction_id.as_deref() != Some(junction.id.as_str()) {
+                continue;
+            }
+
+            for (r, lane_id, at_end, target) in &links {
+                // 驶入车道：正向车道的前驱位于起点，反向车道的前驱位于终点
+                let incoming_side = (*lane_id < 0 && !at_end) || (*lane_id > 0 && *at_end);
+                if *r != road_idx || !incoming_side || junction_of(target.road).is_some() {
+                    continue;
+                }
+
+                let incoming = groups[target.road].road_id.clone();
+                let contact = if *at_end { "end" } else { "start" };
+                let lane_link = OdrJunctionLaneLink::new(target.lane_id, *lane_id, None);
+
+                match connections.iter_mut().find(|(inc, conn, cp, _)| {
+                    *inc == incoming && *conn == group.road_id && *cp == contact
+                }) {
+                    Some((_, _, _, lane_links)) => lane_links.push(lane_link),
+                    None => connections.push((
+                        incoming,
+                        group.road_id.clone(),
+                        contact,
+                        vec![lane_link],
+                    )),
+                }
+            }
+        }
+
+        for (i, (incoming, connecting, contact, lane_links)) in connections.into_iter().enumerate()
+        {
+            let mut connection = OdrConnection::new(
+                i.to_string(),
+                Some(incoming),
+                Some(connecting),
+                None,
+                Some(contact.to_string()),
+            );
+            for lane_link in lane_links {
+                connection.add_lane_link(lane_link);
+            }
+            junction.add_connection(connection);
+        }
+    }
+}
+
+fn find_lane_mut(road: &mut OdrRoad, lane_id: i32) -> Option<&mut OdrLane> {
+    let section = road.lanes.first_mut()?;
+    section
+        .left
+        .iter_mut()
+        .chain(section.right.iter_mut())
+        .find(|l| l.id == lane_id)
+}
+
+/// 将信号灯和停车标志转换为 signal，停止线转换为 object
+fn attach_signals(map: &hdmap::Map, roads: &mut [OdrRoad]) {
+    for signal in &map.signal {
+        let id = id_of(&signal.id).to_string();
+        let boundary = polygon_points(&signal.boundary);
+        let stop_lines: Vec<Vec<Vec3>> = signal
+            .stop_line
+            .iter()
+            .map(|c| curve_points(&Some(c.clone())))
+            .collect();
+
+        let position = centroid(&boundary).or_else(|| stop_lines.first().and_then(|l| midpoint(l)));
+        let Some(position) = position else { continue };
+        let Some((road_idx, s, t, h)) = locate(roads, position) else {
+            continue;
+        };
+
+        let mut odr_signal = OdrSignal::new(
+            id.clone(),
+            s,
+            t,
+            true,
+            signal_orientation(roads, road_idx, t, &stop_lines),
+            "1000001".to_string(),
+            "-1".to_string(),
+        );
+        odr_signal.name = Some(signal.r#type().as_str_name().to_string());
+        odr_signal.country = Some("OpenDRIVE".to_string());
+        odr_signal.z_offset = h;
+        if !boundary.is_empty() {
+            let (min_z, max_z) = boundary.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.z), hi.max(p.z))
+            });
+            odr_signal.height = Some(max_z - min_z);
+            odr_signal.width = Some(horizontal_extent(&boundary));
+            odr_signal.z_offset = h - (position.z - min_z);
+        }
+        roads[road_idx].signals.push(odr_signal);
+
+        attach_stop_lines(roads, &id, &stop_lines);
+    }
+
+    for stop_sign in &map.stop_sign {
+        let id = id_of(&stop_sign.id).to_string();
+        let stop_lines: Vec<Vec<Vec3>> = stop_sign
+            .stop_line
+            .iter()
+            .map(|c| curve_points(&Some(c.clone())))
+            .collect();
+
+        let Some(mid) = stop_lines.first().and_then(|l| midpoint(l)) else {
+            continue;
+        };
+        let Some((road_idx, _, mid_t, _)) = locate(roads, mid) else {
+            continue;
+        };
+
+        // 标志位于停止线的道路外侧端点
+        let road = &roads[road_idx];
+        let ends: Vec<Vec3> = stop_lines[0].iter().flat_map(|p| [*p]).collect();
+        let edge = ends
+            .iter()
+            .map(|p| road.xyz_to_sth(p.x, p.y, p.z))
+            .max_by(|a, b| {
+                let key = |v: &Vec3| if mid_t <= 0.0 { -v.y } else { v.y };
+                key(a).total_cmp(&key(b))
+            });
+        let Some(edge) = edge else { continue };
+
+        let orientation = if mid_t <= 0.0 {
+            OdrOrientation::Positive
+        } else {
+            OdrOrientation::Negative
+        };
+        let mut odr_signal = OdrSignal::new(
+            id.clone(),
+            edge.x,
+            edge.y,
+            false,
+            orientation,
+            "206".to_string(),
+            "-1".to_string(),
+        );
+        odr_signal.name = Some("STOP".to_string());
+        odr_signal.country = Some("DE".to_string());
+        roads[road_idx].signals.push(odr_signal);
+
+        attach_stop_lines(roads, &id, &stop_lines);
+    }
+}
+
+/// 将停止线转换为 roadMark 类型的 object（开放轮廓）
+fn attach_stop_lines(roads: &mut [OdrRoad], owner_id: &str, stop_lines: &[Vec<Vec3>]) {
+    for (i, line) in stop_lines.iter().enumerate() {
+        let Some(mid) = midpoint(line) else { continue };
+        let Some((road_idx, s, t, _)) = locate(roads, mid) else {
+            continue;
+        };
+        let road = &roads[road_idx];
+
+        let mut outline = OdrOutline::new();
+        outline.id = Some(0);
+        outline.closed = Some(false);
+        for (corner_id, p) in line.iter().enumerate() {
+            let sth = road.xyz_to_sth(p.x, p.y, p.z);
+            let mut corner = OdrCornerRoad::new(sth.x, sth.y, 0.0, 0.0);
+            corner.id = Some(corner_id as u32);
+            outline.corner_road.push(corner);
+        }
+
+        let mut object = OdrObject::new(format!("{}_stopline_{}", owner_id, i), s, t, 0.0);
+        object.name = Some("stopLine".to_string());
+        object.object_type = Some("roadMark".to_string());
+        object.subtype = Some("stopLine".to_string());
+        object.outlines.push(outline);
+        roads[road_idx].objects.push(object);
+    }
+}
+
+/// 信号朝向：控制参考线右侧（正向）车道的信号为 "+"，否则为 "-"
+fn signal_orientation(
+    roads: &[OdrRoad],
+    road_idx: usize,
+    signal_t: f64,
+    stop_lines: &[Vec<Vec3>],
+) -> OdrOrientation {
+    let t = stop_lines
+        .first()
+        .and_then(|l| midpoint(l))
+        .map(|mid| roads[road_idx].xyz_to_sth(mid.x, mid.y, mid.z).y)
+        .unwrap_or(signal_t);
+
+    if t <= 0.0 {
+        OdrOrientation::Positive
+    } else {
+        OdrOrientation::Negative
+    }
+}
+
+/// 查找距离点最近的道路，返回 (道路索引, s, t, h)
+fn locate(roads: &[OdrRoad], p: Vec3) -> Option<(usize, f64, f64, f64)> {
+    roads
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| !r.plan_view.is_empty())
+        .map(|(i, road)| {
+            let sth = road.xyz_to_sth(p.x, p.y, p.z);
+            let q = road.sth_to_xyz(sth.x, sth.y, 0.0);
+            let dist = ((q.x - p.x).powi(2) + (q.y - p.y).powi(2)).sqrt();
+            (i, sth, dist)
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(i, sth, _)| (i, sth.x, sth.y, sth.z))
+}
+
+fn build_header(map: &hdmap::Map) -> OdrHeader {
+    let text = |bytes: &Option<Vec<u8>>| {
+        bytes
+            .as_ref()
+            .map(|b| String::from_utf8_lossy(b).to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    match &map.header {
+        Some(h) => OdrHeader::new(
+            1,
+            8,
+            text(&h.district),
+            text(&h.version),
+            text(&h.date),
+            h.top,
+            h.bottom,
+            h.right,
+            h.left,
+            text(&h.vendor),
+            h.projection.as_ref().and_then(|p| p.proj.clone()),
+            None,
+        ),
+        None => OdrHeader::new(
+            1, 8, None, None, None, None, None, None, None, None, None, None,
+        ),
+    }
+}
+
+fn new_lane(
+    id: i32,
+    lane_type: String,
+    width: Vec<OdrLaneWidth>,
+    speed: Vec<OdrLaneSpeed>,
+    road_marks: Vec<OdrRoadMark>,
+) -> OdrLane {
+    OdrLane::new(
+        id,
+        lane_type,
+        None,
+        None,
+        OdrLaneLink::new(None, None),
+        width,
+        Vec::new(),
+        Vec::new(),
+        speed,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        road_marks,
+    )
+}
+
+fn id_of(id: &Option<hdmap::Id>) -> &str {
+    id.as_ref().and_then(|i| i.id.as_deref()).unwrap_or("")
+}
+
+fn point_of(p: &PointEnu) -> Vec3 {
+    let finite = |v: Option<f64>| v.filter(|v| v.is_finite()).unwrap_or(0.0);
+    Vec3::new(finite(p.x), finite(p.y), finite(p.z))
+}
+
+/// 拼接 Curve 中所有 LineSegment 的点
+fn curve_points(curve: &Option<hdmap::Curve>) -> Vec<Vec3> {
+    let points = curve
+        .iter()
+        .flat_map(|c| c.segment.iter())
+        .filter_map(|seg| {
+            seg.curve_type
+                .as_ref()
+                .map(|hdmap::curve_segment::CurveType::LineSegment(line)| line)
+        })
+        .flat_map(|line| line.point.iter().map(point_of))
+        .collect();
+    polyline::dedup(points, 1e-6)
+}
+
+fn polygon_points(polygon: &Option<hdmap::Polygon>) -> Vec<Vec3> {
+    polygon
+        .iter()
+        .flat_map(|p| p.point.iter().map(point_of))
+        .collect()
+}
+
+fn centroid(points: &[Vec3]) -> Option<Vec3> {
+    if points.is_empty() {
+        return None;
+    }
+    let sum = points.iter().fold(Vec3::default(), |acc, p| acc + *p);
+    Some(sum * (1.0 / points.len() as f64))
+}
+
+fn midpoint(points: &[Vec3]) -> Option<Vec3> {
+    let lengths = polyline::cumulative_lengths(points);
+    let total = *lengths.last()?;
+    Some(polyline::interpolate(points, &lengths, total / 2.0))
+}
+
+fn horizontal_extent(points: &[Vec3]) -> f64 {
+    let mut max = 0.0f64;
+    for a in points {
+        for b in points {
+            max = max.max(((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt());
+        }
+    }
+    max
+}
+
+/// 移除 s 不严格递增的采样点
+fn dedup_samples(samples: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    let mut result: Vec<(f64, f64)> = Vec::with_capacity(samples.len());
+    for sample in samples {
+        if result.last().is_none_or(|last| sample.0 > last.0 + 1e-9) {
+            result.push(sample);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apollo::generated::hdmap::{
+        Curve, CurveSegment, Id, LaneBoundary, LaneBoundaryType, LineSegment,
+    };
+
+    fn id(s: &str) -> Option<Id> {
+        Some(Id {
+            id: Some(s.to_string()),
+        })
+    }
+
+    fn ids(list: &[&str]) -> Vec<Id> {
+        list.iter()
+            .map(|s| Id {
+                id: Some(s.to_string()),
+            })
+            .collect()
+    }
+
+    fn curve(points: &[(f64, f64)]) -> Option<Curve> {
+        Some(Curve {
+            segment: vec![CurveSegment {
+                curve_type: Some(hdmap::curve_segment::CurveType::LineSegment(LineSegment {
+                    point: points
+                        .iter()
+                        .map(|&(x, y)| PointEnu {
+                            x: Some(x),
+                            y: Some(y),
+                            z: Some(0.0),
+                        })
+                        .collect(),
+                })),
+                ..Default::default()
+            }],
+        })
+    }
+
+    fn boundary(points: &[(f64, f64)], kind: lane_boundary_type::Type) -> Option<LaneBoundary> {
+        Some(LaneBoundary {
+            curve: curve(points),
+            boundary_type: vec![LaneBoundaryType {
+                s: Some(0.0),
+                types: vec![kind as i32],
+            }],
+            ..Default::default()
+        })
+    }
+
+    /// 沿 x 轴的直线车道，左右边界 y 坐标分别为 y_left/y_right
+    fn straight_lane(lane_id: &str, x0: f64, x1: f64, y_left: f64, y_right: f64) -> hdmap::Lane {
+        let line = |y: f64| {
+            (0..=10)
+                .map(|i| (x0 + (x1 - x0) * i as f64 / 10.0, y))
+                .collect::<Vec<_>>()
+        };
+        hdmap::Lane {
+            id: id(lane_id),
+            central_curve: curve(&line((y_left + y_right) / 2.0)),
+            left_boundary: boundary(&line(y_left), lane_boundary_type::Type::SolidYellow),
+            right_boundary: boundary(&line(y_right), lane_boundary_type::Type::DottedWhite),
+            speed_limit: Some(10.0),
+            r#type: Some(LaneType::CityDriving as i32),
+            ..Default::default()
+        }
+    }
+
+    fn two_lane_map() -> hdmap::Map {
+        let mut l1 = straight_lane("l1", 0.0, 100.0, 0.0, -3.5);
+        let mut l2 = straight_lane("l2", 0.0, 100.0, -3.5, -6.5);
+        let l3 = straight_lane("l3", 100.0, 150.0, 0.0, -3.5);
+        l1.right_neighbor_forward_lane_id = ids(&["l2"]);
+        l2.left_neighbor_forward_lane_id = ids(&["l1"]);
+        l1.successor_id = ids(&["l3"]);
+
+        hdmap::Map {
+            lane: vec![l1, l2, l3],
+            road: vec![
+                hdmap::Road {
+                    id: id("r1"),
+                    section: vec![hdmap::RoadSection {
+                        id: id("s1"),
+                        lane_id: ids(&["l1", "l2"]),
+                        boundary: None,
+                    }],
+                    ..Default::default()
+                },
+                hdmap::Road {
+                    id: id("r2"),
+                    section: vec![hdmap::RoadSection {
+                        id: id("s1"),
+                        lane_id: ids(&["l3"]),
+                        boundary: None,
+                    }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_convert_straight_road() {
+        let odr = apollo_to_opendrive(&two_lane_map(), &ApolloToOdrOptions::default()).unwrap();
+        assert_eq!(odr.roads.len(), 2);
+
+        let road = &odr.roads[0];
+        assert_eq!(road.id, "r1");
+        assert!((road.length - 100.0).abs() < 1e-6);
+        assert_eq!(road.plan_view.len(), 1);
+
+        let section = &road.lanes[0];
+        assert_eq!(section.right.len(), 2);
+        assert!(section.left.is_empty());
+        assert!((section.right[0].eval_width(50.0) - 3.5).abs() < 1e-3);
+        assert!((section.right[1].eval_width(50.0) - 3.0).abs() < 1e-3);
+        assert_eq!(section.right[0].lane_type, "driving");
+        assert!((section.right[0].speed[0].max - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_convert_road_marks() {
+        let odr = apollo_to_opendrive(&two_lane_map(), &ApolloToOdrOptions::default()).unwrap();
+        let section = &odr.roads[0].lanes[0];
+
+        assert!(matches!(
+            section.center.road_marks[0].mark_type,
+            OdrRoadMarkType::Solid
+        ));
+        assert!(matches!(
+            section.center.road_marks[0].color,
+            OdrRoadMarkColor::Yellow
+        ));
+        assert!(matches!(
+            section.right[0].road_marks[0].mark_type,
+            OdrRoadMarkType::Broken
+        ));
+        assert!(matches!(
+            section.right[0].road_marks[0].color,
+            OdrRoadMarkColor::White
+        ));
+    }
+
+    #[test]
+    fn test_convert_lane_links() {
+        let odr = apollo_to_opendrive(&two_lane_map(), &ApolloToOdrOptions::default()).unwrap();
+        let road = &odr.roads[0];
+
+        let successor = road.successor.as_ref().unwrap();
+        assert_eq!(successor.element_id, "r2");
+        assert!(matches!(
+            successor.contact_point,
+            Some(OdrContactPoint::Start)
+        ));
+        assert_eq!(road.lanes[0].right[0].link.successor, Some(-1));
+        assert_eq!(odr.roads[1].lanes[0].right[0].link.predecessor, Some(-1));
+    }
+
+    #[test]
+    fn test_convert_junction_connection() {
+        let mut map = two_lane_map();
+        map.road[1].junction_id = id("j1");
+        map.junction.push(hdmap::Junction {
+            id: id("j1"),
+            ..Default::default()
+        });
+
+        let odr = apollo_to_opendrive(&map, &ApolloToOdrOptions::default()).unwrap();
+        assert_eq!(odr.roads[1].junction, "j1");
+        assert!(matches!(
+            odr.roads[0].successor.as_ref().unwrap().element_type,
+            OdrRoadLinkElementType::Junction
+        ));
+
+        let junction = &odr.junctions[0];
+        assert_eq!(junction.connections.len(), 1);
+        let connection = &junction.connections[0];
+        assert_eq!(connection.incoming_road.as_deref(), Some("r1"));
+        assert_eq!(connection.connecting_road.as_deref(), Some("r2"));
+        assert_eq!(connection.lane_links[0].from, -1);
+        assert_eq!(connection.lane_links[0].to, -1);
+    }
+
+    #[test]
+    fn test_convert_reverse_lane() {
+        let mut map = two_lane_map();
+        // 反向车道：沿 -x 方向行驶，右边界位于 y = 3.0
+        let line = |y: f64| {
+            (0..=10)
+                .map(|i| (100.0 - 10.0 * i as f64, y))
+                .collect::<Vec<_>>()
+        };
+        map.lane.push(hdmap::Lane {
+            id: id("l4"),
+            central_curve: curve(&line(1.5)),
+            left_boundary: boundary(&line(0.0), lane_boundary_type::Type::SolidYellow),
+            right_boundary: boundary(&line(3.0), lane_boundary_type::Type::Curb),
+            left_neighbor_reverse_lane_id: ids(&["l1"]),
+            ..Default::default()
+        });
+        map.lane[0].left_neighbor_reverse_lane_id = ids(&["l4"]);
+        map.road[0].section[0].lane_id.push(Id {
+            id: Some("l4".to_string()),
+        });
+
+        let odr = apollo_to_opendrive(&map, &ApolloToOdrOptions::default()).unwrap();
+        let section = &odr.roads[0].lanes[0];
+        assert_eq!(section.left.len(), 1);
+        assert_eq!(section.left[0].id, 1);
+        assert!((section.left[0].eval_width(20.0) - 3.0).abs() < 1e-3);
+        assert!(matches!(
+            section.left[0].road_marks[0].mark_type,
+            OdrRoadMarkType::Curb
+        ));
+    }
+
+    #[test]
+    fn test_convert_signal() {
+        let mut map = two_lane_map();
+        map.signal.push(hdmap::Signal {
+            id: id("sig1"),
+            boundary: Some(hdmap::Polygon {
+                point: vec![
+                    PointEnu {
+                        x: Some(95.0),
+                        y: Some(-8.0),
+                        z: Some(5.0),
+                    },
+                    PointEnu {
+                        x: Some(95.0),
+                        y: Some(-8.0),
+                        z: Some(6.0),
+                    },
+                ],
+            }),
+            stop_line: vec![curve(&[(98.0, 0.0), (98.0, -6.5)]).unwrap()],
+            ..Default::default()
+        });
+
+        let odr = apollo_to_opendrive(&map, &ApolloToOdrOptions::default()).unwrap();
+        let road = &odr.roads[0];
+        assert_eq!(road.signals.len(), 1);
+
+        let signal = &road.signals[0];
+        assert!((signal.s - 95.0).abs() < 1e-3);
+        assert!((signal.t + 8.0).abs() < 1e-3);
+        assert!((signal.z_offset - 5.0).abs() < 1e-3);
+        assert!(matches!(signal.orientation, OdrOrientation::Positive));
+
+        assert_eq!(road.objects.len(), 1);
+        assert_eq!(road.objects[0].outlines[0].corner_road.len(), 2);
+    }
+}
@@ -0,0 +1,699 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{Result, anyhow};
+
+use crate::commonroad::models::{
+    CommonRoadAdjacent, CommonRoadBound, CommonRoadCycleElement, CommonRoadIncoming,
+    CommonRoadIntersection, CommonRoadLanelet, CommonRoadMap, CommonRoadTrafficLight,
+    CommonRoadTrafficSign, CommonRoadTrafficSignElement,
+};
+use crate::lanelet2::from_odr::geo_transform;
+use crate::math::vec3::Vec3;
+use crate::odr::models::{
+    enums::{OdrOrientation, OdrRoadMarkWeight},
+    lane::{OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+    opendrive::{LaneKey, OpenDrive},
+    road::OdrRoad,
+    signal::OdrSignal,
+};
+
+/// 德国交通标志目录中的限速标志编号
+const SPEED_LIMIT_SIGN: &str = "274";
+
+/// 转弯判定阈值（度），航向变化超过该值视为左/右转
+const TURN_THRESHOLD: f64 = 45.0;
+
+/// OpenDRIVE → CommonRoad 转换选项
+#[derive(Debug, Clone, Copy)]
+pub struct OdrToCommonRoadOptions {
+    /// 沿车道采样边界的步长（米）
+    pub sample_step: f64,
+}
+
+impl Default for OdrToCommonRoadOptions {
+    fn default() -> Self {
+        Self { sample_step: 1.0 }
+    }
+}
+
+/// 将 OpenDRIVE 地图转换为 CommonRoad 车道网络
+///
+/// driving、biking、sidewalk 车道按车道段生成 lanelet，左右边界通过
+/// `sth_to_xyz` 沿行驶方向采样，前驱/后继来自车道连接与 junction connection。
+/// 信号灯和静态标志转换为 trafficLight/trafficSign，车道限速转换为虚拟的
+/// 274 限速标志，junction 转换为 intersection。
+pub fn opendrive_to_commonroad(
+    odr: &OpenDrive,
+    options: &OdrToCommonRoadOptions,
+) -> Result<CommonRoadMap> {
+    if options.sample_step <= 0.0 {
+        return Err(anyhow!("sample_step 必须为正数"));
+    }
+
+    let road_index: HashMap<&str, usize> = odr
+        .roads
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (r.id.as_str(), i))
+        .collect();
+
+    // 1. 车道几何
+    let mut lanelets: Vec<CommonRoadLanelet> = Vec::new();
+    let mut keys: Vec<LaneKey> = Vec::new();
+    let mut lane_index: HashMap<LaneKey, usize> = HashMap::new();
+
+    for (road_idx, road) in odr.roads.iter().enumerate() {
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            if s_end - s_start < 1e-6 {
+                continue;
+            }
+
+            for lane in section.left.iter().chain(section.right.iter()) {
+                let Some(lanelet_types) = lanelet_types(road, lane) else {
+                    continue;
+                };
+                let (left, right) =
+                    sample_bounds(road, section, lane, s_start, s_end, options.sample_step);
+                if left
+                    .iter()
+                    .zip(&right)
+                    .all(|(l, r)| (*l - *r).length() < 5e-4)
+                {
+                    continue;
+                }
+
+                let (user_one_way, user_bidirectional) = match lane.lane_type.as_str() {
+                    "sidewalk" => (Vec::new(), vec!["pedestrian".to_string()]),
+                    "biking" => (vec!["bicycle".to_string()], Vec::new()),
+                    _ => (vec!["vehicle".to_string()], Vec::new()),
+                };
+
+                let inner = lane.id - lane.id.signum();
+                lane_index.insert((road_idx, section_idx, lane.id), lanelets.len());
+                keys.push((road_idx, section_idx, lane.id));
+                lanelets.push(CommonRoadLanelet {
+                    id: lanelets.len() as i64 + 1,
+                    left_bound: CommonRoadBound {
+                        points: left,
+                        line_marking: Some(line_marking(section, inner, lane.id).to_string()),
+                    },
+                    right_bound: CommonRoadBound {
+                        points: right,
+                        line_marking: Some(line_marking(section, lane.id, lane.id).to_string()),
+                    },
+                    lanelet_types,
+                    user_one_way,
+                    user_bidirectional,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // 2. 相邻车道
+    for (idx, &(road_idx, section_idx, lane_id)) in keys.iter().enumerate() {
+        let adjacent = |other: i32, same_direction: bool| {
+            lane_index
+                .get(&(road_idx, section_idx, other))
+                .map(|&i| CommonRoadAdjacent {
+                    reference: lanelets[i].id,
+                    same_direction,
+                })
+        };
+        let step = lane_id.signum();
+
+        // 两侧车道的驾驶员左侧都指向参考线
+        let left = if lane_id.abs() > 1 {
+            adjacent(lane_id - step, true)
+        } else {
+            adjacent(-lane_id, false)
+        };
+        let right = adjacent(lane_id + step, true);
+
+        lanelets[idx].adjacent_left = left;
+        lanelets[idx].adjacent_right = right;
+    }
+
+    // 3. 前驱/后继
+    for (from, to) in odr.lane_flows(&road_index, &lane_index) {
+        let from_id = lanelets[from].id;
+        let to_id = lanelets[to].id;
+        lanelets[from].successors.push(to_id);
+        lanelets[to].predecessors.push(from_id);
+    }
+
+    let mut next_id = lanelets.len() as i64 + 1;
+
+    // 4. 车道限速：相同限速共用一个虚拟限速标志
+    let mut traffic_signs: Vec<CommonRoadTrafficSign> = Vec::new();
+    let mut speed_signs: HashMap<String, i64> = HashMap::new();
+    for (idx, &(road_idx, section_idx, lane_id)) in keys.iter().enumerate() {
+        let section = &odr.roads[road_idx].lanes[section_idx];
+        let Some(speed) = section.find_lane(lane_id).and_then(|l| l.speed.first()) else {
+            continue;
+        };
+        let value = format!("{:.2}", speed.max_mps());
+        let sign_id = *speed_signs.entry(value.clone()).or_insert_with(|| {
+            traffic_signs.push(CommonRoadTrafficSign {
+                id: next_id,
+                elements: vec![CommonRoadTrafficSignElement {
+                    sign_id: SPEED_LIMIT_SIGN.to_string(),
+                    additional_values: vec![value],
+                }],
+                position: lanelets[idx].right_bound.points.first().copied(),
+                is_virtual: true,
+            });
+            next_id += 1;
+            next_id - 1
+        });
+        lanelets[idx].traffic_sign_refs.push(sign_id);
+    }
+
+    // 5. 信号灯与静态标志
+    let mut traffic_lights = Vec::new();
+    for (road_idx, road) in odr.roads.iter().enumerate() {
+        for signal in &road.signals {
+            let position = road.sth_to_xyz(signal.s, signal.t, signal.z_offset);
+            let affected: Vec<usize> = keys
+                .iter()
+                .enumerate()
+                .filter(|(_, key)| signal_applies(road, road_idx, signal, key))
+                .map(|(i, _)| i)
+                .collect();
+
+            let id = next_id;
+            next_id += 1;
+            if signal.dynamic {
+                traffic_lights.push(CommonRoadTrafficLight {
+                    id,
+                    // OpenDRIVE 不包含配时信息，使用固定周期占位
+                    cycle: vec![
+                        cycle_element(30.0, "red"),
+                        cycle_element(3.0, "redYellow"),
+                        cycle_element(30.0, "green"),
+                        cycle_element(3.0, "yellow"),
+                    ],
+                    position: Some(position),
+                    direction: Some("all".to_string()),
+                    active: true,
+                });
+                for &i in &affected {
+                    lanelets[i].traffic_light_refs.push(id);
+                }
+            } else {
+                traffic_signs.push(CommonRoadTrafficSign {
+                    id,
+                    elements: vec![sign_element(signal)],
+                    position: Some(position),
+                    is_virtual: false,
+                });
+                for &i in &affected {
+                    lanelets[i].traffic_sign_refs.push(id);
+                }
+            }
+        }
+    }
+
+    // 6. 路口：驶入路口内车道的上游车道按道路分组为 incoming
+    let mut intersections = Vec::new();
+    for junction in &odr.junctions {
+        let mut incomings: BTreeMap<usize, CommonRoadIncoming> = BTreeMap::new();
+
+        for (idx, &(road_idx, _, _)) in keys.iter().enumerate() {
+            if odr.roads[road_idx].junction != junction.id {
+                continue;
+            }
+            let turn = turn_of(&lanelets[idx]);
+            let successor = lanelets[idx].id;
+
+            for &pred in &lanelets[idx].predecessors {
+                let pred_idx = (pred - 1) as usize;
+                let pred_road = keys[pred_idx].0;
+                if odr.roads[pred_road].junction == junction.id {
+                    continue;
+                }
+
+                let incoming = incomings.entry(pred_road).or_default();
+                if !incoming.incoming_lanelets.contains(&pred) {
+                    incoming.incoming_lanelets.push(pred);
+                }
+                let list = match turn {
+                    Turn::Left => &mut incoming.successors_left,
+                    Turn::Right => &mut incoming.successors_right,
+                    Turn::Straight => &mut incoming.successors_straight,
+                };
+                if !list.contains(&successor) {
+                    list.push(successor);
+                }
+            }
+        }
+
+        if incomings.is_empty() {
+            continue;
+        }
+
+        let intersection_id = next_id;
+        next_id += 1;
+        let incomings = incomings
+            .into_values()
+            .map(|mut incoming| {
+                incoming.id = next_id;
+                next_id += 1;
+                incoming
+            })
+            .collect();
+
+        intersections.push(CommonRoadIntersection {
+            id: intersection_id,
+            incomings,
+            crossings: Vec::new(),
+        });
+    }
+
+    let name = odr
+        .header
+        .name
+        .as_deref()
+        .map(|n| {
+            n.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+        })
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "OpenDrive".to_string());
+
+    Ok(CommonRoadMap::new(
+        Some(format!("ZAM_{}-1_1_T-1", name)),
+        Some("2020a".to_string()),
+        geo_location(odr),
+        lanelets,
+        traffic_signs,
+        traffic_lights,
+        intersections,
+    ))
+}
+
+/// OpenDRIVE 车道类型到 CommonRoad `laneletType` 的映射，不支持的车道返回 None
+fn lanelet_types(road: &OdrRoad, lane: &OdrLane) -> Option<Vec<String>> {
+    let mut types = match lane.lane_type.as_str() {
+        "driving" => {
+            let category = match road.road_types.first().map(|t| t.road_type.as_str()) {
+                Some("motorway") => "highway",
+                Some("rural") => "country",
+                Some("town")
+                | Some("lowSpeed")
+                | Some("townCollector")
+                | Some("townArterial")
+                | Some("townPrivate")
+                | Some("townLocal") => "urban",
+                _ => "unknown",
+            };
+            vec![category]
+        }
+        "entry" | "onRamp" => vec!["accessRamp"],
+        "exit" | "offRamp" => vec!["exitRamp"],
+        "biking" => vec!["bicycleLane"],
+        "sidewalk" => vec!["sidewalk"],
+        "bus" => vec!["busLane"],
+        _ => return None,
+    };
+    if road.junction != "-1" && !road.junction.is_empty() {
+        types.push("intersection");
+    }
+    Some(types.into_iter().map(str::to_string).collect())
+}
+
+/// 沿行驶方向采样车道的左右边界
+fn sample_bounds(
+    road: &OdrRoad,
+    section: &OdrLaneSection,
+    lane: &OdrLane,
+    s_start: f64,
+    s_end: f64,
+    step: f64,
+) -> (Vec<Vec3>, Vec<Vec3>) {
+    let num_samples = (((s_end - s_start) / step).ceil() as usize).max(1) + 1;
+    let point = |s: f64, t: f64| road.sth_to_xyz(s, t, road.eval_shape(s, t));
+
+    let mut left = Vec::with_capacity(num_samples);
+    let mut right = Vec::with_capacity(num_samples);
+    for i in 0..num_samples {
+        let ratio = i as f64 / (num_samples - 1) as f64;
+        // 左侧车道沿 -s 行驶
+        let s = if lane.id > 0 {
+            s_end - ratio * (s_end - s_start)
+        } else {
+            s_start + ratio * (s_end - s_start)
+        };
+        let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+        left.push(point(s, t_inner));
+        right.push(point(s, t_outer));
+    }
+    (left, right)
+}
+
+/// 边界 `boundary`（外侧为该边界的车道 ID，0 为中心线）在车道 `viewer` 看来的标线
+fn line_marking(section: &OdrLaneSection, boundary: i32, viewer: i32) -> &'static str {
+    let Some(mark) = section
+        .find_lane(boundary)
+        .and_then(|l| l.road_marks.first())
+    else {
+        return "no_marking";
+    };
+
+    // 双线标线中第一条位于靠近参考线一侧
+    let from_inside = if boundary == 0 {
+        viewer < 0
+    } else {
+        boundary == viewer
+    };
+    let broad = matches!(mark.weight, Some(OdrRoadMarkWeight::Bold));
+
+    let solid = match mark.mark_type {
+        OdrRoadMarkType::Solid | OdrRoadMarkType::SolidSolid => true,
+        OdrRoadMarkType::Broken | OdrRoadMarkType::BrokenBroken | OdrRoadMarkType::BottsDots => {
+            false
+        }
+        OdrRoadMarkType::SolidBroken => from_inside,
+        OdrRoadMarkType::BrokenSolid => !from_inside,
+        _ => return "no_marking",
+    };
+
+    match (solid, broad) {
+        (true, false) => "solid",
+        (true, true) => "broad_solid",
+        (false, false) => "dashed",
+        (false, true) => "broad_dashed",
+    }
+}
+
+/// 信号是否作用于车道
+fn signal_applies(road: &OdrRoad, road_idx: usize, signal: &OdrSignal, key: &LaneKey) -> bool {
+    let &(lane_road, section_idx, lane_id) = key;
+    if lane_road != road_idx {
+        return false;
+    }
+    let (s_start, s_end) = road.section_range(section_idx);
+    if signal.s < s_start || signal.s > s_end {
+        return false;
+    }
+
+    let direction_ok = match signal.orientation {
+        OdrOrientation::Positive => lane_id < 0,
+        OdrOrientation::Negative => lane_id > 0,
+        _ => true,
+    };
+    let validity_ok = signal.validity.is_empty()
+        || signal.validity.iter().any(|v| {
+            v.from_lane.min(v.to_lane) <= lane_id && lane_id <= v.from_lane.max(v.to_lane)
+        });
+
+    direction_ok && validity_ok
+}
+
+fn sign_element(signal: &OdrSignal) -> CommonRoadTrafficSignElement {
+    let mut additional_values = Vec::new();
+    if let Some(value) = signal.value {
+        // 限速标志的附加值在 CommonRoad 中以 m/s 表示
+        let value = if signal.signal_type == SPEED_LIMIT_SIGN {
+            match signal.unit.as_deref() {
+                Some("km/h") => value / 3.6,
+                Some("mph") => value * 0.44704,
+                _ => value,
+            }
+        } else {
+            value
+        };
+        additional_values.push(format!("{:.2}", value));
+    }
+
+    CommonRoadTrafficSignElement {
+        sign_id: signal.signal_type.clone(),
+        additional_values,
+    }
+}
+
+fn cycle_element(duration: f64, color: &str) -> CommonRoadCycleElement {
+    CommonRoadCycleElement {
+        duration,
+        color: color.to_string(),
+    }
+}
+
+enum Turn {
+    Left,
+    Right,
+    Straight,
+}
+
+/// 根据车道首尾航向变化判定转向
+fn turn_of(lanelet: &CommonRoadLanelet) -> Turn {
+    let center: Vec<Vec3> = lanelet
+        .left_bound
+        .points
+        .iter()
+        .zip(&lanelet.right_bound.points)
+        .map(|(l, r)| (*l + *r) * 0.5)
+        .collect();
+    if center.len() < 2 {
+        return Turn::Straight;
+    }
+
+    let heading = |a: Vec3, b: Vec3| (b.y - a.y).atan2(b.x - a.x);
+    let n = center.len();
+    let start = heading(center[0], center[1]);
+    let end = heading(center[n - 2], center[n - 1]);
+    let mut delta = end - start;
+    while delta > std::f64::consts::PI {
+        delta -= std::f64::consts::TAU;
+    }
+    while delta < -std::f64::consts::PI {
+        delta += std::f64::consts::TAU;
+    }
+    let delta = delta.to_degrees();
+
+    if delta > TURN_THRESHOLD {
+        Turn::Left
+    } else if delta < -TURN_THRESHOLD {
+        Turn::Right
+    } else {
+        Turn::Straight
+    }
+}
+
+/// 由 geoReference 计算地图原点的经纬度
+fn geo_location(odr: &OpenDrive) -> Option<(f64, f64)> {
+    geo_transform(odr).ok().map(|to_geo| to_geo(0.0, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commonroad::parser::parse_commonroad;
+    use crate::commonroad::writer::write_commonroad;
+    use crate::odr::{
+        models::{
+            enums::{OdrContactPoint, OdrSpeedUnit},
+            header::OdrHeader,
+            junction::{
+                OdrJunction, OdrJunctionType, connection::OdrConnection,
+                lane_link::OdrJunctionLaneLink,
+            },
+            lane::lane_speed::OdrLaneSpeed,
+            road::{
+                road_geometry::OdrRoadGeometry,
+                road_link::{OdrRoadLink, OdrRoadLinkElementType},
+            },
+        },
+        test_support::{self, road_link},
+    };
+
+    fn lane(id: i32, mark: OdrRoadMarkType) -> OdrLane {
+        OdrLane {
+            speed: vec![OdrLaneSpeed::new(0.0, 36.0, Some(OdrSpeedUnit::KMH))],
+            ..test_support::lane(id, 3.5, mark)
+        }
+    }
+
+    fn road(id: &str, geometry: OdrRoadGeometry, junction: &str) -> OdrRoad {
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, OdrRoadMarkType::Solid)],
+            vec![
+                lane(-1, OdrRoadMarkType::Broken),
+                lane(-2, OdrRoadMarkType::Solid),
+            ],
+            lane(0, OdrRoadMarkType::Solid),
+            None,
+        );
+        OdrRoad {
+            junction: junction.to_string(),
+            ..test_support::road(id, geometry, vec![section])
+        }
+    }
+
+    /// 道路 1 经路口 100 内的左转道路 2 驶入
+    fn junction_map() -> OpenDrive {
+        let mut r1 = road(
+            "1",
+            OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 50.0),
+            "-1",
+        );
+        r1.successor = Some(OdrRoadLink {
+            element_id: "100".to_string(),
+            element_type: OdrRoadLinkElementType::Junction,
+            contact_point: None,
+            element_dir: None,
+            element_s: None,
+        });
+        let radius = 10.0;
+        let length = radius * std::f64::consts::FRAC_PI_2;
+        let mut r2 = road(
+            "2",
+            OdrRoadGeometry::create_arc(0.0, 50.0, 0.0, 0.0, length, 1.0 / radius),
+            "100",
+        );
+        r2.predecessor = road_link("1", OdrContactPoint::End);
+
+        let mut junction = OdrJunction::new("100".to_string(), None, OdrJunctionType::Default);
+        let mut connection = OdrConnection::new(
+            "0".to_string(),
+            Some("1".to_string()),
+            Some("2".to_string()),
+            None,
+            Some("start".to_string()),
+        );
+        connection.add_lane_link(OdrJunctionLaneLink::new(-1, -1, None));
+        junction.add_connection(connection);
+
+        let header = OdrHeader::new(
+            1,
+            6,
+            Some("Test Map".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        OpenDrive::new(header, vec![r1, r2], vec![junction])
+    }
+
+    fn find(
+        map: &CommonRoadMap,
+        predicate: impl Fn(&CommonRoadLanelet) -> bool,
+    ) -> &CommonRoadLanelet {
+        map.lanelets.iter().find(|l| predicate(l)).unwrap()
+    }
+
+    #[test]
+    fn test_export_lanelets() {
+        let map =
+            opendrive_to_commonroad(&junction_map(), &OdrToCommonRoadOptions::default()).unwrap();
+        assert_eq!(map.lanelets.len(), 6);
+        assert_eq!(map.benchmark_id.as_deref(), Some("ZAM_TestMap-1_1_T-1"));
+
+        // 道路 1 的 -1 车道
+        let l = &map.lanelets[1];
+        assert_eq!(l.left_bound.points.len(), l.right_bound.points.len());
+        assert!((l.left_bound.points[0].y).abs() < 1e-9);
+        assert!((l.right_bound.points[0].y + 3.5).abs() < 1e-9);
+        assert_eq!(l.left_bound.line_marking.as_deref(), Some("solid"));
+        assert_eq!(l.right_bound.line_marking.as_deref(), Some("dashed"));
+        assert_eq!(l.lanelet_types, vec!["unknown"]);
+        assert_eq!(l.user_one_way, vec!["vehicle"]);
+
+        let adjacent_left = l.adjacent_left.unwrap();
+        assert_eq!(adjacent_left.reference, map.lanelets[0].id);
+        assert!(!adjacent_left.same_direction);
+        assert_eq!(l.adjacent_right.unwrap().reference, map.lanelets[2].id);
+
+        // 左侧车道沿 -s 采样
+        let left_lane = &map.lanelets[0];
+        assert!(left_lane.left_bound.points[0].x > left_lane.left_bound.points[1].x);
+    }
+
+    #[test]
+    fn test_export_topology_and_intersection() {
+        let map =
+            opendrive_to_commonroad(&junction_map(), &OdrToCommonRoadOptions::default()).unwrap();
+
+        let incoming = &map.lanelets[1];
+        let connecting = find(&map, |l| {
+            l.lanelet_types.contains(&"intersection".to_string())
+                && l.predecessors == vec![incoming.id]
+        });
+        assert_eq!(incoming.successors, vec![connecting.id]);
+
+        assert_eq!(map.intersections.len(), 1);
+        let intersection = &map.intersections[0];
+        assert_eq!(intersection.incomings.len(), 1);
+        assert_eq!(
+            intersection.incomings[0].incoming_lanelets,
+            vec![incoming.id]
+        );
+        assert_eq!(
+            intersection.incomings[0].successors_left,
+            vec![connecting.id]
+        );
+
+        // 36 km/h = 10 m/s，全部车道共用一个限速标志
+        assert_eq!(map.traffic_signs.len(), 1);
+        assert_eq!(map.traffic_signs[0].speed_limit(), Some(10.0));
+        assert!(
+            map.lanelets
+                .iter()
+                .all(|l| l.traffic_sign_refs == vec![map.traffic_signs[0].id])
+        );
+    }
+
+    #[test]
+    fn test_signal_refs() {
+        let mut odr = junction_map();
+        let mut signal = OdrSignal::new(
+            "s1".to_string(),
+            45.0,
+            -8.0,
+            true,
+            OdrOrientation::Positive,
+            "1000001".to_string(),
+            "-1".to_string(),
+        );
+        signal.z_offset = 3.0;
+        odr.roads[0].signals.push(signal);
+
+        let map = opendrive_to_commonroad(&odr, &OdrToCommonRoadOptions::default()).unwrap();
+        assert_eq!(map.traffic_lights.len(), 1);
+        let light_id = map.traffic_lights[0].id;
+        let refs: Vec<i64> = map
+            .lanelets
+            .iter()
+            .filter(|l| l.traffic_light_refs.contains(&light_id))
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(refs, vec![map.lanelets[1].id, map.lanelets[2].id]);
+    }
+
+    #[test]
+    fn test_xml_round_trip() {
+        let map =
+            opendrive_to_commonroad(&junction_map(), &OdrToCommonRoadOptions::default()).unwrap();
+        let xml = write_commonroad(&map);
+        let parsed = parse_commonroad(xml.as_bytes()).unwrap();
+
+        assert_eq!(parsed.lanelets.len(), map.lanelets.len());
+        assert_eq!(parsed.lanelets[1].successors, map.lanelets[1].successors);
+        assert_eq!(
+            parsed.lanelets[1].right_bound.line_marking,
+            map.lanelets[1].right_bound.line_marking
+        );
+        assert_eq!(parsed.intersections.len(), 1);
+        assert_eq!(parsed.traffic_signs[0].speed_limit(), Some(10.0));
+        assert!(parsed.latitude.is_none());
+    }
+}
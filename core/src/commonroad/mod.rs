@@ -0,0 +1,40 @@
+use wasm_bindgen::prelude::*;
+
+use crate::lanelet2::models::Lanelet2Map;
+use crate::odr::models::opendrive::OpenDrive;
+
+pub mod from_odr;
+pub mod models;
+pub mod parser;
+pub mod to_lanelet2;
+pub mod writer;
+
+use models::CommonRoadMap;
+
+#[wasm_bindgen]
+impl CommonRoadMap {
+    /// 由 OpenDRIVE 地图生成 CommonRoad 车道网络
+    #[wasm_bindgen(js_name = fromOpenDrive)]
+    pub fn from_opendrive(odr: &OpenDrive) -> Result<CommonRoadMap, String> {
+        from_odr::opendrive_to_commonroad(odr, &from_odr::OdrToCommonRoadOptions::default())
+            .map_err(|e| e.to_string())
+    }
+
+    /// 编码为 CommonRoad XML
+    #[wasm_bindgen(js_name = toXml)]
+    pub fn to_xml(&self) -> Vec<u8> {
+        writer::write_commonroad(self).into_bytes()
+    }
+
+    /// 转换为 Lanelet2 模型，用于复用 Lanelet2 的网格构建
+    #[wasm_bindgen(js_name = toLanelet2)]
+    pub fn to_lanelet2(&self) -> Lanelet2Map {
+        to_lanelet2::commonroad_to_lanelet2(self)
+    }
+}
+
+/// 将 OpenDRIVE 地图导出为 CommonRoad XML
+#[wasm_bindgen(js_name = exportOpendriveToCommonRoad)]
+pub fn export_opendrive_to_commonroad(odr: &OpenDrive) -> Result<Vec<u8>, String> {
+    CommonRoadMap::from_opendrive(odr).map(|map| map.to_xml())
+}
@@ -0,0 +1,253 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::vec3::Vec3;
+
+/// 车道边界
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CommonRoadBound {
+    #[wasm_bindgen(getter_with_clone)]
+    pub points: Vec<Vec3>,
+    /// 标线类型：dashed、solid、broad_dashed、broad_solid、no_marking、unknown
+    #[wasm_bindgen(getter_with_clone, js_name = "lineMarking")]
+    pub line_marking: Option<String>,
+}
+
+/// 停止线
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CommonRoadStopLine {
+    #[wasm_bindgen(getter_with_clone)]
+    pub points: Vec<Vec3>,
+    #[wasm_bindgen(getter_with_clone, js_name = "lineMarking")]
+    pub line_marking: Option<String>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficSignRefs")]
+    pub traffic_sign_refs: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficLightRefs")]
+    pub traffic_light_refs: Vec<i64>,
+}
+
+/// 相邻车道
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct CommonRoadAdjacent {
+    pub reference: i64,
+    /// 是否与当前车道同向
+    #[wasm_bindgen(js_name = "sameDirection")]
+    pub same_direction: bool,
+}
+
+/// 车道单元 (Lanelet)
+///
+/// 左右边界点数相同且按行驶方向排列。
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CommonRoadLanelet {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone, js_name = "leftBound")]
+    pub left_bound: CommonRoadBound,
+    #[wasm_bindgen(getter_with_clone, js_name = "rightBound")]
+    pub right_bound: CommonRoadBound,
+    #[wasm_bindgen(getter_with_clone)]
+    pub predecessors: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub successors: Vec<i64>,
+    #[wasm_bindgen(js_name = "adjacentLeft")]
+    pub adjacent_left: Option<CommonRoadAdjacent>,
+    #[wasm_bindgen(js_name = "adjacentRight")]
+    pub adjacent_right: Option<CommonRoadAdjacent>,
+    #[wasm_bindgen(getter_with_clone, js_name = "stopLine")]
+    pub stop_line: Option<CommonRoadStopLine>,
+    /// 车道类型，如 urban、country、highway、sidewalk
+    #[wasm_bindgen(getter_with_clone, js_name = "laneletTypes")]
+    pub lanelet_types: Vec<String>,
+    /// 单向通行的交通参与者类型
+    #[wasm_bindgen(getter_with_clone, js_name = "userOneWay")]
+    pub user_one_way: Vec<String>,
+    /// 双向通行的交通参与者类型
+    #[wasm_bindgen(getter_with_clone, js_name = "userBidirectional")]
+    pub user_bidirectional: Vec<String>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficSignRefs")]
+    pub traffic_sign_refs: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficLightRefs")]
+    pub traffic_light_refs: Vec<i64>,
+}
+
+/// 交通标志元素
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadTrafficSignElement {
+    /// 标志编号，如德国限速标志 274
+    #[wasm_bindgen(getter_with_clone, js_name = "signId")]
+    pub sign_id: String,
+    /// 附加值，如限速值（m/s）
+    #[wasm_bindgen(getter_with_clone, js_name = "additionalValues")]
+    pub additional_values: Vec<String>,
+}
+
+/// 交通标志
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadTrafficSign {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone)]
+    pub elements: Vec<CommonRoadTrafficSignElement>,
+    pub position: Option<Vec3>,
+    /// 虚拟标志（现实中不存在，仅用于表达规则）
+    #[wasm_bindgen(js_name = "isVirtual")]
+    pub is_virtual: bool,
+}
+
+impl CommonRoadTrafficSign {
+    /// 限速值 (m/s)，来自编号 274 的标志元素
+    pub fn speed_limit(&self) -> Option<f64> {
+        self.elements
+            .iter()
+            .find(|e| e.sign_id == "274" || e.sign_id.ends_with("274"))
+            .and_then(|e| e.additional_values.first())
+            .and_then(|v| v.trim().parse().ok())
+    }
+}
+
+/// 信号灯周期中的一个相位
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadCycleElement {
+    /// 持续时间（时间步）
+    pub duration: f64,
+    /// 颜色：red、redYellow、green、yellow
+    #[wasm_bindgen(getter_with_clone)]
+    pub color: String,
+}
+
+/// 交通信号灯
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadTrafficLight {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone)]
+    pub cycle: Vec<CommonRoadCycleElement>,
+    pub position: Option<Vec3>,
+    /// 控制方向：right、straight、left、all 等
+    #[wasm_bindgen(getter_with_clone)]
+    pub direction: Option<String>,
+    pub active: bool,
+}
+
+/// 路口入口
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CommonRoadIncoming {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone, js_name = "incomingLanelets")]
+    pub incoming_lanelets: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone, js_name = "successorsRight")]
+    pub successors_right: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone, js_name = "successorsStraight")]
+    pub successors_straight: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone, js_name = "successorsLeft")]
+    pub successors_left: Vec<i64>,
+    /// 位于哪个入口的左侧
+    #[wasm_bindgen(js_name = "isLeftOf")]
+    pub is_left_of: Option<i64>,
+}
+
+/// 路口
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadIntersection {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone)]
+    pub incomings: Vec<CommonRoadIncoming>,
+    /// 人行横道等穿越路口的车道
+    #[wasm_bindgen(getter_with_clone)]
+    pub crossings: Vec<i64>,
+}
+
+/// CommonRoad 车道网络
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommonRoadMap {
+    #[wasm_bindgen(getter_with_clone, js_name = "benchmarkId")]
+    pub benchmark_id: Option<String>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub version: Option<String>,
+    /// 地理位置（gpsLatitude/gpsLongitude，未知时为 None）
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub lanelets: Vec<CommonRoadLanelet>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficSigns")]
+    pub traffic_signs: Vec<CommonRoadTrafficSign>,
+    #[wasm_bindgen(getter_with_clone, js_name = "trafficLights")]
+    pub traffic_lights: Vec<CommonRoadTrafficLight>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub intersections: Vec<CommonRoadIntersection>,
+    center: Vec3,
+}
+
+#[wasm_bindgen]
+impl CommonRoadMap {
+    /// 获取地图的中心点
+    #[wasm_bindgen(getter)]
+    pub fn center(&self) -> Vec3 {
+        self.center
+    }
+}
+
+impl CommonRoadMap {
+    pub fn new(
+        benchmark_id: Option<String>,
+        version: Option<String>,
+        location: Option<(f64, f64)>,
+        lanelets: Vec<CommonRoadLanelet>,
+        traffic_signs: Vec<CommonRoadTrafficSign>,
+        traffic_lights: Vec<CommonRoadTrafficLight>,
+        intersections: Vec<CommonRoadIntersection>,
+    ) -> Self {
+        let center = Self::compute_center(&lanelets);
+        Self {
+            benchmark_id,
+            version,
+            latitude: location.map(|l| l.0),
+            longitude: location.map(|l| l.1),
+            lanelets,
+            traffic_signs,
+            traffic_lights,
+            intersections,
+            center,
+        }
+    }
+
+    /// 计算所有车道边界点边界框的中心
+    fn compute_center(lanelets: &[CommonRoadLanelet]) -> Vec3 {
+        let mut min_x = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut min_y = f64::MAX;
+        let mut max_y = f64::MIN;
+
+        for p in lanelets
+            .iter()
+            .flat_map(|l| l.left_bound.points.iter().chain(&l.right_bound.points))
+        {
+            min_x = min_x.min(p.x);
+            max_x = max_x.max(p.x);
+            min_y = min_y.min(p.y);
+            max_y = max_y.max(p.y);
+        }
+
+        if min_x == f64::MAX {
+            return Vec3::new(0.0, 0.0, 0.0);
+        }
+
+        Vec3::new((min_x + max_x) / 2.0, (min_y + max_y) / 2.0, 0.0)
+    }
+
+    pub fn find_lanelet(&self, id: i64) -> Option<&CommonRoadLanelet> {
+        self.lanelets.iter().find(|l| l.id == id)
+    }
+
+    pub fn find_traffic_sign(&self, id: i64) -> Option<&CommonRoadTrafficSign> {
+        self.traffic_signs.iter().find(|s| s.id == id)
+    }
+}
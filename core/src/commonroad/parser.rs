@@ -0,0 +1,502 @@
+use anyhow::{Context, Result, anyhow};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use wasm_bindgen::prelude::*;
+
+use crate::commonroad::models::{
+    CommonRoadAdjacent, CommonRoadBound, CommonRoadCycleElement, CommonRoadIncoming,
+    CommonRoadIntersection, CommonRoadLanelet, CommonRoadMap, CommonRoadStopLine,
+    CommonRoadTrafficLight, CommonRoadTrafficSign, CommonRoadTrafficSignElement,
+};
+use crate::fs::Files;
+use crate::math::vec3::Vec3;
+
+/// 解析 CommonRoad XML 中的车道网络
+#[wasm_bindgen(js_name = parseCommonRoad)]
+pub fn parse_commonroad(xml: &[u8]) -> Result<CommonRoadMap, String> {
+    parse_commonroad_internal(xml).map_err(|e| e.to_string())
+}
+
+/// 从 Files 中解析多个 CommonRoad 场景
+#[wasm_bindgen(js_name = parseCommonRoadFromFiles)]
+pub fn parse_commonroad_from_files(files: &Files) -> Result<Vec<CommonRoadMap>, String> {
+    let xml_files: Vec<_> = files
+        .filter_by_extension(".xml")
+        .into_iter()
+        .filter(|f| is_commonroad(f.get_data()))
+        .collect();
+
+    if xml_files.is_empty() {
+        return Err("未找到 CommonRoad 格式的 .xml 文件".to_string());
+    }
+
+    let mut maps = Vec::new();
+    for file in xml_files {
+        let map = parse_commonroad_internal(file.get_data()).map_err(|e| e.to_string())?;
+        maps.push(map);
+    }
+
+    Ok(maps)
+}
+
+/// 判断 XML 数据是否为 CommonRoad 场景（根元素为 commonRoad）
+pub fn is_commonroad(xml: &[u8]) -> bool {
+    let head = &xml[..xml.len().min(4096)];
+    head.windows(b"<commonRoad".len())
+        .any(|w| w == b"<commonRoad")
+}
+
+/// 简化的 XML 元素树
+///
+/// CommonRoad 的几何与引用大量使用嵌套子元素和文本内容，
+/// 先构建元素树再提取比事件流更直观。
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|c| c.text.trim())
+    }
+
+    fn child_f64(&self, name: &str) -> Result<Option<f64>> {
+        self.child_text(name)
+            .map(|v| v.parse::<f64>())
+            .transpose()
+            .with_context(|| format!("解析 <{}> 错误", name))
+    }
+
+    fn id(&self) -> Result<i64> {
+        self.attr("id")
+            .ok_or_else(|| anyhow!("<{}> 缺少 id 属性", self.name))?
+            .parse()
+            .with_context(|| format!("解析 <{}> id 错误", self.name))
+    }
+
+    /// 所有名为 `name` 的子元素的 ref 属性
+    fn refs(&self, name: &str) -> Result<Vec<i64>> {
+        self.children(name)
+            .map(|c| {
+                c.attr("ref")
+                    .ok_or_else(|| anyhow!("<{}> 缺少 ref 属性", name))?
+                    .parse()
+                    .with_context(|| format!("解析 <{}> ref 错误", name))
+            })
+            .collect()
+    }
+}
+
+fn start_element(e: &BytesStart) -> Result<Element> {
+    let mut element = Element {
+        name: String::from_utf8_lossy(e.name().as_ref()).into_owned(),
+        ..Default::default()
+    };
+    for attr in e.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let value = attr.unescape_value().context("解析属性值错误")?;
+        element.attributes.push((
+            String::from_utf8_lossy(attr.key.as_ref()).into_owned(),
+            value.into_owned(),
+        ));
+    }
+    Ok(element)
+}
+
+fn read_tree(xml: &[u8]) -> Result<Element> {
+    let mut reader = Reader::from_reader(xml);
+    let mut stack: Vec<Element> = vec![Element::default()];
+    let mut buf = Vec::new();
+
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|e| anyhow!("XML 解析错误: {}", e))?
+        {
+            Event::Start(ref e) => stack.push(start_element(e)?),
+            Event::Empty(ref e) => {
+                let element = start_element(e)?;
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(element);
+                }
+            }
+            Event::Text(ref e) => {
+                if let Some(current) = stack.last_mut() {
+                    let text = e.decode().context("解析文本错误")?;
+                    current.text.push_str(&text);
+                }
+            }
+            Event::CData(ref e) => {
+                if let Some(current) = stack.last_mut() {
+                    current.text.push_str(&String::from_utf8_lossy(e));
+                }
+            }
+            Event::End(_) if stack.len() > 1 => {
+                let element = stack.pop().unwrap();
+                stack.last_mut().unwrap().children.push(element);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let document = stack.swap_remove(0);
+    document
+        .children
+        .into_iter()
+        .find(|c| c.name == "commonRoad")
+        .ok_or_else(|| anyhow!("未找到 commonRoad 根元素"))
+}
+
+fn parse_commonroad_internal(xml: &[u8]) -> Result<CommonRoadMap> {
+    let root = read_tree(xml)?;
+
+    let location = root.child("location").and_then(|l| {
+        let lat = l.child_f64("gpsLatitude").ok().flatten()?;
+        let lon = l.child_f64("gpsLongitude").ok().flatten()?;
+        // 999 表示未知位置
+        (lat.abs() <= 90.0 && lon.abs() <= 180.0).then_some((lat, lon))
+    });
+
+    // 车道网络元素位于根元素下，部分工具会额外包一层 laneletNetwork
+    let network = root.child("laneletNetwork").unwrap_or(&root);
+
+    let lanelets = network
+        .children("lanelet")
+        .map(parse_lanelet)
+        .collect::<Result<Vec<_>>>()?;
+    let traffic_signs = network
+        .children("trafficSign")
+        .map(parse_traffic_sign)
+        .collect::<Result<Vec<_>>>()?;
+    let traffic_lights = network
+        .children("trafficLight")
+        .map(parse_traffic_light)
+        .collect::<Result<Vec<_>>>()?;
+    let intersections = network
+        .children("intersection")
+        .map(parse_intersection)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(CommonRoadMap::new(
+        root.attr("benchmarkID").map(str::to_string),
+        root.attr("commonRoadVersion").map(str::to_string),
+        location,
+        lanelets,
+        traffic_signs,
+        traffic_lights,
+        intersections,
+    ))
+}
+
+fn parse_point(point: &Element) -> Result<Vec3> {
+    let x = point
+        .child_f64("x")?
+        .ok_or_else(|| anyhow!("<point> 缺少 x"))?;
+    let y = point
+        .child_f64("y")?
+        .ok_or_else(|| anyhow!("<point> 缺少 y"))?;
+    let z = point.child_f64("z")?.unwrap_or(0.0);
+    Ok(Vec3::new(x, y, z))
+}
+
+fn parse_points(element: &Element) -> Result<Vec<Vec3>> {
+    element.children("point").map(parse_point).collect()
+}
+
+fn parse_bound(element: &Element) -> Result<CommonRoadBound> {
+    Ok(CommonRoadBound {
+        points: parse_points(element)?,
+        line_marking: element.child_text("lineMarking").map(str::to_string),
+    })
+}
+
+fn parse_adjacent(element: &Element) -> Result<CommonRoadAdjacent> {
+    Ok(CommonRoadAdjacent {
+        reference: element
+            .attr("ref")
+            .ok_or_else(|| anyhow!("相邻车道缺少 ref 属性"))?
+            .parse()
+            .context("解析相邻车道 ref 错误")?,
+        same_direction: element.attr("drivingDir") != Some("opposite"),
+    })
+}
+
+fn parse_lanelet(element: &Element) -> Result<CommonRoadLanelet> {
+    let id = element.id()?;
+    let bound = |name: &str| {
+        element
+            .child(name)
+            .ok_or_else(|| anyhow!("lanelet {} 缺少 <{}>", id, name))
+            .and_then(parse_bound)
+    };
+    let texts = |name: &str| {
+        element
+            .children(name)
+            .map(|c| c.text.trim().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let stop_line = element
+        .child("stopLine")
+        .map(|s| -> Result<CommonRoadStopLine> {
+            Ok(CommonRoadStopLine {
+                points: parse_points(s)?,
+                line_marking: s.child_text("lineMarking").map(str::to_string),
+                traffic_sign_refs: s.refs("trafficSignRef")?,
+                traffic_light_refs: s.refs("trafficLightRef")?,
+            })
+        })
+        .transpose()?;
+
+    Ok(CommonRoadLanelet {
+        id,
+        left_bound: bound("leftBound")?,
+        right_bound: bound("rightBound")?,
+        predecessors: element.refs("predecessor")?,
+        successors: element.refs("successor")?,
+        adjacent_left: element
+            .child("adjacentLeft")
+            .map(parse_adjacent)
+            .transpose()?,
+        adjacent_right: element
+            .child("adjacentRight")
+            .map(parse_adjacent)
+            .transpose()?,
+        stop_line,
+        lanelet_types: texts("laneletType"),
+        user_one_way: texts("userOneWay"),
+        user_bidirectional: texts("userBidirectional"),
+        traffic_sign_refs: element.refs("trafficSignRef")?,
+        traffic_light_refs: element.refs("trafficLightRef")?,
+    })
+}
+
+fn parse_position(element: &Element) -> Result<Option<Vec3>> {
+    element
+        .child("position")
+        .and_then(|p| p.child("point"))
+        .map(parse_point)
+        .transpose()
+}
+
+fn parse_traffic_sign(element: &Element) -> Result<CommonRoadTrafficSign> {
+    let elements = element
+        .children("trafficSignElement")
+        .map(|e| CommonRoadTrafficSignElement {
+            sign_id: e
+                .child_text("trafficSignID")
+                .unwrap_or_default()
+                .to_string(),
+            additional_values: e
+                .children("additionalValue")
+                .map(|v| v.text.trim().to_string())
+                .collect(),
+        })
+        .collect();
+
+    Ok(CommonRoadTrafficSign {
+        id: element.id()?,
+        elements,
+        position: parse_position(element)?,
+        is_virtual: element.child_text("virtual") == Some("true"),
+    })
+}
+
+fn parse_traffic_light(element: &Element) -> Result<CommonRoadTrafficLight> {
+    let cycle = element
+        .child("cycle")
+        .map(|c| {
+            c.children("cycleElement")
+                .map(|e| -> Result<CommonRoadCycleElement> {
+                    Ok(CommonRoadCycleElement {
+                        duration: e.child_f64("duration")?.unwrap_or(0.0),
+                        color: e.child_text("color").unwrap_or_default().to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(CommonRoadTrafficLight {
+        id: element.id()?,
+        cycle,
+        position: parse_position(element)?,
+        direction: element.child_text("direction").map(str::to_string),
+        active: element.child_text("active") != Some("false"),
+    })
+}
+
+fn parse_intersection(element: &Element) -> Result<CommonRoadIntersection> {
+    let incomings = element
+        .children("incoming")
+        .map(|incoming| -> Result<CommonRoadIncoming> {
+            Ok(CommonRoadIncoming {
+                id: incoming.id()?,
+                incoming_lanelets: incoming.refs("incomingLanelet")?,
+                successors_right: incoming.refs("successorsRight")?,
+                successors_straight: incoming.refs("successorsStraight")?,
+                successors_left: incoming.refs("successorsLeft")?,
+                is_left_of: incoming.refs("isLeftOf")?.first().copied(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let crossings = element
+        .children("crossing")
+        .map(|c| c.refs("crossingLanelet"))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    Ok(CommonRoadIntersection {
+        id: element.id()?,
+        incomings,
+        crossings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCENARIO: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<commonRoad commonRoadVersion="2020a" benchmarkID="ZAM_Test-1_1_T-1">
+  <location>
+    <geoNameId>-999</geoNameId>
+    <gpsLatitude>999</gpsLatitude>
+    <gpsLongitude>999</gpsLongitude>
+  </location>
+  <lanelet id="1">
+    <leftBound>
+      <point><x>0</x><y>3.5</y></point>
+      <point><x>10</x><y>3.5</y></point>
+      <lineMarking>dashed</lineMarking>
+    </leftBound>
+    <rightBound>
+      <point><x>0</x><y>0</y><z>1.0</z></point>
+      <point><x>10</x><y>0</y><z>1.0</z></point>
+      <lineMarking>solid</lineMarking>
+    </rightBound>
+    <successor ref="3"/>
+    <adjacentLeft ref="2" drivingDir="same"/>
+    <stopLine>
+      <point><x>10</x><y>0</y></point>
+      <point><x>10</x><y>3.5</y></point>
+      <lineMarking>solid</lineMarking>
+      <trafficLightRef ref="20"/>
+    </stopLine>
+    <laneletType>urban</laneletType>
+    <userOneWay>vehicle</userOneWay>
+    <trafficSignRef ref="10"/>
+    <trafficLightRef ref="20"/>
+  </lanelet>
+  <lanelet id="2">
+    <leftBound>
+      <point><x>0</x><y>7</y></point>
+      <point><x>10</x><y>7</y></point>
+    </leftBound>
+    <rightBound>
+      <point><x>0</x><y>3.5</y></point>
+      <point><x>10</x><y>3.5</y></point>
+    </rightBound>
+    <adjacentRight ref="1" drivingDir="same"/>
+  </lanelet>
+  <trafficSign id="10">
+    <trafficSignElement>
+      <trafficSignID>274</trafficSignID>
+      <additionalValue>13.89</additionalValue>
+    </trafficSignElement>
+    <position><point><x>0</x><y>-1</y></point></position>
+    <virtual>false</virtual>
+  </trafficSign>
+  <trafficLight id="20">
+    <cycle>
+      <cycleElement><duration>30</duration><color>red</color></cycleElement>
+      <cycleElement><duration>25</duration><color>green</color></cycleElement>
+    </cycle>
+    <position><point><x>10</x><y>-1</y></point></position>
+    <direction>all</direction>
+    <active>true</active>
+  </trafficLight>
+  <intersection id="30">
+    <incoming id="31">
+      <incomingLanelet ref="1"/>
+      <successorsStraight ref="3"/>
+      <isLeftOf ref="32"/>
+    </incoming>
+    <crossing><crossingLanelet ref="4"/></crossing>
+  </intersection>
+</commonRoad>"#;
+
+    #[test]
+    fn test_parse_lanelets() {
+        let map = parse_commonroad_internal(SCENARIO.as_bytes()).unwrap();
+        assert_eq!(map.version.as_deref(), Some("2020a"));
+        assert!(map.latitude.is_none());
+        assert_eq!(map.lanelets.len(), 2);
+
+        let l1 = map.find_lanelet(1).unwrap();
+        assert_eq!(l1.left_bound.points.len(), 2);
+        assert_eq!(l1.left_bound.line_marking.as_deref(), Some("dashed"));
+        assert_eq!(l1.right_bound.points[1].z, 1.0);
+        assert_eq!(l1.successors, vec![3]);
+        let adjacent = l1.adjacent_left.unwrap();
+        assert_eq!(adjacent.reference, 2);
+        assert!(adjacent.same_direction);
+        assert_eq!(l1.lanelet_types, vec!["urban"]);
+        assert_eq!(l1.user_one_way, vec!["vehicle"]);
+        assert_eq!(l1.stop_line.as_ref().unwrap().traffic_light_refs, vec![20]);
+        assert_eq!(l1.traffic_sign_refs, vec![10]);
+
+        assert_eq!(map.center().y, 3.5);
+    }
+
+    #[test]
+    fn test_parse_signs_and_intersections() {
+        let map = parse_commonroad_internal(SCENARIO.as_bytes()).unwrap();
+
+        let sign = map.find_traffic_sign(10).unwrap();
+        assert_eq!(sign.speed_limit(), Some(13.89));
+        assert_eq!(sign.position.unwrap().y, -1.0);
+        assert!(!sign.is_virtual);
+
+        let light = &map.traffic_lights[0];
+        assert_eq!(light.cycle.len(), 2);
+        assert_eq!(light.cycle[1].color, "green");
+        assert_eq!(light.direction.as_deref(), Some("all"));
+
+        let intersection = &map.intersections[0];
+        assert_eq!(intersection.incomings[0].incoming_lanelets, vec![1]);
+        assert_eq!(intersection.incomings[0].successors_straight, vec![3]);
+        assert_eq!(intersection.incomings[0].is_left_of, Some(32));
+        assert_eq!(intersection.crossings, vec![4]);
+    }
+
+    #[test]
+    fn test_is_commonroad() {
+        assert!(is_commonroad(SCENARIO.as_bytes()));
+        assert!(!is_commonroad(b"<OpenDRIVE></OpenDRIVE>"));
+    }
+}
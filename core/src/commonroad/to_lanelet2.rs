@@ -0,0 +1,186 @@
+use crate::commonroad::models::{CommonRoadAdjacent, CommonRoadBound, CommonRoadMap};
+use crate::lanelet2::models::{Lanelet2Lanelet, Lanelet2LineString, Lanelet2Map, Lanelet2Tag};
+
+/// 将 CommonRoad 车道网络转换为 Lanelet2 车道模型
+///
+/// 便于复用 Lanelet2 的网格构建器进行渲染。边界的 `lineMarking` 映射为
+/// 线串的 `type`/`subtype` 标签，编号 274 的交通标志映射为车道限速。
+pub fn commonroad_to_lanelet2(map: &CommonRoadMap) -> Lanelet2Map {
+    // 线串与节点从 lanelet 最大 ID 之后开始编号
+    let mut next_id = map.lanelets.iter().map(|l| l.id).max().unwrap_or(0) + 1;
+    let mut line_strings = Vec::with_capacity(map.lanelets.len() * 2);
+    let mut lanelets = Vec::with_capacity(map.lanelets.len());
+
+    let mut line_string = |bound: &CommonRoadBound| {
+        let (line_type, subtype) = line_type_from_marking(bound.line_marking.as_deref());
+        let point_ids = bound
+            .points
+            .iter()
+            .map(|_| {
+                next_id += 1;
+                next_id - 1
+            })
+            .collect();
+        let mut tags = vec![tag("type", line_type)];
+        if let Some(subtype) = subtype {
+            tags.push(tag("subtype", subtype));
+        }
+
+        let ls = Lanelet2LineString {
+            id: next_id,
+            line_type: line_type.to_string(),
+            subtype: subtype.map(str::to_string),
+            points: bound.points.clone(),
+            point_ids,
+            tags,
+        };
+        next_id += 1;
+        ls
+    };
+
+    for lanelet in &map.lanelets {
+        let left = line_string(&lanelet.left_bound);
+        let right = line_string(&lanelet.right_bound);
+
+        let speed_limit = lanelet
+            .traffic_sign_refs
+            .iter()
+            .filter_map(|id| map.find_traffic_sign(*id))
+            .find_map(|sign| sign.speed_limit())
+            .map(|mps| mps * 3.6);
+
+        let subtype = lanelet
+            .lanelet_types
+            .iter()
+            .find_map(|t| match t.as_str() {
+                "sidewalk" => Some("walkway"),
+                "crosswalk" => Some("crosswalk"),
+                "bicycleLane" => Some("bicycle_lane"),
+                "busLane" => Some("bus_lane"),
+                _ => None,
+            })
+            .unwrap_or("road");
+
+        let mut tags = vec![tag("type", "lanelet"), tag("subtype", subtype)];
+        tags.extend(
+            lanelet
+                .lanelet_types
+                .iter()
+                .map(|t| tag("commonroad:laneletType", t)),
+        );
+
+        lanelets.push(Lanelet2Lanelet {
+            id: lanelet.id,
+            subtype: Some(subtype.to_string()),
+            left: left.clone(),
+            right: right.clone(),
+            centerline: None,
+            speed_limit,
+            one_way: lanelet.user_bidirectional.is_empty(),
+            location: lanelet.lanelet_types.iter().find_map(|t| match t.as_str() {
+                "urban" => Some("urban".to_string()),
+                "country" | "highway" | "interstate" => Some("nonurban".to_string()),
+                _ => None,
+            }),
+            turn_direction: None,
+            regulatory_elements: Vec::new(),
+            successors: lanelet.successors.clone(),
+            predecessors: lanelet.predecessors.clone(),
+            left_neighbors: same_direction(lanelet.adjacent_left),
+            right_neighbors: same_direction(lanelet.adjacent_right),
+            tags,
+        });
+        line_strings.push(left);
+        line_strings.push(right);
+    }
+
+    // CommonRoad 的 location 只是场景的大致位置，不是局部坐标的投影原点
+    Lanelet2Map::new(line_strings, lanelets, Vec::new(), Vec::new(), None)
+}
+
+/// 同向相邻车道的 ID 列表
+fn same_direction(adjacent: Option<CommonRoadAdjacent>) -> Vec<i64> {
+    adjacent
+        .filter(|a| a.same_direction)
+        .map(|a| a.reference)
+        .into_iter()
+        .collect()
+}
+
+/// CommonRoad `lineMarking` 到 Lanelet2 线串 `type`/`subtype` 的映射
+pub fn line_type_from_marking(marking: Option<&str>) -> (&'static str, Option<&'static str>) {
+    match marking {
+        Some("solid") => ("line_thin", Some("solid")),
+        Some("dashed") => ("line_thin", Some("dashed")),
+        Some("broad_solid") => ("line_thick", Some("solid")),
+        Some("broad_dashed") => ("line_thick", Some("dashed")),
+        _ => ("virtual", None),
+    }
+}
+
+fn tag(key: &str, value: &str) -> Lanelet2Tag {
+    Lanelet2Tag {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commonroad::models::{
+        CommonRoadLanelet, CommonRoadTrafficSign, CommonRoadTrafficSignElement,
+    };
+    use crate::math::vec3::Vec3;
+
+    #[test]
+    fn test_convert_lanelet() {
+        let bound = |y: f64, marking: &str| CommonRoadBound {
+            points: vec![Vec3::new(0.0, y, 0.0), Vec3::new(10.0, y, 0.0)],
+            line_marking: Some(marking.to_string()),
+        };
+        let lanelet = CommonRoadLanelet {
+            id: 5,
+            left_bound: bound(3.5, "broad_dashed"),
+            right_bound: bound(0.0, "no_marking"),
+            adjacent_left: Some(CommonRoadAdjacent {
+                reference: 6,
+                same_direction: true,
+            }),
+            lanelet_types: vec!["urban".to_string()],
+            user_one_way: vec!["vehicle".to_string()],
+            traffic_sign_refs: vec![7],
+            ..Default::default()
+        };
+        let sign = CommonRoadTrafficSign {
+            id: 7,
+            elements: vec![CommonRoadTrafficSignElement {
+                sign_id: "274".to_string(),
+                additional_values: vec!["13.89".to_string()],
+            }],
+            position: None,
+            is_virtual: true,
+        };
+        let map = CommonRoadMap::new(
+            None,
+            None,
+            None,
+            vec![lanelet],
+            vec![sign],
+            Vec::new(),
+            Vec::new(),
+        );
+
+        let converted = commonroad_to_lanelet2(&map);
+        assert_eq!(converted.line_strings.len(), 2);
+        let l = &converted.lanelets[0];
+        assert_eq!(l.left.line_type, "line_thick");
+        assert_eq!(l.left.subtype.as_deref(), Some("dashed"));
+        assert_eq!(l.right.line_type, "virtual");
+        assert!(l.left.id > 5 && l.left.point_ids.iter().all(|id| *id > 5));
+        assert!((l.speed_limit.unwrap() - 50.0).abs() < 0.01);
+        assert_eq!(l.location.as_deref(), Some("urban"));
+        assert_eq!(l.left_neighbors, vec![6]);
+        assert!(l.one_way);
+    }
+}
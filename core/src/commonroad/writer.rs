@@ -0,0 +1,197 @@
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+
+use crate::commonroad::models::{CommonRoadAdjacent, CommonRoadBound, CommonRoadMap};
+use crate::math::vec3::Vec3;
+
+/// 将 CommonRoad 车道网络序列化为 CommonRoad 2020a XML
+///
+/// 仅输出车道网络（lanelet、trafficSign、trafficLight、intersection），
+/// 不包含障碍物与规划问题。位置未知时按规范写入 999。
+pub fn write_commonroad(map: &CommonRoadMap) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<commonRoad commonRoadVersion=\"2020a\" benchmarkID=\"{}\" date=\"\" author=\"maphub\" affiliation=\"\" source=\"maphub\" timeStepSize=\"0.1\">",
+        escape(map.benchmark_id.as_deref().unwrap_or("ZAM_Map-1_1_T-1"))
+    );
+
+    xml.push_str("  <location>\n");
+    write_text(&mut xml, 2, "geoNameId", "-999");
+    let (lat, lon) = map
+        .latitude
+        .zip(map.longitude)
+        .map(|(lat, lon)| (format!("{lat:.9}"), format!("{lon:.9}")))
+        .unwrap_or_else(|| ("999".to_string(), "999".to_string()));
+    write_text(&mut xml, 2, "gpsLatitude", &lat);
+    write_text(&mut xml, 2, "gpsLongitude", &lon);
+    xml.push_str("  </location>\n");
+
+    for lanelet in &map.lanelets {
+        let _ = writeln!(xml, "  <lanelet id=\"{}\">", lanelet.id);
+        write_bound(&mut xml, "leftBound", &lanelet.left_bound);
+        write_bound(&mut xml, "rightBound", &lanelet.right_bound);
+        write_refs(&mut xml, 2, "predecessor", &lanelet.predecessors);
+        write_refs(&mut xml, 2, "successor", &lanelet.successors);
+        write_adjacent(&mut xml, "adjacentLeft", lanelet.adjacent_left);
+        write_adjacent(&mut xml, "adjacentRight", lanelet.adjacent_right);
+        if let Some(stop_line) = &lanelet.stop_line {
+            xml.push_str("    <stopLine>\n");
+            for p in &stop_line.points {
+                write_point(&mut xml, 3, p);
+            }
+            if let Some(marking) = &stop_line.line_marking {
+                write_text(&mut xml, 3, "lineMarking", marking);
+            }
+            write_refs(&mut xml, 3, "trafficSignRef", &stop_line.traffic_sign_refs);
+            write_refs(
+                &mut xml,
+                3,
+                "trafficLightRef",
+                &stop_line.traffic_light_refs,
+            );
+            xml.push_str("    </stopLine>\n");
+        }
+        // laneletType 至少出现一次
+        if lanelet.lanelet_types.is_empty() {
+            write_text(&mut xml, 2, "laneletType", "unknown");
+        }
+        for lanelet_type in &lanelet.lanelet_types {
+            write_text(&mut xml, 2, "laneletType", lanelet_type);
+        }
+        for user in &lanelet.user_one_way {
+            write_text(&mut xml, 2, "userOneWay", user);
+        }
+        for user in &lanelet.user_bidirectional {
+            write_text(&mut xml, 2, "userBidirectional", user);
+        }
+        write_refs(&mut xml, 2, "trafficSignRef", &lanelet.traffic_sign_refs);
+        write_refs(&mut xml, 2, "trafficLightRef", &lanelet.traffic_light_refs);
+        xml.push_str("  </lanelet>\n");
+    }
+
+    for sign in &map.traffic_signs {
+        let _ = writeln!(xml, "  <trafficSign id=\"{}\">", sign.id);
+        for element in &sign.elements {
+            xml.push_str("    <trafficSignElement>\n");
+            write_text(&mut xml, 3, "trafficSignID", &element.sign_id);
+            for value in &element.additional_values {
+                write_text(&mut xml, 3, "additionalValue", value);
+            }
+            xml.push_str("    </trafficSignElement>\n");
+        }
+        write_position(&mut xml, sign.position.as_ref());
+        write_text(&mut xml, 2, "virtual", &sign.is_virtual.to_string());
+        xml.push_str("  </trafficSign>\n");
+    }
+
+    for light in &map.traffic_lights {
+        let _ = writeln!(xml, "  <trafficLight id=\"{}\">", light.id);
+        xml.push_str("    <cycle>\n");
+        for element in &light.cycle {
+            xml.push_str("      <cycleElement>\n");
+            write_text(&mut xml, 4, "duration", &element.duration.to_string());
+            write_text(&mut xml, 4, "color", &element.color);
+            xml.push_str("      </cycleElement>\n");
+        }
+        xml.push_str("    </cycle>\n");
+        write_position(&mut xml, light.position.as_ref());
+        if let Some(direction) = &light.direction {
+            write_text(&mut xml, 2, "direction", direction);
+        }
+        write_text(&mut xml, 2, "active", &light.active.to_string());
+        xml.push_str("  </trafficLight>\n");
+    }
+
+    for intersection in &map.intersections {
+        let _ = writeln!(xml, "  <intersection id=\"{}\">", intersection.id);
+        for incoming in &intersection.incomings {
+            let _ = writeln!(xml, "    <incoming id=\"{}\">", incoming.id);
+            write_refs(&mut xml, 3, "incomingLanelet", &incoming.incoming_lanelets);
+            write_refs(&mut xml, 3, "successorsRight", &incoming.successors_right);
+            write_refs(
+                &mut xml,
+                3,
+                "successorsStraight",
+                &incoming.successors_straight,
+            );
+            write_refs(&mut xml, 3, "successorsLeft", &incoming.successors_left);
+            if let Some(is_left_of) = incoming.is_left_of {
+                write_refs(&mut xml, 3, "isLeftOf", &[is_left_of]);
+            }
+            xml.push_str("    </incoming>\n");
+        }
+        if !intersection.crossings.is_empty() {
+            xml.push_str("    <crossing>\n");
+            write_refs(&mut xml, 3, "crossingLanelet", &intersection.crossings);
+            xml.push_str("    </crossing>\n");
+        }
+        xml.push_str("  </intersection>\n");
+    }
+
+    xml.push_str("</commonRoad>\n");
+    xml
+}
+
+fn indent(xml: &mut String, level: usize) {
+    for _ in 0..level {
+        xml.push_str("  ");
+    }
+}
+
+fn write_text(xml: &mut String, level: usize, name: &str, value: &str) {
+    indent(xml, level);
+    let _ = writeln!(xml, "<{name}>{}</{name}>", escape(value));
+}
+
+fn write_refs(xml: &mut String, level: usize, name: &str, refs: &[i64]) {
+    for reference in refs {
+        indent(xml, level);
+        let _ = writeln!(xml, "<{name} ref=\"{reference}\"/>");
+    }
+}
+
+fn write_point(xml: &mut String, level: usize, p: &Vec3) {
+    indent(xml, level);
+    let _ = writeln!(
+        xml,
+        "<point><x>{:.4}</x><y>{:.4}</y><z>{:.4}</z></point>",
+        p.x, p.y, p.z
+    );
+}
+
+fn write_bound(xml: &mut String, name: &str, bound: &CommonRoadBound) {
+    let _ = writeln!(xml, "    <{name}>");
+    for p in &bound.points {
+        write_point(xml, 3, p);
+    }
+    if let Some(marking) = &bound.line_marking {
+        write_text(xml, 3, "lineMarking", marking);
+    }
+    let _ = writeln!(xml, "    </{name}>");
+}
+
+fn write_adjacent(xml: &mut String, name: &str, adjacent: Option<CommonRoadAdjacent>) {
+    if let Some(adjacent) = adjacent {
+        let direction = if adjacent.same_direction {
+            "same"
+        } else {
+            "opposite"
+        };
+        let _ = writeln!(
+            xml,
+            "    <{name} ref=\"{}\" drivingDir=\"{direction}\"/>",
+            adjacent.reference
+        );
+    }
+}
+
+fn write_position(xml: &mut String, position: Option<&Vec3>) {
+    if let Some(p) = position {
+        xml.push_str("    <position>\n");
+        write_point(xml, 3, p);
+        xml.push_str("    </position>\n");
+    }
+}
@@ -1,6 +1,8 @@
 use wasm_bindgen::prelude::*;
 
 use crate::MapFormatType;
+use crate::commonroad::parser::is_commonroad;
+use crate::lanelet2::parser::is_lanelet2;
 
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
@@ -33,6 +35,12 @@ impl Files {
             if file.name.ends_with(".xodr") {
                 return Ok(MapFormatType::OpenDrive);
             }
+            if file.name.ends_with(".osm") && is_lanelet2(&file.data) {
+                return Ok(MapFormatType::Lanelet2);
+            }
+            if file.name.ends_with(".xml") && is_commonroad(&file.data) {
+                return Ok(MapFormatType::CommonRoad);
+            }
         }
         Err("No format detected".to_string())
     }
@@ -0,0 +1,380 @@
+use anyhow::{Result, anyhow};
+
+use crate::geojson::writer::{GeoJsonFeature, GeoJsonGeometry, write_feature_collection};
+use crate::lanelet2::from_odr::geo_transform;
+use crate::math::vec3::Vec3;
+use crate::odr::models::{
+    lane::{
+        OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection,
+        lane_speed::OdrLaneSpeed,
+    },
+    opendrive::OpenDrive,
+    road::OdrRoad,
+};
+
+/// OpenDRIVE → GeoJSON 导出选项
+#[derive(Debug, Clone, Copy)]
+pub struct OdrToGeoJsonOptions {
+    /// 沿 s 方向的采样步长（米）
+    pub sample_step: f64,
+    /// 为 true 时按 header 的 geoReference 输出 WGS84 经纬度，
+    /// 否则输出 OpenDRIVE 惯性坐标系下的米制坐标
+    pub geographic: bool,
+}
+
+impl Default for OdrToGeoJsonOptions {
+    fn default() -> Self {
+        Self {
+            sample_step: 1.0,
+            geographic: false,
+        }
+    }
+}
+
+/// 将 OpenDRIVE 地图导出为 GeoJSON FeatureCollection
+///
+/// 每个要素带有 `layer` 属性用于在 GIS 中分层：
+/// - `reference_line`：道路参考线 (LineString)
+/// - `lane`：车道面 (Polygon)，含道路 ID、车道段序号、车道 ID、类型和限速 (m/s)
+/// - `road_mark`：车道外边界上的标线 (LineString)，含类型、颜色和宽度
+/// - `object`：有轮廓的对象为 Polygon，否则为 Point
+/// - `signal`：信号 (Point)
+pub fn export_geojson(odr: &OpenDrive, options: &OdrToGeoJsonOptions) -> Result<String> {
+    if options.sample_step <= 0.0 {
+        return Err(anyhow!("sample_step 必须为正数"));
+    }
+
+    let mut features = Vec::new();
+    for road in &odr.roads {
+        export_road(road, options.sample_step, &mut features);
+    }
+
+    if !options.geographic {
+        return Ok(write_feature_collection(&features, 4));
+    }
+
+    let to_geo = geo_transform(odr)?;
+    let to_lon_lat = |p: &mut Vec3| {
+        let (lat, lon) = to_geo(p.x, p.y);
+        p.x = lon;
+        p.y = lat;
+    };
+    for feature in &mut features {
+        match &mut feature.geometry {
+            GeoJsonGeometry::Point(p) => to_lon_lat(p),
+            GeoJsonGeometry::LineString(points) => points.iter_mut().for_each(to_lon_lat),
+            GeoJsonGeometry::Polygon(rings) => rings.iter_mut().flatten().for_each(to_lon_lat),
+        }
+    }
+    Ok(write_feature_collection(&features, 9))
+}
+
+fn export_road(road: &OdrRoad, step: f64, features: &mut Vec<GeoJsonFeature>) {
+    let junction = (road.junction != "-1" && !road.junction.is_empty()).then_some(&road.junction);
+
+    let reference_line = sample_line(0.0, road.length, step, |s| road.sth_to_xyz(s, 0.0, 0.0));
+    features.push(
+        GeoJsonFeature::new(GeoJsonGeometry::LineString(reference_line))
+            .with("layer", "reference_line")
+            .with("road_id", road.id.as_str())
+            .with("name", road.name.clone())
+            .with("length", road.length)
+            .with("junction", junction.cloned()),
+    );
+
+    for (section_idx, section) in road.lanes.iter().enumerate() {
+        let (s_start, s_end) = road.section_range(section_idx);
+        if s_end - s_start < 1e-6 {
+            continue;
+        }
+
+        for lane in section.left.iter().chain(section.right.iter()) {
+            if let Some(polygon) = lane_polygon(road, section, lane, s_start, s_end, step) {
+                features.push(
+                    GeoJsonFeature::new(polygon)
+                        .with("layer", "lane")
+                        .with("road_id", road.id.as_str())
+                        .with("section", section_idx)
+                        .with("lane_id", lane.id)
+                        .with("type", lane.lane_type.as_str())
+                        .with("speed", lane.speed.first().map(OdrLaneSpeed::max_mps)),
+                );
+            }
+        }
+
+        let lanes = std::iter::once(&section.center)
+            .chain(section.left.iter())
+            .chain(section.right.iter());
+        for lane in lanes {
+            export_road_marks(road, section, lane, s_end, step, section_idx, features);
+        }
+    }
+
+    for object in &road.objects {
+        let outlines = object.outline_points(road);
+        let geometries: Vec<GeoJsonGeometry> = if outlines.is_empty() {
+            vec![GeoJsonGeometry::Point(road.sth_to_xyz(
+                object.s,
+                object.t,
+                object.z_offset,
+            ))]
+        } else {
+            outlines
+                .into_iter()
+                .zip(
+                    object
+                        .outlines
+                        .iter()
+                        .map(Some)
+                        .chain(std::iter::repeat(None)),
+                )
+                .filter(|(points, _)| points.len() >= 2)
+                .map(|(points, outline)| {
+                    let closed = outline.and_then(|o| o.closed) != Some(false);
+                    if closed && points.len() >= 3 {
+                        GeoJsonGeometry::Polygon(vec![points])
+                    } else {
+                        GeoJsonGeometry::LineString(points)
+                    }
+                })
+                .collect()
+        };
+
+        for geometry in geometries {
+            features.push(
+                GeoJsonFeature::new(geometry)
+                    .with("layer", "object")
+                    .with("road_id", road.id.as_str())
+                    .with("id", object.id.as_str())
+                    .with("name", object.name.clone())
+                    .with("type", object.object_type.clone())
+                    .with("subtype", object.subtype.clone())
+                    .with("height", object.height),
+            );
+        }
+    }
+
+    for signal in &road.signals {
+        let position = road.sth_to_xyz(signal.s, signal.t, signal.z_offset);
+        features.push(
+            GeoJsonFeature::new(GeoJsonGeometry::Point(position))
+                .with("layer", "signal")
+                .with("road_id", road.id.as_str())
+                .with("id", signal.id.as_str())
+                .with("name", signal.name.clone())
+                .with("type", signal.signal_type.as_str())
+                .with("subtype", signal.subtype.as_str())
+                .with("country", signal.country.clone())
+                .with("value", signal.value)
+                .with("unit", signal.unit.clone())
+                .with("dynamic", signal.dynamic)
+                .with("orientation", signal.orientation.as_str()),
+        );
+    }
+}
+
+/// 车道面多边形，外环按逆时针排列（右侧边界正向 + 左侧边界反向）
+fn lane_polygon(
+    road: &OdrRoad,
+    section: &OdrLaneSection,
+    lane: &OdrLane,
+    s_start: f64,
+    s_end: f64,
+    step: f64,
+) -> Option<GeoJsonGeometry> {
+    let mut inner = Vec::new();
+    let mut outer = Vec::new();
+    let mut has_width = false;
+    for_each_s(s_start, s_end, step, |s| {
+        let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+        has_width |= (t_outer - t_inner).abs() > 1e-6;
+        inner.push(road.sth_to_xyz(s, t_inner, road.eval_shape(s, t_inner)));
+        outer.push(road.sth_to_xyz(s, t_outer, road.eval_shape(s, t_outer)));
+    });
+    if !has_width {
+        return None;
+    }
+
+    let (mut right, left) = if lane.id < 0 {
+        (outer, inner)
+    } else {
+        (inner, outer)
+    };
+    right.extend(left.into_iter().rev());
+    Some(GeoJsonGeometry::Polygon(vec![right]))
+}
+
+/// 车道外边界上的标线，每条 roadMark 记录输出为一条线
+fn export_road_marks(
+    road: &OdrRoad,
+    section: &OdrLaneSection,
+    lane: &OdrLane,
+    s_end: f64,
+    step: f64,
+    section_idx: usize,
+    features: &mut Vec<GeoJsonFeature>,
+) {
+    for (i, mark) in lane.road_marks.iter().enumerate() {
+        if matches!(mark.mark_type, OdrRoadMarkType::None) {
+            continue;
+        }
+        let mark_start = section.s + mark.s_offset;
+        let mark_end = lane
+            .road_marks
+            .get(i + 1)
+            .map(|next| section.s + next.s_offset)
+            .unwrap_or(s_end)
+            .min(s_end);
+        if mark_end - mark_start < 1e-6 {
+            continue;
+        }
+
+        let points = sample_line(mark_start, mark_end, step, |s| {
+            let t = if lane.id == 0 {
+                road.eval_lane_offset(s)
+            } else {
+                road.lane_t_bounds(section, lane, s).1
+            };
+            road.sth_to_xyz(s, t, road.eval_shape(s, t))
+        });
+
+        features.push(
+            GeoJsonFeature::new(GeoJsonGeometry::LineString(points))
+                .with("layer", "road_mark")
+                .with("road_id", road.id.as_str())
+                .with("section", section_idx)
+                .with("lane_id", lane.id)
+                .with("type", mark.mark_type.as_str())
+                .with("color", mark.color.as_str())
+                .with("width", mark.width),
+        );
+    }
+}
+
+fn sample_line(s_start: f64, s_end: f64, step: f64, point: impl Fn(f64) -> Vec3) -> Vec<Vec3> {
+    let mut points = Vec::new();
+    for_each_s(s_start, s_end, step, |s| points.push(point(s)));
+    points
+}
+
+/// 在 [s_start, s_end] 上等距采样，首尾端点都包含在内
+fn for_each_s(s_start: f64, s_end: f64, step: f64, mut f: impl FnMut(f64)) {
+    let num_samples = (((s_end - s_start) / step).ceil() as usize).max(1) + 1;
+    for i in 0..num_samples {
+        let ratio = i as f64 / (num_samples - 1) as f64;
+        f(s_start + ratio * (s_end - s_start));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::{
+            enums::{OdrOrientation, OdrSpeedUnit},
+            header::{OdrHeader, OdrOffset},
+            lane::lane_speed::OdrLaneSpeed,
+            object::OdrObject,
+            signal::OdrSignal,
+        },
+        test_support::{self, line_road},
+    };
+
+    fn lane(id: i32, mark: OdrRoadMarkType) -> OdrLane {
+        let mut lane = OdrLane {
+            speed: vec![OdrLaneSpeed::new(0.0, 36.0, Some(OdrSpeedUnit::KMH))],
+            ..test_support::lane(id, 3.5, mark)
+        };
+        lane.road_marks[0].width = Some(0.12);
+        lane
+    }
+
+    fn map(geo_reference: Option<&str>, offset: Option<OdrOffset>) -> OpenDrive {
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, OdrRoadMarkType::Solid)],
+            vec![lane(-1, OdrRoadMarkType::Broken)],
+            lane(0, OdrRoadMarkType::None),
+            None,
+        );
+        let mut road = line_road("7", 0.0, 0.0, 20.0, vec![section]);
+
+        let mut object = OdrObject::new("o1".to_string(), 5.0, 6.0, 0.0);
+        object.length = Some(2.0);
+        object.width = Some(1.0);
+        road.objects.push(object);
+        road.objects
+            .push(OdrObject::new("o2".to_string(), 8.0, -6.0, 0.0));
+        road.signals.push(OdrSignal::new(
+            "s1".to_string(),
+            10.0,
+            -5.0,
+            false,
+            OdrOrientation::Positive,
+            "274".to_string(),
+            "50".to_string(),
+        ));
+
+        let header = OdrHeader::new(
+            1,
+            6,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            geo_reference.map(str::to_string),
+            offset,
+        );
+        OpenDrive::new(header, vec![road], Vec::new())
+    }
+
+    fn count(json: &str, pattern: &str) -> usize {
+        json.matches(pattern).count()
+    }
+
+    #[test]
+    fn test_export_layers() {
+        let json = export_geojson(&map(None, None), &OdrToGeoJsonOptions::default()).unwrap();
+
+        assert_eq!(count(&json, "\"layer\":\"reference_line\""), 1);
+        assert_eq!(count(&json, "\"layer\":\"lane\""), 2);
+        // 中心线标线为 none，不输出
+        assert_eq!(count(&json, "\"layer\":\"road_mark\""), 2);
+        assert_eq!(count(&json, "\"layer\":\"object\""), 2);
+        assert_eq!(count(&json, "\"layer\":\"signal\""), 1);
+
+        assert!(json.contains(
+            "\"layer\":\"lane\",\"road_id\":\"7\",\"section\":0,\"lane_id\":-1,\"type\":\"driving\",\"speed\":10"
+        ));
+        assert!(json.contains("\"type\":\"broken\",\"color\":\"white\",\"width\":0.12"));
+        // 有尺寸的对象输出为多边形，其余为点
+        assert!(json.contains("{\"type\":\"Polygon\",\"coordinates\":[[[4.0000,5.5000,0.000]"));
+        assert!(json.contains("{\"type\":\"Point\",\"coordinates\":[8.0000,-6.0000,0.000]}"));
+        // 右侧车道多边形：沿右边界 (t = -3.5) 正向出发
+        assert!(json.contains(
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0.0000,-3.5000,0.000],[1.0000,-3.5000,0.000]"
+        ));
+    }
+
+    #[test]
+    fn test_export_geographic() {
+        let odr = map(
+            Some("+proj=utm +zone=32 +ellps=WGS84 +datum=WGS84 +units=m +no_defs"),
+            Some(OdrOffset::new(500000.0, 5316000.0, 0.0, 0.0)),
+        );
+        let options = OdrToGeoJsonOptions {
+            geographic: true,
+            ..Default::default()
+        };
+        let json = export_geojson(&odr, &options).unwrap();
+        // 参考线起点位于 UTM32 中央经线 9°E 附近，坐标顺序为 [lon, lat]
+        assert!(json.contains("\"coordinates\":[[9.0000000"));
+        assert!(json.contains(",47.99"));
+
+        assert!(export_geojson(&map(None, None), &options).is_err());
+    }
+}
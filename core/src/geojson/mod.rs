@@ -0,0 +1,21 @@
+use wasm_bindgen::prelude::*;
+
+use crate::odr::models::opendrive::OpenDrive;
+
+pub mod from_odr;
+pub mod writer;
+
+/// 将 OpenDRIVE 地图导出为 GeoJSON
+///
+/// `geographic` 为 true 时使用 header 的 geoReference 输出 WGS84 经纬度，
+/// 否则输出局部米制坐标。
+#[wasm_bindgen(js_name = exportOpendriveToGeoJson)]
+pub fn export_opendrive_to_geojson(odr: &OpenDrive, geographic: bool) -> Result<Vec<u8>, String> {
+    let options = from_odr::OdrToGeoJsonOptions {
+        geographic,
+        ..Default::default()
+    };
+    from_odr::export_geojson(odr, &options)
+        .map(String::into_bytes)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,159 @@
+use crate::json::{JsonValue, write_string};
+use crate::math::vec3::Vec3;
+
+/// GeoJSON 几何
+///
+/// 坐标按 (x, y, z) 存放；经纬度输出时 x 为经度、y 为纬度。
+#[derive(Debug, Clone)]
+pub enum GeoJsonGeometry {
+    Point(Vec3),
+    LineString(Vec<Vec3>),
+    /// 第一个环为外环，其余为内环；未闭合的环在输出时自动闭合
+    Polygon(Vec<Vec<Vec3>>),
+}
+
+/// GeoJSON 要素，属性按插入顺序输出
+#[derive(Debug, Clone)]
+pub struct GeoJsonFeature {
+    pub geometry: GeoJsonGeometry,
+    pub properties: Vec<(String, JsonValue)>,
+}
+
+impl GeoJsonFeature {
+    pub fn new(geometry: GeoJsonGeometry) -> Self {
+        Self {
+            geometry,
+            properties: Vec::new(),
+        }
+    }
+
+    /// 追加一个属性
+    pub fn with(mut self, key: &str, value: impl Into<JsonValue>) -> Self {
+        self.properties.push((key.to_string(), value.into()));
+        self
+    }
+}
+
+/// 将要素序列化为 FeatureCollection
+///
+/// `precision` 为 x/y 坐标的小数位数，高程固定保留 3 位。
+pub fn write_feature_collection(features: &[GeoJsonFeature], precision: usize) -> String {
+    let mut json = String::new();
+    json.push_str("{\"type\":\"FeatureCollection\",\"features\":[");
+
+    for (i, feature) in features.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str("\n{\"type\":\"Feature\",\"geometry\":");
+        write_geometry(&mut json, &feature.geometry, precision);
+        json.push_str(",\"properties\":{");
+        for (j, (key, value)) in feature.properties.iter().enumerate() {
+            if j > 0 {
+                json.push(',');
+            }
+            write_string(&mut json, key);
+            json.push(':');
+            value.write(&mut json);
+        }
+        json.push_str("}}");
+    }
+
+    json.push_str("\n]}\n");
+    json
+}
+
+fn write_geometry(json: &mut String, geometry: &GeoJsonGeometry, precision: usize) {
+    match geometry {
+        GeoJsonGeometry::Point(p) => {
+            json.push_str("{\"type\":\"Point\",\"coordinates\":");
+            write_position(json, p, precision);
+        }
+        GeoJsonGeometry::LineString(points) => {
+            json.push_str("{\"type\":\"LineString\",\"coordinates\":");
+            write_positions(json, points.iter(), precision);
+        }
+        GeoJsonGeometry::Polygon(rings) => {
+            json.push_str("{\"type\":\"Polygon\",\"coordinates\":[");
+            for (i, ring) in rings.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                // 线性环的首尾坐标必须相同
+                let closing = match (ring.first(), ring.last()) {
+                    (Some(first), Some(last)) if ring.len() > 1 && first != last => Some(first),
+                    _ => None,
+                };
+                write_positions(json, ring.iter().chain(closing), precision);
+            }
+            json.push(']');
+        }
+    }
+    json.push('}');
+}
+
+fn write_positions<'a>(
+    json: &mut String,
+    points: impl Iterator<Item = &'a Vec3>,
+    precision: usize,
+) {
+    json.push('[');
+    for (i, p) in points.enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_position(json, p, precision);
+    }
+    json.push(']');
+}
+
+fn write_position(json: &mut String, p: &Vec3, precision: usize) {
+    json.push('[');
+    write_coordinate(json, p.x, precision);
+    json.push(',');
+    write_coordinate(json, p.y, precision);
+    json.push(',');
+    write_coordinate(json, p.z, 3);
+    json.push(']');
+}
+
+/// 按固定小数位输出坐标，舍入为零的负数不带负号
+fn write_coordinate(json: &mut String, value: f64, precision: usize) {
+    let text = format!("{value:.precision$}");
+    match text.strip_prefix('-') {
+        Some(abs) if abs.bytes().all(|b| b == b'0' || b == b'.') => json.push_str(abs),
+        _ => json.push_str(&text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_feature_collection() {
+        let square = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+        let features = vec![
+            GeoJsonFeature::new(GeoJsonGeometry::Point(Vec3::new(1.5, -0.001, 0.25)))
+                .with("name", "a \"quoted\"\nname")
+                .with("value", None::<f64>)
+                .with("dynamic", true),
+            GeoJsonFeature::new(GeoJsonGeometry::Polygon(vec![square])).with("lane", -1),
+        ];
+
+        let json = write_feature_collection(&features, 2);
+        assert!(json.starts_with("{\"type\":\"FeatureCollection\""));
+        assert!(json.contains("\"coordinates\":[1.50,0.00,0.250]"));
+        assert!(json.contains("\"name\":\"a \\\"quoted\\\"\\nname\""));
+        assert!(json.contains("\"value\":null,\"dynamic\":true"));
+        // 多边形自动闭合
+        assert!(json.contains(
+            "[[[0.00,0.00,0.000],[1.00,0.00,0.000],[1.00,1.00,0.000],[0.00,0.00,0.000]]]"
+        ));
+        assert!(json.contains("\"lane\":-1"));
+    }
+}
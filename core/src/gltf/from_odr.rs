@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use crate::gltf::writer::{GltfMaterial, GltfNode, GltfPrimitive, write_glb};
+use crate::json::JsonValue;
+use crate::math::frame::MeshFrame;
+use crate::odr::mesh::{
+    export::lane_type_color,
+    lane_builder::LaneMeshBuilder,
+    object_builder::{ObjectMeshBuilder, ObjectMeshKind},
+    road_mark_builder::RoadMarkMeshBuilder,
+    road_mark_profile::RoadMarkProfile,
+};
+use crate::odr::models::opendrive::OpenDrive;
+
+/// OpenDRIVE → GLB 导出选项
+#[derive(Debug, Clone, Copy)]
+pub struct OdrToGlbOptions {
+    /// 车道面沿 s 方向的采样步长（米）
+    pub lane_sample_step: f64,
+    /// 标线沿 s 方向的采样步长（米）
+    pub mark_sample_step: f64,
+    pub include_road_marks: bool,
+    pub include_objects: bool,
+}
+
+impl Default for OdrToGlbOptions {
+    fn default() -> Self {
+        Self {
+            lane_sample_step: 1.0,
+            mark_sample_step: 0.2,
+            include_road_marks: true,
+            include_objects: true,
+        }
+    }
+}
+
+/// 按名称去重的材质表
+#[derive(Default)]
+struct Materials {
+    materials: Vec<GltfMaterial>,
+    index: HashMap<String, usize>,
+}
+
+impl Materials {
+    fn get(&mut self, name: &str, create: impl FnOnce() -> GltfMaterial) -> usize {
+        if let Some(&i) = self.index.get(name) {
+            return i;
+        }
+        self.materials.push(create());
+        self.index
+            .insert(name.to_string(), self.materials.len() - 1);
+        self.materials.len() - 1
+    }
+
+    fn lane(&mut self, lane_type: &str) -> usize {
+        let name = format!("lane-{}", lane_type);
+        self.get(&name, || {
+            GltfMaterial::from_srgb(&name, lane_type_color(lane_type), 0.2, 0.6)
+        })
+    }
+
+    fn road_mark(&mut self, rgb: u32) -> usize {
+        let name = format!("roadmark-{:06x}", rgb);
+        self.get(&name, || GltfMaterial::from_srgb(&name, rgb, 0.1, 0.4))
+    }
+
+    /// 对象网格的材质：标记沿用标线颜色，边框与实体各用一种灰色
+    fn object(&mut self, kind: ObjectMeshKind, rgb: Option<u32>) -> usize {
+        match (kind, rgb) {
+            (ObjectMeshKind::Marking, Some(rgb)) => self.road_mark(rgb),
+            (ObjectMeshKind::Border, _) => self.get("object-border", || {
+                GltfMaterial::from_srgb("object-border", 0x777777, 0.1, 0.9)
+            }),
+            _ => self.get("object", || {
+                GltfMaterial::from_srgb("object", 0x999999, 0.1, 0.8)
+            }),
+        }
+    }
+}
+
+/// 将 OpenDRIVE 地图导出为 GLB
+///
+/// 车道面、标线和对象分别由 `LaneMeshBuilder`、`RoadMarkMeshBuilder`、
+/// `ObjectMeshBuilder` 构建，节点层级为 road > laneSection > lane，
+/// 对象挂在 road 下的 objects 节点，每个对象网格一个 primitive。
+/// 材质按车道类型、对象网格类型和标线的 sRGB 颜色（按道路国家的标线配置解析）分配，
+/// 各元素的 ID 写入节点 extras。
+/// 顶点坐标为相对地图中心的 Y-up 坐标，中心点记录在根节点 extras 中。
+pub fn export_glb(odr: &OpenDrive, options: &OdrToGlbOptions) -> Result<Vec<u8>> {
+    if options.lane_sample_step <= 0.0 || options.mark_sample_step <= 0.0 {
+        return Err(anyhow!("采样步长必须为正数"));
+    }
+
+    let center = odr.center();
+    let lane_builder = LaneMeshBuilder::new(Some(options.lane_sample_step), Some(center));
+    let mark_builder = RoadMarkMeshBuilder::new(Some(options.mark_sample_step), Some(center));
+    let object_builder = ObjectMeshBuilder::with_frame(None, &MeshFrame::webgl(Some(center)));
+    let mut materials = Materials::default();
+
+    let mut root = GltfNode::new(
+        odr.header.name.clone().unwrap_or_else(|| "map".to_string()),
+        JsonValue::object([("center", vec![center.x, center.y, center.z].into())]),
+    );
+
+    for road in &odr.roads {
+        let mut road_node = GltfNode::new(
+            format!("road {}", road.id),
+            JsonValue::object([
+                ("roadId", road.id.as_str().into()),
+                ("name", road.name.clone().into()),
+                ("junction", road.junction.as_str().into()),
+                ("length", road.length.into()),
+            ]),
+        );
+
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            let mut section_node = GltfNode::new(
+                format!("laneSection {}", section_idx),
+                JsonValue::object([
+                    ("roadId", road.id.as_str().into()),
+                    ("laneSection", section_idx.into()),
+                    ("s", section.s.into()),
+                ]),
+            );
+
+            let lanes = section
+                .left
+                .iter()
+                .chain(std::iter::once(&section.center))
+                .chain(section.right.iter());
+            for lane in lanes {
+                let mut lane_node = GltfNode::new(
+                    format!("lane {}", lane.id),
+                    JsonValue::object([
+                        ("roadId", road.id.as_str().into()),
+                        ("laneSection", section_idx.into()),
+                        ("laneId", lane.id.into()),
+                        ("type", lane.lane_type.as_str().into()),
+                    ]),
+                );
+
+                let surface = lane_builder.build_lane_mesh(road, section, lane, s_start, s_end);
+                if !surface.indices.is_empty() {
+                    lane_node.primitives.push(GltfPrimitive {
+                        mesh: surface,
+                        material: materials.lane(&lane.lane_type),
+                    });
+                }
+
+                if options.include_road_marks {
+                    let marks =
+                        mark_builder.build_lane_road_marks(road, section, lane, s_start, s_end);
+                    for item in marks.into_items() {
+                        let (mesh, _, rgb) = item.into_parts();
+                        lane_node.primitives.push(GltfPrimitive {
+                            mesh,
+                            material: materials.road_mark(rgb),
+                        });
+                    }
+                }
+
+                section_node.children.push(lane_node);
+            }
+            road_node.children.push(section_node);
+        }
+
+        if options.include_objects {
+            let mut objects_node = GltfNode::new("objects".to_string(), JsonValue::Null);
+            for object in &road.objects {
+                let items = object_builder
+                    .build_object_meshes(road, object)
+                    .into_items();
+                if items.is_empty() {
+                    continue;
+                }
+                let mut object_node = GltfNode::new(
+                    format!("object {}", object.id),
+                    JsonValue::object([
+                        ("roadId", road.id.as_str().into()),
+                        ("objectId", object.id.as_str().into()),
+                        ("name", object.name.clone().into()),
+                        ("type", object.object_type.clone().into()),
+                        ("subtype", object.subtype.clone().into()),
+                    ]),
+                );
+                let profile = RoadMarkProfile::for_road(road, object.s);
+                for item in items {
+                    let (mesh, kind, color) = item.into_parts();
+                    let rgb = color.map(|color| profile.color_rgb(color));
+                    object_node.primitives.push(GltfPrimitive {
+                        mesh,
+                        material: materials.object(kind, rgb),
+                    });
+                }
+                objects_node.children.push(object_node);
+            }
+            road_node.children.push(objects_node);
+        }
+
+        root.children.push(road_node);
+    }
+
+    Ok(write_glb(&[root], &materials.materials))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gltf::writer::tests::split_glb;
+    use crate::odr::{
+        models::{
+            enums::OdrRoadMarkColor,
+            lane::{OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+            object::{OdrMarking, OdrObject, OdrSideType},
+        },
+        test_support::{self, header, line_road},
+    };
+
+    fn lane(id: i32, lane_type: &str, color: OdrRoadMarkColor) -> OdrLane {
+        let width = if id == 0 { 0.0 } else { 3.5 };
+        let mut lane = OdrLane {
+            lane_type: lane_type.to_string(),
+            ..test_support::lane(id, width, OdrRoadMarkType::Solid)
+        };
+        lane.road_marks[0].color = color;
+        lane
+    }
+
+    fn map() -> OpenDrive {
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, "sidewalk", OdrRoadMarkColor::White)],
+            vec![lane(-1, "driving", OdrRoadMarkColor::White)],
+            lane(0, "none", OdrRoadMarkColor::Yellow),
+            None,
+        );
+        let mut road = line_road("12", 0.0, 0.0, 10.0, vec![section]);
+        let mut object = OdrObject::new("box".to_string(), 5.0, 8.0, 0.0);
+        object.length = Some(2.0);
+        object.width = Some(1.0);
+        object.height = Some(1.5);
+        object.markings.push(OdrMarking::new(
+            OdrRoadMarkColor::Blue,
+            1.0,
+            0.0,
+            OdrSideType::Front,
+            0.0,
+            0.0,
+        ));
+        road.objects.push(object);
+        // 无轮廓的圆柱代理体
+        let mut pole = OdrObject::new("pole".to_string(), 2.0, -4.0, 0.0);
+        pole.radius = Some(0.1);
+        pole.height = Some(3.0);
+        road.objects.push(pole);
+
+        OpenDrive::new(header(), vec![road], Vec::new())
+    }
+
+    #[test]
+    fn test_export_glb_hierarchy() {
+        let glb = export_glb(&map(), &OdrToGlbOptions::default()).unwrap();
+        let (json, bin) = split_glb(&glb);
+        assert!(!bin.is_empty());
+
+        assert!(json.contains(r#""name":"road 12""#));
+        assert!(json.contains(r#""name":"laneSection 0""#));
+        assert!(
+            json.contains(
+                r#""extras":{"roadId":"12","laneSection":0,"laneId":-1,"type":"driving"}"#
+            )
+        );
+        assert!(json.contains(r#""objectId":"box""#));
+
+        // 材质按车道类型和标线颜色去重
+        for name in [
+            "lane-driving",
+            "lane-sidewalk",
+            "roadmark-ffffff",
+            "roadmark-ffcc00",
+            "roadmark-0066cc",
+            "object",
+        ] {
+            assert_eq!(json.matches(&format!(r#""name":"{}""#, name)).count(), 1);
+        }
+        // 中心线只有标线，没有车道面
+        assert!(!json.contains("lane-none"));
+    }
+
+    #[test]
+    fn test_object_primitives() {
+        let primitives = |options: &OdrToGlbOptions| {
+            let glb = export_glb(&map(), options).unwrap();
+            let (json, _) = split_glb(&glb);
+            json.matches(r#""attributes":"#).count()
+        };
+        let without_objects = OdrToGlbOptions {
+            include_objects: false,
+            ..Default::default()
+        };
+
+        // 包围盒、其前边标记和无轮廓的圆柱各一个 primitive
+        let count = primitives(&OdrToGlbOptions::default()) - primitives(&without_objects);
+        assert_eq!(count, 3);
+
+        let glb = export_glb(&map(), &OdrToGlbOptions::default()).unwrap();
+        let (json, _) = split_glb(&glb);
+        assert!(json.contains(r#""objectId":"pole""#));
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let options = OdrToGlbOptions {
+            lane_sample_step: 0.0,
+            ..Default::default()
+        };
+        assert!(export_glb(&map(), &options).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use wasm_bindgen::prelude::*;
+
+use crate::odr::models::opendrive::OpenDrive;
+
+pub mod from_odr;
+pub mod writer;
+
+/// 将 OpenDRIVE 地图导出为 GLB（二进制 glTF 2.0）
+#[wasm_bindgen(js_name = exportOpendriveToGlb)]
+pub fn export_opendrive_to_glb(odr: &OpenDrive) -> Result<Vec<u8>, String> {
+    from_odr::export_glb(odr, &from_odr::OdrToGlbOptions::default()).map_err(|e| e.to_string())
+}
@@ -0,0 +1,388 @@
+use crate::json::JsonValue;
+use crate::math::mesh::MeshData;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const COMPONENT_FLOAT: u32 = 5126;
+const COMPONENT_UNSIGNED_SHORT: u32 = 5123;
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+
+/// PBR 金属度-粗糙度材质
+#[derive(Debug, Clone)]
+pub struct GltfMaterial {
+    pub name: String,
+    /// 线性空间的 RGBA 颜色
+    pub base_color: [f32; 4],
+    pub metallic: f32,
+    pub roughness: f32,
+}
+
+impl GltfMaterial {
+    /// 由 sRGB 十六进制颜色（如 0x555555）创建不透明材质
+    pub fn from_srgb(name: &str, hex: u32, metallic: f32, roughness: f32) -> Self {
+        let channel = |shift: u32| srgb_to_linear(((hex >> shift) & 0xff) as f32 / 255.0);
+        Self {
+            name: name.to_string(),
+            base_color: [channel(16), channel(8), channel(0), 1.0],
+            metallic,
+            roughness,
+        }
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// 网格图元，`material` 为材质列表中的索引
+#[derive(Debug, Clone)]
+pub struct GltfPrimitive {
+    pub mesh: MeshData,
+    pub material: usize,
+}
+
+/// 场景节点
+///
+/// 节点的图元合并为一个 glTF mesh，`extras` 原样写入节点的 extras。
+#[derive(Debug, Clone)]
+pub struct GltfNode {
+    pub name: String,
+    pub primitives: Vec<GltfPrimitive>,
+    pub children: Vec<GltfNode>,
+    pub extras: JsonValue,
+}
+
+impl GltfNode {
+    pub fn new(name: String, extras: JsonValue) -> Self {
+        Self {
+            name,
+            primitives: Vec::new(),
+            children: Vec::new(),
+            extras,
+        }
+    }
+
+    /// 节点及其子节点是否不包含任何图元
+    pub fn is_empty(&self) -> bool {
+        self.primitives.is_empty() && self.children.iter().all(GltfNode::is_empty)
+    }
+}
+
+/// 输出 glTF 时的中间状态：JSON 数组与二进制缓冲区
+#[derive(Default)]
+struct GltfDocument {
+    nodes: Vec<JsonValue>,
+    meshes: Vec<JsonValue>,
+    accessors: Vec<JsonValue>,
+    buffer_views: Vec<JsonValue>,
+    bin: Vec<u8>,
+}
+
+impl GltfDocument {
+    fn add_buffer_view(&mut self, bytes: &[u8], target: u32) -> usize {
+        while !self.bin.len().is_multiple_of(4) {
+            self.bin.push(0);
+        }
+        self.buffer_views.push(JsonValue::object([
+            ("buffer", 0.into()),
+            ("byteOffset", self.bin.len().into()),
+            ("byteLength", bytes.len().into()),
+            ("target", target.into()),
+        ]));
+        self.bin.extend_from_slice(bytes);
+        self.buffer_views.len() - 1
+    }
+
+    fn add_vec2_accessor(&mut self, values: &[f32]) -> usize {
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let view = self.add_buffer_view(&bytes, ARRAY_BUFFER);
+        self.accessors.push(JsonValue::object([
+            ("bufferView", view.into()),
+            ("componentType", COMPONENT_FLOAT.into()),
+            ("count", (values.len() / 2).into()),
+            ("type", "VEC2".into()),
+        ]));
+        self.accessors.len() - 1
+    }
+
+    fn add_vec3_accessor(&mut self, values: &[f32], with_bounds: bool) -> usize {
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let view = self.add_buffer_view(&bytes, ARRAY_BUFFER);
+
+        let (min, max) = if with_bounds {
+            let mut min = [f32::MAX; 3];
+            let mut max = [f32::MIN; 3];
+            for v in values.chunks_exact(3) {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(v[axis]);
+                    max[axis] = max[axis].max(v[axis]);
+                }
+            }
+            (Some(min.to_vec()), Some(max.to_vec()))
+        } else {
+            (None, None)
+        };
+
+        self.accessors.push(JsonValue::object([
+            ("bufferView", view.into()),
+            ("componentType", COMPONENT_FLOAT.into()),
+            ("count", (values.len() / 3).into()),
+            ("type", "VEC3".into()),
+            ("min", min.into()),
+            ("max", max.into()),
+        ]));
+        self.accessors.len() - 1
+    }
+
+    /// 索引均在 u16 范围内时写为 UNSIGNED_SHORT，否则为 UNSIGNED_INT
+    fn add_index_accessor(&mut self, indices: &[u32]) -> usize {
+        let (bytes, component_type): (Vec<u8>, u32) =
+            if indices.iter().all(|&i| i <= u16::MAX as u32) {
+                let bytes = indices
+                    .iter()
+                    .flat_map(|&i| (i as u16).to_le_bytes())
+                    .collect();
+                (bytes, COMPONENT_UNSIGNED_SHORT)
+            } else {
+                let bytes = indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+                (bytes, COMPONENT_UNSIGNED_INT)
+            };
+        let view = self.add_buffer_view(&bytes, ELEMENT_ARRAY_BUFFER);
+        self.accessors.push(JsonValue::object([
+            ("bufferView", view.into()),
+            ("componentType", component_type.into()),
+            ("count", indices.len().into()),
+            ("type", "SCALAR".into()),
+        ]));
+        self.accessors.len() - 1
+    }
+
+    /// 深度优先写入节点，返回节点索引
+    fn add_node(&mut self, node: &GltfNode) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(JsonValue::Null);
+
+        let primitives: Vec<JsonValue> = node
+            .primitives
+            .iter()
+            .filter(|p| !p.mesh.indices.is_empty())
+            .map(|p| {
+                let position = self.add_vec3_accessor(&p.mesh.vertices, true);
+                let normal = self.add_vec3_accessor(&p.mesh.normals, false);
+                let uv = (!p.mesh.uvs.is_empty()).then(|| self.add_vec2_accessor(&p.mesh.uvs));
+                let indices = self.add_index_accessor(&p.mesh.indices);
+                JsonValue::object([
+                    (
+                        "attributes",
+                        JsonValue::object([
+                            ("POSITION", position.into()),
+                            ("NORMAL", normal.into()),
+                            ("TEXCOORD_0", uv.into()),
+                        ]),
+                    ),
+                    ("indices", indices.into()),
+                    ("material", p.material.into()),
+                ])
+            })
+            .collect();
+
+        let mesh = (!primitives.is_empty()).then(|| {
+            self.meshes.push(JsonValue::object([
+                ("name", node.name.as_str().into()),
+                ("primitives", JsonValue::Array(primitives)),
+            ]));
+            self.meshes.len() - 1
+        });
+
+        let children: Vec<usize> = node
+            .children
+            .iter()
+            .filter(|c| !c.is_empty())
+            .map(|c| self.add_node(c))
+            .collect();
+
+        self.nodes[index] = JsonValue::object([
+            ("name", node.name.as_str().into()),
+            ("mesh", mesh.into()),
+            (
+                "children",
+                (!children.is_empty()).then_some(children).into(),
+            ),
+            ("extras", node.extras.clone()),
+        ]);
+        index
+    }
+}
+
+/// 将节点树编码为二进制 glTF 2.0 (GLB)
+///
+/// 不含任何图元的节点分支会被省略。
+pub fn write_glb(roots: &[GltfNode], materials: &[GltfMaterial]) -> Vec<u8> {
+    let mut doc = GltfDocument::default();
+    let scene_nodes: Vec<usize> = roots
+        .iter()
+        .filter(|n| !n.is_empty())
+        .map(|n| doc.add_node(n))
+        .collect();
+    while !doc.bin.len().is_multiple_of(4) {
+        doc.bin.push(0);
+    }
+
+    let materials: Vec<JsonValue> = materials
+        .iter()
+        .map(|m| {
+            JsonValue::object([
+                ("name", m.name.as_str().into()),
+                (
+                    "pbrMetallicRoughness",
+                    JsonValue::object([
+                        ("baseColorFactor", m.base_color.to_vec().into()),
+                        ("metallicFactor", m.metallic.into()),
+                        ("roughnessFactor", m.roughness.into()),
+                    ]),
+                ),
+                ("doubleSided", true.into()),
+            ])
+        })
+        .collect();
+
+    let empty = |items: Vec<JsonValue>| (!items.is_empty()).then_some(JsonValue::Array(items));
+    let has_bin = !doc.bin.is_empty();
+    let json = JsonValue::object([
+        (
+            "asset",
+            JsonValue::object([("version", "2.0".into()), ("generator", "maphub".into())]),
+        ),
+        ("scene", 0.into()),
+        (
+            "scenes",
+            JsonValue::Array(vec![JsonValue::object([("nodes", scene_nodes.into())])]),
+        ),
+        ("nodes", empty(doc.nodes).into()),
+        ("meshes", empty(doc.meshes).into()),
+        ("materials", empty(materials).into()),
+        ("accessors", empty(doc.accessors).into()),
+        ("bufferViews", empty(doc.buffer_views).into()),
+        (
+            "buffers",
+            has_bin
+                .then(|| {
+                    JsonValue::Array(vec![JsonValue::object([(
+                        "byteLength",
+                        doc.bin.len().into(),
+                    )])])
+                })
+                .into(),
+        ),
+    ]);
+
+    let mut json = json.to_json().into_bytes();
+    while !json.len().is_multiple_of(4) {
+        json.push(b' ');
+    }
+
+    let bin_chunk_len = if has_bin { 8 + doc.bin.len() } else { 0 };
+    let total = 12 + 8 + json.len() + bin_chunk_len;
+
+    let mut glb = Vec::with_capacity(total);
+    glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total as u32).to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&CHUNK_JSON.to_le_bytes());
+    glb.extend_from_slice(&json);
+    if has_bin {
+        glb.extend_from_slice(&(doc.bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&CHUNK_BIN.to_le_bytes());
+        glb.extend_from_slice(&doc.bin);
+    }
+    glb
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// 拆分 GLB，返回 (JSON 文本, 二进制块)
+    pub(crate) fn split_glb(glb: &[u8]) -> (String, Vec<u8>) {
+        let u32_at = |i: usize| u32::from_le_bytes(glb[i..i + 4].try_into().unwrap());
+        assert_eq!(u32_at(0), GLB_MAGIC);
+        assert_eq!(u32_at(4), 2);
+        assert_eq!(u32_at(8) as usize, glb.len());
+
+        let json_len = u32_at(12) as usize;
+        assert_eq!(u32_at(16), CHUNK_JSON);
+        let json = String::from_utf8(glb[20..20 + json_len].to_vec()).unwrap();
+
+        let bin_start = 20 + json_len;
+        let bin = if bin_start < glb.len() {
+            assert_eq!(u32_at(bin_start + 4), CHUNK_BIN);
+            let len = u32_at(bin_start) as usize;
+            glb[bin_start + 8..bin_start + 8 + len].to_vec()
+        } else {
+            Vec::new()
+        };
+        (json, bin)
+    }
+
+    fn triangle() -> MeshData {
+        let vertices = vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1.0];
+        let indices = vec![0, 1, 2];
+        let normals = MeshData::calculate_normals(&vertices, &indices, [0.0, 1.0, 0.0]);
+        MeshData::new(vertices, indices, normals)
+    }
+
+    #[test]
+    fn test_write_glb() {
+        let mut child = GltfNode::new("lane".to_string(), JsonValue::object([("id", 1.into())]));
+        child.primitives.push(GltfPrimitive {
+            mesh: triangle(),
+            material: 0,
+        });
+        let mut root = GltfNode::new("road".to_string(), JsonValue::Null);
+        root.children.push(child);
+        root.children
+            .push(GltfNode::new("empty".to_string(), JsonValue::Null));
+
+        let material = GltfMaterial::from_srgb("white", 0xffffff, 0.0, 1.0);
+        let glb = write_glb(&[root], &[material]);
+        let (json, bin) = split_glb(&glb);
+
+        assert_eq!(json.len() % 4, 0);
+        // 3 个位置 + 3 个法线 (各 36 字节) + 3 个 u16 索引 (对齐到 4)
+        assert_eq!(bin.len(), 36 + 36 + 8);
+        assert!(json.contains(
+            r#""nodes":[{"name":"road","children":[1]},{"name":"lane","mesh":0,"extras":{"id":1}}]"#
+        ));
+        assert!(json.contains(r#""min":[0,0,-1],"max":[1,0,0]"#));
+        assert!(json.contains(r#""baseColorFactor":[1,1,1,1]"#));
+        assert!(!json.contains("empty"));
+    }
+
+    #[test]
+    fn test_write_glb_u32_indices() {
+        let count = 70000u32;
+        let vertices = vec![0.0; count as usize * 3];
+        let normals = vertices.clone();
+        let indices = vec![0, 1, count - 1];
+        let mut node = GltfNode::new("large".to_string(), JsonValue::Null);
+        node.primitives.push(GltfPrimitive {
+            mesh: MeshData::new(vertices, indices, normals),
+            material: 0,
+        });
+
+        let glb = write_glb(&[node], &[]);
+        let (json, bin) = split_glb(&glb);
+        assert!(json.contains(r#""componentType":5125,"count":3"#));
+        let tail = &bin[bin.len() - 4..];
+        assert_eq!(u32::from_le_bytes(tail.try_into().unwrap()), count - 1);
+    }
+}
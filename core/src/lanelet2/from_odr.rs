@@ -0,0 +1,704 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use crate::lanelet2::models::{
+    Lanelet2Lanelet, Lanelet2LineString, Lanelet2Map, Lanelet2Member, Lanelet2RegulatoryElement,
+    Lanelet2Tag,
+};
+use crate::lanelet2::parser::{connect_lanelets, tag_value};
+use crate::lanelet2::writer::{OsmFrame, write_osm};
+use crate::math::geo::TransverseMercator;
+use crate::math::vec3::Vec3;
+use crate::odr::mesh::{cross_section::lane_speed, road_mark_polyline::active_road_mark};
+use crate::odr::models::{
+    enums::{OdrRoadMarkColor, OdrRoadMarkWeight},
+    lane::{OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+    opendrive::{LaneKey, OpenDrive},
+    road::OdrRoad,
+};
+
+/// OpenDRIVE → Lanelet2 转换选项
+#[derive(Debug, Clone, Copy)]
+pub struct OdrToLanelet2Options {
+    /// 沿车道边界采样的步长（米）
+    pub sample_step: f64,
+    /// 输出经纬度（需要 header 中的 geoReference），否则输出 local_x/local_y
+    pub geographic: bool,
+}
+
+impl Default for OdrToLanelet2Options {
+    fn default() -> Self {
+        Self {
+            sample_step: 1.0,
+            geographic: false,
+        }
+    }
+}
+
+/// 车道段分段内的车道边界：(道路索引, 车道段索引, 分段索引, 边界编号)
+///
+/// 边界编号 0 为中心线，其余与外侧为该边界的车道 ID 相同。
+type BoundaryKey = (usize, usize, usize, i32);
+
+/// 转换过程中的 lanelet
+struct LaneletDraft {
+    key: LaneKey,
+    left: usize,
+    right: usize,
+    /// 左侧车道沿 -s 行驶，边界需反转
+    reversed: bool,
+    speed_limit: Option<f64>,
+    location: &'static str,
+}
+
+/// 将 OpenDRIVE 地图转换为 Lanelet2 地图
+///
+/// 车道段在限速和标线记录的 sOffset 处切分，每条 driving 车道在每个分段
+/// 生成一个 lanelet，同一分段内相邻车道共用边界线串，线串的
+/// `type`/`subtype` 由分段内生效的标线推导。车道连接处的端点合并为
+/// 同一节点，使 lanelet 的前后继关系与 OpenDRIVE 车道拓扑一致；车道限速
+/// 转换为 speed_limit 交通规则。坐标保持为 OpenDRIVE 惯性坐标系。
+pub fn opendrive_to_lanelet2(
+    odr: &OpenDrive,
+    options: &OdrToLanelet2Options,
+) -> Result<Lanelet2Map> {
+    if options.sample_step <= 0.0 {
+        return Err(anyhow!("sample_step 必须为正数"));
+    }
+
+    let road_index: HashMap<&str, usize> = odr
+        .roads
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (r.id.as_str(), i))
+        .collect();
+
+    let mut nodes: Vec<Vec3> = Vec::new();
+    // 每条边界的节点下标和标签
+    let mut ways: Vec<(Vec<usize>, Vec<Lanelet2Tag>)> = Vec::new();
+    let mut boundary_index: HashMap<BoundaryKey, usize> = HashMap::new();
+    let mut drafts: Vec<LaneletDraft> = Vec::new();
+    // 车道 → 序号，每个序号对应该车道沿 +s 的 (分段索引, lanelet 下标)
+    let mut lane_index: HashMap<LaneKey, usize> = HashMap::new();
+    let mut lane_pieces: Vec<Vec<(usize, usize)>> = Vec::new();
+
+    // 1. 车道边界与 lanelet
+    for (road_idx, road) in odr.roads.iter().enumerate() {
+        let location = match road.road_types.first().map(|t| t.road_type.as_str()) {
+            Some("town") | Some("lowSpeed") | Some("pedestrian") | None => "urban",
+            _ => "nonurban",
+        };
+
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            if s_end - s_start < 1e-6 {
+                continue;
+            }
+            for (piece_idx, (p_start, p_end)) in section_pieces(section, s_start, s_end)
+                .into_iter()
+                .enumerate()
+            {
+                let num_samples =
+                    (((p_end - p_start) / options.sample_step).ceil() as usize).max(1) + 1;
+                let samples: Vec<f64> = (0..num_samples)
+                    .map(|i| p_start + (p_end - p_start) * i as f64 / (num_samples - 1) as f64)
+                    .collect();
+
+                for lane in section.left.iter().chain(section.right.iter()) {
+                    if lane.lane_type != "driving" {
+                        continue;
+                    }
+                    let widths: Vec<f64> = samples
+                        .iter()
+                        .map(|&s| lane.eval_width(s - section.s).abs())
+                        .collect();
+                    if widths.iter().all(|w| *w < 5e-4) {
+                        continue;
+                    }
+
+                    let step = lane.id.signum();
+                    let mut boundary = |b: i32| {
+                        *boundary_index
+                            .entry((road_idx, section_idx, piece_idx, b))
+                            .or_insert_with(|| {
+                                let ids = samples
+                                    .iter()
+                                    .map(|&s| {
+                                        let t = boundary_t(road, section, b, s);
+                                        nodes.push(road.sth_to_xyz(s, t, road.eval_shape(s, t)));
+                                        nodes.len() - 1
+                                    })
+                                    .collect();
+                                ways.push((ids, boundary_tags(section, b, p_start)));
+                                ways.len() - 1
+                            })
+                    };
+                    let inner = boundary(lane.id - step);
+                    let outer = boundary(lane.id);
+
+                    let key = (road_idx, section_idx, lane.id);
+                    let ordinal = *lane_index.entry(key).or_insert_with(|| {
+                        lane_pieces.push(Vec::new());
+                        lane_pieces.len() - 1
+                    });
+                    lane_pieces[ordinal].push((piece_idx, drafts.len()));
+
+                    // 驾驶员左侧始终是内边界
+                    drafts.push(LaneletDraft {
+                        key,
+                        left: inner,
+                        right: outer,
+                        reversed: lane.id > 0,
+                        speed_limit: lane_speed(section, lane, p_start)
+                            .map(|speed| (speed * 36.0).round() / 10.0),
+                        location,
+                    });
+                }
+            }
+        }
+    }
+
+    // 2. 沿车流方向合并连接处的端点
+    let mut parent: Vec<usize> = (0..nodes.len()).collect();
+    let endpoints = |draft: &LaneletDraft, way: usize, at_end: bool| {
+        let ids = &ways[way].0;
+        if at_end != draft.reversed {
+            ids[ids.len() - 1]
+        } else {
+            ids[0]
+        }
+    };
+    // 车道在连接处的 lanelet：沿 +s 的最后一段或第一段
+    let contact = |ordinal: usize, at_end: bool| {
+        let pieces = &lane_pieces[ordinal];
+        let reversed = drafts[pieces[0].1].reversed;
+        if at_end != reversed {
+            pieces[pieces.len() - 1].1
+        } else {
+            pieces[0].1
+        }
+    };
+    let mut flows: Vec<(usize, usize)> = Vec::new();
+    for pieces in &lane_pieces {
+        for pair in pieces.windows(2) {
+            let ((piece_a, a), (piece_b, b)) = (pair[0], pair[1]);
+            if piece_b != piece_a + 1 {
+                continue;
+            }
+            flows.push(if drafts[a].reversed { (b, a) } else { (a, b) });
+        }
+    }
+    for (from, to) in odr.lane_flows(&road_index, &lane_index) {
+        flows.push((contact(from, true), contact(to, false)));
+    }
+    for (from, to) in flows {
+        let (a, b) = (&drafts[from], &drafts[to]);
+        for (from_node, to_node) in [
+            (endpoints(a, a.left, true), endpoints(b, b.left, false)),
+            (endpoints(a, a.right, true), endpoints(b, b.right, false)),
+        ] {
+            let root_from = find(&mut parent, from_node);
+            let root_to = find(&mut parent, to_node);
+            if root_from != root_to {
+                parent[root_to] = root_from;
+            }
+        }
+    }
+
+    // 3. 分配 ID：节点、线串、交通规则、lanelet 依次编号，保证全局唯一
+    let mut node_ids = vec![0i64; nodes.len()];
+    let mut next_id = 1i64;
+    for (i, node_id) in node_ids.iter_mut().enumerate() {
+        if find(&mut parent, i) == i {
+            *node_id = next_id;
+            next_id += 1;
+        }
+    }
+
+    let mut line_strings = Vec::with_capacity(ways.len());
+    for (node_list, tags) in &ways {
+        let mut point_ids: Vec<i64> = Vec::with_capacity(node_list.len());
+        let mut points = Vec::with_capacity(node_list.len());
+        for &n in node_list {
+            let root = find(&mut parent, n);
+            if point_ids.last() != Some(&node_ids[root]) {
+                point_ids.push(node_ids[root]);
+                points.push(nodes[root]);
+            }
+        }
+
+        line_strings.push(Lanelet2LineString {
+            id: next_id,
+            line_type: tag_value(tags, "type").unwrap_or_default().to_string(),
+            subtype: tag_value(tags, "subtype").map(str::to_string),
+            points,
+            point_ids,
+            tags: tags.clone(),
+        });
+        next_id += 1;
+    }
+
+    // 4. 限速交通规则：相同限速共用一个规则，每个 lanelet 起点处生成一个
+    //    traffic_sign 线串作为规则的 refers 成员
+    let mut regulatory_elements: Vec<Lanelet2RegulatoryElement> = Vec::new();
+    let mut speed_elements: HashMap<String, usize> = HashMap::new();
+    let mut lanelets = Vec::with_capacity(drafts.len());
+
+    for draft in &drafts {
+        let orient = |way: usize| {
+            let ls = &line_strings[way];
+            if draft.reversed {
+                ls.reversed()
+            } else {
+                ls.clone()
+            }
+        };
+        let left = orient(draft.left);
+        let right = orient(draft.right);
+        let (road_idx, section_idx, lane_id) = draft.key;
+
+        let mut tags = vec![
+            tag("type", "lanelet"),
+            tag("subtype", "road"),
+            tag("location", draft.location),
+            tag("one_way", "yes"),
+            tag("participant:vehicle", "yes"),
+        ];
+
+        let mut regulatory_refs = Vec::new();
+        if let Some(speed) = draft.speed_limit {
+            let value = format!("{} km/h", speed);
+            tags.push(tag("speed_limit", &value));
+
+            let sign_tags = vec![tag("type", "traffic_sign"), tag("subtype", "speed_limit")];
+            let sign = Lanelet2LineString {
+                id: next_id,
+                line_type: "traffic_sign".to_string(),
+                subtype: Some("speed_limit".to_string()),
+                points: vec![left.points[0], right.points[0]],
+                point_ids: vec![left.point_ids[0], right.point_ids[0]],
+                tags: sign_tags,
+            };
+            next_id += 1;
+
+            let re_idx = *speed_elements.entry(value.clone()).or_insert_with(|| {
+                regulatory_elements.push(Lanelet2RegulatoryElement {
+                    id: next_id,
+                    subtype: Some("speed_limit".to_string()),
+                    members: Vec::new(),
+                    tags: vec![
+                        tag("type", "regulatory_element"),
+                        tag("subtype", "speed_limit"),
+                        tag("speed_limit", &value),
+                    ],
+                });
+                next_id += 1;
+                regulatory_elements.len() - 1
+            });
+            let re = &mut regulatory_elements[re_idx];
+            re.members.push(Lanelet2Member {
+                member_type: "way".to_string(),
+                reference: sign.id,
+                role: "refers".to_string(),
+            });
+            regulatory_refs.push(re.id);
+            line_strings.push(sign);
+        }
+
+        tags.push(tag("odr:road", &odr.roads[road_idx].id));
+        tags.push(tag("odr:section", &section_idx.to_string()));
+        tags.push(tag("odr:lane", &lane_id.to_string()));
+
+        lanelets.push(Lanelet2Lanelet {
+            id: next_id,
+            subtype: Some("road".to_string()),
+            left,
+            right,
+            centerline: None,
+            speed_limit: draft.speed_limit,
+            one_way: true,
+            location: Some(draft.location.to_string()),
+            turn_direction: None,
+            regulatory_elements: regulatory_refs,
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            left_neighbors: Vec::new(),
+            right_neighbors: Vec::new(),
+            tags,
+        });
+        next_id += 1;
+    }
+
+    connect_lanelets(&mut lanelets);
+
+    Ok(Lanelet2Map::new(
+        line_strings,
+        lanelets,
+        Vec::new(),
+        regulatory_elements,
+        None,
+    ))
+}
+
+/// 将 OpenDRIVE 地图导出为 Lanelet2 OSM XML
+///
+/// `geographic` 为 true 时使用 header 的 geoReference（以及 offset）将
+/// 坐标转换为经纬度，否则输出 local_x/local_y 局部米制坐标。
+pub fn opendrive_to_osm(odr: &OpenDrive, options: &OdrToLanelet2Options) -> Result<String> {
+    let map = opendrive_to_lanelet2(odr, options)?;
+
+    if !options.geographic {
+        return Ok(write_osm(&map, &OsmFrame::Local));
+    }
+
+    let to_geo = geo_transform(odr)?;
+    Ok(write_osm(&map, &OsmFrame::Geographic(&to_geo)))
+}
+
+/// OpenDRIVE 惯性坐标 (x, y) 到经纬度 (lat, lon) 的转换
+///
+/// 先按 header offset 旋转平移到投影坐标，再按 geoReference 反算经纬度。
+pub(crate) fn geo_transform(odr: &OpenDrive) -> Result<impl Fn(f64, f64) -> (f64, f64)> {
+    let proj = odr
+        .header
+        .geo_reference
+        .as_deref()
+        .ok_or_else(|| anyhow!("OpenDRIVE header 缺少 geoReference，无法输出经纬度"))?;
+    let projection = TransverseMercator::from_proj(proj)
+        .ok_or_else(|| anyhow!("不支持的 geoReference 投影: {}", proj.trim()))?;
+    let offset = odr.header.offset().unwrap_or_default();
+    let (sin, cos) = offset.hdg.sin_cos();
+
+    Ok(move |x: f64, y: f64| {
+        projection.inverse(x * cos - y * sin + offset.x, x * sin + y * cos + offset.y)
+    })
+}
+
+/// 边界在 s 处的横向坐标 t
+fn boundary_t(road: &OdrRoad, section: &OdrLaneSection, b: i32, s: f64) -> f64 {
+    let lanes = if b > 0 { &section.left } else { &section.right };
+    match lanes.iter().find(|l| l.id == b) {
+        Some(lane) if b != 0 => road.lane_t_bounds(section, lane, s).1,
+        _ => road.eval_lane_offset(s),
+    }
+}
+
+/// 车道段在限速和标线记录 sOffset 处切分出的 s 区间
+fn section_pieces(section: &OdrLaneSection, s_start: f64, s_end: f64) -> Vec<(f64, f64)> {
+    let mut breaks: Vec<f64> = section
+        .left
+        .iter()
+        .chain(section.right.iter())
+        .chain(std::iter::once(&section.center))
+        .flat_map(|lane| {
+            let marks = lane.road_marks.iter().map(|m| m.s_offset);
+            marks.chain(lane.speed.iter().map(|sp| sp.s_offset))
+        })
+        .map(|offset| section.s + offset)
+        .filter(|&s| s > s_start + 1e-6 && s < s_end - 1e-6)
+        .collect();
+    breaks.sort_by(f64::total_cmp);
+    breaks.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+
+    let mut pieces = Vec::with_capacity(breaks.len() + 1);
+    let mut start = s_start;
+    for s in breaks {
+        pieces.push((start, s));
+        start = s;
+    }
+    pieces.push((start, s_end));
+    pieces
+}
+
+/// 由边界上 s 处生效的标线推导线串的 `type`/`subtype` 标签
+fn boundary_tags(section: &OdrLaneSection, b: i32, s: f64) -> Vec<Lanelet2Tag> {
+    let lane: Option<&OdrLane> = if b == 0 {
+        Some(&section.center)
+    } else if b > 0 {
+        section.left.iter().find(|l| l.id == b)
+    } else {
+        section.right.iter().find(|l| l.id == b)
+    };
+    let outermost = if b > 0 {
+        section.left.iter().all(|l| l.id <= b)
+    } else {
+        b < 0 && section.right.iter().all(|l| l.id >= b)
+    };
+
+    let mut tags = Vec::new();
+    let Some(mark) = lane.and_then(|l| active_road_mark(section, l, s)) else {
+        let line_type = if outermost { "road_border" } else { "virtual" };
+        tags.push(tag("type", line_type));
+        return tags;
+    };
+
+    let thick = matches!(mark.weight, Some(OdrRoadMarkWeight::Bold))
+        || mark.width.is_some_and(|w| w >= 0.2);
+    let line = if thick { "line_thick" } else { "line_thin" };
+
+    // 线串沿 +s 方向，左侧车道的内侧位于线串右侧
+    let (inner_first, outer_first) = if b > 0 {
+        ("dashed_solid", "solid_dashed")
+    } else {
+        ("solid_dashed", "dashed_solid")
+    };
+
+    let (line_type, subtype) = match mark.mark_type {
+        OdrRoadMarkType::Solid => (line, Some("solid")),
+        OdrRoadMarkType::Broken | OdrRoadMarkType::BottsDots | OdrRoadMarkType::BrokenBroken => {
+            (line, Some("dashed"))
+        }
+        OdrRoadMarkType::SolidSolid => (line, Some("solid_solid")),
+        OdrRoadMarkType::SolidBroken => (line, Some(inner_first)),
+        OdrRoadMarkType::BrokenSolid => (line, Some(outer_first)),
+        OdrRoadMarkType::Curb => ("curbstone", Some("high")),
+        OdrRoadMarkType::Edge | OdrRoadMarkType::Grass => ("road_border", None),
+        _ if outermost => ("road_border", None),
+        _ => ("virtual", None),
+    };
+
+    tags.push(tag("type", line_type));
+    if let Some(subtype) = subtype {
+        tags.push(tag("subtype", subtype));
+    }
+    if line_type.starts_with("line_")
+        && matches!(
+            mark.color,
+            OdrRoadMarkColor::Yellow | OdrRoadMarkColor::Orange
+        )
+    {
+        tags.push(tag("color", "yellow"));
+    }
+    tags
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    // 路径压缩
+    let mut cur = i;
+    while parent[cur] != root {
+        let next = parent[cur];
+        parent[cur] = root;
+        cur = next;
+    }
+    root
+}
+
+fn tag(key: &str, value: &str) -> Lanelet2Tag {
+    Lanelet2Tag {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lanelet2::parser::parse_lanelet2;
+    use crate::odr::{
+        models::{
+            enums::{OdrContactPoint, OdrSpeedUnit},
+            header::{OdrHeader, OdrOffset},
+            lane::lane_speed::OdrLaneSpeed,
+        },
+        test_support::{self, line_road, road_link},
+    };
+
+    fn lane(id: i32, width: f64, mark: OdrRoadMarkType) -> OdrLane {
+        OdrLane {
+            speed: vec![OdrLaneSpeed::new(0.0, 50.0, Some(OdrSpeedUnit::KMH))],
+            ..test_support::lane(id, width, mark)
+        }
+    }
+
+    fn straight_road(id: &str, x: f64, length: f64) -> OdrRoad {
+        let mut center = lane(0, 0.0, OdrRoadMarkType::SolidSolid);
+        center.road_marks[0].color = OdrRoadMarkColor::Yellow;
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, 3.5, OdrRoadMarkType::Solid)],
+            vec![
+                lane(-1, 3.5, OdrRoadMarkType::Broken),
+                lane(-2, 3.0, OdrRoadMarkType::Solid),
+            ],
+            center,
+            None,
+        );
+        line_road(id, x, 0.0, length, vec![section])
+    }
+
+    fn two_roads(geo_reference: Option<&str>, offset: Option<OdrOffset>) -> OpenDrive {
+        let mut r1 = straight_road("1", 0.0, 50.0);
+        let mut r2 = straight_road("2", 50.0, 30.0);
+        r1.successor = road_link("2", OdrContactPoint::Start);
+        r2.predecessor = road_link("1", OdrContactPoint::End);
+        let section = &mut r1.lanes[0];
+        for lane in section.right.iter_mut().chain(section.left.iter_mut()) {
+            lane.link.successor = Some(lane.id);
+        }
+
+        let header = OdrHeader::new(
+            1,
+            6,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            geo_reference.map(str::to_string),
+            offset,
+        );
+        OpenDrive::new(header, vec![r1, r2], Vec::new())
+    }
+
+    fn find<'a>(map: &'a Lanelet2Map, road: &str, lane: i32) -> &'a Lanelet2Lanelet {
+        map.lanelets
+            .iter()
+            .find(|l| {
+                l.tag("odr:road") == Some(road) && l.tag("odr:lane") == Some(&lane.to_string())
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_export_shared_boundaries() {
+        let odr = two_roads(None, None);
+        let map = opendrive_to_lanelet2(&odr, &OdrToLanelet2Options::default()).unwrap();
+        assert_eq!(map.lanelets.len(), 6);
+
+        let l1 = find(&map, "1", -1);
+        let l2 = find(&map, "1", -2);
+        assert_eq!(l1.right.id, l2.left.id);
+        assert_eq!(l1.right.line_type, "line_thin");
+        assert_eq!(l1.right.subtype.as_deref(), Some("dashed"));
+        assert_eq!(l2.right.subtype.as_deref(), Some("solid"));
+        assert_eq!(l2.left_neighbors, vec![l1.id]);
+
+        // 左侧车道沿 -s 行驶，与右侧车道共用反向的中心线
+        let left = find(&map, "1", 1);
+        assert_eq!(left.left.id, l1.left.id);
+        assert_eq!(left.left.point_ids.first(), l1.left.point_ids.last());
+        assert_eq!(left.left.subtype.as_deref(), Some("solid_solid"));
+        assert_eq!(left.left.tag("color"), Some("yellow"));
+        assert!(left.left.points[0].x > left.left.points[1].x);
+    }
+
+    #[test]
+    fn test_export_successors() {
+        let odr = two_roads(None, None);
+        let map = opendrive_to_lanelet2(&odr, &OdrToLanelet2Options::default()).unwrap();
+
+        let a = find(&map, "1", -1);
+        let b = find(&map, "2", -1);
+        assert_eq!(a.successors, vec![b.id]);
+        assert_eq!(a.left.point_ids.last(), b.left.point_ids.first());
+
+        // 左侧车道从道路 2 驶向道路 1
+        let a = find(&map, "2", 1);
+        let b = find(&map, "1", 1);
+        assert_eq!(a.successors, vec![b.id]);
+    }
+
+    #[test]
+    fn test_export_speed_limit() {
+        let odr = two_roads(None, None);
+        let map = opendrive_to_lanelet2(&odr, &OdrToLanelet2Options::default()).unwrap();
+
+        assert_eq!(map.regulatory_elements.len(), 1);
+        let re = &map.regulatory_elements[0];
+        assert_eq!(re.subtype.as_deref(), Some("speed_limit"));
+        assert_eq!(re.tag("speed_limit"), Some("50 km/h"));
+        assert_eq!(re.members.len(), 6);
+        assert!(
+            map.lanelets
+                .iter()
+                .all(|l| l.regulatory_elements == vec![re.id])
+        );
+    }
+
+    #[test]
+    fn test_export_split_at_records() {
+        let mut odr = two_roads(None, None);
+        let lane = &mut odr.roads[0].lanes[0].right[0];
+        lane.speed
+            .push(OdrLaneSpeed::new(20.0, 30.0, Some(OdrSpeedUnit::KMH)));
+        let mut mark = lane.road_marks[0].clone();
+        mark.s_offset = 20.0;
+        mark.mark_type = OdrRoadMarkType::Solid;
+        lane.road_marks.push(mark);
+
+        let map = opendrive_to_lanelet2(&odr, &OdrToLanelet2Options::default()).unwrap();
+        // 道路 1 在 s = 20 处切分为两段
+        assert_eq!(map.lanelets.len(), 9);
+        let pieces: Vec<&Lanelet2Lanelet> = map
+            .lanelets
+            .iter()
+            .filter(|l| l.tag("odr:road") == Some("1") && l.tag("odr:lane") == Some("-1"))
+            .collect();
+        assert_eq!(pieces.len(), 2);
+        let (a, b) = (pieces[0], pieces[1]);
+        assert_eq!(a.speed_limit, Some(50.0));
+        assert_eq!(b.speed_limit, Some(30.0));
+        assert_eq!(a.right.subtype.as_deref(), Some("dashed"));
+        assert_eq!(b.right.subtype.as_deref(), Some("solid"));
+        assert_eq!(a.successors, vec![b.id]);
+        assert_eq!(a.right.point_ids.last(), b.right.point_ids.first());
+        assert!((a.right.points.last().unwrap().x - 20.0).abs() < 1e-6);
+        assert_eq!(b.successors, vec![find(&map, "2", -1).id]);
+
+        // 左侧车道沿 -s 行驶，先经过 s = 20 之后的一段
+        let left: Vec<&Lanelet2Lanelet> = map
+            .lanelets
+            .iter()
+            .filter(|l| l.tag("odr:road") == Some("1") && l.tag("odr:lane") == Some("1"))
+            .collect();
+        assert_eq!(find(&map, "2", 1).successors, vec![left[1].id]);
+        assert_eq!(left[1].successors, vec![left[0].id]);
+    }
+
+    #[test]
+    fn test_osm_round_trip() {
+        let odr = two_roads(None, None);
+        let xml = opendrive_to_osm(&odr, &OdrToLanelet2Options::default()).unwrap();
+        let map = parse_lanelet2(xml.as_bytes(), None, None).unwrap();
+
+        assert_eq!(map.lanelets.len(), 6);
+        let a = find(&map, "1", -1);
+        let b = find(&map, "2", -1);
+        assert_eq!(a.successors, vec![b.id]);
+        assert_eq!(a.speed_limit, Some(50.0));
+        assert!((a.right.points[0].y + 3.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_osm_geographic() {
+        let odr = two_roads(None, None);
+        let options = OdrToLanelet2Options {
+            geographic: true,
+            ..Default::default()
+        };
+        assert!(opendrive_to_osm(&odr, &options).is_err());
+
+        // UTM 32N 中央子午线 (9°E) 上的点
+        let odr = two_roads(
+            Some("+proj=utm +zone=32 +datum=WGS84 +units=m +no_defs"),
+            Some(OdrOffset::new(500000.0, 5316000.0, 0.0, 0.0)),
+        );
+        let xml = opendrive_to_osm(&odr, &options).unwrap();
+        assert!(!xml.contains("local_x"));
+
+        let map = parse_lanelet2(xml.as_bytes(), None, None).unwrap();
+        assert_eq!(map.lanelets.len(), 6);
+        let lat = map.origin_lat.unwrap();
+        let lon = map.origin_lon.unwrap();
+        assert!((lat - 48.0).abs() < 0.01, "lat = {lat}");
+        assert!((lon - 9.0).abs() < 0.01, "lon = {lon}");
+    }
+}
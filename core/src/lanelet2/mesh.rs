@@ -0,0 +1,422 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    lanelet2::models::{Lanelet2Area, Lanelet2Lanelet, Lanelet2LineString},
+    math::{
+        frame::MeshFrame,
+        mesh::MeshData,
+        polyline::{cumulative_lengths, interpolate},
+        triangulate::triangulate_polygon,
+        vec3::Vec3,
+    },
+    odr::{
+        mesh::{
+            road_mark_builder::{RoadMarkKind, RoadMarkMeshItem, RoadMarkMeshList},
+            road_mark_profile::RoadMarkProfile,
+        },
+        models::enums::OdrRoadMarkColor,
+    },
+};
+
+/// 标线抬高路面的高度（米），避免与路面 z-fighting
+const MARK_LIFT: f64 = 0.005;
+
+/// 虚线实线段长度（米）
+const DASH_LENGTH: f64 = 3.0;
+
+/// 虚线间隔长度（米）
+const DASH_SPACE: f64 = 6.0;
+
+/// 双线之间的间隙（米）
+const DOUBLE_LINE_GAP: f64 = 0.1;
+
+/// 单条标线的线型
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LinePattern {
+    Solid,
+    Dashed,
+}
+
+/// 由 `type`/`subtype` 标签推导出的标线样式
+#[derive(Clone, Debug)]
+struct MarkStyle {
+    width: f64,
+    /// 高出路面的高度
+    height: f64,
+    /// (横向偏移, 线型)，偏移以线串左侧为正
+    lines: Vec<(f64, LinePattern)>,
+    color: OdrRoadMarkColor,
+    kind: RoadMarkKind,
+}
+
+/// Lanelet2 网格构建器
+///
+/// 将 lanelet 构建为车道面，area 构建为多边形面，线串按 `type`/`subtype`
+/// 标签构建为标线网格。
+#[wasm_bindgen]
+pub struct Lanelet2MeshBuilder {
+    /// 沿边界方向的采样步长（米）
+    sample_step: f64,
+    /// 输出坐标系
+    frame: MeshFrame,
+}
+
+#[wasm_bindgen]
+impl Lanelet2MeshBuilder {
+    /// 创建新的 Lanelet2 网格构建器
+    ///
+    /// # 参数
+    /// - `sample_step`: 沿边界方向的采样间隔（米），默认 1.0
+    /// - `center`: 地图中心点，从 Lanelet2Map.center 获取
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_step: Option<f64>, center: Option<Vec3>) -> Self {
+        Self::with_frame(sample_step, &MeshFrame::webgl(center))
+    }
+
+    /// 使用指定输出坐标系创建 Lanelet2 网格构建器
+    ///
+    /// # 参数
+    /// - `sample_step`: 沿边界方向的采样间隔（米），默认 1.0
+    /// - `frame`: 输出坐标系（轴约定、手性、单位和原点）
+    #[wasm_bindgen(js_name = "withFrame")]
+    pub fn with_frame(sample_step: Option<f64>, frame: &MeshFrame) -> Self {
+        Self {
+            sample_step: sample_step.unwrap_or(1.0),
+            frame: *frame,
+        }
+    }
+
+    /// 构建 lanelet 的车道面网格
+    ///
+    /// 左右边界按各自长度等比例重采样为相同点数，再连接为三角形带。
+    #[wasm_bindgen(js_name = "buildLaneletMesh")]
+    pub fn build_lanelet_mesh(&self, lanelet: &Lanelet2Lanelet) -> MeshData {
+        let left = &lanelet.left.points;
+        let right = &lanelet.right.points;
+        if left.len() < 2 || right.len() < 2 {
+            return MeshData::empty();
+        }
+
+        let left_lengths = cumulative_lengths(left);
+        let right_lengths = cumulative_lengths(right);
+        let left_total = *left_lengths.last().unwrap();
+        let right_total = *right_lengths.last().unwrap();
+
+        let max_length = left_total.max(right_total);
+        let num_samples = ((max_length / self.sample_step).ceil() as usize).max(1) + 1;
+
+        let mut vertices = Vec::with_capacity(num_samples * 6);
+        for i in 0..num_samples {
+            let ratio = i as f64 / (num_samples - 1) as f64;
+            // 左侧为内侧，保证法线朝上
+            let inner = interpolate(left, &left_lengths, ratio * left_total);
+            let outer = interpolate(right, &right_lengths, ratio * right_total);
+            self.push_vertex(&mut vertices, inner);
+            self.push_vertex(&mut vertices, outer);
+        }
+
+        let indices = MeshData::generate_strip_indices(num_samples);
+        self.frame.mesh(vertices, indices)
+    }
+
+    /// 构建 area 外环的多边形网格
+    #[wasm_bindgen(js_name = "buildAreaMesh")]
+    pub fn build_area_mesh(&self, area: &Lanelet2Area) -> MeshData {
+        let triangles = triangulate_polygon(&area.outer);
+        if triangles.is_empty() {
+            return MeshData::empty();
+        }
+
+        let mut vertices = Vec::with_capacity(area.outer.len() * 3);
+        for p in &area.outer {
+            self.push_vertex(&mut vertices, *p);
+        }
+
+        let indices: Vec<u32> = triangles
+            .iter()
+            .flat_map(|t| t.iter().map(|&i| i as u32))
+            .collect();
+        self.frame.mesh(vertices, indices)
+    }
+
+    /// 构建线串的标线网格
+    ///
+    /// 支持 `line_thin`、`line_thick`、`stop_line`、`curbstone` 类型，
+    /// 以及 `solid`、`dashed`、`solid_solid`、`dashed_solid`、`solid_dashed`
+    /// 子类型。其他类型（如 `virtual`）不生成网格。
+    #[wasm_bindgen(js_name = "buildLineStringMarks")]
+    pub fn build_line_string_marks(&self, line_string: &Lanelet2LineString) -> RoadMarkMeshList {
+        let Some(style) = Self::mark_style(line_string) else {
+            return RoadMarkMeshList::new(Vec::new());
+        };
+        if line_string.points.len() < 2 {
+            return RoadMarkMeshList::new(Vec::new());
+        }
+
+        let lengths = cumulative_lengths(&line_string.points);
+        let total = *lengths.last().unwrap();
+
+        let mut mesh = MeshData::empty();
+        for &(offset, pattern) in &style.lines {
+            match pattern {
+                LinePattern::Solid => {
+                    mesh.merge(self.build_line_segment(
+                        &line_string.points,
+                        &lengths,
+                        0.0,
+                        total,
+                        offset,
+                        &style,
+                    ));
+                }
+                LinePattern::Dashed => {
+                    let mut s = 0.0;
+                    while s < total {
+                        let s_end = (s + DASH_LENGTH).min(total);
+                        mesh.merge(self.build_line_segment(
+                            &line_string.points,
+                            &lengths,
+                            s,
+                            s_end,
+                            offset,
+                            &style,
+                        ));
+                        s += DASH_LENGTH + DASH_SPACE;
+                    }
+                }
+            }
+        }
+
+        if mesh.vertices.is_empty() {
+            return RoadMarkMeshList::new(Vec::new());
+        }
+
+        // Lanelet2 没有国家信息，使用通用标线配置的颜色
+        let rgb = RoadMarkProfile::default().color_rgb(style.color.clone());
+        RoadMarkMeshList::new(vec![RoadMarkMeshItem::new(
+            mesh,
+            style.color,
+            style.kind,
+            rgb,
+        )])
+    }
+}
+
+impl Lanelet2MeshBuilder {
+    /// 根据线串标签确定标线样式
+    fn mark_style(line_string: &Lanelet2LineString) -> Option<MarkStyle> {
+        let (width, height) = match line_string.line_type.as_str() {
+            "line_thin" => (0.1, MARK_LIFT),
+            "line_thick" => (0.2, MARK_LIFT),
+            "stop_line" => (0.3, MARK_LIFT),
+            "curbstone" => {
+                let height = match line_string.subtype.as_deref() {
+                    Some("low") => 0.05,
+                    _ => 0.15,
+                };
+                (0.15, height)
+            }
+            _ => return None,
+        };
+
+        let color = if line_string.line_type == "curbstone" {
+            OdrRoadMarkColor::Standard
+        } else {
+            match line_string.tag("color") {
+                Some("yellow") => OdrRoadMarkColor::Yellow,
+                _ => OdrRoadMarkColor::White,
+            }
+        };
+
+        let double = (width + DOUBLE_LINE_GAP) / 2.0;
+        let lines = match (
+            line_string.line_type.as_str(),
+            line_string.subtype.as_deref(),
+        ) {
+            ("stop_line", _) | ("curbstone", _) => vec![(0.0, LinePattern::Solid)],
+            (_, Some("dashed")) => vec![(0.0, LinePattern::Dashed)],
+            (_, Some("solid_solid")) => {
+                vec![(double, LinePattern::Solid), (-double, LinePattern::Solid)]
+            }
+            (_, Some("dashed_solid")) => {
+                vec![(double, LinePattern::Dashed), (-double, LinePattern::Solid)]
+            }
+            (_, Some("solid_dashed")) => {
+                vec![(double, LinePattern::Solid), (-double, LinePattern::Dashed)]
+            }
+            _ => vec![(0.0, LinePattern::Solid)],
+        };
+
+        let kind = if line_string.line_type == "curbstone" {
+            RoadMarkKind::Curb
+        } else {
+            RoadMarkKind::Paint
+        };
+
+        Some(MarkStyle {
+            width,
+            height,
+            lines,
+            color,
+            kind,
+        })
+    }
+
+    /// 沿线串 [s_start, s_end] 构建一段带状网格
+    fn build_line_segment(
+        &self,
+        points: &[Vec3],
+        lengths: &[f64],
+        s_start: f64,
+        s_end: f64,
+        offset: f64,
+        style: &MarkStyle,
+    ) -> MeshData {
+        let length = s_end - s_start;
+        if length <= 1e-6 {
+            return MeshData::empty();
+        }
+
+        let half_width = style.width / 2.0;
+        let num_samples = ((length / self.sample_step).ceil() as usize).max(1) + 1;
+
+        let mut vertices = Vec::with_capacity(num_samples * 6);
+        for i in 0..num_samples {
+            let s = s_start + length * i as f64 / (num_samples - 1) as f64;
+            let p = interpolate(points, lengths, s);
+
+            // 切线取前后中心差分
+            let ahead = interpolate(points, lengths, s + 0.01);
+            let behind = interpolate(points, lengths, s - 0.01);
+            let tangent = (ahead - behind).normalize();
+            let normal = Vec3::new(-tangent.y, tangent.x, 0.0);
+
+            let lift = Vec3::new(0.0, 0.0, style.height);
+            let inner = p + normal * (offset + half_width) + lift;
+            let outer = p + normal * (offset - half_width) + lift;
+            self.push_vertex(&mut vertices, inner);
+            self.push_vertex(&mut vertices, outer);
+        }
+
+        let indices = MeshData::generate_strip_indices(num_samples);
+        self.frame.mesh(vertices, indices)
+    }
+
+    /// 坐标系转换：Lanelet2 局部坐标 -> 输出坐标系
+    fn push_vertex(&self, vertices: &mut Vec<f32>, p: Vec3) {
+        self.frame.push_point(vertices, p);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_string(
+        line_type: &str,
+        subtype: Option<&str>,
+        points: Vec<Vec3>,
+    ) -> Lanelet2LineString {
+        Lanelet2LineString {
+            id: 1,
+            line_type: line_type.to_string(),
+            subtype: subtype.map(str::to_string),
+            point_ids: (0..points.len() as i64).collect(),
+            points,
+            tags: Vec::new(),
+        }
+    }
+
+    fn straight(y: f64, length: f64) -> Vec<Vec3> {
+        vec![Vec3::new(0.0, y, 0.0), Vec3::new(length, y, 0.0)]
+    }
+
+    #[test]
+    fn test_lanelet_mesh_normals_up() {
+        let lanelet = Lanelet2Lanelet {
+            id: 1,
+            subtype: None,
+            left: line_string("line_thin", None, straight(3.0, 10.0)),
+            right: line_string("line_thin", None, straight(0.0, 10.0)),
+            centerline: None,
+            speed_limit: None,
+            one_way: true,
+            location: None,
+            turn_direction: None,
+            regulatory_elements: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            left_neighbors: Vec::new(),
+            right_neighbors: Vec::new(),
+            tags: Vec::new(),
+        };
+
+        let mesh = Lanelet2MeshBuilder::new(Some(2.0), None).build_lanelet_mesh(&lanelet);
+        assert_eq!(mesh.vertices.len(), 6 * 2 * 3);
+        assert!(mesh.normals.chunks(3).all(|n| n[1] > 0.99));
+    }
+
+    #[test]
+    fn test_dashed_marks() {
+        let builder = Lanelet2MeshBuilder::new(Some(1.0), None);
+
+        let solid = builder.build_line_string_marks(&line_string(
+            "line_thin",
+            Some("solid"),
+            straight(0.0, 20.0),
+        ));
+        assert_eq!(solid.length(), 1);
+        assert!(matches!(
+            solid.get(0).unwrap().color(),
+            OdrRoadMarkColor::White
+        ));
+
+        // 20 m 虚线：[0,3]、[9,12]、[18,20] 三段
+        let dashed = builder.build_line_string_marks(&line_string(
+            "line_thin",
+            Some("dashed"),
+            straight(0.0, 20.0),
+        ));
+        let mesh = dashed.get(0).unwrap().mesh();
+        assert_eq!(mesh.vertices.len() / 3, (4 + 4 + 3) * 2);
+
+        let ignored =
+            builder.build_line_string_marks(&line_string("virtual", None, straight(0.0, 20.0)));
+        assert_eq!(ignored.length(), 0);
+    }
+
+    #[test]
+    fn test_double_line_offsets() {
+        let style = Lanelet2MeshBuilder::mark_style(&line_string(
+            "line_thin",
+            Some("dashed_solid"),
+            straight(0.0, 1.0),
+        ))
+        .unwrap();
+
+        assert_eq!(style.lines.len(), 2);
+        assert!(style.lines[0].0 > 0.0 && style.lines[0].1 == LinePattern::Dashed);
+        assert!(style.lines[1].0 < 0.0 && style.lines[1].1 == LinePattern::Solid);
+    }
+
+    #[test]
+    fn test_area_mesh() {
+        let area = Lanelet2Area {
+            id: 1,
+            subtype: None,
+            outer: vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(0.0, 4.0, 0.0),
+                Vec3::new(4.0, 4.0, 0.0),
+                Vec3::new(4.0, 0.0, 0.0),
+            ],
+            inner: Vec::new(),
+            regulatory_elements: Vec::new(),
+            tags: Vec::new(),
+        };
+
+        let mesh = Lanelet2MeshBuilder::new(None, None).build_area_mesh(&area);
+        assert_eq!(mesh.indices.len(), 6);
+        assert!(mesh.normals.chunks(3).all(|n| n[1] > 0.99));
+    }
+}
@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::geo::LocalProjector;
+use crate::odr::models::opendrive::OpenDrive;
+
+pub mod from_odr;
+pub mod mesh;
+pub mod models;
+pub mod parser;
+pub mod writer;
+
+use models::Lanelet2Map;
+use writer::OsmFrame;
+
+#[wasm_bindgen]
+impl Lanelet2Map {
+    /// 由 OpenDRIVE 地图生成 Lanelet2 地图（OpenDRIVE 惯性坐标系）
+    #[wasm_bindgen(js_name = fromOpenDrive)]
+    pub fn from_opendrive(odr: &OpenDrive) -> Result<Lanelet2Map, String> {
+        from_odr::opendrive_to_lanelet2(odr, &from_odr::OdrToLanelet2Options::default())
+            .map_err(|e| e.to_string())
+    }
+
+    /// 编码为 Lanelet2 OSM XML
+    ///
+    /// `geographic` 为 true 时按投影原点反算经纬度，需要地图带有原点。
+    #[wasm_bindgen(js_name = toOsm)]
+    pub fn to_osm(&self, geographic: bool) -> Result<Vec<u8>, String> {
+        if !geographic {
+            return Ok(writer::write_osm(self, &OsmFrame::Local).into_bytes());
+        }
+
+        let (Some(lat), Some(lon)) = (self.origin_lat, self.origin_lon) else {
+            return Err("地图缺少投影原点，无法输出经纬度".to_string());
+        };
+        let projector = LocalProjector::utm(lat, lon);
+        let to_geo = |x: f64, y: f64| projector.to_geo(x, y);
+        Ok(writer::write_osm(self, &OsmFrame::Geographic(&to_geo)).into_bytes())
+    }
+}
+
+/// 将 OpenDRIVE 地图导出为 Lanelet2 OSM
+///
+/// `geographic` 为 true 时使用 header 的 geoReference 输出经纬度，
+/// 否则输出 local_x/local_y 局部坐标。
+#[wasm_bindgen(js_name = exportOpendriveToLanelet2)]
+pub fn export_opendrive_to_lanelet2(odr: &OpenDrive, geographic: bool) -> Result<Vec<u8>, String> {
+    let options = from_odr::OdrToLanelet2Options {
+        geographic,
+        ..Default::default()
+    };
+    from_odr::opendrive_to_osm(odr, &options)
+        .map(String::into_bytes)
+        .map_err(|e| e.to_string())
+}
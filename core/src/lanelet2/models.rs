@@ -0,0 +1,254 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::vec3::Vec3;
+
+/// OSM 键值标签
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2Tag {
+    #[wasm_bindgen(getter_with_clone)]
+    pub key: String,
+    #[wasm_bindgen(getter_with_clone)]
+    pub value: String,
+}
+
+/// 线串
+///
+/// 车道边界、停止线、路缘石等线状要素，`type`/`subtype` 标签决定其语义，
+/// 例如 `line_thin` + `dashed` 表示细虚线。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2LineString {
+    pub id: i64,
+    /// `type` 标签
+    #[wasm_bindgen(getter_with_clone, js_name = "lineType")]
+    pub line_type: String,
+    /// `subtype` 标签
+    #[wasm_bindgen(getter_with_clone)]
+    pub subtype: Option<String>,
+    /// 局部坐标系下的顶点
+    #[wasm_bindgen(getter_with_clone)]
+    pub points: Vec<Vec3>,
+    /// 顶点对应的 OSM node ID
+    #[wasm_bindgen(getter_with_clone, js_name = "pointIds")]
+    pub point_ids: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub tags: Vec<Lanelet2Tag>,
+}
+
+impl Lanelet2LineString {
+    /// 获取标签值
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        find_tag(&self.tags, key)
+    }
+
+    /// 反转顶点顺序
+    pub fn reversed(&self) -> Self {
+        let mut result = self.clone();
+        result.points.reverse();
+        result.point_ids.reverse();
+        result
+    }
+}
+
+/// 车道单元 (Lanelet)
+///
+/// 由左右两条边界线串围成，行驶方向与左边界方向一致。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2Lanelet {
+    pub id: i64,
+    /// `subtype` 标签，如 road、crosswalk、walkway
+    #[wasm_bindgen(getter_with_clone)]
+    pub subtype: Option<String>,
+    /// 左边界（已与行驶方向对齐）
+    #[wasm_bindgen(getter_with_clone)]
+    pub left: Lanelet2LineString,
+    /// 右边界（已与行驶方向对齐）
+    #[wasm_bindgen(getter_with_clone)]
+    pub right: Lanelet2LineString,
+    /// 可选的中心线
+    #[wasm_bindgen(getter_with_clone)]
+    pub centerline: Option<Lanelet2LineString>,
+    /// 限速 (km/h)
+    #[wasm_bindgen(js_name = "speedLimit")]
+    pub speed_limit: Option<f64>,
+    /// 是否为单向车道
+    #[wasm_bindgen(js_name = "oneWay")]
+    pub one_way: bool,
+    /// `location` 标签，如 urban、nonurban
+    #[wasm_bindgen(getter_with_clone)]
+    pub location: Option<String>,
+    /// `turn_direction` 标签
+    #[wasm_bindgen(getter_with_clone, js_name = "turnDirection")]
+    pub turn_direction: Option<String>,
+    /// 引用的交通规则 ID
+    #[wasm_bindgen(getter_with_clone, js_name = "regulatoryElements")]
+    pub regulatory_elements: Vec<i64>,
+    /// 后继车道（终点边界与其起点边界相接）
+    #[wasm_bindgen(getter_with_clone)]
+    pub successors: Vec<i64>,
+    /// 前驱车道
+    #[wasm_bindgen(getter_with_clone)]
+    pub predecessors: Vec<i64>,
+    /// 共用左边界的同向车道
+    #[wasm_bindgen(getter_with_clone, js_name = "leftNeighbors")]
+    pub left_neighbors: Vec<i64>,
+    /// 共用右边界的同向车道
+    #[wasm_bindgen(getter_with_clone, js_name = "rightNeighbors")]
+    pub right_neighbors: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub tags: Vec<Lanelet2Tag>,
+}
+
+impl Lanelet2Lanelet {
+    /// 获取标签值
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        find_tag(&self.tags, key)
+    }
+}
+
+/// 区域 (Area)
+///
+/// 由外环和可选内环围成的面状要素，如停车场、绿化带。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2Area {
+    pub id: i64,
+    #[wasm_bindgen(getter_with_clone)]
+    pub subtype: Option<String>,
+    /// 外环顶点（不重复首点）
+    #[wasm_bindgen(getter_with_clone)]
+    pub outer: Vec<Vec3>,
+    /// 内环顶点
+    #[wasm_bindgen(skip)]
+    pub inner: Vec<Vec<Vec3>>,
+    #[wasm_bindgen(getter_with_clone, js_name = "regulatoryElements")]
+    pub regulatory_elements: Vec<i64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub tags: Vec<Lanelet2Tag>,
+}
+
+/// 关系成员
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2Member {
+    /// 成员类型：node、way 或 relation
+    #[wasm_bindgen(getter_with_clone, js_name = "memberType")]
+    pub member_type: String,
+    /// 成员 ID
+    pub reference: i64,
+    /// 成员角色，如 refers、ref_line、yield
+    #[wasm_bindgen(getter_with_clone)]
+    pub role: String,
+}
+
+/// 交通规则 (Regulatory Element)
+///
+/// 如信号灯、停车让行、限速等，通过成员角色引用相关的线串和车道。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2RegulatoryElement {
+    pub id: i64,
+    /// `subtype` 标签，如 traffic_light、traffic_sign、speed_limit
+    #[wasm_bindgen(getter_with_clone)]
+    pub subtype: Option<String>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub members: Vec<Lanelet2Member>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub tags: Vec<Lanelet2Tag>,
+}
+
+impl Lanelet2RegulatoryElement {
+    /// 获取标签值
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        find_tag(&self.tags, key)
+    }
+}
+
+/// Lanelet2 地图
+///
+/// 与 OSM 存储格式无关的车道模型：所有坐标已解析为局部米制坐标。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Lanelet2Map {
+    /// 所有线串（包括未被 lanelet 引用的）
+    #[wasm_bindgen(getter_with_clone, js_name = "lineStrings")]
+    pub line_strings: Vec<Lanelet2LineString>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub lanelets: Vec<Lanelet2Lanelet>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub areas: Vec<Lanelet2Area>,
+    #[wasm_bindgen(getter_with_clone, js_name = "regulatoryElements")]
+    pub regulatory_elements: Vec<Lanelet2RegulatoryElement>,
+    /// 投影原点纬度（使用经纬度坐标时）
+    #[wasm_bindgen(js_name = "originLat")]
+    pub origin_lat: Option<f64>,
+    /// 投影原点经度（使用经纬度坐标时）
+    #[wasm_bindgen(js_name = "originLon")]
+    pub origin_lon: Option<f64>,
+    center: Vec3,
+}
+
+#[wasm_bindgen]
+impl Lanelet2Map {
+    /// 获取地图的中心点
+    #[wasm_bindgen(getter)]
+    pub fn center(&self) -> Vec3 {
+        self.center
+    }
+}
+
+impl Lanelet2Map {
+    pub fn new(
+        line_strings: Vec<Lanelet2LineString>,
+        lanelets: Vec<Lanelet2Lanelet>,
+        areas: Vec<Lanelet2Area>,
+        regulatory_elements: Vec<Lanelet2RegulatoryElement>,
+        origin: Option<(f64, f64)>,
+    ) -> Self {
+        let center = Self::compute_center(&line_strings);
+        Self {
+            line_strings,
+            lanelets,
+            areas,
+            regulatory_elements,
+            origin_lat: origin.map(|o| o.0),
+            origin_lon: origin.map(|o| o.1),
+            center,
+        }
+    }
+
+    /// 计算所有线串顶点边界框的中心
+    fn compute_center(line_strings: &[Lanelet2LineString]) -> Vec3 {
+        let mut min_x = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut min_y = f64::MAX;
+        let mut max_y = f64::MIN;
+
+        for p in line_strings.iter().flat_map(|ls| ls.points.iter()) {
+            min_x = min_x.min(p.x);
+            max_x = max_x.max(p.x);
+            min_y = min_y.min(p.y);
+            max_y = max_y.max(p.y);
+        }
+
+        if min_x == f64::MAX {
+            return Vec3::new(0.0, 0.0, 0.0);
+        }
+
+        Vec3::new((min_x + max_x) / 2.0, (min_y + max_y) / 2.0, 0.0)
+    }
+
+    pub fn find_lanelet(&self, id: i64) -> Option<&Lanelet2Lanelet> {
+        self.lanelets.iter().find(|l| l.id == id)
+    }
+
+    pub fn find_regulatory_element(&self, id: i64) -> Option<&Lanelet2RegulatoryElement> {
+        self.regulatory_elements.iter().find(|r| r.id == id)
+    }
+}
+
+fn find_tag<'a>(tags: &'a [Lanelet2Tag], key: &str) -> Option<&'a str> {
+    tags.iter().find(|t| t.key == key).map(|t| t.value.as_str())
+}
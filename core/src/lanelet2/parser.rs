@@ -0,0 +1,753 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, anyhow};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use wasm_bindgen::prelude::*;
+
+use crate::fs::Files;
+use crate::lanelet2::models::{
+    Lanelet2Area, Lanelet2Lanelet, Lanelet2LineString, Lanelet2Map, Lanelet2Member,
+    Lanelet2RegulatoryElement, Lanelet2Tag,
+};
+use crate::math::geo::LocalProjector;
+use crate::math::vec3::Vec3;
+
+/// 英里每小时到千米每小时的换算系数
+const MPH_TO_KMH: f64 = 1.609344;
+
+/// 解析 Lanelet2 OSM XML
+///
+/// 节点带有 `local_x`/`local_y` 标签时直接使用局部坐标，否则将经纬度
+/// 以 UTM 投影到以原点为中心的局部坐标系。未指定原点时使用第一个节点。
+#[wasm_bindgen(js_name = parseLanelet2)]
+pub fn parse_lanelet2(
+    xml: &[u8],
+    origin_lat: Option<f64>,
+    origin_lon: Option<f64>,
+) -> Result<Lanelet2Map, String> {
+    let origin = origin_lat.zip(origin_lon);
+    parse_lanelet2_internal(xml, origin).map_err(|e| e.to_string())
+}
+
+/// 从 Files 中解析多个 Lanelet2 地图，跳过不含 lanelet 的普通 OSM 文件
+#[wasm_bindgen(js_name = parseLanelet2FromFiles)]
+pub fn parse_lanelet2_from_files(files: &Files) -> Result<Vec<Lanelet2Map>, String> {
+    let osm_files: Vec<_> = files
+        .filter_by_extension(".osm")
+        .into_iter()
+        .filter(|f| is_lanelet2(f.get_data()))
+        .collect();
+
+    if osm_files.is_empty() {
+        return Err("未找到 Lanelet2 格式的 .osm 文件".to_string());
+    }
+
+    let mut maps = Vec::new();
+    for file in osm_files {
+        let map = parse_lanelet2_internal(file.get_data(), None).map_err(|e| e.to_string())?;
+        maps.push(map);
+    }
+
+    Ok(maps)
+}
+
+/// 判断 OSM 数据是否为 Lanelet2 地图（包含 `type=lanelet` 的 relation）
+///
+/// 普通 OpenStreetMap 数据同样使用 `.osm` 扩展名，不能仅凭扩展名判断。
+pub fn is_lanelet2(xml: &[u8]) -> bool {
+    let contains = |needle: &[u8]| xml.windows(needle.len()).any(|w| w == needle);
+    contains(b"<relation") && (contains(br#"v="lanelet""#) || contains(b"v='lanelet'"))
+}
+
+struct RawNode {
+    id: i64,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    tags: Vec<Lanelet2Tag>,
+}
+
+struct RawWay {
+    id: i64,
+    nodes: Vec<i64>,
+    tags: Vec<Lanelet2Tag>,
+}
+
+struct RawRelation {
+    id: i64,
+    members: Vec<Lanelet2Member>,
+    tags: Vec<Lanelet2Tag>,
+}
+
+enum RawElement {
+    Node(RawNode),
+    Way(RawWay),
+    Relation(RawRelation),
+}
+
+#[derive(Default)]
+struct RawOsm {
+    nodes: Vec<RawNode>,
+    ways: Vec<RawWay>,
+    relations: Vec<RawRelation>,
+}
+
+impl RawOsm {
+    fn push(&mut self, element: RawElement) {
+        match element {
+            RawElement::Node(n) => self.nodes.push(n),
+            RawElement::Way(w) => self.ways.push(w),
+            RawElement::Relation(r) => self.relations.push(r),
+        }
+    }
+}
+
+fn parse_lanelet2_internal(xml: &[u8], origin: Option<(f64, f64)>) -> Result<Lanelet2Map> {
+    let raw = read_osm(xml)?;
+    build_map(raw, origin)
+}
+
+/// 读取 OSM 的 node、way、relation 元素
+fn read_osm(xml: &[u8]) -> Result<RawOsm> {
+    let mut reader = Reader::from_reader(xml);
+    let mut raw = RawOsm::default();
+    let mut current: Option<RawElement> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .map_err(|e| anyhow!("XML 解析错误: {}", e))?;
+        match event {
+            Event::Start(ref e) => {
+                if let Some(element) = start_element(e)? {
+                    current = Some(element);
+                }
+            }
+            Event::Empty(ref e) => match e.name().as_ref() {
+                b"node" | b"way" | b"relation" => {
+                    if let Some(element) = start_element(e)? {
+                        raw.push(element);
+                    }
+                }
+                b"tag" => {
+                    let tag = parse_tag(e)?;
+                    match current.as_mut() {
+                        Some(RawElement::Node(n)) => n.tags.push(tag),
+                        Some(RawElement::Way(w)) => w.tags.push(tag),
+                        Some(RawElement::Relation(r)) => r.tags.push(tag),
+                        None => {}
+                    }
+                }
+                b"nd" => {
+                    if let Some(RawElement::Way(w)) = current.as_mut() {
+                        let reference = attribute(e, b"ref")?
+                            .ok_or_else(|| anyhow!("way {} 的 nd 缺少 ref 属性", w.id))?;
+                        w.nodes.push(reference.parse().context("解析 nd ref 错误")?);
+                    }
+                }
+                b"member" => {
+                    if let Some(RawElement::Relation(r)) = current.as_mut() {
+                        r.members.push(parse_member(e)?);
+                    }
+                }
+                _ => {}
+            },
+            Event::End(ref e) => {
+                if matches!(e.name().as_ref(), b"node" | b"way" | b"relation")
+                    && let Some(element) = current.take()
+                {
+                    raw.push(element);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(raw)
+}
+
+/// 解析 node/way/relation 起始标签
+fn start_element(e: &BytesStart) -> Result<Option<RawElement>> {
+    let kind = e.name().as_ref().to_vec();
+    if !matches!(kind.as_slice(), b"node" | b"way" | b"relation") {
+        return Ok(None);
+    }
+
+    let id: i64 = attribute(e, b"id")?
+        .ok_or_else(|| anyhow!("OSM 元素缺少 id 属性"))?
+        .parse()
+        .context("解析 id 错误")?;
+
+    let element = match kind.as_slice() {
+        b"node" => {
+            let lat = attribute(e, b"lat")?
+                .map(|v| v.parse::<f64>())
+                .transpose()
+                .context("解析 lat 错误")?;
+            let lon = attribute(e, b"lon")?
+                .map(|v| v.parse::<f64>())
+                .transpose()
+                .context("解析 lon 错误")?;
+            RawElement::Node(RawNode {
+                id,
+                lat,
+                lon,
+                tags: Vec::new(),
+            })
+        }
+        b"way" => RawElement::Way(RawWay {
+            id,
+            nodes: Vec::new(),
+            tags: Vec::new(),
+        }),
+        _ => RawElement::Relation(RawRelation {
+            id,
+            members: Vec::new(),
+            tags: Vec::new(),
+        }),
+    };
+
+    Ok(Some(element))
+}
+
+fn attribute(e: &BytesStart, name: &[u8]) -> Result<Option<String>> {
+    for attr in e.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        if attr.key.as_ref() == name {
+            let value = attr.unescape_value().context("解析属性值错误")?;
+            return Ok(Some(value.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+fn parse_tag(e: &BytesStart) -> Result<Lanelet2Tag> {
+    Ok(Lanelet2Tag {
+        key: attribute(e, b"k")?.ok_or_else(|| anyhow!("tag 缺少 k 属性"))?,
+        value: attribute(e, b"v")?.unwrap_or_default(),
+    })
+}
+
+fn parse_member(e: &BytesStart) -> Result<Lanelet2Member> {
+    let reference = attribute(e, b"ref")?
+        .ok_or_else(|| anyhow!("member 缺少 ref 属性"))?
+        .parse()
+        .context("解析 member ref 错误")?;
+    Ok(Lanelet2Member {
+        member_type: attribute(e, b"type")?.unwrap_or_default(),
+        reference,
+        role: attribute(e, b"role")?.unwrap_or_default(),
+    })
+}
+
+pub(crate) fn tag_value<'a>(tags: &'a [Lanelet2Tag], key: &str) -> Option<&'a str> {
+    tags.iter().find(|t| t.key == key).map(|t| t.value.as_str())
+}
+
+fn tag_f64(tags: &[Lanelet2Tag], key: &str) -> Result<Option<f64>> {
+    tag_value(tags, key)
+        .map(|v| v.trim().parse::<f64>())
+        .transpose()
+        .with_context(|| format!("解析 {} 标签错误", key))
+}
+
+/// 经纬度原点 (lat, lon)
+type GeoOrigin = (f64, f64);
+
+/// 计算所有节点的局部坐标
+///
+/// # 返回值
+/// 节点坐标表以及实际使用的投影原点（未进行投影时为 None）
+fn resolve_nodes(
+    nodes: &[RawNode],
+    origin: Option<GeoOrigin>,
+) -> Result<(HashMap<i64, Vec3>, Option<GeoOrigin>)> {
+    let needs_projection = nodes.iter().any(|n| {
+        tag_value(&n.tags, "local_x").is_none() || tag_value(&n.tags, "local_y").is_none()
+    });
+
+    let origin = if needs_projection {
+        match origin {
+            Some(o) => Some(o),
+            None => nodes.iter().find_map(|n| n.lat.zip(n.lon)),
+        }
+    } else {
+        None
+    };
+    let projector = origin.map(|(lat, lon)| LocalProjector::utm(lat, lon));
+
+    let mut positions = HashMap::with_capacity(nodes.len());
+    for node in nodes {
+        let z = tag_f64(&node.tags, "ele")?.unwrap_or(0.0);
+        let local = tag_f64(&node.tags, "local_x")?.zip(tag_f64(&node.tags, "local_y")?);
+
+        let (x, y) = match (local, node.lat.zip(node.lon), projector.as_ref()) {
+            (Some(xy), _, _) => xy,
+            (None, Some((lat, lon)), Some(projector)) => projector.to_local(lat, lon),
+            _ => return Err(anyhow!("节点 {} 缺少坐标", node.id)),
+        };
+        positions.insert(node.id, Vec3::new(x, y, z));
+    }
+
+    Ok((positions, origin))
+}
+
+fn build_line_string(way: &RawWay, positions: &HashMap<i64, Vec3>) -> Result<Lanelet2LineString> {
+    let points = way
+        .nodes
+        .iter()
+        .map(|id| {
+            positions
+                .get(id)
+                .copied()
+                .ok_or_else(|| anyhow!("线串 {} 引用了不存在的节点 {}", way.id, id))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Lanelet2LineString {
+        id: way.id,
+        line_type: tag_value(&way.tags, "type").unwrap_or_default().to_string(),
+        subtype: tag_value(&way.tags, "subtype").map(str::to_string),
+        points,
+        point_ids: way.nodes.clone(),
+        tags: way.tags.clone(),
+    })
+}
+
+/// 使线串方向与参考线串一致
+fn align_to(line: Lanelet2LineString, reference: &Lanelet2LineString) -> Lanelet2LineString {
+    let (Some(&r0), Some(&r1), Some(&l0), Some(&l1)) = (
+        reference.points.first(),
+        reference.points.last(),
+        line.points.first(),
+        line.points.last(),
+    ) else {
+        return line;
+    };
+
+    let same = (r0 - l0).length() + (r1 - l1).length();
+    let opposite = (r0 - l1).length() + (r1 - l0).length();
+    if opposite < same {
+        line.reversed()
+    } else {
+        line
+    }
+}
+
+/// 解析限速值，支持 "50"、"50 km/h"、"30 mph" 等写法
+fn parse_speed(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let speed: f64 = value[..end].parse().ok()?;
+    if value[end..].trim().eq_ignore_ascii_case("mph") {
+        Some(speed * MPH_TO_KMH)
+    } else {
+        Some(speed)
+    }
+}
+
+/// 将多个 way 按首尾节点首尾相接拼成闭合环
+fn join_ring(ways: &[&Lanelet2LineString]) -> Vec<Vec3> {
+    let mut remaining: Vec<Lanelet2LineString> = ways.iter().map(|w| (*w).clone()).collect();
+    if remaining.is_empty() {
+        return Vec::new();
+    }
+
+    let first = remaining.remove(0);
+    let mut ids = first.point_ids;
+    let mut points = first.points;
+
+    while !remaining.is_empty() {
+        let end = *ids.last().unwrap_or(&0);
+        let Some(index) = remaining
+            .iter()
+            .position(|w| w.point_ids.first() == Some(&end) || w.point_ids.last() == Some(&end))
+        else {
+            break;
+        };
+        let mut next = remaining.remove(index);
+        if next.point_ids.first() != Some(&end) {
+            next = next.reversed();
+        }
+        ids.extend(next.point_ids.into_iter().skip(1));
+        points.extend(next.points.into_iter().skip(1));
+    }
+
+    if ids.len() > 1 && ids.first() == ids.last() {
+        points.pop();
+    }
+    points
+}
+
+fn build_map(raw: RawOsm, origin: Option<(f64, f64)>) -> Result<Lanelet2Map> {
+    let (positions, origin) = resolve_nodes(&raw.nodes, origin)?;
+
+    let line_strings = raw
+        .ways
+        .iter()
+        .map(|w| build_line_string(w, &positions))
+        .collect::<Result<Vec<_>>>()?;
+    let way_index: HashMap<i64, usize> = line_strings
+        .iter()
+        .enumerate()
+        .map(|(i, ls)| (ls.id, i))
+        .collect();
+    let find_way = |relation: &RawRelation, id: i64| {
+        way_index
+            .get(&id)
+            .map(|&i| &line_strings[i])
+            .ok_or_else(|| anyhow!("关系 {} 引用了不存在的 way {}", relation.id, id))
+    };
+
+    let regulatory_elements: Vec<Lanelet2RegulatoryElement> = raw
+        .relations
+        .iter()
+        .filter(|r| tag_value(&r.tags, "type") == Some("regulatory_element"))
+        .map(|r| Lanelet2RegulatoryElement {
+            id: r.id,
+            subtype: tag_value(&r.tags, "subtype").map(str::to_string),
+            members: r.members.clone(),
+            tags: r.tags.clone(),
+        })
+        .collect();
+
+    let mut lanelets = Vec::new();
+    let mut areas = Vec::new();
+
+    for relation in &raw.relations {
+        let relation_type = tag_value(&relation.tags, "type");
+        let regulatory_refs: Vec<i64> = relation
+            .members
+            .iter()
+            .filter(|m| m.member_type == "relation" && m.role == "regulatory_element")
+            .map(|m| m.reference)
+            .collect();
+
+        match relation_type {
+            Some("lanelet") => {
+                let way_with_role = |role: &str| {
+                    relation
+                        .members
+                        .iter()
+                        .find(|m| m.member_type == "way" && m.role == role)
+                        .map(|m| find_way(relation, m.reference))
+                        .transpose()
+                };
+                let left = way_with_role("left")?
+                    .ok_or_else(|| anyhow!("lanelet {} 缺少左边界", relation.id))?
+                    .clone();
+                let right = way_with_role("right")?
+                    .ok_or_else(|| anyhow!("lanelet {} 缺少右边界", relation.id))?
+                    .clone();
+                let right = align_to(right, &left);
+                let centerline = way_with_role("centerline")?.map(|c| align_to(c.clone(), &left));
+
+                let speed_limit = tag_value(&relation.tags, "speed_limit")
+                    .and_then(parse_speed)
+                    .or_else(|| {
+                        regulatory_refs.iter().find_map(|id| {
+                            regulatory_elements
+                                .iter()
+                                .find(|r| {
+                                    r.id == *id && r.subtype.as_deref() == Some("speed_limit")
+                                })
+                                .and_then(|r| r.tag("speed_limit"))
+                                .and_then(parse_speed)
+                        })
+                    });
+
+                lanelets.push(Lanelet2Lanelet {
+                    id: relation.id,
+                    subtype: tag_value(&relation.tags, "subtype").map(str::to_string),
+                    left,
+                    right,
+                    centerline,
+                    speed_limit,
+                    one_way: tag_value(&relation.tags, "one_way") != Some("no"),
+                    location: tag_value(&relation.tags, "location").map(str::to_string),
+                    turn_direction: tag_value(&relation.tags, "turn_direction").map(str::to_string),
+                    regulatory_elements: regulatory_refs,
+                    successors: Vec::new(),
+                    predecessors: Vec::new(),
+                    left_neighbors: Vec::new(),
+                    right_neighbors: Vec::new(),
+                    tags: relation.tags.clone(),
+                });
+            }
+            Some("multipolygon") | Some("area") => {
+                let ring_ways = |role: &str| {
+                    relation
+                        .members
+                        .iter()
+                        .filter(|m| m.member_type == "way" && m.role == role)
+                        .map(|m| find_way(relation, m.reference))
+                        .collect::<Result<Vec<_>>>()
+                };
+                let outer = join_ring(&ring_ways("outer")?);
+                if outer.len() < 3 {
+                    continue;
+                }
+                let inner = ring_ways("inner")?
+                    .into_iter()
+                    .map(|w| join_ring(&[w]))
+                    .filter(|ring| ring.len() >= 3)
+                    .collect();
+
+                areas.push(Lanelet2Area {
+                    id: relation.id,
+                    subtype: tag_value(&relation.tags, "subtype").map(str::to_string),
+                    outer,
+                    inner,
+                    regulatory_elements: regulatory_refs,
+                    tags: relation.tags.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    connect_lanelets(&mut lanelets);
+
+    Ok(Lanelet2Map::new(
+        line_strings,
+        lanelets,
+        areas,
+        regulatory_elements,
+        origin,
+    ))
+}
+
+/// 根据共享节点和共享边界计算前后继与左右邻接关系
+pub(crate) fn connect_lanelets(lanelets: &mut [Lanelet2Lanelet]) {
+    // (左边界起点, 右边界起点) -> lanelet 下标
+    let mut by_start: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    // 右边界 way ID -> lanelet 下标
+    let mut by_right: HashMap<i64, Vec<usize>> = HashMap::new();
+
+    for (i, lanelet) in lanelets.iter().enumerate() {
+        if let (Some(&l), Some(&r)) = (
+            lanelet.left.point_ids.first(),
+            lanelet.right.point_ids.first(),
+        ) {
+            by_start.entry((l, r)).or_default().push(i);
+        }
+        by_right.entry(lanelet.right.id).or_default().push(i);
+    }
+
+    let mut successors = vec![Vec::new(); lanelets.len()];
+    let mut predecessors = vec![Vec::new(); lanelets.len()];
+    let mut left_neighbors = vec![Vec::new(); lanelets.len()];
+    let mut right_neighbors = vec![Vec::new(); lanelets.len()];
+
+    for (i, lanelet) in lanelets.iter().enumerate() {
+        if let (Some(&l), Some(&r)) = (
+            lanelet.left.point_ids.last(),
+            lanelet.right.point_ids.last(),
+        ) {
+            for &j in by_start.get(&(l, r)).into_iter().flatten() {
+                if i != j {
+                    successors[i].push(lanelets[j].id);
+                    predecessors[j].push(lanelet.id);
+                }
+            }
+        }
+
+        // 另一条 lanelet 的右边界即本 lanelet 的左边界，且方向相同
+        for &j in by_right.get(&lanelet.left.id).into_iter().flatten() {
+            if i != j && lanelets[j].right.point_ids.first() == lanelet.left.point_ids.first() {
+                left_neighbors[i].push(lanelets[j].id);
+                right_neighbors[j].push(lanelet.id);
+            }
+        }
+    }
+
+    for (i, lanelet) in lanelets.iter_mut().enumerate() {
+        lanelet.successors = std::mem::take(&mut successors[i]);
+        lanelet.predecessors = std::mem::take(&mut predecessors[i]);
+        lanelet.left_neighbors = std::mem::take(&mut left_neighbors[i]);
+        lanelet.right_neighbors = std::mem::take(&mut right_neighbors[i]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 两条并排、首尾相接的 lanelet：
+    ///
+    /// ```text
+    ///  1 ---- 2 ---- 3     (way 10, 11)
+    ///  |  100 |  101 |
+    ///  4 ---- 5 ---- 6     (way 12, 13)
+    ///  |  102 |
+    ///  7 ---- 8            (way 14)
+    /// ```
+    fn local_map() -> String {
+        let node = |id: i64, x: f64, y: f64| {
+            format!(
+                r#"<node id="{id}" lat="0" lon="0"><tag k="local_x" v="{x}"/><tag k="local_y" v="{y}"/><tag k="ele" v="1.5"/></node>"#
+            )
+        };
+        let way = |id: i64, nodes: &[i64], subtype: &str| {
+            let nds: String = nodes
+                .iter()
+                .map(|n| format!(r#"<nd ref="{n}"/>"#))
+                .collect();
+            format!(
+                r#"<way id="{id}">{nds}<tag k="type" v="line_thin"/><tag k="subtype" v="{subtype}"/></way>"#
+            )
+        };
+        let lanelet = |id: i64, left: i64, right: i64, extra: &str| {
+            format!(
+                r#"<relation id="{id}"><member type="way" ref="{left}" role="left"/><member type="way" ref="{right}" role="right"/><tag k="type" v="lanelet"/><tag k="subtype" v="road"/>{extra}</relation>"#
+            )
+        };
+
+        let mut xml = String::from(r#"<?xml version="1.0"?><osm version="0.6">"#);
+        xml += &node(1, 0.0, 3.0);
+        xml += &node(2, 10.0, 3.0);
+        xml += &node(3, 20.0, 3.0);
+        xml += &node(4, 0.0, 0.0);
+        xml += &node(5, 10.0, 0.0);
+        xml += &node(6, 20.0, 0.0);
+        xml += &node(7, 0.0, -3.0);
+        xml += &node(8, 10.0, -3.0);
+        xml += &way(10, &[1, 2], "solid");
+        xml += &way(11, &[2, 3], "solid");
+        xml += &way(12, &[4, 5], "dashed");
+        // 反向存储的右边界
+        xml += &way(13, &[6, 5], "solid");
+        xml += &way(14, &[7, 8], "solid");
+        xml += &lanelet(100, 10, 12, r#"<tag k="speed_limit" v="30 mph"/>"#);
+        xml += &lanelet(101, 11, 13, "");
+        xml += &lanelet(
+            102,
+            12,
+            14,
+            r#"<member type="relation" ref="200" role="regulatory_element"/>"#,
+        );
+        xml += r#"<relation id="200"><member type="way" ref="12" role="ref_line"/><tag k="type" v="regulatory_element"/><tag k="subtype" v="speed_limit"/><tag k="speed_limit" v="50"/></relation>"#;
+        xml += "</osm>";
+        xml
+    }
+
+    #[test]
+    fn test_parse_local_coordinates() {
+        let map = parse_lanelet2_internal(local_map().as_bytes(), None).unwrap();
+
+        assert_eq!(map.line_strings.len(), 5);
+        assert_eq!(map.lanelets.len(), 3);
+        assert_eq!(map.regulatory_elements.len(), 1);
+        assert!(map.origin_lat.is_none());
+
+        let l100 = map.find_lanelet(100).unwrap();
+        assert_eq!(l100.left.points[1].x, 10.0);
+        assert_eq!(l100.left.points[0].z, 1.5);
+        assert_eq!(l100.subtype.as_deref(), Some("road"));
+        assert!((l100.speed_limit.unwrap() - 30.0 * MPH_TO_KMH).abs() < 1e-9);
+        assert!(l100.one_way);
+
+        // 限速来自交通规则
+        assert_eq!(map.find_lanelet(102).unwrap().speed_limit, Some(50.0));
+
+        // 右边界已对齐到行驶方向
+        let l101 = map.find_lanelet(101).unwrap();
+        assert_eq!(l101.right.point_ids, vec![5, 6]);
+
+        assert_eq!(map.center().x, 10.0);
+        assert_eq!(map.center().y, 0.0);
+    }
+
+    #[test]
+    fn test_is_lanelet2() {
+        assert!(is_lanelet2(local_map().as_bytes()));
+        let osm = r#"<osm version="0.6"><node id="1" lat="0" lon="0"/><relation id="2"><tag k="type" v="route"/></relation></osm>"#;
+        assert!(!is_lanelet2(osm.as_bytes()));
+    }
+
+    #[test]
+    fn test_topology() {
+        let map = parse_lanelet2_internal(local_map().as_bytes(), None).unwrap();
+
+        let l100 = map.find_lanelet(100).unwrap();
+        assert_eq!(l100.successors, vec![101]);
+        assert_eq!(l100.right_neighbors, vec![102]);
+        assert!(l100.left_neighbors.is_empty());
+
+        let l101 = map.find_lanelet(101).unwrap();
+        assert_eq!(l101.predecessors, vec![100]);
+
+        let l102 = map.find_lanelet(102).unwrap();
+        assert_eq!(l102.left_neighbors, vec![100]);
+        assert!(l102.successors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_lat_lon() {
+        let xml = r#"<osm>
+            <node id="1" lat="48.0" lon="11.0"/>
+            <node id="2" lat="48.0" lon="11.001"/>
+            <node id="3" lat="48.00003" lon="11.0"/>
+            <node id="4" lat="48.00003" lon="11.001"/>
+            <way id="10"><nd ref="3"/><nd ref="4"/><tag k="type" v="line_thin"/></way>
+            <way id="11"><nd ref="1"/><nd ref="2"/><tag k="type" v="line_thin"/></way>
+            <relation id="20">
+                <member type="way" ref="10" role="left"/>
+                <member type="way" ref="11" role="right"/>
+                <tag k="type" v="lanelet"/>
+            </relation>
+        </osm>"#;
+
+        let map = parse_lanelet2_internal(xml.as_bytes(), None).unwrap();
+        assert_eq!(map.origin_lat, Some(48.0));
+        assert_eq!(map.origin_lon, Some(11.0));
+
+        let lanelet = &map.lanelets[0];
+        let right = &lanelet.right.points;
+        assert!(right[0].x.abs() < 1e-6 && right[0].y.abs() < 1e-6);
+        // 0.001° 经度在 48° 纬度约 74.6 m
+        assert!((right[1].x - 74.6).abs() < 0.5);
+        assert!((lanelet.left.points[0].y - 3.336).abs() < 0.05);
+
+        // 指定原点
+        let map = parse_lanelet2_internal(xml.as_bytes(), Some((48.00003, 11.0))).unwrap();
+        assert!(map.lanelets[0].left.points[0].y.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_area() {
+        let xml = r#"<osm>
+            <node id="1" lat="0" lon="0"><tag k="local_x" v="0"/><tag k="local_y" v="0"/></node>
+            <node id="2" lat="0" lon="0"><tag k="local_x" v="4"/><tag k="local_y" v="0"/></node>
+            <node id="3" lat="0" lon="0"><tag k="local_x" v="4"/><tag k="local_y" v="4"/></node>
+            <node id="4" lat="0" lon="0"><tag k="local_x" v="0"/><tag k="local_y" v="4"/></node>
+            <way id="10"><nd ref="1"/><nd ref="2"/><nd ref="3"/></way>
+            <way id="11"><nd ref="1"/><nd ref="4"/><nd ref="3"/></way>
+            <relation id="20">
+                <member type="way" ref="10" role="outer"/>
+                <member type="way" ref="11" role="outer"/>
+                <tag k="type" v="multipolygon"/>
+                <tag k="subtype" v="parking"/>
+            </relation>
+        </osm>"#;
+
+        let map = parse_lanelet2_internal(xml.as_bytes(), None).unwrap();
+        assert_eq!(map.areas.len(), 1);
+        let area = &map.areas[0];
+        assert_eq!(area.subtype.as_deref(), Some("parking"));
+        assert_eq!(area.outer.len(), 4);
+        assert_eq!(area.outer[2].x, 4.0);
+        assert_eq!(area.outer[2].y, 4.0);
+    }
+
+    #[test]
+    fn test_missing_node() {
+        let xml = r#"<osm><way id="1"><nd ref="9"/></way></osm>"#;
+        assert!(parse_lanelet2_internal(xml.as_bytes(), None).is_err());
+    }
+}
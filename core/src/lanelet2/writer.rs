@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+
+use crate::lanelet2::models::{Lanelet2Map, Lanelet2Tag};
+use crate::math::vec3::Vec3;
+
+/// 局部坐标 (x, y) 到经纬度 (lat, lon) 的转换
+pub type GeoTransform<'a> = &'a dyn Fn(f64, f64) -> (f64, f64);
+
+/// 输出 OSM 节点时使用的坐标系
+pub enum OsmFrame<'a> {
+    /// 局部米制坐标：写入 `local_x`/`local_y` 标签，lat/lon 置 0
+    Local,
+    /// 经纬度：通过转换函数计算 lat/lon
+    Geographic(GeoTransform<'a>),
+}
+
+/// 将 Lanelet2 地图序列化为 OSM XML
+///
+/// 所有线串（含 lanelet 边界）按 ID 去重输出为 way，area 的外环和内环
+/// 以新分配的节点和 way 输出为 multipolygon。
+pub fn write_osm(map: &Lanelet2Map, frame: &OsmFrame) -> String {
+    // 节点与 way 按 ID 去重
+    let mut nodes: BTreeMap<i64, Vec3> = BTreeMap::new();
+    let mut ways = BTreeMap::new();
+    let line_strings = map
+        .line_strings
+        .iter()
+        .chain(map.lanelets.iter().flat_map(|l| {
+            [Some(&l.left), Some(&l.right), l.centerline.as_ref()]
+                .into_iter()
+                .flatten()
+        }));
+    for ls in line_strings {
+        for (id, p) in ls.point_ids.iter().zip(&ls.points) {
+            nodes.entry(*id).or_insert(*p);
+        }
+        ways.entry(ls.id).or_insert(ls);
+    }
+
+    let mut next_id = nodes
+        .keys()
+        .chain(ways.keys())
+        .chain(map.lanelets.iter().map(|l| &l.id))
+        .chain(map.areas.iter().map(|a| &a.id))
+        .chain(map.regulatory_elements.iter().map(|r| &r.id))
+        .copied()
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    // area 的环需要新建节点和 way：(way ID, 节点 ID)
+    let mut area_ways: Vec<(i64, Vec<i64>)> = Vec::new();
+    let mut area_members: Vec<Vec<(i64, &str)>> = Vec::new();
+    for area in &map.areas {
+        let mut members = Vec::new();
+        let rings = std::iter::once((&area.outer, "outer"))
+            .chain(area.inner.iter().map(|ring| (ring, "inner")));
+        for (ring, role) in rings {
+            let mut ids: Vec<i64> = ring
+                .iter()
+                .map(|p| {
+                    let id = next_id;
+                    next_id += 1;
+                    nodes.insert(id, *p);
+                    id
+                })
+                .collect();
+            if let Some(&first) = ids.first() {
+                ids.push(first);
+            }
+            let way_id = next_id;
+            next_id += 1;
+            area_ways.push((way_id, ids));
+            members.push((way_id, role));
+        }
+        area_members.push(members);
+    }
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<osm version=\"0.6\" generator=\"maphub\">\n");
+
+    for (id, p) in &nodes {
+        let (lat, lon) = match frame {
+            OsmFrame::Local => (0.0, 0.0),
+            OsmFrame::Geographic(to_geo) => to_geo(p.x, p.y),
+        };
+        let _ = writeln!(
+            xml,
+            "  <node id=\"{id}\" lat=\"{lat:.9}\" lon=\"{lon:.9}\">"
+        );
+        if matches!(frame, OsmFrame::Local) {
+            write_tag(&mut xml, "local_x", &format!("{:.4}", p.x));
+            write_tag(&mut xml, "local_y", &format!("{:.4}", p.y));
+        }
+        write_tag(&mut xml, "ele", &format!("{:.4}", p.z));
+        xml.push_str("  </node>\n");
+    }
+
+    for (id, ls) in &ways {
+        let _ = writeln!(xml, "  <way id=\"{id}\">");
+        for node in &ls.point_ids {
+            let _ = writeln!(xml, "    <nd ref=\"{node}\"/>");
+        }
+        if ls.tag("type").is_none() && !ls.line_type.is_empty() {
+            write_tag(&mut xml, "type", &ls.line_type);
+        }
+        if ls.tag("subtype").is_none()
+            && let Some(subtype) = &ls.subtype
+        {
+            write_tag(&mut xml, "subtype", subtype);
+        }
+        write_tags(&mut xml, &ls.tags);
+        xml.push_str("  </way>\n");
+    }
+    for (id, node_ids) in &area_ways {
+        let _ = writeln!(xml, "  <way id=\"{id}\">");
+        for node in node_ids {
+            let _ = writeln!(xml, "    <nd ref=\"{node}\"/>");
+        }
+        xml.push_str("  </way>\n");
+    }
+
+    for re in &map.regulatory_elements {
+        let _ = writeln!(xml, "  <relation id=\"{}\">", re.id);
+        for m in &re.members {
+            write_member(&mut xml, &m.member_type, m.reference, &m.role);
+        }
+        if re.tag("type").is_none() {
+            write_tag(&mut xml, "type", "regulatory_element");
+        }
+        write_tags(&mut xml, &re.tags);
+        xml.push_str("  </relation>\n");
+    }
+
+    for lanelet in &map.lanelets {
+        let _ = writeln!(xml, "  <relation id=\"{}\">", lanelet.id);
+        write_member(&mut xml, "way", lanelet.left.id, "left");
+        write_member(&mut xml, "way", lanelet.right.id, "right");
+        if let Some(centerline) = &lanelet.centerline {
+            write_member(&mut xml, "way", centerline.id, "centerline");
+        }
+        for re in &lanelet.regulatory_elements {
+            write_member(&mut xml, "relation", *re, "regulatory_element");
+        }
+        if lanelet.tag("type").is_none() {
+            write_tag(&mut xml, "type", "lanelet");
+        }
+        write_tags(&mut xml, &lanelet.tags);
+        xml.push_str("  </relation>\n");
+    }
+
+    for (area, members) in map.areas.iter().zip(&area_members) {
+        let _ = writeln!(xml, "  <relation id=\"{}\">", area.id);
+        for (way, role) in members {
+            write_member(&mut xml, "way", *way, role);
+        }
+        for re in &area.regulatory_elements {
+            write_member(&mut xml, "relation", *re, "regulatory_element");
+        }
+        if !area.tags.iter().any(|t| t.key == "type") {
+            write_tag(&mut xml, "type", "multipolygon");
+        }
+        write_tags(&mut xml, &area.tags);
+        xml.push_str("  </relation>\n");
+    }
+
+    xml.push_str("</osm>\n");
+    xml
+}
+
+fn write_tag(xml: &mut String, key: &str, value: &str) {
+    let _ = writeln!(
+        xml,
+        "    <tag k=\"{}\" v=\"{}\"/>",
+        escape(key),
+        escape(value)
+    );
+}
+
+fn write_tags(xml: &mut String, tags: &[Lanelet2Tag]) {
+    for tag in tags {
+        write_tag(xml, &tag.key, &tag.value);
+    }
+}
+
+fn write_member(xml: &mut String, member_type: &str, reference: i64, role: &str) {
+    let _ = writeln!(
+        xml,
+        "    <member type=\"{}\" ref=\"{}\" role=\"{}\"/>",
+        escape(member_type),
+        reference,
+        escape(role)
+    );
+}
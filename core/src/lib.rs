@@ -3,7 +3,12 @@ mod utils;
 use wasm_bindgen::prelude::*;
 
 pub mod apollo;
+pub mod commonroad;
 pub mod fs;
+pub mod geojson;
+pub mod gltf;
+pub mod json;
+pub mod lanelet2;
 pub mod math;
 pub mod odr;
 
@@ -17,4 +22,6 @@ extern "C" {
 pub enum MapFormatType {
     OpenDrive = "opendrive",
     Apollo = "apollo",
+    Lanelet2 = "lanelet2",
+    CommonRoad = "commonroad",
 }
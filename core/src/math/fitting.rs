@@ -0,0 +1,174 @@
+/// 三次多项式分段
+///
+/// `f(s) = a + b*ds + c*ds² + d*ds³`，其中 `ds = s - self.s`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicSegment {
+    /// 分段起点
+    pub s: f64,
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+}
+
+impl CubicSegment {
+    /// 计算分段在 s 处的值
+    pub fn eval(&self, s: f64) -> f64 {
+        let ds = s - self.s;
+        self.a + self.b * ds + self.c * ds * ds + self.d * ds * ds * ds
+    }
+
+    /// 由两端的值和导数构造 Hermite 三次多项式
+    ///
+    /// # 参数
+    /// - `s0`, `s1`: 分段起点和终点
+    /// - `v0`, `v1`: 两端的值
+    /// - `m0`, `m1`: 两端的导数
+    pub fn hermite(s0: f64, s1: f64, v0: f64, v1: f64, m0: f64, m1: f64) -> Self {
+        let h = s1 - s0;
+        if h.abs() < 1e-12 {
+            return Self {
+                s: s0,
+                a: v0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+            };
+        }
+
+        let slope = (v1 - v0) / h;
+        Self {
+            s: s0,
+            a: v0,
+            b: m0,
+            c: (3.0 * slope - 2.0 * m0 - m1) / h,
+            d: (m0 + m1 - 2.0 * slope) / (h * h),
+        }
+    }
+}
+
+/// 用分段三次多项式拟合采样序列
+///
+/// 以 Hermite 插值连接采样点，误差超过 `tolerance` 时在中点处递归细分，
+/// 因此结果在分段之间保持值和一阶导数连续。
+///
+/// # 参数
+/// - `samples`: (s, value) 采样点，s 必须严格递增
+/// - `tolerance`: 允许的最大绝对误差
+pub fn fit_cubic_segments(samples: &[(f64, f64)], tolerance: f64) -> Vec<CubicSegment> {
+    match samples.len() {
+        0 => return Vec::new(),
+        1 => {
+            return vec![CubicSegment {
+                s: samples[0].0,
+                a: samples[0].1,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+            }];
+        }
+        _ => {}
+    }
+
+    let slopes = estimate_slopes(samples);
+    let mut segments = Vec::new();
+    fit_range(
+        samples,
+        &slopes,
+        0,
+        samples.len() - 1,
+        tolerance,
+        &mut segments,
+    );
+    segments
+}
+
+/// 有限差分估计每个采样点处的导数
+fn estimate_slopes(samples: &[(f64, f64)]) -> Vec<f64> {
+    let n = samples.len();
+    (0..n)
+        .map(|i| {
+            let (i0, i1) = match i {
+                0 => (0, 1),
+                _ if i == n - 1 => (n - 2, n - 1),
+                _ => (i - 1, i + 1),
+            };
+            let ds = samples[i1].0 - samples[i0].0;
+            if ds.abs() < 1e-12 {
+                0.0
+            } else {
+                (samples[i1].1 - samples[i0].1) / ds
+            }
+        })
+        .collect()
+}
+
+fn fit_range(
+    samples: &[(f64, f64)],
+    slopes: &[f64],
+    start: usize,
+    end: usize,
+    tolerance: f64,
+    out: &mut Vec<CubicSegment>,
+) {
+    let (s0, v0) = samples[start];
+    let (s1, v1) = samples[end];
+    let segment = CubicSegment::hermite(s0, s1, v0, v1, slopes[start], slopes[end]);
+
+    let fits = samples[start + 1..end]
+        .iter()
+        .all(|&(s, v)| (segment.eval(s) - v).abs() <= tolerance);
+
+    if fits || end - start <= 1 {
+        out.push(segment);
+        return;
+    }
+
+    let mid = (start + end) / 2;
+    fit_range(samples, slopes, start, mid, tolerance, out);
+    fit_range(samples, slopes, mid, end, tolerance, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hermite_reproduces_cubic() {
+        // f(s) = 1 + 2s - s² + 0.5s³
+        let f = |s: f64| 1.0 + 2.0 * s - s * s + 0.5 * s * s * s;
+        let df = |s: f64| 2.0 - 2.0 * s + 1.5 * s * s;
+
+        let seg = CubicSegment::hermite(0.0, 2.0, f(0.0), f(2.0), df(0.0), df(2.0));
+        for i in 0..=10 {
+            let s = i as f64 * 0.2;
+            assert!((seg.eval(s) - f(s)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fit_constant() {
+        let samples: Vec<(f64, f64)> = (0..20).map(|i| (i as f64, 3.5)).collect();
+        let segments = fit_cubic_segments(&samples, 1e-3);
+
+        assert_eq!(segments.len(), 1);
+        assert!((segments[0].a - 3.5).abs() < 1e-10);
+        assert!(segments[0].b.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_fit_within_tolerance() {
+        let samples: Vec<(f64, f64)> = (0..200)
+            .map(|i| {
+                let s = i as f64 * 0.5;
+                (s, (s * 0.1).sin())
+            })
+            .collect();
+        let segments = fit_cubic_segments(&samples, 1e-3);
+
+        for &(s, v) in &samples {
+            let seg = segments.iter().rfind(|seg| seg.s <= s).unwrap();
+            assert!((seg.eval(s) - v).abs() <= 1e-3);
+        }
+    }
+}
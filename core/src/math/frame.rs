@@ -0,0 +1,196 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::{mesh::MeshData, vec3::Vec3};
+
+/// 坐标系的上方向
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpAxis {
+    #[default]
+    Y,
+    Z,
+}
+
+/// 坐标系手性
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Handedness {
+    #[default]
+    Right,
+    Left,
+}
+
+/// 网格构建器的输出坐标系
+///
+/// 由上方向、手性、单位缩放和原点组成。OpenDRIVE 坐标 (x, y, z) 先减去原点、
+/// 乘以单位缩放，再按轴约定映射：
+///
+/// | 上方向 | 手性 | 输出 | 典型用途 |
+/// |---|---|---|---|
+/// | Y | 右手 | (x, z, -y) | WebGL / three.js（默认） |
+/// | Y | 左手 | (x, z, y) | Unity |
+/// | Z | 右手 | (x, y, z) | ROS (ENU) |
+/// | Z | 左手 | (x, -y, z) | Unreal |
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshFrame {
+    pub up_axis: UpAxis,
+    pub handedness: Handedness,
+    /// 每米对应的输出单位数，如厘米为 100
+    pub unit_scale: f64,
+    /// 输出坐标原点（OpenDRIVE 坐标），用于解决大坐标精度问题
+    pub origin: Vec3,
+}
+
+#[wasm_bindgen]
+impl MeshFrame {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        up_axis: UpAxis,
+        handedness: Handedness,
+        unit_scale: Option<f64>,
+        origin: Option<Vec3>,
+    ) -> Self {
+        Self {
+            up_axis,
+            handedness,
+            unit_scale: unit_scale.unwrap_or(1.0),
+            origin: origin.unwrap_or_default(),
+        }
+    }
+
+    /// WebGL / three.js：Y 轴向上、右手系、米
+    pub fn webgl(origin: Option<Vec3>) -> Self {
+        Self::new(UpAxis::Y, Handedness::Right, None, origin)
+    }
+
+    /// ROS ENU：Z 轴向上、右手系、米
+    pub fn enu(origin: Option<Vec3>) -> Self {
+        Self::new(UpAxis::Z, Handedness::Right, None, origin)
+    }
+
+    /// Unreal：Z 轴向上、左手系、厘米
+    pub fn unreal(origin: Option<Vec3>) -> Self {
+        Self::new(UpAxis::Z, Handedness::Left, Some(100.0), origin)
+    }
+}
+
+impl Default for MeshFrame {
+    fn default() -> Self {
+        Self::webgl(None)
+    }
+}
+
+impl MeshFrame {
+    /// 将 OpenDRIVE 坐标转换为输出坐标
+    pub fn transform_point(&self, p: Vec3) -> [f32; 3] {
+        let x = (p.x - self.origin.x) * self.unit_scale;
+        let y = (p.y - self.origin.y) * self.unit_scale;
+        let z = (p.z - self.origin.z) * self.unit_scale;
+        let v = match (self.up_axis, self.handedness) {
+            (UpAxis::Y, Handedness::Right) => [x, z, -y],
+            (UpAxis::Y, Handedness::Left) => [x, z, y],
+            (UpAxis::Z, Handedness::Right) => [x, y, z],
+            (UpAxis::Z, Handedness::Left) => [x, -y, z],
+        };
+        v.map(|c| c as f32)
+    }
+
+    /// 输出坐标系下的单位上方向
+    pub fn up(&self) -> [f32; 3] {
+        match self.up_axis {
+            UpAxis::Y => [0.0, 1.0, 0.0],
+            UpAxis::Z => [0.0, 0.0, 1.0],
+        }
+    }
+
+    /// 输出坐标对应的水平坐标（相对原点的 OpenDRIVE x/y，米）
+    pub fn ground_xy(&self, v: [f32; 3]) -> [f32; 2] {
+        let [x, y] = match (self.up_axis, self.handedness) {
+            (UpAxis::Y, Handedness::Right) => [v[0], -v[2]],
+            (UpAxis::Y, Handedness::Left) => [v[0], v[2]],
+            (UpAxis::Z, Handedness::Right) => [v[0], v[1]],
+            (UpAxis::Z, Handedness::Left) => [v[0], -v[1]],
+        };
+        let scale = self.unit_scale as f32;
+        [x / scale, y / scale]
+    }
+
+    /// 转换坐标并追加到顶点数组
+    pub fn push_point(&self, vertices: &mut Vec<f32>, p: Vec3) {
+        vertices.extend_from_slice(&self.transform_point(p));
+    }
+
+    /// 由输出坐标系下的顶点和索引生成网格
+    ///
+    /// 三角形保持物理绕序不变；左手系下叉积方向相反，因此将求得的法线取反，
+    /// 使路面法线始终朝上。退化顶点的法线取坐标系的上方向。
+    pub fn mesh(&self, vertices: Vec<f32>, indices: Vec<u32>) -> MeshData {
+        let left = self.handedness == Handedness::Left;
+        let up = self.up().map(|c| if left { -c } else { c });
+        let mut normals = MeshData::calculate_normals(&vertices, &indices, up);
+        if left {
+            normals.iter_mut().for_each(|n| *n = -*n);
+        }
+        MeshData::new(vertices, indices, normals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_point() {
+        let origin = Some(Vec3::new(10.0, 20.0, 1.0));
+        let p = Vec3::new(11.0, 22.0, 4.0);
+
+        assert_eq!(
+            MeshFrame::webgl(origin).transform_point(p),
+            [1.0, 3.0, -2.0]
+        );
+        assert_eq!(MeshFrame::enu(origin).transform_point(p), [1.0, 2.0, 3.0]);
+        assert_eq!(
+            MeshFrame::unreal(origin).transform_point(p),
+            [100.0, -200.0, 300.0]
+        );
+        let unity = MeshFrame::new(UpAxis::Y, Handedness::Left, None, origin);
+        assert_eq!(unity.transform_point(p), [1.0, 3.0, 2.0]);
+
+        for frame in [
+            MeshFrame::webgl(origin),
+            MeshFrame::enu(origin),
+            MeshFrame::unreal(origin),
+            unity,
+        ] {
+            assert_eq!(frame.ground_xy(frame.transform_point(p)), [1.0, 2.0]);
+        }
+    }
+
+    #[test]
+    fn test_mesh_normals_point_up() {
+        // OpenDRIVE 中逆时针（从上方看）的三角形
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        for (frame, up) in [
+            (MeshFrame::webgl(None), 1),
+            (MeshFrame::enu(None), 2),
+            (MeshFrame::unreal(None), 2),
+            (MeshFrame::new(UpAxis::Y, Handedness::Left, None, None), 1),
+        ] {
+            let mut vertices = Vec::new();
+            for p in points {
+                frame.push_point(&mut vertices, p);
+            }
+            let mesh = frame.mesh(vertices, vec![0, 1, 2]);
+            assert!((mesh.normals[up] - 1.0).abs() < 1e-6, "{:?}", frame);
+
+            // 退化三角形的法线取坐标系的上方向
+            let mesh = frame.mesh(vec![0.0; 9], vec![0, 1, 2]);
+            assert_eq!(&mesh.normals[..3], &frame.up(), "{:?}", frame);
+        }
+    }
+}
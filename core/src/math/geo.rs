@@ -0,0 +1,298 @@
+//! 经纬度与平面坐标之间的投影
+//!
+//! 仅实现 WGS84 椭球上的横轴墨卡托（包括 UTM）投影，满足 OpenDRIVE geoReference、
+//! Lanelet2 原点投影等场景的需要。
+
+/// WGS84 长半轴（米）
+const WGS84_A: f64 = 6378137.0;
+/// WGS84 扁率
+const WGS84_F: f64 = 1.0 / 298.257223563;
+/// UTM 比例因子
+const UTM_K0: f64 = 0.9996;
+
+/// 横轴墨卡托投影（WGS84 椭球）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransverseMercator {
+    /// 原点纬度（弧度）
+    pub lat0: f64,
+    /// 中央经线（弧度）
+    pub lon0: f64,
+    /// 中央经线比例因子
+    pub k0: f64,
+    /// 东偏移（米）
+    pub false_easting: f64,
+    /// 北偏移（米）
+    pub false_northing: f64,
+}
+
+impl TransverseMercator {
+    /// 创建横轴墨卡托投影，角度单位为度
+    pub fn new(lat0: f64, lon0: f64, k0: f64, false_easting: f64, false_northing: f64) -> Self {
+        Self {
+            lat0: lat0.to_radians(),
+            lon0: lon0.to_radians(),
+            k0,
+            false_easting,
+            false_northing,
+        }
+    }
+
+    /// 创建 UTM 投影
+    ///
+    /// # 参数
+    /// - `zone`: UTM 分带号 (1..=60)
+    /// - `south`: 是否为南半球
+    pub fn utm(zone: u8, south: bool) -> Self {
+        let lon0 = zone as f64 * 6.0 - 183.0;
+        let false_northing = if south { 10_000_000.0 } else { 0.0 };
+        Self::new(0.0, lon0, UTM_K0, 500_000.0, false_northing)
+    }
+
+    /// 创建经纬度所在分带的 UTM 投影
+    pub fn utm_for(lat: f64, lon: f64) -> Self {
+        Self::utm(utm_zone(lon), lat < 0.0)
+    }
+
+    /// 从 PROJ 字符串解析投影
+    ///
+    /// 支持 `+proj=tmerc` 和 `+proj=utm`，其余投影返回 None。
+    ///
+    /// ```text
+    /// +proj=tmerc +lat_0=49.0 +lon_0=8.4 +k=1 +x_0=0 +y_0=0 +ellps=WGS84
+    /// +proj=utm +zone=32 +ellps=WGS84 +units=m
+    /// ```
+    pub fn from_proj(proj: &str) -> Option<Self> {
+        let params = parse_proj_params(proj);
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .and_then(|(_, v)| v.parse::<f64>().ok())
+        };
+
+        match params.iter().find(|(k, _)| k == "proj")?.1.as_str() {
+            "utm" => {
+                let zone = get("zone")? as u8;
+                let south = params.iter().any(|(k, _)| k == "south");
+                Some(Self::utm(zone, south))
+            }
+            "tmerc" => Some(Self::new(
+                get("lat_0").unwrap_or(0.0),
+                get("lon_0").unwrap_or(0.0),
+                get("k").or_else(|| get("k_0")).unwrap_or(1.0),
+                get("x_0").unwrap_or(0.0),
+                get("y_0").unwrap_or(0.0),
+            )),
+            _ => None,
+        }
+    }
+
+    /// 生成 PROJ 字符串
+    pub fn to_proj(&self) -> String {
+        format!(
+            "+proj=tmerc +lat_0={} +lon_0={} +k={} +x_0={} +y_0={} +ellps=WGS84 +units=m +no_defs",
+            self.lat0.to_degrees(),
+            self.lon0.to_degrees(),
+            self.k0,
+            self.false_easting,
+            self.false_northing
+        )
+    }
+
+    /// 经纬度（度）投影为平面坐标 (x, y)
+    pub fn forward(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let ep2 = e2 / (1.0 - e2);
+
+        let phi = lat.to_radians();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let tan_phi = phi.tan();
+
+        let n = WGS84_A / (1.0 - e2 * sin_phi * sin_phi).sqrt();
+        let t = tan_phi * tan_phi;
+        let c = ep2 * cos_phi * cos_phi;
+        let a = (lon.to_radians() - self.lon0) * cos_phi;
+
+        let m = meridian_arc(phi);
+        let m0 = meridian_arc(self.lat0);
+
+        let x = self.k0
+            * n
+            * (a + (1.0 - t + c) * a.powi(3) / 6.0
+                + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * a.powi(5) / 120.0);
+        let y = self.k0
+            * (m - m0
+                + n * tan_phi
+                    * (a * a / 2.0
+                        + (5.0 - t + 9.0 * c + 4.0 * c * c) * a.powi(4) / 24.0
+                        + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * a.powi(6) / 720.0));
+
+        (x + self.false_easting, y + self.false_northing)
+    }
+
+    /// 平面坐标反投影为经纬度（度），返回 (lat, lon)
+    pub fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let ep2 = e2 / (1.0 - e2);
+        let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+        let m = meridian_arc(self.lat0) + (y - self.false_northing) / self.k0;
+        let mu = m / (WGS84_A * (1.0 - e2 / 4.0 - 3.0 * e2 * e2 / 64.0 - 5.0 * e2.powi(3) / 256.0));
+
+        // 底点纬度
+        let phi1 = mu
+            + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+            + (21.0 * e1 * e1 / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+            + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+            + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+        let (sin_phi1, cos_phi1) = phi1.sin_cos();
+        let tan_phi1 = phi1.tan();
+        let n1 = WGS84_A / (1.0 - e2 * sin_phi1 * sin_phi1).sqrt();
+        let r1 = WGS84_A * (1.0 - e2) / (1.0 - e2 * sin_phi1 * sin_phi1).powf(1.5);
+        let t1 = tan_phi1 * tan_phi1;
+        let c1 = ep2 * cos_phi1 * cos_phi1;
+        let d = (x - self.false_easting) / (n1 * self.k0);
+
+        let lat = phi1
+            - (n1 * tan_phi1 / r1)
+                * (d * d / 2.0
+                    - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1 - 9.0 * ep2) * d.powi(4) / 24.0
+                    + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1 * t1
+                        - 252.0 * ep2
+                        - 3.0 * c1 * c1)
+                        * d.powi(6)
+                        / 720.0);
+        let lon = self.lon0
+            + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+                + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1 * c1 + 8.0 * ep2 + 24.0 * t1 * t1)
+                    * d.powi(5)
+                    / 120.0)
+                / cos_phi1;
+
+        (lat.to_degrees(), lon.to_degrees())
+    }
+}
+
+/// 以某个经纬度原点为局部坐标原点的投影
+///
+/// 先用横轴墨卡托投影，再减去原点的投影坐标，使局部坐标数值较小。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalProjector {
+    pub projection: TransverseMercator,
+    /// 原点在投影坐标系下的坐标
+    pub offset_x: f64,
+    pub offset_y: f64,
+}
+
+impl LocalProjector {
+    /// 使用原点所在分带的 UTM 投影（与 Lanelet2 的 UtmProjector 一致）
+    pub fn utm(origin_lat: f64, origin_lon: f64) -> Self {
+        Self::new(
+            TransverseMercator::utm_for(origin_lat, origin_lon),
+            origin_lat,
+            origin_lon,
+        )
+    }
+
+    pub fn new(projection: TransverseMercator, origin_lat: f64, origin_lon: f64) -> Self {
+        let (offset_x, offset_y) = projection.forward(origin_lat, origin_lon);
+        Self {
+            projection,
+            offset_x,
+            offset_y,
+        }
+    }
+
+    /// 经纬度（度）转局部坐标 (x, y)
+    pub fn to_local(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let (x, y) = self.projection.forward(lat, lon);
+        (x - self.offset_x, y - self.offset_y)
+    }
+
+    /// 局部坐标转经纬度（度），返回 (lat, lon)
+    pub fn to_geo(&self, x: f64, y: f64) -> (f64, f64) {
+        self.projection
+            .inverse(x + self.offset_x, y + self.offset_y)
+    }
+}
+
+/// 经度所在的 UTM 分带号
+pub fn utm_zone(lon: f64) -> u8 {
+    (((lon + 180.0) / 6.0).floor() as i32).clamp(0, 59) as u8 + 1
+}
+
+/// 子午线弧长
+fn meridian_arc(phi: f64) -> f64 {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let e4 = e2 * e2;
+    let e6 = e4 * e2;
+
+    WGS84_A
+        * ((1.0 - e2 / 4.0 - 3.0 * e4 / 64.0 - 5.0 * e6 / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e4 / 32.0 + 45.0 * e6 / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e4 / 256.0 + 45.0 * e6 / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e6 / 3072.0) * (6.0 * phi).sin())
+}
+
+/// 解析 PROJ 字符串中的 `+key=value` 参数
+fn parse_proj_params(proj: &str) -> Vec<(String, String)> {
+    proj.split_whitespace()
+        .filter_map(|token| token.strip_prefix('+'))
+        .map(|token| match token.split_once('=') {
+            Some((k, v)) => (k.to_string(), v.to_string()),
+            None => (token.to_string(), String::new()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utm_forward() {
+        // 中央经线上：x 为东偏移，y 为缩放后的子午线弧长（45° 处为 4984944.378 米）
+        let utm = TransverseMercator::utm(32, false);
+        let (x, y) = utm.forward(45.0, 9.0);
+        assert!((x - 500_000.0).abs() < 1e-6);
+        assert!((y - 4_984_944.378 * 0.9996).abs() < 0.01);
+
+        let (x, _) = utm.forward(45.0, 8.0);
+        assert!(x < 500_000.0);
+        assert_eq!(utm_zone(8.4), 32);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tm = TransverseMercator::new(35.0, 139.0, 1.0, 0.0, 0.0);
+        let (x, y) = tm.forward(35.01, 139.02);
+        let (lat, lon) = tm.inverse(x, y);
+        assert!((lat - 35.01).abs() < 1e-9);
+        assert!((lon - 139.02).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_local_projector() {
+        let projector = LocalProjector::utm(49.0, 8.4);
+        let (x, y) = projector.to_local(49.0, 8.4);
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+
+        // 向北约 111 米
+        let (_, y) = projector.to_local(49.001, 8.4);
+        assert!((y - 111.2).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_from_proj() {
+        let tm =
+            TransverseMercator::from_proj("+proj=tmerc +lat_0=49 +lon_0=8.4 +k=1 +x_0=0 +y_0=0")
+                .unwrap();
+        assert!((tm.lon0.to_degrees() - 8.4).abs() < 1e-12);
+        assert_eq!(tm.k0, 1.0);
+
+        let utm = TransverseMercator::from_proj("+proj=utm +zone=51 +south").unwrap();
+        assert_eq!(utm.false_northing, 10_000_000.0);
+        assert!(TransverseMercator::from_proj("+proj=merc").is_none());
+    }
+}
@@ -1,6 +1,9 @@
 use wasm_bindgen::prelude::*;
 
-use super::vec3::Vec3;
+use super::{frame::MeshFrame, vec3::Vec3};
+
+pub mod obj;
+pub mod ply;
 
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
@@ -8,19 +11,34 @@ pub struct MeshData {
     #[wasm_bindgen(getter_with_clone)]
     pub vertices: Vec<f32>,
     #[wasm_bindgen(getter_with_clone)]
-    pub indices: Vec<u16>,
+    pub indices: Vec<u32>,
     #[wasm_bindgen(getter_with_clone)]
     pub normals: Vec<f32>,
+    /// 纹理坐标 (u, v)，每个顶点 2 个分量；为空表示网格没有 UV
+    #[wasm_bindgen(getter_with_clone)]
+    pub uvs: Vec<f32>,
+    /// 命名的逐顶点标量属性，每个顶点 1 个分量
+    #[wasm_bindgen(skip)]
+    pub attributes: Vec<MeshAttribute>,
+}
+
+/// 命名的逐顶点标量属性
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshAttribute {
+    pub name: String,
+    pub values: Vec<f32>,
 }
 
 #[wasm_bindgen]
 impl MeshData {
     #[wasm_bindgen(constructor)]
-    pub fn new(vertices: Vec<f32>, indices: Vec<u16>, normals: Vec<f32>) -> Self {
+    pub fn new(vertices: Vec<f32>, indices: Vec<u32>, normals: Vec<f32>) -> Self {
         Self {
             vertices,
             indices,
             normals,
+            uvs: Vec::new(),
+            attributes: Vec::new(),
         }
     }
 
@@ -30,20 +48,89 @@ impl MeshData {
             vertices: Vec::new(),
             indices: Vec::new(),
             normals: Vec::new(),
+            uvs: Vec::new(),
+            attributes: Vec::new(),
         }
     }
+
+    /// 所有顶点属性的名称
+    #[wasm_bindgen(js_name = "attributeNames")]
+    pub fn attribute_names(&self) -> Vec<String> {
+        self.attributes.iter().map(|a| a.name.clone()).collect()
+    }
+
+    /// 按名称获取顶点属性，不存在时返回 undefined
+    pub fn attribute(&self, name: &str) -> Option<Vec<f32>> {
+        self.attributes
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| a.values.clone())
+    }
 }
 
 impl MeshData {
+    /// 设置纹理坐标
+    pub fn with_uvs(mut self, uvs: Vec<f32>) -> Self {
+        debug_assert_eq!(uvs.len() / 2, self.vertices.len() / 3);
+        self.uvs = uvs;
+        self
+    }
+
+    /// 设置顶点属性
+    pub fn with_attributes(mut self, attributes: Vec<MeshAttribute>) -> Self {
+        debug_assert!(
+            attributes
+                .iter()
+                .all(|a| a.values.len() == self.vertices.len() / 3)
+        );
+        self.attributes = attributes;
+        self
+    }
+
     /// 合并另一个 mesh 到当前 mesh
     ///
-    /// 自动处理索引偏移
+    /// 自动处理索引偏移。任一网格有 UV 时，缺少 UV 的一方以 (0, 0) 补齐；
+    /// 顶点属性按名称合并，缺少的属性以 0 补齐。
     pub fn merge(&mut self, other: MeshData) {
         if other.vertices.is_empty() {
             return;
         }
 
-        let vertex_offset = (self.vertices.len() / 3) as u16;
+        let self_count = self.vertices.len() / 3;
+        let vertex_count = self_count + other.vertices.len() / 3;
+        if !self.uvs.is_empty() || !other.uvs.is_empty() {
+            self.uvs.resize(self_count * 2, 0.0);
+            self.uvs.extend_from_slice(&other.uvs);
+            self.uvs.resize(vertex_count * 2, 0.0);
+        }
+        for attribute in other.attributes {
+            let target = match self
+                .attributes
+                .iter()
+                .position(|a| a.name == attribute.name)
+            {
+                Some(i) => &mut self.attributes[i],
+                None => {
+                    self.attributes.push(MeshAttribute {
+                        name: attribute.name,
+                        values: Vec::new(),
+                    });
+                    self.attributes.last_mut().unwrap()
+                }
+            };
+            target.values.resize(self_count, 0.0);
+            target.values.extend_from_slice(&attribute.values);
+        }
+        for attribute in &mut self.attributes {
+            attribute.values.resize(vertex_count, 0.0);
+        }
+
+        let vertex_offset = u32::try_from(self.vertices.len() / 3)
+            .expect("mesh vertex count exceeds u32 index range");
+        debug_assert!(
+            (self.vertices.len() + other.vertices.len()) / 3 <= u32::MAX as usize,
+            "merged mesh vertex count exceeds u32 index range"
+        );
 
         self.vertices.extend_from_slice(&other.vertices);
 
@@ -60,11 +147,11 @@ impl MeshData {
     ///
     /// # 参数
     /// * `num_samples` - 采样点数量（每个采样点有 2 个顶点）
-    pub fn generate_strip_indices(num_samples: usize) -> Vec<u16> {
+    pub fn generate_strip_indices(num_samples: usize) -> Vec<u32> {
         let mut indices = Vec::new();
 
         for i in 0..(num_samples - 1) {
-            let base = (i * 2) as u16;
+            let base = (i * 2) as u32;
 
             // 每个四边形分成两个三角形
             // Triangle 1: inner[i], outer[i], inner[i+1]
@@ -83,8 +170,8 @@ impl MeshData {
 
     /// 计算顶点法线
     ///
-    /// 使用面法线的平均值作为顶点法线
-    pub fn calculate_normals(vertices: &[f32], indices: &[u16]) -> Vec<f32> {
+    /// 使用面法线的平均值作为顶点法线，不属于任何有效三角形的顶点使用 `up`
+    pub fn calculate_normals(vertices: &[f32], indices: &[u32], up: [f32; 3]) -> Vec<f32> {
         let mut normals = vec![0.0f32; vertices.len()];
 
         // 遍历每个三角形，累加面法线到顶点
@@ -128,10 +215,8 @@ impl MeshData {
                 normals[i + 1] /= len;
                 normals[i + 2] /= len;
             } else {
-                // 如果法线长度太小，使用默认向上方向（Y 轴）
-                normals[i] = 0.0;
-                normals[i + 1] = 1.0;
-                normals[i + 2] = 0.0;
+                // 如果法线长度太小，使用坐标系的上方向
+                normals[i..i + 3].copy_from_slice(&up);
             }
         }
 
@@ -159,3 +244,137 @@ impl LineMeshData {
         }
     }
 }
+
+/// OBJ/PLY 导出选项
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeshExportOptions {
+    /// 分组网格所在的坐标系
+    pub frame: MeshFrame,
+    /// 网格没有 UV 时按水平面平面投影生成 UV，值为一个纹理重复单元对应的米数
+    pub uv_scale: Option<f32>,
+}
+
+impl MeshExportOptions {
+    /// 顶点（输出坐标）的平面投影 UV
+    pub fn uv(&self, v: [f32; 3]) -> Option<[f32; 2]> {
+        let [x, y] = self.frame.ground_xy(v);
+        self.uv_scale.map(|scale| [x / scale, y / scale])
+    }
+
+    /// 网格各顶点的 UV
+    ///
+    /// 优先使用构建器生成的 `MeshData.uvs`，没有时退回到平面投影；
+    /// 两者都没有时返回 `None`。
+    pub fn mesh_uvs(&self, mesh: &MeshData) -> Option<Vec<[f32; 2]>> {
+        if !mesh.uvs.is_empty() && mesh.uvs.len() / 2 == mesh.vertices.len() / 3 {
+            return Some(mesh.uvs.chunks_exact(2).map(|uv| [uv[0], uv[1]]).collect());
+        }
+        self.uv_scale?;
+        mesh.vertices
+            .chunks_exact(3)
+            .map(|v| self.uv([v[0], v[1], v[2]]))
+            .collect()
+    }
+}
+
+/// 按材质分组的网格，组内网格合并为一个 `MeshData`
+#[derive(Debug, Clone)]
+pub struct MeshGroup {
+    pub name: String,
+    /// sRGB 颜色，分量范围 0~1
+    pub color: [f32; 3],
+    pub mesh: MeshData,
+}
+
+impl MeshGroup {
+    pub fn new(name: &str, color: [f32; 3]) -> Self {
+        Self {
+            name: name.to_string(),
+            color,
+            mesh: MeshData::empty(),
+        }
+    }
+
+    /// 合并一个网格到分组中
+    pub fn push(&mut self, mesh: MeshData) {
+        self.mesh.merge(mesh);
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.mesh.vertices.len() / 3
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.mesh.indices.len() / 3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip(num_samples: usize) -> MeshData {
+        let vertices = vec![0.0; num_samples * 2 * 3];
+        let indices = MeshData::generate_strip_indices(num_samples);
+        let normals = vec![0.0; num_samples * 2 * 3];
+        MeshData::new(vertices, indices, normals)
+    }
+
+    #[test]
+    fn test_merge_beyond_u16_range() {
+        // 两段合计 80000 个顶点，超出 u16 索引范围
+        let mut mesh = MeshData::empty();
+        mesh.merge(strip(20000));
+        mesh.merge(strip(20000));
+
+        assert_eq!(mesh.vertices.len() / 3, 80000);
+        assert_eq!(mesh.indices[(20000 - 1) * 6], 40000);
+        assert_eq!(*mesh.indices.iter().max().unwrap(), 79999);
+        assert!(
+            mesh.indices
+                .iter()
+                .all(|&i| (i as usize) < mesh.vertices.len() / 3)
+        );
+    }
+
+    #[test]
+    fn test_merge_pads_missing_uvs() {
+        let mut mesh = strip(2);
+        mesh.merge(strip(2).with_uvs(vec![1.0; 8]));
+        mesh.merge(strip(2));
+
+        assert_eq!(mesh.uvs.len(), 12 * 2);
+        assert_eq!(&mesh.uvs[..8], &[0.0; 8]);
+        assert_eq!(&mesh.uvs[8..16], &[1.0; 8]);
+        assert_eq!(&mesh.uvs[16..], &[0.0; 8]);
+    }
+
+    #[test]
+    fn test_merge_attributes_by_name() {
+        let attribute = |name: &str, value: f32| MeshAttribute {
+            name: name.to_string(),
+            values: vec![value; 4],
+        };
+        let mut mesh = strip(2).with_attributes(vec![attribute("s", 1.0)]);
+        mesh.merge(strip(2).with_attributes(vec![attribute("t", 2.0), attribute("s", 3.0)]));
+        mesh.merge(strip(2));
+
+        assert_eq!(mesh.attribute_names(), ["s", "t"]);
+        let s = mesh.attribute("s").unwrap();
+        assert_eq!(s, [[1.0; 4], [3.0; 4], [0.0; 4]].concat());
+        let t = mesh.attribute("t").unwrap();
+        assert_eq!(t, [[0.0; 4], [2.0; 4], [0.0; 4]].concat());
+        assert_eq!(mesh.attribute("lane"), None);
+    }
+
+    #[test]
+    fn test_group_merges_into_single_mesh() {
+        let mut group = MeshGroup::new("test", [1.0; 3]);
+        group.push(strip(20000));
+        group.push(MeshData::empty());
+        group.push(strip(20000));
+
+        assert_eq!(group.vertex_count(), 80000);
+        assert_eq!(group.triangle_count(), 2 * 19999 * 2);
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::Write;
+
+use super::{MeshExportOptions, MeshGroup};
+
+/// OBJ 与配套的 MTL 文件内容
+#[derive(Debug, Clone)]
+pub struct ObjFiles {
+    pub obj: String,
+    pub mtl: String,
+}
+
+/// 将分组网格写为 Wavefront OBJ + MTL
+///
+/// 每个分组输出为一个对象 (`o`) 并使用同名材质，`mtl_name` 为 OBJ 中
+/// `mtllib` 引用的文件名。顶点和法线按 `options.frame` 坐标原样写出。
+/// 网格带有 UV 时原样写出，否则按 `options.uv_scale` 平面投影。
+pub fn write_obj(groups: &[MeshGroup], mtl_name: &str, options: &MeshExportOptions) -> ObjFiles {
+    let mut obj = String::new();
+    let mut mtl = String::new();
+    obj.push_str("# maphub\n");
+    let _ = writeln!(obj, "mtllib {}", mtl_name);
+    mtl.push_str("# maphub\n");
+
+    // OBJ 索引从 1 开始，在所有对象间全局累加
+    let mut offset = 1usize;
+    for group in groups.iter().filter(|g| g.vertex_count() > 0) {
+        let name = sanitize(&group.name);
+        let _ = writeln!(obj, "o {}", name);
+        let _ = writeln!(obj, "usemtl {}", name);
+
+        let mesh = &group.mesh;
+        for v in mesh.vertices.chunks_exact(3) {
+            let _ = writeln!(obj, "v {} {} {}", v[0], v[1], v[2]);
+        }
+        let uvs = options.mesh_uvs(mesh);
+        for uv in uvs.iter().flatten() {
+            let _ = writeln!(obj, "vt {} {}", uv[0], uv[1]);
+        }
+        for n in mesh.normals.chunks_exact(3) {
+            let _ = writeln!(obj, "vn {} {} {}", n[0], n[1], n[2]);
+        }
+        for tri in mesh.indices.chunks_exact(3) {
+            obj.push('f');
+            for &i in tri {
+                let i = i as usize + offset;
+                if uvs.is_some() {
+                    let _ = write!(obj, " {i}/{i}/{i}");
+                } else {
+                    let _ = write!(obj, " {i}//{i}");
+                }
+            }
+            obj.push('\n');
+        }
+        offset += mesh.vertices.len() / 3;
+
+        let [r, g, b] = group.color;
+        let _ = writeln!(mtl, "newmtl {}", name);
+        let _ = writeln!(mtl, "Ka 0 0 0");
+        let _ = writeln!(mtl, "Kd {} {} {}", r, g, b);
+        let _ = writeln!(mtl, "Ks 0 0 0");
+        let _ = writeln!(mtl, "d 1");
+        let _ = writeln!(mtl, "illum 1");
+    }
+
+    ObjFiles { obj, mtl }
+}
+
+/// OBJ/MTL 名称不能包含空白
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_whitespace() { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{frame::MeshFrame, mesh::MeshData, vec3::Vec3};
+
+    fn group(name: &str) -> MeshGroup {
+        let vertices = vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -2.0];
+        let indices = vec![0, 1, 2];
+        let normals = MeshData::calculate_normals(&vertices, &indices, [0.0, 1.0, 0.0]);
+        let mut group = MeshGroup::new(name, [1.0, 0.5, 0.0]);
+        group.push(MeshData::new(vertices, indices, normals));
+        group
+    }
+
+    #[test]
+    fn test_write_obj() {
+        let groups = [group("lane driving"), group("roadmark-white")];
+        let files = write_obj(&groups, "map.mtl", &MeshExportOptions::default());
+
+        assert!(
+            files
+                .obj
+                .starts_with("# maphub\nmtllib map.mtl\no lane_driving\n")
+        );
+        assert!(files.obj.contains("v 0 0 -2\n"));
+        assert!(files.obj.contains("vn 0 1 0\n"));
+        assert!(files.obj.contains("f 1//1 2//2 3//3\n"));
+        // 第二个对象的索引接续第一个对象
+        assert!(files.obj.contains("f 4//4 5//5 6//6\n"));
+        assert!(!files.obj.contains("vt "));
+        assert!(files.mtl.contains("newmtl roadmark-white\n"));
+        assert!(files.mtl.contains("Kd 1 0.5 0\n"));
+    }
+
+    #[test]
+    fn test_write_obj_enu_with_uv() {
+        let frame = MeshFrame::enu(None);
+        let mut points = Vec::new();
+        for p in [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        ] {
+            frame.push_point(&mut points, p);
+        }
+        let mut group = MeshGroup::new("lane", [1.0, 1.0, 1.0]);
+        group.push(frame.mesh(points, vec![0, 1, 2]));
+        let options = MeshExportOptions {
+            frame,
+            uv_scale: Some(2.0),
+        };
+        let files = write_obj(&[group], "map.mtl", &options);
+
+        // 顶点按 ENU 坐标原样写出，UV 取自水平坐标
+        assert!(files.obj.contains("v 0 2 0\n"));
+        assert!(files.obj.contains("vn 0 0 1\n"));
+        assert!(files.obj.contains("vt 0 1\n"));
+        assert!(files.obj.contains("f 1/1/1 2/2/2 3/3/3\n"));
+    }
+}
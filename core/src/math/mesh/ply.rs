@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+use super::{MeshExportOptions, MeshGroup};
+
+/// 将分组网格写为二进制 (little endian) PLY
+///
+/// PLY 没有分组的概念，分组颜色写入顶点颜色，分组名写入头部注释。
+/// 任一分组带有 UV（网格自带或按 `options.uv_scale` 平面投影）时顶点额外带有
+/// `s`/`t` 属性，没有 UV 的分组写为 0。
+pub fn write_ply(groups: &[MeshGroup], options: &MeshExportOptions) -> Vec<u8> {
+    let vertex_count: usize = groups.iter().map(MeshGroup::vertex_count).sum();
+    let face_count: usize = groups.iter().map(MeshGroup::triangle_count).sum();
+
+    let group_uvs: Vec<_> = groups.iter().map(|g| options.mesh_uvs(&g.mesh)).collect();
+    let has_uv = group_uvs.iter().any(Option::is_some);
+
+    let mut header = String::new();
+    header.push_str("ply\nformat binary_little_endian 1.0\ncomment maphub\n");
+    for group in groups {
+        let _ = writeln!(
+            header,
+            "comment group {} {} vertices",
+            group.name.replace(char::is_whitespace, "_"),
+            group.vertex_count()
+        );
+    }
+    let _ = writeln!(header, "element vertex {}", vertex_count);
+    for property in ["x", "y", "z", "nx", "ny", "nz"] {
+        let _ = writeln!(header, "property float {}", property);
+    }
+    if has_uv {
+        header.push_str("property float s\nproperty float t\n");
+    }
+    header.push_str("property uchar red\nproperty uchar green\nproperty uchar blue\n");
+    let _ = writeln!(header, "element face {}", face_count);
+    header.push_str("property list uchar uint vertex_indices\nend_header\n");
+
+    let mut vertices = Vec::new();
+    let mut faces = Vec::new();
+    let mut offset = 0u32;
+    for (group, uvs) in groups.iter().zip(&group_uvs) {
+        let color = group
+            .color
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        let mesh = &group.mesh;
+        for (i, (v, n)) in mesh
+            .vertices
+            .chunks_exact(3)
+            .zip(mesh.normals.chunks_exact(3))
+            .enumerate()
+        {
+            for value in v.iter().chain(n) {
+                vertices.extend_from_slice(&value.to_le_bytes());
+            }
+            if has_uv {
+                let uv = uvs.as_ref().map_or([0.0, 0.0], |uvs| uvs[i]);
+                vertices.extend_from_slice(&uv[0].to_le_bytes());
+                vertices.extend_from_slice(&uv[1].to_le_bytes());
+            }
+            vertices.extend_from_slice(&color);
+        }
+        for tri in mesh.indices.chunks_exact(3) {
+            faces.push(3u8);
+            for &i in tri {
+                faces.extend_from_slice(&(i + offset).to_le_bytes());
+            }
+        }
+        offset += (mesh.vertices.len() / 3) as u32;
+    }
+
+    let mut ply = header.into_bytes();
+    ply.extend_from_slice(&vertices);
+    ply.extend_from_slice(&faces);
+    ply
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mesh::MeshData;
+
+    #[test]
+    fn test_write_ply() {
+        let vertices = vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1.0];
+        let indices = vec![0, 1, 2];
+        let normals = MeshData::calculate_normals(&vertices, &indices, [0.0, 1.0, 0.0]);
+        let mut group = MeshGroup::new("lane driving", [1.0, 0.0, 0.5]);
+        group.push(MeshData::new(
+            vertices.clone(),
+            indices.clone(),
+            normals.clone(),
+        ));
+        group.push(MeshData::new(vertices, indices, normals));
+
+        let options = MeshExportOptions {
+            uv_scale: Some(1.0),
+            ..Default::default()
+        };
+        let ply = write_ply(&[group], &options);
+
+        let header_end = ply
+            .windows(b"end_header\n".len())
+            .position(|w| w == b"end_header\n")
+            .unwrap()
+            + b"end_header\n".len();
+        let header = std::str::from_utf8(&ply[..header_end]).unwrap();
+        assert!(header.contains("comment group lane_driving 6 vertices\n"));
+        assert!(header.contains("element vertex 6\n"));
+        assert!(header.contains("property float t\n"));
+        assert!(header.contains("element face 2\n"));
+
+        // 每个顶点 8 个 float + 3 字节颜色，每个面 1 + 3 * 4 字节
+        let body = &ply[header_end..];
+        assert_eq!(body.len(), 6 * (8 * 4 + 3) + 2 * 13);
+        assert_eq!(&body[32..35], &[255, 0, 128]);
+
+        // 合并后的第二个三角形索引为 3, 4, 5
+        let face = &body[6 * 35 + 13..];
+        assert_eq!(face[0], 3);
+        assert_eq!(u32::from_le_bytes(face[1..5].try_into().unwrap()), 3);
+        assert_eq!(u32::from_le_bytes(face[9..13].try_into().unwrap()), 5);
+    }
+}
@@ -1,5 +1,10 @@
+pub mod fitting;
+pub mod frame;
+pub mod geo;
 pub mod mesh;
+pub mod polyline;
 pub mod quat;
 pub mod reference;
+pub mod triangulate;
 pub mod vec2;
 pub mod vec3;
@@ -0,0 +1,328 @@
+use super::vec3::Vec3;
+
+/// 计算折线在 xy 平面上的累计长度
+///
+/// 返回数组与 `points` 等长，第一个元素为 0
+pub fn cumulative_lengths(points: &[Vec3]) -> Vec<f64> {
+    let mut lengths = Vec::with_capacity(points.len());
+    let mut acc = 0.0;
+
+    for (i, p) in points.iter().enumerate() {
+        if i > 0 {
+            let prev = &points[i - 1];
+            acc += ((p.x - prev.x).powi(2) + (p.y - prev.y).powi(2)).sqrt();
+        }
+        lengths.push(acc);
+    }
+
+    lengths
+}
+
+/// 计算折线在 xy 平面上的总长度
+pub fn length(points: &[Vec3]) -> f64 {
+    cumulative_lengths(points).last().copied().unwrap_or(0.0)
+}
+
+/// 按累计长度在折线上线性插值
+///
+/// # 参数
+/// - `points`: 折线顶点
+/// - `lengths`: 由 [`cumulative_lengths`] 计算的累计长度
+/// - `s`: 沿折线的距离，超出范围时截断到端点
+pub fn interpolate(points: &[Vec3], lengths: &[f64], s: f64) -> Vec3 {
+    if points.is_empty() {
+        return Vec3::default();
+    }
+    if s <= 0.0 || points.len() == 1 {
+        return points[0];
+    }
+
+    let total = *lengths.last().unwrap();
+    if s >= total {
+        return *points.last().unwrap();
+    }
+
+    // 二分查找 s 所在的线段
+    let idx = lengths
+        .partition_point(|&l| l <= s)
+        .clamp(1, points.len() - 1);
+    let l0 = lengths[idx - 1];
+    let l1 = lengths[idx];
+    let ratio = if l1 - l0 > 1e-12 {
+        (s - l0) / (l1 - l0)
+    } else {
+        0.0
+    };
+
+    let p0 = points[idx - 1];
+    let p1 = points[idx];
+    p0 + (p1 - p0) * ratio
+}
+
+/// 计算折线上距离点 (x, y) 最近的点
+///
+/// # 返回值
+/// (最近点, 沿折线的距离 s, 平面距离)
+pub fn closest_point(points: &[Vec3], x: f64, y: f64) -> Option<(Vec3, f64, f64)> {
+    if points.is_empty() {
+        return None;
+    }
+    if points.len() == 1 {
+        let p = points[0];
+        return Some((p, 0.0, ((p.x - x).powi(2) + (p.y - y).powi(2)).sqrt()));
+    }
+
+    let lengths = cumulative_lengths(points);
+    let mut best: Option<(Vec3, f64, f64)> = None;
+
+    for i in 0..points.len() - 1 {
+        let p0 = points[i];
+        let p1 = points[i + 1];
+        let dx = p1.x - p0.x;
+        let dy = p1.y - p0.y;
+        let len_sq = dx * dx + dy * dy;
+
+        let ratio = if len_sq > 1e-24 {
+            (((x - p0.x) * dx + (y - p0.y) * dy) / len_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let q = p0 + (p1 - p0) * ratio;
+        let dist = ((q.x - x).powi(2) + (q.y - y).powi(2)).sqrt();
+
+        if best.is_none_or(|(_, _, d)| dist < d) {
+            let s = lengths[i] + ratio * (lengths[i + 1] - lengths[i]);
+            best = Some((q, s, dist));
+        }
+    }
+
+    best
+}
+
+/// 计算直线 origin + k * dir 与折线的交点
+///
+/// 返回 |k| 最小的交点参数 k 与交点坐标。`dir` 不要求为单位向量。
+pub fn intersect_line(
+    points: &[Vec3],
+    origin_x: f64,
+    origin_y: f64,
+    dir_x: f64,
+    dir_y: f64,
+) -> Option<(f64, Vec3)> {
+    let mut best: Option<(f64, Vec3)> = None;
+
+    for seg in points.windows(2) {
+        let p0 = seg[0];
+        let p1 = seg[1];
+        let ex = p1.x - p0.x;
+        let ey = p1.y - p0.y;
+
+        // 解 origin + k*dir = p0 + u*e
+        let denom = dir_x * ey - dir_y * ex;
+        if denom.abs() < 1e-12 {
+            continue;
+        }
+
+        let wx = p0.x - origin_x;
+        let wy = p0.y - origin_y;
+        let k = (wx * ey - wy * ex) / denom;
+        let u = (wx * dir_y - wy * dir_x) / denom;
+
+        if !(-1e-9..=1.0 + 1e-9).contains(&u) {
+            continue;
+        }
+
+        if best.is_none_or(|(bk, _)| k.abs() < bk.abs()) {
+            best = Some((k, p0 + (p1 - p0) * u.clamp(0.0, 1.0)));
+        }
+    }
+
+    best
+}
+
+/// 判断点 (x, y) 是否位于多边形内部（射线法，仅考虑 xy 平面）
+pub fn point_in_polygon(polygon: &[Vec3], x: f64, y: f64) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+
+    for i in 0..polygon.len() {
+        let pi = polygon[i];
+        let pj = polygon[j];
+
+        if (pi.y > y) != (pj.y > y) && x < (pj.x - pi.x) * (y - pi.y) / (pj.y - pi.y) + pi.x {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+/// 计算两条折线的所有交点
+///
+/// # 返回值
+/// 每个交点分别沿折线 a 和折线 b 的距离 (s_a, s_b)
+pub fn intersections(a: &[Vec3], b: &[Vec3]) -> Vec<(f64, f64)> {
+    let lengths_a = cumulative_lengths(a);
+    let lengths_b = cumulative_lengths(b);
+    let mut result = Vec::new();
+
+    for i in 0..a.len().saturating_sub(1) {
+        let p0 = a[i];
+        let dx = a[i + 1].x - p0.x;
+        let dy = a[i + 1].y - p0.y;
+
+        for j in 0..b.len().saturating_sub(1) {
+            let q0 = b[j];
+            let ex = b[j + 1].x - q0.x;
+            let ey = b[j + 1].y - q0.y;
+
+            let denom = dx * ey - dy * ex;
+            if denom.abs() < 1e-12 {
+                continue;
+            }
+
+            let wx = q0.x - p0.x;
+            let wy = q0.y - p0.y;
+            let u = (wx * ey - wy * ex) / denom;
+            let v = (wx * dy - wy * dx) / denom;
+
+            if (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v) {
+                let s_a = lengths_a[i] + u * (lengths_a[i + 1] - lengths_a[i]);
+                let s_b = lengths_b[j] + v * (lengths_b[j + 1] - lengths_b[j]);
+                result.push((s_a, s_b));
+            }
+        }
+    }
+
+    result
+}
+
+/// 计算点集在 xy 平面上的凸包（Andrew 单调链算法）
+///
+/// 返回逆时针排列的凸包顶点，不重复首点
+pub fn convex_hull(points: &[Vec3]) -> Vec<Vec3> {
+    let mut sorted: Vec<Vec3> = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross =
+        |o: &Vec3, a: &Vec3, b: &Vec3| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+    let mut hull: Vec<Vec3> = Vec::with_capacity(sorted.len() * 2);
+
+    // 下凸包
+    for p in &sorted {
+        while hull.len() >= 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // 上凸包
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    hull.pop();
+    hull
+}
+
+/// 移除相邻的重复点
+pub fn dedup(points: Vec<Vec3>, epsilon: f64) -> Vec<Vec3> {
+    let mut result: Vec<Vec3> = Vec::with_capacity(points.len());
+
+    for p in points {
+        if let Some(last) = result.last()
+            && ((p.x - last.x).powi(2) + (p.y - last.y).powi(2)).sqrt() <= epsilon
+        {
+            continue;
+        }
+        result.push(p);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Vec3> {
+        vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(10.0, 10.0, 0.0),
+            Vec3::new(0.0, 10.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_cumulative_lengths() {
+        let lengths = cumulative_lengths(&square());
+        assert_eq!(lengths, vec![0.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let points = square();
+        let lengths = cumulative_lengths(&points);
+        let p = interpolate(&points, &lengths, 15.0);
+        assert!((p.x - 10.0).abs() < 1e-10);
+        assert!((p.y - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let (q, s, dist) = closest_point(&square(), 5.0, -2.0).unwrap();
+        assert!((q.x - 5.0).abs() < 1e-10);
+        assert!((s - 5.0).abs() < 1e-10);
+        assert!((dist - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intersect_line() {
+        let points = vec![Vec3::new(0.0, -3.0, 0.0), Vec3::new(10.0, -3.0, 0.0)];
+        let (k, p) = intersect_line(&points, 5.0, 0.0, 0.0, 1.0).unwrap();
+        assert!((k + 3.0).abs() < 1e-10);
+        assert!((p.x - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        assert!(point_in_polygon(&square(), 5.0, 5.0));
+        assert!(!point_in_polygon(&square(), 15.0, 5.0));
+    }
+
+    #[test]
+    fn test_intersections() {
+        let a = vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(10.0, 0.0, 0.0)];
+        let b = vec![Vec3::new(4.0, -5.0, 0.0), Vec3::new(4.0, 5.0, 0.0)];
+        let hits = intersections(&a, &b);
+        assert_eq!(hits.len(), 1);
+        assert!((hits[0].0 - 4.0).abs() < 1e-10);
+        assert!((hits[0].1 - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let mut points = square();
+        points.push(Vec3::new(5.0, 5.0, 0.0));
+        points.push(Vec3::new(2.0, 7.0, 0.0));
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        assert!(!hull.iter().any(|p| p.x == 5.0 && p.y == 5.0));
+    }
+}
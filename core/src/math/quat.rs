@@ -66,6 +66,34 @@ impl Quat {
         }
     }
 
+    /// 从正交基创建四元数
+    ///
+    /// `x_axis`、`y_axis`、`z_axis` 为旋转后局部坐标轴在世界坐标系下的方向，
+    /// 需为右手单位正交基。
+    #[wasm_bindgen(js_name = "fromBasis")]
+    pub fn from_basis(x_axis: &Vec3, y_axis: &Vec3, z_axis: &Vec3) -> Self {
+        // 旋转矩阵 m[行][列]，列为各局部坐标轴
+        let (m00, m01, m02) = (x_axis.x, y_axis.x, z_axis.x);
+        let (m10, m11, m12) = (x_axis.y, y_axis.y, z_axis.y);
+        let (m20, m21, m22) = (x_axis.z, y_axis.z, z_axis.z);
+
+        let trace = m00 + m11 + m22;
+        let q = if trace > 0.0 {
+            let k = 0.5 / (trace + 1.0).sqrt();
+            Self::new((m21 - m12) * k, (m02 - m20) * k, (m10 - m01) * k, 0.25 / k)
+        } else if m00 > m11 && m00 > m22 {
+            let k = 2.0 * (1.0 + m00 - m11 - m22).sqrt();
+            Self::new(0.25 * k, (m01 + m10) / k, (m02 + m20) / k, (m21 - m12) / k)
+        } else if m11 > m22 {
+            let k = 2.0 * (1.0 + m11 - m00 - m22).sqrt();
+            Self::new((m01 + m10) / k, 0.25 * k, (m12 + m21) / k, (m02 - m20) / k)
+        } else {
+            let k = 2.0 * (1.0 + m22 - m00 - m11).sqrt();
+            Self::new((m02 + m20) / k, (m12 + m21) / k, 0.25 * k, (m10 - m01) / k)
+        };
+        q.normalize()
+    }
+
     /// 计算四元数的模长
     #[wasm_bindgen(getter)]
     pub fn length(&self) -> f64 {
@@ -283,6 +311,24 @@ mod tests {
         assert!((rotated.z - 0.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_from_basis() {
+        for (h, p, r) in [
+            (0.0, 0.0, 0.0),
+            (0.3, -0.2, 0.1),
+            (3.0, 0.5, -1.2),
+            (-2.5, 1.4, 3.0),
+        ] {
+            let q = Quat::from_hpr(h, p, r);
+            let x = q.rotate_vector(&Vec3::new(1.0, 0.0, 0.0));
+            let y = q.rotate_vector(&Vec3::new(0.0, 1.0, 0.0));
+            let z = q.rotate_vector(&Vec3::new(0.0, 0.0, 1.0));
+            let b = Quat::from_basis(&x, &y, &z);
+            // q 与 -q 表示同一旋转
+            assert!((b.dot(&q).abs() - 1.0).abs() < 1e-10);
+        }
+    }
+
     #[test]
     fn test_rotate_vector() {
         use std::f64::consts::PI;
@@ -0,0 +1,119 @@
+use super::vec3::Vec3;
+
+/// 计算多边形在 xy 平面上的有向面积，逆时针为正
+pub fn signed_area(points: &[Vec3]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let p = points[i];
+            let q = points[(i + 1) % n];
+            p.x * q.y - q.x * p.y
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// 耳切法三角化简单多边形（仅考虑 xy 平面）
+///
+/// 无论输入顶点顺序如何，输出三角形均为逆时针方向。
+/// 多边形自相交时退化为扇形三角化剩余顶点。
+///
+/// # 返回值
+/// 三角形顶点在 `points` 中的索引
+pub fn triangulate_polygon(points: &[Vec3]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    let mut remaining: Vec<usize> = (0..n).collect();
+    if signed_area(points) < 0.0 {
+        remaining.reverse();
+    }
+
+    let cross = |a: usize, b: usize, c: usize| {
+        let (pa, pb, pc) = (points[a], points[b], points[c]);
+        (pb.x - pa.x) * (pc.y - pa.y) - (pb.y - pa.y) * (pc.x - pa.x)
+    };
+
+    let mut triangles = Vec::with_capacity(n - 2);
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let mut clipped = false;
+
+        for i in 0..len {
+            let a = remaining[(i + len - 1) % len];
+            let b = remaining[i];
+            let c = remaining[(i + 1) % len];
+
+            // 凸顶点
+            if cross(a, b, c) <= 1e-12 {
+                continue;
+            }
+
+            // 三角形内不能包含其他顶点
+            let contains_other = remaining.iter().any(|&p| {
+                p != a
+                    && p != b
+                    && p != c
+                    && cross(a, b, p) >= 0.0
+                    && cross(b, c, p) >= 0.0
+                    && cross(c, a, p) >= 0.0
+            });
+            if contains_other {
+                continue;
+            }
+
+            triangles.push([a, b, c]);
+            remaining.remove(i);
+            clipped = true;
+            break;
+        }
+
+        if !clipped {
+            // 找不到耳朵（自相交或共线），扇形处理剩余顶点
+            for i in 1..remaining.len() - 1 {
+                triangles.push([remaining[0], remaining[i], remaining[i + 1]]);
+            }
+            remaining.clear();
+        }
+    }
+
+    if remaining.len() == 3 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangulate_concave() {
+        // L 形多边形（顺时针）
+        let points = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+        ];
+        let triangles = triangulate_polygon(&points);
+        assert_eq!(triangles.len(), 4);
+
+        let area: f64 = triangles
+            .iter()
+            .map(|t| signed_area(&[points[t[0]], points[t[1]], points[t[2]]]))
+            .sum();
+        assert!((area - 3.0).abs() < 1e-10);
+        assert!(
+            triangles
+                .iter()
+                .all(|t| signed_area(&[points[t[0]], points[t[1]], points[t[2]]]) > 0.0)
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    math::mesh::MeshAttribute,
+    odr::models::{lane::OdrLane, road::OdrRoad},
+};
+
+/// 内置的逐顶点属性
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexAttribute {
+    /// 道路 s 坐标（米），属性名 `s`
+    S,
+    /// 横向 t 坐标（米），属性名 `t`
+    T,
+    /// 车道 ID，属性名 `laneId`
+    LaneId,
+    /// 参考线曲率（1/米），属性名 `curvature`
+    Curvature,
+    /// 纵坡（高程对 s 的导数），属性名 `slope`
+    Slope,
+    /// 超高角度（弧度），属性名 `superelevation`
+    Superelevation,
+}
+
+impl VertexAttribute {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VertexAttribute::S => "s",
+            VertexAttribute::T => "t",
+            VertexAttribute::LaneId => "laneId",
+            VertexAttribute::Curvature => "curvature",
+            VertexAttribute::Slope => "slope",
+            VertexAttribute::Superelevation => "superelevation",
+        }
+    }
+
+    fn eval(&self, sample: &VertexSample) -> f32 {
+        let value = match self {
+            VertexAttribute::S => sample.s,
+            VertexAttribute::T => sample.t,
+            VertexAttribute::LaneId => sample.lane.id as f64,
+            VertexAttribute::Curvature => sample.road.eval_curvature(sample.s),
+            VertexAttribute::Slope => sample.road.eval_slope(sample.s),
+            VertexAttribute::Superelevation => sample.road.eval_superelevation(sample.s),
+        };
+        value as f32
+    }
+}
+
+/// 生成顶点时的道路坐标上下文，供属性回调使用
+pub struct VertexSample<'a> {
+    pub road: &'a OdrRoad,
+    pub lane: &'a OdrLane,
+    pub s: f64,
+    pub t: f64,
+}
+
+/// 自定义属性回调
+pub type AttributeFn = Box<dyn Fn(&VertexSample) -> f32>;
+
+enum Channel {
+    Builtin(VertexAttribute),
+    Custom(String, AttributeFn),
+}
+
+impl Channel {
+    fn name(&self) -> &str {
+        match self {
+            Channel::Builtin(attribute) => attribute.name(),
+            Channel::Custom(name, _) => name,
+        }
+    }
+}
+
+/// 网格构建器上配置的顶点属性通道
+///
+/// 通道按添加顺序输出，同名通道后添加的覆盖先添加的。
+#[derive(Default)]
+pub struct AttributeChannels {
+    channels: Vec<Channel>,
+}
+
+impl AttributeChannels {
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.channels.clear();
+    }
+
+    /// 添加内置属性
+    pub fn add(&mut self, attribute: VertexAttribute) {
+        self.insert(Channel::Builtin(attribute));
+    }
+
+    /// 添加由回调计算的自定义属性
+    pub fn add_fn(&mut self, name: &str, f: impl Fn(&VertexSample) -> f32 + 'static) {
+        self.insert(Channel::Custom(name.to_string(), Box::new(f)));
+    }
+
+    fn insert(&mut self, channel: Channel) {
+        match self
+            .channels
+            .iter()
+            .position(|c| c.name() == channel.name())
+        {
+            Some(i) => self.channels[i] = channel,
+            None => self.channels.push(channel),
+        }
+    }
+
+    /// 为每个通道创建空的属性数组
+    pub fn begin(&self, vertex_count: usize) -> Vec<MeshAttribute> {
+        self.channels
+            .iter()
+            .map(|c| MeshAttribute {
+                name: c.name().to_string(),
+                values: Vec::with_capacity(vertex_count),
+            })
+            .collect()
+    }
+
+    /// 计算一个顶点的所有属性并追加到 `begin` 创建的数组
+    pub fn push(&self, attributes: &mut [MeshAttribute], sample: &VertexSample) {
+        for (channel, attribute) in self.channels.iter().zip(attributes) {
+            let value = match channel {
+                Channel::Builtin(builtin) => builtin.eval(sample),
+                Channel::Custom(_, f) => f(sample),
+            };
+            attribute.values.push(value);
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use wasm_bindgen::prelude::*;
+
+use crate::odr::{
+    mesh::{
+        road_mark_polyline::{RoadMarkSemantics, active_road_mark},
+        road_mark_profile::RoadMarkProfile,
+        sampling::uniform_samples,
+    },
+    models::{
+        lane::{OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+        road::OdrRoad,
+    },
+};
+
+/// 有 shape 时车道横断面的采样间隔（米）
+const SHAPE_PROFILE_STEP: f64 = 0.25;
+
+/// 道路在某个 s 处的横断面
+///
+/// 高度均相对于参考线高程 `elevation`，包含超高、shape 和车道高度。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CrossSection {
+    /// 横断面位置
+    pub s: f64,
+
+    /// 所在车道段的索引
+    #[wasm_bindgen(js_name = "sectionIndex")]
+    pub section_index: usize,
+
+    /// 参考线高程（米）
+    pub elevation: f64,
+
+    /// 超高角度（弧度）
+    pub superelevation: f64,
+
+    /// 车道偏移（米）
+    #[wasm_bindgen(js_name = "laneOffset")]
+    pub lane_offset: f64,
+
+    /// 车道，按 t 从左到右排列（不含中心车道）
+    #[wasm_bindgen(getter_with_clone)]
+    pub lanes: Vec<CrossSectionLane>,
+
+    /// 在 s 处生效的道路标线，按 t 从左到右排列
+    #[wasm_bindgen(getter_with_clone, js_name = "roadMarks")]
+    pub road_marks: Vec<CrossSectionRoadMark>,
+}
+
+/// 横断面上的一条车道
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CrossSectionLane {
+    /// 车道 ID
+    #[wasm_bindgen(js_name = "laneId")]
+    pub lane_id: i32,
+
+    /// 车道类型
+    #[wasm_bindgen(getter_with_clone, js_name = "laneType")]
+    pub lane_type: String,
+
+    /// 内侧边界 t 坐标
+    #[wasm_bindgen(js_name = "tInner")]
+    pub t_inner: f64,
+
+    /// 外侧边界 t 坐标
+    #[wasm_bindgen(js_name = "tOuter")]
+    pub t_outer: f64,
+
+    /// 内侧边界的车道高度（`<height>` inner）
+    #[wasm_bindgen(js_name = "heightInner")]
+    pub height_inner: f64,
+
+    /// 外侧边界的车道高度（`<height>` outer）
+    #[wasm_bindgen(js_name = "heightOuter")]
+    pub height_outer: f64,
+
+    /// 路面轮廓点，从内侧到外侧，扁平 `[t, h, ...]`
+    ///
+    /// 没有 shape 时只有内外边界两点；有 shape 时按 0.25 米间隔采样。
+    #[wasm_bindgen(getter_with_clone)]
+    pub profile: Vec<f64>,
+}
+
+/// 横断面上的道路标线
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CrossSectionRoadMark {
+    /// 标线所属车道 ID（位于该车道外侧边界，0 为中心车道）
+    #[wasm_bindgen(js_name = "laneId")]
+    pub lane_id: i32,
+
+    /// 标线中心的 t 坐标
+    pub t: f64,
+
+    /// 标线处路面高度
+    pub h: f64,
+
+    /// 标线宽度（米），未指定时取所在国家的默认线宽
+    pub width: f64,
+
+    /// 标线语义
+    #[wasm_bindgen(getter_with_clone)]
+    pub semantics: RoadMarkSemantics,
+}
+
+/// 沿 s 采样的纵断面
+///
+/// 各数组与 `s` 一一对应。相邻车道段在分界处各采样一次，因此分界 s 会出现
+/// 两次，便于图表在此处断开。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct LongitudinalProfile {
+    /// 车道 ID，未指定时为整条道路
+    #[wasm_bindgen(js_name = "laneId")]
+    pub lane_id: Option<i32>,
+
+    /// 采样位置
+    #[wasm_bindgen(getter_with_clone)]
+    pub s: Vec<f64>,
+
+    /// 路面高程（米）：指定车道时为车道中心处的路面，否则为参考线
+    #[wasm_bindgen(getter_with_clone)]
+    pub elevation: Vec<f64>,
+
+    /// 纵坡（对应 `elevation` 沿行驶方向的坡度）
+    #[wasm_bindgen(getter_with_clone)]
+    pub slope: Vec<f64>,
+
+    /// 车道宽度（米），未指定车道时为所有车道的总宽度；车道不存在时为 NaN
+    #[wasm_bindgen(getter_with_clone, js_name = "laneWidth")]
+    pub lane_width: Vec<f64>,
+
+    /// 限速（m/s），未指定车道时取车道段内的最高限速；没有限速时为 NaN
+    #[wasm_bindgen(getter_with_clone, js_name = "speedLimit")]
+    pub speed_limit: Vec<f64>,
+
+    /// 各车道段的起点 s
+    #[wasm_bindgen(getter_with_clone, js_name = "sectionStarts")]
+    pub section_starts: Vec<f64>,
+}
+
+/// 计算道路在 `s` 处的横断面
+///
+/// 返回所有车道的 t 范围、类型、高度和路面轮廓，以及生效的道路标线位置。
+/// 道路没有车道段时返回 undefined。
+#[wasm_bindgen(js_name = "crossSection")]
+pub fn cross_section(road: &OdrRoad, s: f64) -> Option<CrossSection> {
+    let section_index = road
+        .lanes
+        .iter()
+        .rposition(|l| l.s <= s)
+        .or((!road.lanes.is_empty()).then_some(0))?;
+    let section = &road.lanes[section_index];
+    let ds = s - section.s;
+
+    let lane_offset = road.eval_lane_offset(s);
+    let superelevation = road.eval_superelevation(s);
+    let surface = |t: f64| t * superelevation.tan() + road.eval_shape(s, t);
+
+    let mut lanes = Vec::new();
+    let mut road_marks = Vec::new();
+    let profile = RoadMarkProfile::for_road(road, s);
+
+    let mut push_road_mark = |lane: &OdrLane, t: f64, height: f64| {
+        let Some(road_mark) = active_road_mark(section, lane, s) else {
+            return;
+        };
+        if matches!(road_mark.mark_type, OdrRoadMarkType::None) {
+            return;
+        }
+        let semantics = RoadMarkSemantics::from_road_mark(road_mark);
+        road_marks.push(CrossSectionRoadMark {
+            lane_id: lane.id,
+            t,
+            h: surface(t) + height,
+            width: semantics
+                .width
+                .unwrap_or_else(|| profile.line_width(road_mark.weight.clone())),
+            semantics,
+        });
+    };
+
+    let mut left: Vec<&OdrLane> = section.left.iter().collect();
+    left.sort_by_key(|l| std::cmp::Reverse(l.id));
+    let mut right: Vec<&OdrLane> = section.right.iter().collect();
+    right.sort_by_key(|l| std::cmp::Reverse(l.id));
+
+    for lane in left {
+        let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+        let height = lane.eval_height(ds);
+        push_road_mark(lane, t_outer, height.y);
+        lanes.push(cross_section_lane(
+            road, lane, t_inner, t_outer, height.x, height.y, &surface,
+        ));
+    }
+    push_road_mark(&section.center, lane_offset, 0.0);
+    for lane in right {
+        let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+        let height = lane.eval_height(ds);
+        push_road_mark(lane, t_outer, height.y);
+        lanes.push(cross_section_lane(
+            road, lane, t_inner, t_outer, height.x, height.y, &surface,
+        ));
+    }
+
+    Some(CrossSection {
+        s,
+        section_index,
+        elevation: road.eval_elevation(s),
+        superelevation,
+        lane_offset,
+        lanes,
+        road_marks,
+    })
+}
+
+/// 沿道路采样纵断面
+///
+/// # 参数
+/// - `road`: 道路
+/// - `lane_id`: 车道 ID，未指定时采样参考线和整条道路
+/// - `step`: 采样间隔（米），默认 1.0
+#[wasm_bindgen(js_name = "longitudinalProfile")]
+pub fn longitudinal_profile(
+    road: &OdrRoad,
+    lane_id: Option<i32>,
+    step: Option<f64>,
+) -> LongitudinalProfile {
+    let step = step.unwrap_or(1.0);
+    let mut profile = LongitudinalProfile {
+        lane_id,
+        s: Vec::new(),
+        elevation: Vec::new(),
+        slope: Vec::new(),
+        lane_width: Vec::new(),
+        speed_limit: Vec::new(),
+        section_starts: road.lanes.iter().map(|l| l.s).collect(),
+    };
+
+    for (i, section) in road.lanes.iter().enumerate() {
+        let s_end = road.lanes.get(i + 1).map_or(road.length, |next| next.s);
+        if s_end <= section.s {
+            continue;
+        }
+
+        for s in uniform_samples(section.s, s_end, step) {
+            let ds = s - section.s;
+            let lane = lane_id.and_then(|id| section.find_lane(id));
+            let (elevation, slope, width, speed) = match (lane_id, lane) {
+                (Some(_), Some(lane)) => {
+                    let (t_inner, t_outer) = road.lane_t_bounds(section, lane, s);
+                    let t = (t_inner + t_outer) / 2.0;
+                    let height = lane.eval_height(ds);
+                    let h = road.eval_shape(s, t) + (height.x + height.y) / 2.0;
+                    let tangent = road.eval_surface_frame(s, t).tangent;
+                    (
+                        road.sth_to_xyz(s, t, h).z,
+                        tangent.z / tangent.x.hypot(tangent.y),
+                        lane.eval_width(ds),
+                        lane_speed(section, lane, s).unwrap_or(f64::NAN),
+                    )
+                }
+                (Some(_), None) => (f64::NAN, f64::NAN, f64::NAN, f64::NAN),
+                (None, _) => {
+                    let lanes = section.left.iter().chain(&section.right);
+                    let width = lanes.clone().map(|l| l.eval_width(ds)).sum();
+                    let speed = lanes
+                        .filter_map(|l| lane_speed(section, l, s))
+                        .fold(f64::NAN, f64::max);
+                    (road.eval_elevation(s), road.eval_slope(s), width, speed)
+                }
+            };
+            profile.s.push(s);
+            profile.elevation.push(elevation);
+            profile.slope.push(slope);
+            profile.lane_width.push(width);
+            profile.speed_limit.push(speed);
+        }
+    }
+    profile
+}
+
+/// 车道在 s 处生效的限速（m/s）
+pub(crate) fn lane_speed(section: &OdrLaneSection, lane: &OdrLane, s: f64) -> Option<f64> {
+    lane.speed
+        .iter()
+        .rev()
+        .find(|sp| section.s + sp.s_offset <= s)
+        .map(|sp| sp.max_mps())
+}
+
+fn cross_section_lane(
+    road: &OdrRoad,
+    lane: &OdrLane,
+    t_inner: f64,
+    t_outer: f64,
+    height_inner: f64,
+    height_outer: f64,
+    surface: &impl Fn(f64) -> f64,
+) -> CrossSectionLane {
+    let n = if road.shapes.is_empty() {
+        1
+    } else {
+        (((t_outer - t_inner).abs() / SHAPE_PROFILE_STEP).ceil() as usize).max(1)
+    };
+    let profile = (0..=n)
+        .flat_map(|i| {
+            let f = i as f64 / n as f64;
+            let t = t_inner + (t_outer - t_inner) * f;
+            [
+                t,
+                surface(t) + height_inner + (height_outer - height_inner) * f,
+            ]
+        })
+        .collect();
+
+    CrossSectionLane {
+        lane_id: lane.id,
+        lane_type: lane.lane_type.clone(),
+        t_inner,
+        t_outer,
+        height_inner,
+        height_outer,
+        profile,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::{
+            enums::OdrSpeedUnit,
+            lane::{
+                lane_geometry::OdrLaneHeight, lane_offset::OdrLaneOffset, lane_speed::OdrLaneSpeed,
+            },
+            road::{road_elevation::OdrRoadElevation, superelevation::OdrSuperelevation},
+        },
+        test_support::{self, line_road},
+    };
+
+    fn lane(id: i32, lane_type: &str, width: f64, mark_type: OdrRoadMarkType) -> OdrLane {
+        OdrLane {
+            lane_type: lane_type.into(),
+            ..test_support::lane(id, width, mark_type)
+        }
+    }
+
+    /// 50 米直线道路，纵坡 0.1，超高 0.02，车道偏移 0.5
+    ///
+    /// 车道段 0（s = 0）：1、-1（限速 36 km/h）、-2（人行道，高 0.15）；
+    /// 车道段 1（s = 30）：只有 -1。
+    fn build_test_road() -> OdrRoad {
+        let mut driving = lane(-1, "driving", 3.5, OdrRoadMarkType::Broken);
+        driving.speed = vec![OdrLaneSpeed::new(0.0, 36.0, Some(OdrSpeedUnit::KMH))];
+        let mut sidewalk = lane(-2, "sidewalk", 2.0, OdrRoadMarkType::None);
+        sidewalk.height = vec![OdrLaneHeight::new(0.0, 0.15, 0.15)];
+
+        let section0 = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, "driving", 3.0, OdrRoadMarkType::Solid)],
+            vec![sidewalk, driving],
+            lane(0, "none", 0.0, OdrRoadMarkType::SolidSolid),
+            None,
+        );
+        let section1 = OdrLaneSection::new(
+            30.0,
+            Vec::new(),
+            vec![lane(-1, "driving", 3.5, OdrRoadMarkType::Solid)],
+            lane(0, "none", 0.0, OdrRoadMarkType::Solid),
+            None,
+        );
+        OdrRoad {
+            elevations: vec![OdrRoadElevation::new(0.0, 0.0, 0.1, 0.0, 0.0)],
+            superelevations: vec![OdrSuperelevation::new(0.0, 0.02, 0.0, 0.0, 0.0)],
+            lane_offsets: vec![OdrLaneOffset::new(0.0, 0.5, 0.0, 0.0, 0.0)],
+            ..line_road("1", 0.0, 0.0, 50.0, vec![section0, section1])
+        }
+    }
+
+    #[test]
+    fn test_cross_section() {
+        let road = build_test_road();
+        let section = cross_section(&road, 10.0).unwrap();
+        assert_eq!(section.section_index, 0);
+        assert!((section.elevation - 1.0).abs() < 1e-12);
+        assert_eq!(section.lane_offset, 0.5);
+
+        let ids: Vec<i32> = section.lanes.iter().map(|l| l.lane_id).collect();
+        assert_eq!(ids, vec![1, -1, -2]);
+        let ranges: Vec<(f64, f64)> = section
+            .lanes
+            .iter()
+            .map(|l| (l.t_inner, l.t_outer))
+            .collect();
+        assert_eq!(ranges, vec![(0.5, 3.5), (0.5, -3.0), (-3.0, -5.0)]);
+
+        // 没有 shape 时轮廓只有内外边界两点，人行道高出路面 0.15
+        let sidewalk = &section.lanes[2];
+        assert_eq!(sidewalk.lane_type, "sidewalk");
+        assert_eq!(sidewalk.profile.len(), 4);
+        assert!((sidewalk.profile[3] - (-5.0 * 0.02_f64.tan() + 0.15)).abs() < 1e-12);
+
+        // 人行道的 none 标线被跳过
+        let marks: Vec<(i32, f64)> = section
+            .road_marks
+            .iter()
+            .map(|m| (m.lane_id, m.t))
+            .collect();
+        assert_eq!(marks, vec![(1, 3.5), (0, 0.5), (-1, -3.0)]);
+        assert!(section.road_marks.iter().all(|m| m.width == 0.15));
+        assert!(matches!(
+            section.road_marks[1].semantics.mark_type,
+            OdrRoadMarkType::SolidSolid
+        ));
+
+        let section = cross_section(&road, 40.0).unwrap();
+        assert_eq!(section.section_index, 1);
+        assert_eq!(section.lanes.len(), 1);
+    }
+
+    #[test]
+    fn test_longitudinal_profile() {
+        let road = build_test_road();
+
+        let profile = longitudinal_profile(&road, Some(-1), Some(10.0));
+        assert_eq!(profile.section_starts, vec![0.0, 30.0]);
+        // 车道段分界处采样两次
+        assert_eq!(profile.s, vec![0.0, 15.0, 30.0, 30.0, 50.0]);
+        assert!(profile.lane_width.iter().all(|&w| w == 3.5));
+        assert!((profile.speed_limit[0] - 10.0).abs() < 1e-12);
+        assert!(profile.speed_limit[3].is_nan());
+        // 车道中心 t = -1.25
+        let z = 1.5 - 1.25 * 0.02_f64.tan();
+        assert!((profile.elevation[1] - z).abs() < 1e-12);
+        assert!(profile.slope.iter().all(|&k| (k - 0.1).abs() < 1e-9));
+
+        let profile = longitudinal_profile(&road, Some(-2), Some(10.0));
+        assert_eq!(profile.lane_width[0], 2.0);
+        assert!(profile.lane_width[3].is_nan() && profile.elevation[4].is_nan());
+
+        let profile = longitudinal_profile(&road, None, Some(10.0));
+        assert_eq!(profile.lane_width, vec![8.5, 8.5, 8.5, 3.5, 3.5]);
+        assert!((profile.elevation[4] - 5.0).abs() < 1e-12);
+        assert!((profile.speed_limit[2] - 10.0).abs() < 1e-12);
+        assert!(profile.speed_limit[3].is_nan());
+    }
+}
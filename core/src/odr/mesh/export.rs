@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+
+use wasm_bindgen::prelude::*;
+
+use crate::math::{
+    frame::MeshFrame,
+    mesh::{
+        MeshExportOptions, MeshGroup,
+        obj::{ObjFiles, write_obj},
+        ply::write_ply,
+    },
+};
+use crate::odr::mesh::{lane_builder::LaneMeshBuilder, road_mark_builder::RoadMarkMeshBuilder};
+use crate::odr::models::opendrive::OpenDrive;
+
+/// 车道类型对应的 sRGB 颜色，与 Web 端车道渲染保持一致
+pub fn lane_type_color(lane_type: &str) -> u32 {
+    match lane_type {
+        "driving" => 0x555555,
+        "sidewalk" => 0xcccccc,
+        "shoulder" => 0x777777,
+        "border" => 0x888888,
+        "parking" => 0x7799aa,
+        "biking" => 0x88aa77,
+        _ => 0x666666,
+    }
+}
+
+fn hex_to_rgb(hex: u32) -> [f32; 3] {
+    [16, 8, 0].map(|shift| ((hex >> shift) & 0xff) as f32 / 255.0)
+}
+
+/// 构建整张地图的网格，按车道类型 (`lane-<type>`) 和标线的 sRGB 颜色
+/// (`roadmark-<rrggbb>`) 分组
+///
+/// 标线颜色由道路国家对应的标线配置解析，同色标线共用一个分组。
+///
+/// # 参数
+/// - `lane_sample_step`: 车道面采样步长（米）
+/// - `mark_sample_step`: 标线采样步长（米）
+/// - `frame`: 输出坐标系
+pub fn build_mesh_groups(
+    odr: &OpenDrive,
+    lane_sample_step: f64,
+    mark_sample_step: f64,
+    frame: &MeshFrame,
+) -> Vec<MeshGroup> {
+    let lane_builder = LaneMeshBuilder::with_frame(Some(lane_sample_step), frame);
+    let mark_builder = RoadMarkMeshBuilder::with_frame(Some(mark_sample_step), frame);
+
+    // 按名称排序，保证输出稳定
+    let mut lanes: BTreeMap<String, MeshGroup> = BTreeMap::new();
+    let mut marks: BTreeMap<String, MeshGroup> = BTreeMap::new();
+
+    for road in &odr.roads {
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            let all_lanes = section
+                .left
+                .iter()
+                .chain(std::iter::once(&section.center))
+                .chain(section.right.iter());
+
+            for lane in all_lanes {
+                let surface = lane_builder.build_lane_mesh(road, section, lane, s_start, s_end);
+                if !surface.indices.is_empty() {
+                    let name = format!("lane-{}", lane.lane_type);
+                    lanes
+                        .entry(name.clone())
+                        .or_insert_with(|| {
+                            MeshGroup::new(&name, hex_to_rgb(lane_type_color(&lane.lane_type)))
+                        })
+                        .push(surface);
+                }
+
+                let items = mark_builder
+                    .build_lane_road_marks(road, section, lane, s_start, s_end)
+                    .into_items();
+                for item in items {
+                    let (mesh, _, rgb) = item.into_parts();
+                    let name = format!("roadmark-{:06x}", rgb);
+                    marks
+                        .entry(name.clone())
+                        .or_insert_with(|| MeshGroup::new(&name, hex_to_rgb(rgb)))
+                        .push(mesh);
+                }
+            }
+        }
+    }
+
+    lanes.into_values().chain(marks.into_values()).collect()
+}
+
+/// OBJ 导出结果
+#[wasm_bindgen]
+pub struct ObjExport {
+    obj: Vec<u8>,
+    mtl: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ObjExport {
+    #[wasm_bindgen(getter)]
+    pub fn obj(&self) -> Vec<u8> {
+        self.obj.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mtl(&self) -> Vec<u8> {
+        self.mtl.clone()
+    }
+}
+
+/// 在 `frame` 坐标系下构建整张地图的分组网格，未指定时为以地图中心为原点的
+/// WebGL 坐标
+fn export_groups(
+    odr: &OpenDrive,
+    frame: Option<MeshFrame>,
+    uv_scale: Option<f32>,
+) -> (Vec<MeshGroup>, MeshExportOptions) {
+    let frame = frame.unwrap_or_else(|| MeshFrame::webgl(Some(odr.center())));
+    let groups = build_mesh_groups(odr, 1.0, 0.2, &frame);
+    let options = MeshExportOptions {
+        frame,
+        uv_scale: uv_scale.filter(|s| *s > 0.0),
+    };
+    (groups, options)
+}
+
+/// 将 OpenDRIVE 地图导出为 OBJ + MTL
+///
+/// # 参数
+/// - `mtl_name`: OBJ 中引用的 MTL 文件名
+/// - `frame`: 输出坐标系，默认以地图中心为原点的 WebGL 坐标
+/// - `uv_scale`: 网格没有 UV 时生成平面投影 UV（每个纹理单元的米数）
+#[wasm_bindgen(js_name = exportOpendriveToObj)]
+pub fn export_opendrive_to_obj(
+    odr: &OpenDrive,
+    mtl_name: &str,
+    frame: Option<MeshFrame>,
+    uv_scale: Option<f32>,
+) -> ObjExport {
+    let (groups, options) = export_groups(odr, frame, uv_scale);
+    let ObjFiles { obj, mtl } = write_obj(&groups, mtl_name, &options);
+    ObjExport {
+        obj: obj.into_bytes(),
+        mtl: mtl.into_bytes(),
+    }
+}
+
+/// 将 OpenDRIVE 地图导出为二进制 PLY
+#[wasm_bindgen(js_name = exportOpendriveToPly)]
+pub fn export_opendrive_to_ply(
+    odr: &OpenDrive,
+    frame: Option<MeshFrame>,
+    uv_scale: Option<f32>,
+) -> Vec<u8> {
+    let (groups, options) = export_groups(odr, frame, uv_scale);
+    write_ply(&groups, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::{
+            enums::OdrRoadMarkColor,
+            lane::{OdrLane, lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+            road::road_type::OdrRoadType,
+        },
+        test_support::{self, header, line_road},
+    };
+
+    fn lane(id: i32, lane_type: &str, color: OdrRoadMarkColor) -> OdrLane {
+        let width = if id == 0 { 0.0 } else { 3.0 };
+        let mut lane = OdrLane {
+            lane_type: lane_type.to_string(),
+            ..test_support::lane(id, width, OdrRoadMarkType::Solid)
+        };
+        lane.road_marks[0].color = color;
+        lane
+    }
+
+    fn test_map() -> OpenDrive {
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, "driving", OdrRoadMarkColor::White)],
+            vec![
+                lane(-1, "driving", OdrRoadMarkColor::White),
+                lane(-2, "sidewalk", OdrRoadMarkColor::Standard),
+            ],
+            lane(0, "none", OdrRoadMarkColor::Yellow),
+            None,
+        );
+        let road = line_road("1", 0.0, 0.0, 10.0, vec![section]);
+        OpenDrive::new(header(), vec![road], Vec::new())
+    }
+
+    #[test]
+    fn test_build_mesh_groups() {
+        let odr = test_map();
+        let groups = build_mesh_groups(&odr, 1.0, 0.5, &MeshFrame::webgl(None));
+        let names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "lane-driving",
+                "lane-sidewalk",
+                // standard 与 white 在通用配置中同为白色
+                "roadmark-ffcc00",
+                "roadmark-ffffff"
+            ]
+        );
+
+        // 两条行车道合并为一个网格，每条 10 个采样点 × 2 个顶点
+        let driving = &groups[0];
+        assert_eq!(driving.vertex_count(), 2 * 10 * 2);
+        assert_eq!(driving.color, [85.0 / 255.0; 3]);
+    }
+
+    #[test]
+    fn test_road_mark_color_from_country_profile() {
+        let mut odr = test_map();
+        odr.roads[0].road_types = vec![OdrRoadType::new(
+            0.0,
+            "town".to_string(),
+            Some("DE".to_string()),
+        )];
+
+        let groups = build_mesh_groups(&odr, 1.0, 0.5, &MeshFrame::webgl(None));
+        // 德国配置的黄色为 RAL 1023
+        let yellow = groups.iter().find(|g| g.name == "roadmark-f7b500").unwrap();
+        assert_eq!(yellow.color, hex_to_rgb(0xf7b500));
+        assert!(groups.iter().all(|g| g.name != "roadmark-ffcc00"));
+
+        let export = export_opendrive_to_obj(&odr, "map.mtl", None, None);
+        let mtl = String::from_utf8(export.mtl).unwrap();
+        assert!(mtl.contains("newmtl roadmark-f7b500\n"));
+    }
+
+    #[test]
+    fn test_export_obj_builder_uvs() {
+        let odr = test_map();
+        let groups = build_mesh_groups(&odr, 1.0, 0.2, &MeshFrame::webgl(Some(odr.center())));
+        let expected: Vec<String> = groups
+            .iter()
+            .flat_map(|g| g.mesh.uvs.chunks_exact(2))
+            .map(|uv| format!("{} {}", uv[0], uv[1]))
+            .collect();
+        assert_eq!(
+            expected.len(),
+            groups.iter().map(MeshGroup::vertex_count).sum::<usize>()
+        );
+
+        // 构建器生成的 UV 优先于平面投影
+        for uv_scale in [None, Some(2.0)] {
+            let export = export_opendrive_to_obj(&odr, "map.mtl", None, uv_scale);
+            let obj = String::from_utf8(export.obj).unwrap();
+            let vt: Vec<&str> = obj.lines().filter_map(|l| l.strip_prefix("vt ")).collect();
+            assert_eq!(vt, expected);
+        }
+    }
+
+    #[test]
+    fn test_export_obj_unreal_frame() {
+        let odr = test_map();
+        let export = export_opendrive_to_obj(&odr, "map.mtl", Some(MeshFrame::unreal(None)), None);
+        let obj = String::from_utf8(export.obj).unwrap();
+
+        // Unreal：Z 轴向上、左手系、厘米，道路沿 x 方向长 10 米
+        let vertices: Vec<Vec<f32>> = obj
+            .lines()
+            .filter_map(|l| l.strip_prefix("v "))
+            .map(|l| l.split(' ').map(|c| c.parse().unwrap()).collect())
+            .collect();
+        let max_x = vertices.iter().map(|v| v[0]).fold(f32::MIN, f32::max);
+        assert!((max_x - 1000.0).abs() < 1e-3);
+        // 平路面，标线略高于路面
+        assert!(vertices.iter().all(|v| v[2] > -1e-3 && v[2] < 5.0));
+        // 右侧车道（OpenDRIVE y < 0）在左手系中 y > 0
+        assert!(vertices.iter().any(|v| v[1] > 500.0));
+        // 平路面的法线沿 Z 轴
+        assert!(
+            obj.lines()
+                .filter_map(|l| l.strip_prefix("vn "))
+                .all(|l| l.ends_with(" 1") || l.ends_with(" -1"))
+        );
+    }
+}
@@ -0,0 +1,548 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    math::{
+        frame::MeshFrame,
+        mesh::{LineMeshData, MeshData},
+        triangulate::triangulate_polygon,
+        vec3::Vec3,
+    },
+    odr::{
+        mesh::sampling::uniform_samples,
+        models::{
+            enums::OdrContactPoint,
+            junction::OdrJunction,
+            lane::OdrLane,
+            opendrive::OpenDrive,
+            road::{
+                OdrRoad,
+                road_link::{OdrRoadLink, OdrRoadLinkElementType},
+            },
+        },
+    },
+};
+
+/// 路口面网格构建器
+///
+/// 将路口内所有连接道路和进入道路端口围成的区域生成一整块路面网格，
+/// 替代逐条连接道路车道面叠加的效果，避免重叠处的 z-fighting 和车道之间的空洞。
+#[wasm_bindgen]
+pub struct JunctionMeshBuilder {
+    /// 沿连接道路边界的采样步长（米）
+    sample_step: f64,
+    /// 输出坐标系
+    frame: MeshFrame,
+}
+
+#[wasm_bindgen]
+impl JunctionMeshBuilder {
+    /// 创建新的路口面网格构建器
+    ///
+    /// # 参数
+    /// - `sample_step`: 沿连接道路边界的采样间隔（米），默认 1.0
+    /// - `center`: 地图中心点，用于解决大坐标精度问题，从 OpenDrive.center 获取
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_step: Option<f64>, center: Option<Vec3>) -> Self {
+        Self::with_frame(sample_step, &MeshFrame::webgl(center))
+    }
+
+    /// 使用指定输出坐标系创建路口面网格构建器
+    #[wasm_bindgen(js_name = "withFrame")]
+    pub fn with_frame(sample_step: Option<f64>, frame: &MeshFrame) -> Self {
+        Self {
+            sample_step: sample_step.unwrap_or(1.0),
+            frame: *frame,
+        }
+    }
+
+    /// 构建路口面网格
+    ///
+    /// 顶点高程取自轮廓所在的道路路面，路口内部由三角形线性插值。
+    /// UV 为相对轮廓包围盒最小角的平面坐标（米）。
+    ///
+    /// # 参数
+    /// - `odr`: 路口所在的地图
+    /// - `junction`: 要构建的路口
+    #[wasm_bindgen(js_name = "buildJunctionMesh")]
+    pub fn build_junction_mesh(&self, odr: &OpenDrive, junction: &OdrJunction) -> MeshData {
+        let outline = junction_outline(odr, junction, self.sample_step);
+        let triangles = triangulate_polygon(&outline);
+        if triangles.is_empty() {
+            return MeshData::empty();
+        }
+
+        let min_x = outline.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+        let min_y = outline.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+
+        let mut vertices = Vec::with_capacity(outline.len() * 3);
+        let mut uvs = Vec::with_capacity(outline.len() * 2);
+        for p in &outline {
+            self.frame.push_point(&mut vertices, *p);
+            uvs.extend_from_slice(&[(p.x - min_x) as f32, (p.y - min_y) as f32]);
+        }
+        let indices = triangles
+            .iter()
+            .flat_map(|t| t.iter().map(|&i| i as u32))
+            .collect();
+
+        self.frame.mesh(vertices, indices).with_uvs(uvs)
+    }
+
+    /// 构建路口轮廓线（闭合折线）
+    #[wasm_bindgen(js_name = "buildJunctionOutline")]
+    pub fn build_junction_outline(&self, odr: &OpenDrive, junction: &OdrJunction) -> LineMeshData {
+        let outline = junction_outline(odr, junction, self.sample_step);
+        if outline.is_empty() {
+            return LineMeshData::empty();
+        }
+
+        let mut vertices = Vec::with_capacity((outline.len() + 1) * 3);
+        for p in outline.iter().chain(outline.first()) {
+            self.frame.push_point(&mut vertices, *p);
+        }
+        LineMeshData::new(vertices)
+    }
+}
+
+/// 计算路口轮廓（OpenDRIVE 坐标，不重复首点）
+///
+/// 路口定义了 `<boundary>`（OpenDRIVE 1.8）时直接使用边界多边形；否则将进入道路
+/// 在接触点处的横断面按绕路口逆时针排列，相邻两个端口之间用连接这两条道路的
+/// 连接道路外侧车道边界相连。T 形、错位和凹形路口的轮廓都沿实际路缘走。
+///
+/// 轮廓顶点高程取自所在道路的路面；路口定义了 `<elevationGrid>` 时改用高程网格。
+///
+/// # 参数
+/// - `sample_step`: 沿道路边缘的采样间隔（米）
+pub fn junction_outline(odr: &OpenDrive, junction: &OdrJunction, sample_step: f64) -> Vec<Vec3> {
+    let mut outline = junction.boundary_polygon(&odr.roads, sample_step);
+    if outline.is_empty() {
+        outline = chained_outline(odr, junction, sample_step);
+    }
+
+    if let Some(grid) = &junction.elevation_grid {
+        let bound = |f: fn(&Vec3) -> f64, init: f64, pick: fn(f64, f64) -> f64| {
+            outline.iter().map(f).fold(init, pick)
+        };
+        let cx = (bound(|p| p.x, f64::INFINITY, f64::min)
+            + bound(|p| p.x, f64::NEG_INFINITY, f64::max))
+            / 2.0;
+        let cy = (bound(|p| p.y, f64::INFINITY, f64::min)
+            + bound(|p| p.y, f64::NEG_INFINITY, f64::max))
+            / 2.0;
+        for p in &mut outline {
+            if let Some(z) = grid.eval(cx, cy, p.x, p.y) {
+                p.z = z;
+            }
+        }
+    }
+
+    outline
+}
+
+/// 进入道路在路口处的端口
+struct Port<'a> {
+    /// 进入道路 ID
+    road_id: &'a str,
+    /// 接触点是否为进入道路终点
+    at_end: bool,
+    /// 横断面上绕路口逆时针方向的第一个端点
+    first: Vec3,
+    /// 横断面上绕路口逆时针方向的第二个端点
+    second: Vec3,
+}
+
+impl Port<'_> {
+    fn matches(&self, key: (&str, bool)) -> bool {
+        self.road_id == key.0 && self.at_end == key.1
+    }
+
+    fn mid(&self) -> (f64, f64) {
+        (
+            (self.first.x + self.second.x) / 2.0,
+            (self.first.y + self.second.y) / 2.0,
+        )
+    }
+}
+
+/// 道路连接指向的进入道路端口（道路 ID，是否为终点）
+fn port_key(link: &OdrRoadLink) -> Option<(&str, bool)> {
+    if !matches!(link.element_type, OdrRoadLinkElementType::Road) {
+        return None;
+    }
+    match link.contact_point {
+        Some(OdrContactPoint::Start) => Some((link.element_id.as_str(), false)),
+        Some(OdrContactPoint::End) => Some((link.element_id.as_str(), true)),
+        _ => None,
+    }
+}
+
+/// 由进入道路端口和连接道路外边界首尾相接得到的路口轮廓
+fn chained_outline(odr: &OpenDrive, junction: &OdrJunction, step: f64) -> Vec<Vec3> {
+    let connecting: Vec<&OdrRoad> = odr
+        .roads
+        .iter()
+        .filter(|r| r.junction == junction.id)
+        .collect();
+
+    let mut ports: Vec<Port> = Vec::new();
+    let links = connecting
+        .iter()
+        .flat_map(|r| r.predecessor.iter().chain(r.successor.iter()));
+    for key in links.filter_map(port_key) {
+        if ports.iter().any(|p| p.matches(key)) {
+            continue;
+        }
+        let Some(incoming) = odr.roads.iter().find(|r| r.id == key.0) else {
+            continue;
+        };
+        if incoming.lanes.is_empty() {
+            continue;
+        }
+        let (section_idx, s) = if key.1 {
+            (incoming.lanes.len() - 1, incoming.length)
+        } else {
+            (0, 0.0)
+        };
+        let [left, right] = road_edges(incoming, section_idx, s);
+        ports.push(Port {
+            road_id: key.0,
+            at_end: key.1,
+            first: left,
+            second: right,
+        });
+    }
+    if ports.is_empty() {
+        return Vec::new();
+    }
+
+    // 以端口中点的中心为原点，端口按角度逆时针排序，端口内两个端点也按逆时针排列
+    let n = ports.len() as f64;
+    let cx = ports.iter().map(|p| p.mid().0).sum::<f64>() / n;
+    let cy = ports.iter().map(|p| p.mid().1).sum::<f64>() / n;
+    for port in &mut ports {
+        let (a, b) = (port.first, port.second);
+        if (a.x - cx) * (b.y - cy) - (a.y - cy) * (b.x - cx) < 0.0 {
+            std::mem::swap(&mut port.first, &mut port.second);
+        }
+    }
+    let angle = |p: &Port| {
+        let (x, y) = p.mid();
+        (y - cy).atan2(x - cx)
+    };
+    ports.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+
+    // 相邻端口之间没有连接道路时直接以直线相连
+    let mut outline = Vec::new();
+    for (i, from) in ports.iter().enumerate() {
+        let to = &ports[(i + 1) % ports.len()];
+        outline.extend([from.first, from.second]);
+        if let Some(edge) = connecting_edge(&connecting, from, to, step) {
+            outline.extend(edge);
+        }
+    }
+
+    let close = |a: &Vec3, b: &Vec3| (a.x - b.x).hypot(a.y - b.y) < 1e-6;
+    outline.dedup_by(|a, b| close(a, b));
+    while outline.len() > 1 && close(&outline[0], &outline[outline.len() - 1]) {
+        outline.pop();
+    }
+    if outline.len() < 3 {
+        return Vec::new();
+    }
+    outline
+}
+
+/// 从 `from` 端口走到 `to` 端口的连接道路外边界
+///
+/// 在连接这两个端口的所有连接道路的左右边缘中，选取起点最接近 `from` 的第二个端点、
+/// 终点最接近 `to` 的第一个端点的那条，即两端口之间最外侧的车道边界。
+fn connecting_edge(
+    connecting: &[&OdrRoad],
+    from: &Port,
+    to: &Port,
+    step: f64,
+) -> Option<Vec<Vec3>> {
+    let dist = |a: &Vec3, b: &Vec3| (a.x - b.x).hypot(a.y - b.y);
+    let mut best: Option<(f64, Vec<Vec3>)> = None;
+
+    for road in connecting {
+        let (Some(start), Some(end)) = (
+            road.predecessor.as_ref().and_then(port_key),
+            road.successor.as_ref().and_then(port_key),
+        ) else {
+            continue;
+        };
+        let forward = from.matches(start) && to.matches(end);
+        let backward = from.matches(end) && to.matches(start);
+        if !forward && !backward {
+            continue;
+        }
+
+        for edge in road_edge_lines(road, step) {
+            let mut candidates = Vec::with_capacity(2);
+            if backward {
+                candidates.push(edge.iter().rev().copied().collect::<Vec<_>>());
+            }
+            if forward {
+                candidates.push(edge);
+            }
+            for candidate in candidates {
+                let (Some(first), Some(last)) = (candidate.first(), candidate.last()) else {
+                    continue;
+                };
+                let score = dist(first, &from.second) + dist(last, &to.first);
+                if best.as_ref().is_none_or(|(b, _)| score < *b) {
+                    best = Some((score, candidate));
+                }
+            }
+        }
+    }
+
+    best.map(|(_, edge)| edge)
+}
+
+/// 道路左右边缘沿 s 的采样折线
+fn road_edge_lines(road: &OdrRoad, step: f64) -> [Vec<Vec3>; 2] {
+    let mut lines = [Vec::new(), Vec::new()];
+    for section_idx in 0..road.lanes.len() {
+        let (s_start, s_end) = road.section_range(section_idx);
+        for s in uniform_samples(s_start, s_end, step) {
+            let [left, right] = road_edges(road, section_idx, s);
+            lines[0].push(left);
+            lines[1].push(right);
+        }
+    }
+    lines
+}
+
+/// 道路在 s 处左右边缘（最外侧车道外边界）的路面点
+fn road_edges(road: &OdrRoad, section_idx: usize, s: f64) -> [Vec3; 2] {
+    let section = &road.lanes[section_idx];
+    let edge = |lanes: &[OdrLane]| {
+        lanes
+            .iter()
+            .max_by_key(|l| l.id.abs())
+            .map(|l| road.lane_t_bounds(section, l, s).1)
+            .unwrap_or_else(|| road.eval_lane_offset(s))
+    };
+    [edge(&section.left), edge(&section.right)]
+        .map(|t| road.sth_to_xyz(s, t, road.eval_shape(s, t)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::{
+        math::triangulate::signed_area,
+        odr::{
+            models::{
+                junction::{
+                    OdrJunctionType,
+                    boundary::{OdrJunctionBoundary, OdrJunctionBoundarySegment},
+                    elevation_grid::{OdrJunctionElevationGrid, OdrJunctionGridElevation},
+                },
+                lane::lane_section::OdrLaneSection,
+                road::{
+                    road_elevation::OdrRoadElevation, road_geometry::OdrRoadGeometry,
+                    road_link::OdrRoadLink,
+                },
+            },
+            test_support::{self, header, plain_lane, road_link},
+        },
+    };
+
+    fn road(
+        id: &str,
+        x: f64,
+        junction: &str,
+        predecessor: Option<OdrRoadLink>,
+        successor: Option<OdrRoadLink>,
+    ) -> OdrRoad {
+        let geometry = OdrRoadGeometry::create_line(0.0, x, 0.0, 0.0, 20.0);
+        road_with(id, geometry, junction, predecessor, successor)
+    }
+
+    fn road_with(
+        id: &str,
+        geometry: OdrRoadGeometry,
+        junction: &str,
+        predecessor: Option<OdrRoadLink>,
+        successor: Option<OdrRoadLink>,
+    ) -> OdrRoad {
+        let lane = |id| plain_lane(id, 3.5);
+        let section = OdrLaneSection::new(0.0, vec![lane(1)], vec![lane(-1)], lane(0), None);
+        OdrRoad {
+            junction: junction.to_string(),
+            elevations: vec![OdrRoadElevation::new(0.0, 2.0, 0.0, 0.0, 0.0)],
+            predecessor,
+            successor,
+            ..test_support::road(id, geometry, vec![section])
+        }
+    }
+
+    fn junction_map() -> (OpenDrive, OdrJunction) {
+        let roads = vec![
+            road("in", -20.0, "-1", None, None),
+            road(
+                "c",
+                0.0,
+                "J",
+                road_link("in", OdrContactPoint::End),
+                road_link("out", OdrContactPoint::Start),
+            ),
+            road("out", 20.0, "-1", None, None),
+        ];
+        let junction = OdrJunction::new("J".to_string(), None, OdrJunctionType::Default);
+        (
+            OpenDrive::new(header(), roads, vec![junction.clone()]),
+            junction,
+        )
+    }
+
+    #[test]
+    fn test_junction_outline() {
+        let (odr, junction) = junction_map();
+        let outline = junction_outline(&odr, &junction, 1.0);
+
+        // 连接道路覆盖 x ∈ [0, 20]、y ∈ [-3.5, 3.5] 的矩形
+        assert!((signed_area(&outline) - 140.0).abs() < 1e-6);
+        assert!(outline.iter().all(|p| (p.z - 2.0).abs() < 1e-9));
+        assert!(outline.iter().all(|p| p.x > -1e-9 && p.x < 20.0 + 1e-9));
+
+        let empty = OdrJunction::new("X".to_string(), None, OdrJunctionType::Default);
+        assert!(junction_outline(&odr, &empty, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_t_junction_outline() {
+        // 西、东两臂直行贯通，南臂经两条半径 10 m 的右转弯道分别连到西臂和东臂
+        let turn = 10.0 * PI / 2.0;
+        let roads = vec![
+            road_with(
+                "w",
+                OdrRoadGeometry::create_line(0.0, -30.0, 0.0, 0.0, 20.0),
+                "-1",
+                None,
+                None,
+            ),
+            road_with(
+                "e",
+                OdrRoadGeometry::create_line(0.0, 10.0, 0.0, 0.0, 20.0),
+                "-1",
+                None,
+                None,
+            ),
+            road_with(
+                "s",
+                OdrRoadGeometry::create_line(0.0, 0.0, -30.0, PI / 2.0, 20.0),
+                "-1",
+                None,
+                None,
+            ),
+            road_with(
+                "we",
+                OdrRoadGeometry::create_line(0.0, -10.0, 0.0, 0.0, 20.0),
+                "T",
+                road_link("w", OdrContactPoint::End),
+                road_link("e", OdrContactPoint::Start),
+            ),
+            road_with(
+                "ws",
+                OdrRoadGeometry::create_arc(0.0, -10.0, 0.0, 0.0, turn, -0.1),
+                "T",
+                road_link("w", OdrContactPoint::End),
+                road_link("s", OdrContactPoint::End),
+            ),
+            road_with(
+                "se",
+                OdrRoadGeometry::create_arc(0.0, 0.0, -10.0, PI / 2.0, turn, -0.1),
+                "T",
+                road_link("s", OdrContactPoint::End),
+                road_link("e", OdrContactPoint::Start),
+            ),
+        ];
+        let junction = OdrJunction::new("T".to_string(), None, OdrJunctionType::Default);
+        let odr = OpenDrive::new(header(), roads, vec![junction.clone()]);
+        let outline = junction_outline(&odr, &junction, 0.5);
+
+        // 矩形 [-10, 10] × [-10, 3.5] 去掉两个半径 6.5 m 的内凹四分之一圆角
+        let expected = 20.0 * 13.5 - 2.0 * PI * 6.5 * 6.5 / 4.0;
+        let area = signed_area(&outline).abs();
+        assert!((area - expected).abs() < 0.5, "{area} vs {expected}");
+
+        // 内凹圆角上的点都在轮廓上，路口中心不在轮廓上
+        for corner in [(-10.0, -10.0), (10.0, -10.0)] {
+            let on_arc = outline
+                .iter()
+                .filter(|p| ((p.x - corner.0).hypot(p.y - corner.1) - 6.5).abs() < 1e-6)
+                .count();
+            assert!(on_arc > 10, "{on_arc}");
+        }
+        assert!(
+            outline
+                .iter()
+                .all(|p| p.y < 3.5 + 1e-6 && p.y > -10.0 - 1e-6)
+        );
+        assert!(!triangulate_polygon(&outline).is_empty());
+    }
+
+    #[test]
+    fn test_build_junction_mesh() {
+        let (odr, junction) = junction_map();
+        let builder = JunctionMeshBuilder::new(Some(1.0), None);
+        let mesh = builder.build_junction_mesh(&odr, &junction);
+
+        let vertex_count = mesh.vertices.len() / 3;
+        assert!(!mesh.indices.is_empty());
+        assert_eq!(mesh.uvs.len(), vertex_count * 2);
+        // WebGL 坐标：高程在 y 轴，法线朝上
+        for i in 0..vertex_count {
+            assert!((mesh.vertices[i * 3 + 1] - 2.0).abs() < 1e-6);
+            assert!((mesh.normals[i * 3 + 1] - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_outline_from_boundary_and_elevation_grid() {
+        let (odr, mut junction) = junction_map();
+        // 只覆盖连接道路右半幅的边界
+        junction.boundary = Some(OdrJunctionBoundary::new(vec![
+            OdrJunctionBoundarySegment::create_lane("c".to_string(), -1, 0.0, 20.0),
+            OdrJunctionBoundarySegment::create_joint(
+                "out".to_string(),
+                OdrContactPoint::Start,
+                -1,
+                0,
+                None,
+            ),
+            OdrJunctionBoundarySegment::create_lane("c".to_string(), 0, 20.0, 0.0),
+            OdrJunctionBoundarySegment::create_joint(
+                "in".to_string(),
+                OdrContactPoint::End,
+                0,
+                -1,
+                None,
+            ),
+        ]));
+        let outline = junction_outline(&odr, &junction, 1.0);
+        assert!((signed_area(&outline).abs() - 70.0).abs() < 1e-6);
+        assert!(outline.iter().all(|p| (p.z - 2.0).abs() < 1e-9));
+
+        // 两行网格位于 x = 5 和 x = 15，之间线性插值，之外取边缘值
+        junction.elevation_grid = Some(OdrJunctionElevationGrid::new(
+            10.0,
+            vec![
+                OdrJunctionGridElevation::new(vec![4.0], Vec::new(), Vec::new()),
+                OdrJunctionGridElevation::new(vec![6.0], Vec::new(), Vec::new()),
+            ],
+        ));
+        let outline = junction_outline(&odr, &junction, 1.0);
+        for p in &outline {
+            let expected = (4.0 + (p.x - 5.0) / 5.0).clamp(4.0, 6.0);
+            assert!((p.z - expected).abs() < 1e-9, "{:?}", p);
+        }
+    }
+}
@@ -2,12 +2,20 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     math::{
+        frame::MeshFrame,
         mesh::{LineMeshData, MeshData},
         vec3::Vec3,
     },
-    odr::models::{
-        lane::{OdrLane, lane_geometry::OdrLaneWidth, lane_section::OdrLaneSection},
-        road::OdrRoad,
+    odr::{
+        mesh::{
+            attributes::{AttributeChannels, VertexAttribute, VertexSample},
+            sampling::{adaptive_samples, lane_width_breaks, uniform_samples},
+            weld::SurfaceWelder,
+        },
+        models::{
+            lane::{OdrLane, lane_geometry::OdrLaneWidth, lane_section::OdrLaneSection},
+            road::OdrRoad,
+        },
     },
 };
 
@@ -18,8 +26,14 @@ use crate::{
 pub struct LaneMeshBuilder {
     /// 沿 s 方向的采样步长（米）
     sample_step: f64,
-    /// 地图中心点，用于解决大坐标精度问题，从 OpenDrive.center 获取
-    center: Vec3,
+    /// 输出坐标系
+    frame: MeshFrame,
+    /// 自适应采样的弦高误差（米），为 None 时按 `sample_step` 均匀采样
+    tolerance: Option<f64>,
+    /// 车道面网格输出的顶点属性
+    attributes: AttributeChannels,
+    /// 接缝焊接距离（米），为 None 时不焊接
+    weld_tolerance: Option<f64>,
 }
 
 #[wasm_bindgen]
@@ -31,12 +45,55 @@ impl LaneMeshBuilder {
     /// - `center`: 地图中心点，用于解决大坐标精度问题，从 OpenDrive.center 获取
     #[wasm_bindgen(constructor)]
     pub fn new(sample_step: Option<f64>, center: Option<Vec3>) -> Self {
+        Self::with_frame(sample_step, &MeshFrame::webgl(center))
+    }
+
+    /// 使用指定输出坐标系创建车道网格构建器
+    ///
+    /// # 参数
+    /// - `sample_step`: 沿参考线方向的采样间隔（米），默认 1.0
+    /// - `frame`: 输出坐标系（轴约定、手性、单位和原点）
+    #[wasm_bindgen(js_name = "withFrame")]
+    pub fn with_frame(sample_step: Option<f64>, frame: &MeshFrame) -> Self {
         Self {
             sample_step: sample_step.unwrap_or(1.0),
-            center: center.unwrap_or_default(),
+            frame: *frame,
+            tolerance: None,
+            attributes: AttributeChannels::default(),
+            weld_tolerance: None,
         }
     }
 
+    /// 设置自适应采样的弦高误差（米）
+    ///
+    /// 设置后按参考线曲率、车道宽度和高程变化自适应采样，直线段只保留少量采样点，
+    /// 此时 `sample_step` 作为最小采样间距。传入 None 恢复均匀采样。
+    #[wasm_bindgen(js_name = "setTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: Option<f64>) {
+        self.tolerance = tolerance.filter(|t| *t > 0.0);
+    }
+
+    /// 设置接缝焊接距离（米）
+    ///
+    /// 设置后 `buildRoadMesh` 会将车道段边界上距离不超过该值的顶点合并到同一位置，
+    /// 并平滑接缝两侧的法线。传入 None 关闭焊接。
+    #[wasm_bindgen(js_name = "setWeldTolerance")]
+    pub fn set_weld_tolerance(&mut self, tolerance: Option<f64>) {
+        self.weld_tolerance = tolerance.filter(|t| *t > 0.0);
+    }
+
+    /// 为车道面网格添加内置顶点属性，通过 `MeshData.attribute(name)` 读取
+    #[wasm_bindgen(js_name = "addAttribute")]
+    pub fn add_attribute(&mut self, attribute: VertexAttribute) {
+        self.attributes.add(attribute);
+    }
+
+    /// 清除所有顶点属性
+    #[wasm_bindgen(js_name = "clearAttributes")]
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
     /// 构建单个车道的网格
     ///
     /// # 参数
@@ -59,47 +116,42 @@ impl LaneMeshBuilder {
             return MeshData::new(Vec::new(), Vec::new(), Vec::new());
         }
 
-        // 计算采样点数量
-        let length = s_end - s_start;
-        let num_samples = ((length / self.sample_step).ceil() as usize).max(2);
+        // 计算采样位置
+        let samples = self.sample_positions(road, lane_section, lane, s_start, s_end);
+        let num_samples = samples.len();
 
         let mut vertices = Vec::new();
+        let mut uvs = Vec::with_capacity(num_samples * 4);
+        let mut attributes = self.attributes.begin(num_samples * 2);
 
         // 沿 s 方向采样，生成车道的内外边界顶点
-        for i in 0..num_samples {
-            let t = i as f64 / (num_samples - 1) as f64;
-            let s = s_start + t * length;
-
-            // 计算车道在当前 s 位置的横向边界
-            let (t_inner, t_outer) = self.get_lane_t_bounds(lane, lane_section, road, s);
-
-            // 计算高度偏移：考虑横断面形状 (shape)
-            let h_inner = road.eval_shape(s, t_inner);
-            let h_outer = road.eval_shape(s, t_outer);
-
-            // 转换为笛卡尔坐标
-            let inner_point = road.sth_to_xyz(s, t_inner, h_inner);
-            let outer_point = road.sth_to_xyz(s, t_outer, h_outer);
-
-            // 添加顶点（内边界和外边界各一个）
-            // 坐标系转换：OpenDRIVE (x, y, z) -> WebGL (x, z, -y)
-            // 减去 center 解决大坐标精度问题
-            vertices.push((inner_point.x - self.center.x) as f32);
-            vertices.push((inner_point.z - self.center.z) as f32);
-            vertices.push(-(inner_point.y - self.center.y) as f32);
-
-            vertices.push((outer_point.x - self.center.x) as f32);
-            vertices.push((outer_point.z - self.center.z) as f32);
-            vertices.push(-(outer_point.y - self.center.y) as f32);
+        for s in samples {
+            let [inner_point, outer_point] = self.lane_edge_points(road, lane_section, lane, s);
+
+            // 添加顶点（内边界和外边界各一个），转换到输出坐标系
+            self.frame.push_point(&mut vertices, inner_point);
+            self.frame.push_point(&mut vertices, outer_point);
+
+            // UV：u 为道路 s 坐标（米），v 从内边界 0 到外边界 1
+            uvs.extend_from_slice(&[s as f32, 0.0, s as f32, 1.0]);
+
+            if !self.attributes.is_empty() {
+                let (t_inner, t_outer) = self.get_lane_t_bounds(lane, lane_section, road, s);
+                for t in [t_inner, t_outer] {
+                    let sample = VertexSample { road, lane, s, t };
+                    self.attributes.push(&mut attributes, &sample);
+                }
+            }
         }
 
         // 生成索引（三角形带）
         let indices = MeshData::generate_strip_indices(num_samples);
 
         // 计算法线
-        let normals = MeshData::calculate_normals(&vertices, &indices);
-
-        MeshData::new(vertices, indices, normals)
+        self.frame
+            .mesh(vertices, indices)
+            .with_uvs(uvs)
+            .with_attributes(attributes)
     }
 
     /// 构建整条道路所有车道的网格
@@ -108,7 +160,7 @@ impl LaneMeshBuilder {
     /// - `road`: 道路对象
     #[wasm_bindgen(js_name = "buildRoadMesh")]
     pub fn build_road_mesh(&self, road: &OdrRoad) -> MeshData {
-        let mut result = MeshData::empty();
+        let mut welder = self.welder();
 
         for (section_idx, lane_section) in road.lanes.iter().enumerate() {
             let s_start = lane_section.s;
@@ -118,19 +170,17 @@ impl LaneMeshBuilder {
                 road.length
             };
 
-            // 构建左侧车道
-            for lane in &lane_section.left {
-                let mesh = self.build_lane_mesh(road, lane_section, lane, s_start, s_end);
-                result.merge(mesh);
-            }
-
-            // 构建右侧车道
-            for lane in &lane_section.right {
+            // 构建左侧和右侧车道
+            for lane in lane_section.left.iter().chain(lane_section.right.iter()) {
                 let mesh = self.build_lane_mesh(road, lane_section, lane, s_start, s_end);
-                result.merge(mesh);
+                welder.push(road, section_idx, mesh);
             }
         }
 
+        let mut result = MeshData::empty();
+        for mesh in welder.finish() {
+            result.merge(mesh);
+        }
         result
     }
 
@@ -159,39 +209,20 @@ impl LaneMeshBuilder {
             return LineMeshData::empty();
         }
 
-        // 计算采样点数量
-        let length = s_end - s_start;
-        let num_samples = ((length / self.sample_step).ceil() as usize).max(2);
+        // 计算采样位置
+        let samples = self.sample_positions(road, lane_section, lane, s_start, s_end);
+        let num_samples = samples.len();
 
         let mut inner_vertices = Vec::new();
         let mut outer_vertices = Vec::new();
 
         // 沿 s 方向采样，生成车道的内外边界顶点
-        for i in 0..num_samples {
-            let t = i as f64 / (num_samples - 1) as f64;
-            let s = s_start + t * length;
-
-            // 计算车道在当前 s 位置的横向边界
-            let (t_inner, t_outer) = self.get_lane_t_bounds(lane, lane_section, road, s);
-
-            // 计算高度偏移：考虑横断面形状 (shape)
-            let h_inner = road.eval_shape(s, t_inner);
-            let h_outer = road.eval_shape(s, t_outer);
-
-            // 转换为笛卡尔坐标
-            let inner_point = road.sth_to_xyz(s, t_inner, h_inner);
-            let outer_point = road.sth_to_xyz(s, t_outer, h_outer);
-
-            // 添加内边界顶点
-            // 坐标系转换：OpenDRIVE (x, y, z) -> WebGL (x, z, -y)
-            inner_vertices.push((inner_point.x - self.center.x) as f32);
-            inner_vertices.push((inner_point.z - self.center.z) as f32);
-            inner_vertices.push(-(inner_point.y - self.center.y) as f32);
-
-            // 添加外边界顶点
-            outer_vertices.push((outer_point.x - self.center.x) as f32);
-            outer_vertices.push((outer_point.z - self.center.z) as f32);
-            outer_vertices.push(-(outer_point.y - self.center.y) as f32);
+        for s in samples {
+            let [inner_point, outer_point] = self.lane_edge_points(road, lane_section, lane, s);
+
+            // 添加内外边界顶点，转换到输出坐标系
+            self.frame.push_point(&mut inner_vertices, inner_point);
+            self.frame.push_point(&mut outer_vertices, outer_point);
         }
 
         // 构建闭合四边形：内边界 -> 外边界(反向) -> 回到起点
@@ -218,6 +249,61 @@ impl LaneMeshBuilder {
 }
 
 impl LaneMeshBuilder {
+    /// 创建接缝焊接器，未启用焊接时返回的焊接器不做任何修改
+    pub fn welder(&self) -> SurfaceWelder {
+        SurfaceWelder::new(self.weld_tolerance.map(|t| t * self.frame.unit_scale))
+    }
+
+    /// 为车道面网格添加由回调计算的顶点属性
+    pub fn add_attribute_fn(&mut self, name: &str, f: impl Fn(&VertexSample) -> f32 + 'static) {
+        self.attributes.add_fn(name, f);
+    }
+
+    /// 车道网格沿 s 方向的采样位置
+    fn sample_positions(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        s_start: f64,
+        s_end: f64,
+    ) -> Vec<f64> {
+        match self.tolerance {
+            Some(tolerance) => adaptive_samples(
+                road,
+                s_start,
+                s_end,
+                lane_width_breaks(section),
+                tolerance,
+                self.sample_step,
+                |s| self.lane_edge_points(road, section, lane, s),
+            ),
+            None => uniform_samples(s_start, s_end, self.sample_step),
+        }
+    }
+
+    /// 计算车道在指定 s 位置的内外边界点（OpenDRIVE 坐标）
+    fn lane_edge_points(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        s: f64,
+    ) -> [Vec3; 2] {
+        // 计算车道在当前 s 位置的横向边界
+        let (t_inner, t_outer) = self.get_lane_t_bounds(lane, section, road, s);
+
+        // 计算高度偏移：考虑横断面形状 (shape)
+        let h_inner = road.eval_shape(s, t_inner);
+        let h_outer = road.eval_shape(s, t_outer);
+
+        // 转换为笛卡尔坐标
+        [
+            road.sth_to_xyz(s, t_inner, h_inner),
+            road.sth_to_xyz(s, t_outer, h_outer),
+        ]
+    }
+
     /// 计算车道在指定 s 位置的横向边界 (t_inner, t_outer)
     ///
     /// # 参数
@@ -262,7 +348,7 @@ impl LaneMeshBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::odr::models::lane::lane_link::OdrLaneLink;
+    use crate::odr::test_support::{self, plain_lane};
 
     #[test]
     fn test_eval_lane_width_constant() {
@@ -320,7 +406,7 @@ mod tests {
         // 单个三角形
         let indices = vec![0, 1, 2];
 
-        let normals = MeshData::calculate_normals(&vertices, &indices);
+        let normals = MeshData::calculate_normals(&vertices, &indices, [0.0, 1.0, 0.0]);
 
         // 水平面的法线应该指向 Y 轴正方向
         assert_eq!(normals.len(), 9);
@@ -334,38 +420,20 @@ mod tests {
 
     #[test]
     fn test_calculate_inner_offset_with_descending_lane_ids() {
-        fn build_lane(id: i32, width: f64) -> OdrLane {
-            OdrLane {
-                id,
-                lane_type: "driving".into(),
-                level: false,
-                road_works: None,
-                link: OdrLaneLink::new(None, None),
-                width: vec![OdrLaneWidth::new(0.0, width, 0.0, 0.0, 0.0)],
-                border: Vec::new(),
-                height: Vec::new(),
-                speed: Vec::new(),
-                access: Vec::new(),
-                rule: Vec::new(),
-                material: Vec::new(),
-                road_marks: Vec::new(),
-            }
-        }
-
         let left = vec![
-            build_lane(4, 4.0),
-            build_lane(3, 3.0),
-            build_lane(2, 2.0),
-            build_lane(1, 1.0),
+            plain_lane(4, 4.0),
+            plain_lane(3, 3.0),
+            plain_lane(2, 2.0),
+            plain_lane(1, 1.0),
         ];
         let right = vec![
-            build_lane(-1, 1.0),
-            build_lane(-2, 2.0),
-            build_lane(-3, 3.0),
-            build_lane(-4, 4.0),
+            plain_lane(-1, 1.0),
+            plain_lane(-2, 2.0),
+            plain_lane(-3, 3.0),
+            plain_lane(-4, 4.0),
         ];
 
-        let section = OdrLaneSection::new(0.0, left, right, build_lane(0, 0.0), None);
+        let section = OdrLaneSection::new(0.0, left, right, plain_lane(0, 0.0), None);
         let s = 5.0; // 任意 s，宽度恒定
 
         assert!((section.calculate_inner_offset(1, s) - 0.0).abs() < 1e-6);
@@ -378,4 +446,109 @@ mod tests {
         assert!((section.calculate_inner_offset(-3, s) + 3.0).abs() < 1e-6);
         assert!((section.calculate_inner_offset(-4, s) + 6.0).abs() < 1e-6);
     }
+
+    #[test]
+    fn test_adaptive_sampling_reduces_vertices() {
+        use crate::odr::models::road::road_geometry::OdrRoadGeometry;
+
+        let section = OdrLaneSection::new(
+            0.0,
+            Vec::new(),
+            vec![plain_lane(-1, 3.5)],
+            plain_lane(0, 0.0),
+            None,
+        );
+        let road = OdrRoad::new(
+            "1".to_string(),
+            2000.0,
+            "-1".to_string(),
+            None,
+            None,
+            None,
+            Some(vec![
+                OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 1900.0),
+                OdrRoadGeometry::create_arc(1900.0, 1900.0, 0.0, 0.0, 100.0, 0.02),
+            ]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            vec![section.clone()],
+            Vec::new(),
+        );
+
+        let mut builder = LaneMeshBuilder::new(Some(0.1), None);
+        let uniform = builder.build_lane_mesh(&road, &section, &section.right[0], 0.0, 2000.0);
+        builder.set_tolerance(Some(0.01));
+        let adaptive = builder.build_lane_mesh(&road, &section, &section.right[0], 0.0, 2000.0);
+
+        assert_eq!(uniform.vertices.len() / 6, 20000);
+        assert!(adaptive.vertices.len() * 10 < uniform.vertices.len());
+        // 弧线段比直线段采样更密
+        let arc_samples = adaptive
+            .vertices
+            .chunks_exact(6)
+            .filter(|v| v[0] > 1900.0)
+            .count();
+        assert!(arc_samples > 1900 / 50);
+    }
+
+    #[test]
+    fn test_vertex_attributes() {
+        use crate::odr::models::road::road_geometry::OdrRoadGeometry;
+
+        let section = OdrLaneSection::new(
+            0.0,
+            Vec::new(),
+            vec![plain_lane(-1, 3.5)],
+            plain_lane(0, 0.0),
+            None,
+        );
+        let road = test_support::road(
+            "1",
+            OdrRoadGeometry::create_arc(0.0, 0.0, 0.0, 0.0, 50.0, 0.02),
+            vec![section.clone()],
+        );
+
+        let mut builder = LaneMeshBuilder::new(Some(5.0), None);
+        builder.add_attribute(VertexAttribute::S);
+        builder.add_attribute(VertexAttribute::T);
+        builder.add_attribute(VertexAttribute::LaneId);
+        builder.add_attribute(VertexAttribute::Curvature);
+        builder.add_attribute_fn("double_s", |v| (v.s * 2.0) as f32);
+        let mesh = builder.build_lane_mesh(&road, &section, &section.right[0], 0.0, 50.0);
+
+        assert_eq!(
+            mesh.attribute_names(),
+            vec!["s", "t", "laneId", "curvature", "double_s"]
+        );
+        let vertex_count = mesh.vertices.len() / 3;
+        let s = mesh.attribute("s").unwrap();
+        let t = mesh.attribute("t").unwrap();
+        assert_eq!(s.len(), vertex_count);
+        assert_eq!(s[vertex_count - 1], 50.0);
+        // 内边界 t = 0，外边界 t = -3.5
+        assert_eq!(t[0], 0.0);
+        assert!((t[1] + 3.5).abs() < 1e-6);
+        assert!(
+            mesh.attribute("laneId")
+                .unwrap()
+                .iter()
+                .all(|&id| id == -1.0)
+        );
+        assert!(
+            mesh.attribute("curvature")
+                .unwrap()
+                .iter()
+                .all(|&k| (k - 0.02).abs() < 1e-6)
+        );
+        let double_s = mesh.attribute("double_s").unwrap();
+        assert!(
+            s.iter()
+                .zip(&double_s)
+                .all(|(s, d)| (s * 2.0 - d).abs() < 1e-4)
+        );
+        assert!(mesh.attribute("slope").is_none());
+    }
 }
@@ -0,0 +1,416 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::{frame::MeshFrame, mesh::MeshData};
+use crate::odr::mesh::{
+    lane_builder::LaneMeshBuilder,
+    road_mark_builder::{RoadMarkKind, RoadMarkMeshBuilder},
+};
+use crate::odr::models::{enums::OdrRoadMarkColor, opendrive::OpenDrive};
+
+/// 交错顶点缓冲区中每个顶点的 float 数：位置 (x, y, z) + 法线 (nx, ny, nz) + UV (u, v)
+pub const VERTEX_STRIDE: usize = 8;
+
+/// 整图网格构建选项
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct MapMeshOptions {
+    /// 车道面采样步长（米），默认 1.0
+    #[wasm_bindgen(js_name = "laneSampleStep")]
+    pub lane_sample_step: f64,
+    /// 标线采样步长（米），默认 0.2
+    #[wasm_bindgen(js_name = "markSampleStep")]
+    pub mark_sample_step: f64,
+    /// 自适应采样的弦高误差（米），为 None 时均匀采样
+    pub tolerance: Option<f64>,
+    /// 车道面接缝焊接距离（米），为 None 时不焊接
+    ///
+    /// 启用后车道段边界和按接触点相连的道路首尾处的车道面顶点会被合并，法线被平滑。
+    #[wasm_bindgen(js_name = "weldTolerance")]
+    pub weld_tolerance: Option<f64>,
+    /// 是否构建标线，默认 true
+    #[wasm_bindgen(js_name = "includeRoadMarks")]
+    pub include_road_marks: bool,
+    /// 输出坐标系，默认以地图中心为原点的 WebGL 坐标
+    pub frame: Option<MeshFrame>,
+}
+
+#[wasm_bindgen]
+impl MapMeshOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for MapMeshOptions {
+    fn default() -> Self {
+        Self {
+            lane_sample_step: 1.0,
+            mark_sample_step: 0.2,
+            tolerance: None,
+            weld_tolerance: None,
+            include_road_marks: true,
+            frame: None,
+        }
+    }
+}
+
+/// 网格区间的类型
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapMeshKind {
+    Lane,
+    RoadMark,
+}
+
+/// 索引缓冲区中属于同一车道（或同一车道的一条标线）的区间，用于拾取
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct MapMeshRange {
+    road_id: String,
+    section: usize,
+    lane_id: i32,
+    lane_type: String,
+    kind: MapMeshKind,
+    color: Option<OdrRoadMarkColor>,
+    mark_kind: Option<RoadMarkKind>,
+    first_index: u32,
+    index_count: u32,
+}
+
+#[wasm_bindgen]
+impl MapMeshRange {
+    #[wasm_bindgen(getter, js_name = "roadId")]
+    pub fn road_id(&self) -> String {
+        self.road_id.clone()
+    }
+
+    /// 车道段在道路中的序号
+    #[wasm_bindgen(getter)]
+    pub fn section(&self) -> usize {
+        self.section
+    }
+
+    #[wasm_bindgen(getter, js_name = "laneId")]
+    pub fn lane_id(&self) -> i32 {
+        self.lane_id
+    }
+
+    #[wasm_bindgen(getter, js_name = "laneType")]
+    pub fn lane_type(&self) -> String {
+        self.lane_type.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> MapMeshKind {
+        self.kind
+    }
+
+    /// 标线颜色，车道面为 undefined
+    #[wasm_bindgen(getter)]
+    pub fn color(&self) -> Option<OdrRoadMarkColor> {
+        self.color.clone()
+    }
+
+    /// 标线几何类型，车道面为 undefined
+    #[wasm_bindgen(getter, js_name = "markKind")]
+    pub fn mark_kind(&self) -> Option<RoadMarkKind> {
+        self.mark_kind
+    }
+
+    /// 区间在索引缓冲区中的起始位置
+    #[wasm_bindgen(getter, js_name = "firstIndex")]
+    pub fn first_index(&self) -> u32 {
+        self.first_index
+    }
+
+    /// 区间的索引数量（三角形数 × 3）
+    #[wasm_bindgen(getter, js_name = "indexCount")]
+    pub fn index_count(&self) -> u32 {
+        self.index_count
+    }
+}
+
+/// 整图网格
+///
+/// 所有车道面和标线写入同一对缓冲区：`vertices` 为交错的位置/法线/UV
+/// （步长 [`VERTEX_STRIDE`]），`indices` 为全局顶点索引。车道面区间在前、
+/// 标线区间在后，两者在索引缓冲区中各自连续，可分别作为 draw group 渲染。
+///
+/// JS 端可通过 `verticesPtr`/`verticesLen` 在 wasm 内存上直接创建
+/// `Float32Array` 视图，避免复制；视图在本对象释放或 wasm 内存增长后失效。
+#[wasm_bindgen]
+pub struct MapMeshes {
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+    ranges: Vec<MapMeshRange>,
+    /// 第一个标线区间的起始索引，无标线时等于索引总数
+    road_mark_start: u32,
+}
+
+#[wasm_bindgen]
+impl MapMeshes {
+    #[wasm_bindgen(js_name = "verticesPtr")]
+    pub fn vertices_ptr(&self) -> *const f32 {
+        self.vertices.as_ptr()
+    }
+
+    /// 交错顶点缓冲区的 float 数
+    #[wasm_bindgen(js_name = "verticesLen")]
+    pub fn vertices_len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    #[wasm_bindgen(js_name = "indicesPtr")]
+    pub fn indices_ptr(&self) -> *const u32 {
+        self.indices.as_ptr()
+    }
+
+    #[wasm_bindgen(js_name = "indicesLen")]
+    pub fn indices_len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// 每个顶点的 float 数
+    #[wasm_bindgen(getter)]
+    pub fn stride(&self) -> usize {
+        VERTEX_STRIDE
+    }
+
+    /// 第一个标线区间的起始索引
+    #[wasm_bindgen(getter, js_name = "roadMarkStart")]
+    pub fn road_mark_start(&self) -> u32 {
+        self.road_mark_start
+    }
+
+    #[wasm_bindgen(getter, js_name = "rangeCount")]
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn range(&self, index: usize) -> Option<MapMeshRange> {
+        self.ranges.get(index).cloned()
+    }
+
+    /// 查找包含指定三角形的区间序号，`face_index` 为三角形序号（如射线拾取的 faceIndex）
+    #[wasm_bindgen(js_name = "findRange")]
+    pub fn find_range(&self, face_index: u32) -> Option<usize> {
+        let index = face_index.checked_mul(3)?;
+        let i = self
+            .ranges
+            .partition_point(|r| r.first_index + r.index_count <= index);
+        (i < self.ranges.len() && self.ranges[i].first_index <= index).then_some(i)
+    }
+}
+
+impl MapMeshes {
+    fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            ranges: Vec::new(),
+            road_mark_start: 0,
+        }
+    }
+
+    /// 追加一个非空网格及其区间信息
+    fn push(&mut self, mesh: MeshData, range: MapMeshRange) {
+        if mesh.indices.is_empty() {
+            return;
+        }
+        let base = (self.vertices.len() / VERTEX_STRIDE) as u32;
+        for (i, (p, n)) in mesh
+            .vertices
+            .chunks_exact(3)
+            .zip(mesh.normals.chunks_exact(3))
+            .enumerate()
+        {
+            self.vertices.extend_from_slice(p);
+            self.vertices.extend_from_slice(n);
+            let uv = mesh.uvs.get(i * 2..i * 2 + 2).unwrap_or(&[0.0, 0.0]);
+            self.vertices.extend_from_slice(uv);
+        }
+        let first_index = self.indices.len() as u32;
+        self.indices.extend(mesh.indices.iter().map(|i| i + base));
+        self.ranges.push(MapMeshRange {
+            first_index,
+            index_count: mesh.indices.len() as u32,
+            ..range
+        });
+    }
+
+    pub fn vertices(&self) -> &[f32] {
+        &self.vertices
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    pub fn ranges(&self) -> &[MapMeshRange] {
+        &self.ranges
+    }
+}
+
+/// 一次性构建整张地图的车道面和标线网格
+#[wasm_bindgen(js_name = "buildMapMeshes")]
+pub fn build_map_meshes(odr: &OpenDrive, options: &MapMeshOptions) -> MapMeshes {
+    let frame = options
+        .frame
+        .unwrap_or_else(|| MeshFrame::webgl(Some(odr.center())));
+    let mut lane_builder = LaneMeshBuilder::with_frame(Some(options.lane_sample_step), &frame);
+    lane_builder.set_tolerance(options.tolerance);
+    lane_builder.set_weld_tolerance(options.weld_tolerance);
+    let mut mark_builder = RoadMarkMeshBuilder::with_frame(Some(options.mark_sample_step), &frame);
+    mark_builder.set_tolerance(options.tolerance);
+
+    let mut result = MapMeshes::new();
+
+    let mut welder = lane_builder.welder();
+    let mut lane_ranges = Vec::new();
+    for road in &odr.roads {
+        welder.link_road(road);
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            for lane in section.left.iter().chain(section.right.iter()) {
+                let mesh = lane_builder.build_lane_mesh(road, section, lane, s_start, s_end);
+                welder.push(road, section_idx, mesh);
+                lane_ranges.push(MapMeshRange {
+                    road_id: road.id.clone(),
+                    section: section_idx,
+                    lane_id: lane.id,
+                    lane_type: lane.lane_type.clone(),
+                    kind: MapMeshKind::Lane,
+                    color: None,
+                    mark_kind: None,
+                    first_index: 0,
+                    index_count: 0,
+                });
+            }
+        }
+    }
+    for (mesh, range) in welder.finish().into_iter().zip(lane_ranges) {
+        result.push(mesh, range);
+    }
+    result.road_mark_start = result.indices.len() as u32;
+
+    if !options.include_road_marks {
+        return result;
+    }
+
+    for road in &odr.roads {
+        for (section_idx, section) in road.lanes.iter().enumerate() {
+            let (s_start, s_end) = road.section_range(section_idx);
+            let all_lanes = section
+                .left
+                .iter()
+                .chain(std::iter::once(&section.center))
+                .chain(section.right.iter());
+            for lane in all_lanes {
+                let items = mark_builder
+                    .build_lane_road_marks(road, section, lane, s_start, s_end)
+                    .into_items();
+                for item in items {
+                    let mark_kind = item.kind();
+                    let (mesh, color, _) = item.into_parts();
+                    result.push(
+                        mesh,
+                        MapMeshRange {
+                            road_id: road.id.clone(),
+                            section: section_idx,
+                            lane_id: lane.id,
+                            lane_type: lane.lane_type.clone(),
+                            kind: MapMeshKind::RoadMark,
+                            color: Some(color),
+                            mark_kind: Some(mark_kind),
+                            first_index: 0,
+                            index_count: 0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::{
+            lane::{lane_road_mark::OdrRoadMarkType, lane_section::OdrLaneSection},
+            road::OdrRoad,
+        },
+        test_support::{header, lane, line_road},
+    };
+
+    fn road(id: &str, y: f64) -> OdrRoad {
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, 3.5, OdrRoadMarkType::Solid)],
+            vec![lane(-1, 3.5, OdrRoadMarkType::Broken)],
+            lane(0, 0.0, OdrRoadMarkType::Solid),
+            None,
+        );
+        line_road(id, 0.0, y, 20.0, vec![section])
+    }
+
+    #[test]
+    fn test_build_map_meshes() {
+        let odr = OpenDrive::new(header(), vec![road("1", 0.0), road("2", 50.0)], Vec::new());
+        let meshes = build_map_meshes(&odr, &MapMeshOptions::default());
+
+        let lanes: Vec<_> = meshes
+            .ranges()
+            .iter()
+            .filter(|r| r.kind == MapMeshKind::Lane)
+            .map(|r| (r.road_id.as_str(), r.lane_id))
+            .collect();
+        assert_eq!(lanes, [("1", 1), ("1", -1), ("2", 1), ("2", -1)]);
+        // 每条道路 3 条标线：左、中心、右（虚线生成多段但属于同一区间）
+        assert_eq!(meshes.range_count(), 4 + 6);
+
+        // 区间首尾相接，覆盖整个索引缓冲区
+        let mut next = 0;
+        for range in meshes.ranges() {
+            assert_eq!(range.first_index, next);
+            next += range.index_count;
+        }
+        assert_eq!(next as usize, meshes.indices_len());
+        assert_eq!(meshes.road_mark_start, meshes.ranges()[4].first_index);
+
+        let vertex_count = (meshes.vertices_len() / VERTEX_STRIDE) as u32;
+        assert!(meshes.indices().iter().all(|&i| i < vertex_count));
+        // 右侧车道面法线朝上 (WebGL Y 轴)
+        let vertex = meshes.indices()[meshes.ranges()[1].first_index as usize] as usize;
+        assert!((meshes.vertices()[vertex * VERTEX_STRIDE + 4] - 1.0).abs() < 1e-6);
+        // 第一个顶点位于 s = 0 的内边界
+        assert_eq!(
+            &meshes.vertices()[vertex * VERTEX_STRIDE + 6..(vertex + 1) * VERTEX_STRIDE],
+            &[0.0, 0.0]
+        );
+
+        let picked = meshes.ranges()[2].first_index / 3 + 1;
+        assert_eq!(meshes.find_range(picked), Some(2));
+        assert_eq!(meshes.find_range(next / 3), None);
+    }
+
+    #[test]
+    fn test_build_map_meshes_without_marks() {
+        let odr = OpenDrive::new(header(), vec![road("1", 0.0)], Vec::new());
+        let options = MapMeshOptions {
+            include_road_marks: false,
+            frame: Some(MeshFrame::enu(None)),
+            ..Default::default()
+        };
+        let meshes = build_map_meshes(&odr, &options);
+
+        assert_eq!(meshes.range_count(), 2);
+        assert_eq!(meshes.road_mark_start as usize, meshes.indices_len());
+        // ENU 坐标 Z 轴向上
+        let vertex = meshes.indices()[meshes.ranges()[1].first_index as usize] as usize;
+        assert!((meshes.vertices()[vertex * VERTEX_STRIDE + 5] - 1.0).abs() < 1e-6);
+    }
+}
@@ -1,2 +1,12 @@
+pub mod attributes;
+pub mod cross_section;
+pub mod export;
+pub mod junction_builder;
 pub mod lane_builder;
+pub mod map_builder;
+pub mod object_builder;
 pub mod road_mark_builder;
+pub mod road_mark_polyline;
+pub mod road_mark_profile;
+pub mod sampling;
+pub mod weld;
@@ -0,0 +1,900 @@
+use std::f64::consts::TAU;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    math::{
+        frame::MeshFrame,
+        mesh::MeshData,
+        triangulate::{signed_area, triangulate_polygon},
+        vec3::Vec3,
+    },
+    odr::{
+        mesh::{
+            road_mark_polyline::dash_intervals, road_mark_profile::RoadMarkProfile,
+            sampling::uniform_samples,
+        },
+        models::{
+            object::{
+                OdrBorder, OdrBorderType, OdrCornerReference, OdrMarking, OdrObject,
+                OdrObjectRepeat, OdrOutline, OdrOutlineFillType, OdrRoadMarkColor, OdrSideType,
+            },
+            pose::OdrPose,
+            road::OdrRoad,
+        },
+    },
+};
+
+/// 圆柱代理体的分段数
+const CYLINDER_SEGMENTS: usize = 16;
+
+/// 对象标记抬高底面的默认高度（米），避免 z-fighting
+const DEFAULT_MARKING_LIFT: f64 = 0.005;
+
+/// 对象网格的类型
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectMeshKind {
+    /// 由 outline 拉伸得到
+    Outline,
+    /// 仅有 length/width/radius/height 时生成的包围盒或圆柱
+    Proxy,
+    /// 对象标记（人行横道、停车位线等）
+    Marking,
+    /// 对象边框（交通岛路缘等）
+    Border,
+}
+
+/// 单个对象网格及其材质信息
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ObjectMeshItem {
+    object_id: String,
+    mesh: MeshData,
+    kind: ObjectMeshKind,
+    fill_type: Option<OdrOutlineFillType>,
+    color: Option<OdrRoadMarkColor>,
+    border_type: Option<OdrBorderType>,
+}
+
+#[wasm_bindgen]
+impl ObjectMeshItem {
+    #[wasm_bindgen(getter, js_name = "objectId")]
+    pub fn object_id(&self) -> String {
+        self.object_id.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mesh(&self) -> MeshData {
+        self.mesh.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> ObjectMeshKind {
+        self.kind
+    }
+
+    /// 轮廓填充类型，仅 outline 网格
+    #[wasm_bindgen(getter, js_name = "fillType")]
+    pub fn fill_type(&self) -> Option<OdrOutlineFillType> {
+        self.fill_type.clone()
+    }
+
+    /// 标记颜色，仅 marking 网格
+    #[wasm_bindgen(getter)]
+    pub fn color(&self) -> Option<OdrRoadMarkColor> {
+        self.color.clone()
+    }
+
+    /// 边框类型，仅 border 网格
+    #[wasm_bindgen(getter, js_name = "borderType")]
+    pub fn border_type(&self) -> Option<OdrBorderType> {
+        self.border_type.clone()
+    }
+}
+
+/// 对象网格列表
+///
+/// wasm-bindgen 不完全支持自定义类型的 `Vec<T>`，用此包装提供类数组访问。
+#[wasm_bindgen]
+pub struct ObjectMeshList {
+    items: Vec<ObjectMeshItem>,
+}
+
+#[wasm_bindgen]
+impl ObjectMeshList {
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<ObjectMeshItem> {
+        self.items.get(index).cloned()
+    }
+}
+
+impl ObjectMeshItem {
+    /// 拆分为网格、网格类型和标记颜色
+    pub(crate) fn into_parts(self) -> (MeshData, ObjectMeshKind, Option<OdrRoadMarkColor>) {
+        (self.mesh, self.kind, self.color)
+    }
+}
+
+impl ObjectMeshList {
+    pub(crate) fn new(items: Vec<ObjectMeshItem>) -> Self {
+        Self { items }
+    }
+
+    pub(crate) fn into_items(self) -> Vec<ObjectMeshItem> {
+        self.items
+    }
+}
+
+/// 对象网格构建器
+///
+/// 将 `<object>` 的轮廓拉伸为实体，展开 `<repeat>`，并生成 `<markings>` 和
+/// `<borders>` 的带状网格。没有轮廓的对象由 length/width/radius/height 生成
+/// 包围盒或圆柱代理体。
+#[wasm_bindgen]
+pub struct ObjectMeshBuilder {
+    /// 连续重复对象和 cornerRoad 轮廓沿 s 的采样步长（米）
+    sample_step: f64,
+    /// 输出坐标系
+    frame: MeshFrame,
+}
+
+#[wasm_bindgen]
+impl ObjectMeshBuilder {
+    /// 创建新的对象网格构建器
+    ///
+    /// # 参数
+    /// - `sample_step`: 连续重复对象沿 s 的采样间隔（米），默认 1.0
+    /// - `center`: 地图中心点，用于解决大坐标精度问题，从 OpenDrive.center 获取
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_step: Option<f64>, center: Option<Vec3>) -> Self {
+        Self::with_frame(sample_step, &MeshFrame::webgl(center))
+    }
+
+    /// 使用指定输出坐标系创建对象网格构建器
+    #[wasm_bindgen(js_name = "withFrame")]
+    pub fn with_frame(sample_step: Option<f64>, frame: &MeshFrame) -> Self {
+        Self {
+            sample_step: sample_step.unwrap_or(1.0),
+            frame: *frame,
+        }
+    }
+
+    /// 构建单个对象的网格
+    ///
+    /// 每个轮廓、代理体、标记和边框各生成一个网格，按类型区分材质。
+    ///
+    /// # 参数
+    /// - `road`: 对象所在道路
+    /// - `object`: 要构建的对象
+    #[wasm_bindgen(js_name = "buildObjectMeshes")]
+    pub fn build_object_meshes(&self, road: &OdrRoad, object: &OdrObject) -> ObjectMeshList {
+        ObjectMeshList::new(self.object_items(road, object))
+    }
+
+    /// 构建道路上所有对象的网格
+    #[wasm_bindgen(js_name = "buildRoadObjectMeshes")]
+    pub fn build_road_object_meshes(&self, road: &OdrRoad) -> ObjectMeshList {
+        ObjectMeshList::new(
+            road.objects
+                .iter()
+                .flat_map(|object| self.object_items(road, object))
+                .collect(),
+        )
+    }
+}
+
+impl ObjectMeshBuilder {
+    /// 单个对象的网格项，去除空网格
+    fn object_items(&self, road: &OdrRoad, object: &OdrObject) -> Vec<ObjectMeshItem> {
+        let mut items = Vec::new();
+
+        if object.repeat.is_empty() {
+            self.build_instance(road, object, &mut items);
+        } else {
+            for repeat in &object.repeat {
+                self.build_repeat(road, object, repeat, &mut items);
+            }
+        }
+
+        let profile = RoadMarkProfile::for_road(road, object.s);
+        let pose = object.world_pose(road);
+        for marking in &object.markings {
+            let mut geometry = Geometry::default();
+            let width = marking
+                .width
+                .unwrap_or_else(|| profile.line_width(marking.weight.clone()));
+            if let Some(path) = marking_path(object, &pose, road, marking) {
+                geometry.dashed_ribbon(&path, width, marking);
+            }
+            items.push(
+                self.item(object, geometry, ObjectMeshKind::Marking, |item| {
+                    item.color = Some(marking.color.clone());
+                }),
+            );
+        }
+
+        for border in &object.borders {
+            let mut geometry = Geometry::default();
+            if let Some(path) = border_path(object, &pose, road, border) {
+                geometry.ribbon(&path, border.width);
+            }
+            items.push(self.item(object, geometry, ObjectMeshKind::Border, |item| {
+                item.border_type = Some(border.border_type.clone());
+            }));
+        }
+
+        items.retain(|item| !item.mesh.vertices.is_empty());
+        items
+    }
+
+    /// 构建对象的一个实例：有轮廓时拉伸轮廓，否则生成代理体
+    fn build_instance(&self, road: &OdrRoad, object: &OdrObject, items: &mut Vec<ObjectMeshItem>) {
+        let pose = object.world_pose(road);
+
+        if !object.outlines.is_empty() {
+            for outline in &object.outlines {
+                let mut geometry = Geometry::default();
+                let (base, top) = outline_rings(&pose, road, outline);
+                geometry.extrude(&base, &top, outline.closed.unwrap_or(true));
+                items.push(
+                    self.item(object, geometry, ObjectMeshKind::Outline, |item| {
+                        item.fill_type = outline.fill_type.clone();
+                    }),
+                );
+            }
+            return;
+        }
+
+        let mut geometry = Geometry::default();
+        let height = object.height.unwrap_or(0.0);
+        let footprint: Vec<(f64, f64)> = if let Some(radius) = object.radius {
+            (0..CYLINDER_SEGMENTS)
+                .map(|i| {
+                    let a = i as f64 / CYLINDER_SEGMENTS as f64 * TAU;
+                    (radius * a.cos(), radius * a.sin())
+                })
+                .collect()
+        } else if let (Some(length), Some(width)) = (object.length, object.width) {
+            let (hl, hw) = (length / 2.0, width / 2.0);
+            vec![(-hl, -hw), (hl, -hw), (hl, hw), (-hl, hw)]
+        } else {
+            return;
+        };
+        let base: Vec<Vec3> = footprint
+            .iter()
+            .map(|&(u, v)| pose.local(u, v, 0.0))
+            .collect();
+        let top: Vec<Vec3> = footprint
+            .iter()
+            .map(|&(u, v)| pose.local(u, v, height))
+            .collect();
+        geometry.extrude(&base, &top, true);
+        items.push(self.item(object, geometry, ObjectMeshKind::Proxy, |_| {}));
+    }
+
+    /// 展开重复对象
+    ///
+    /// `distance` 大于 0 时沿 s 等间距放置实例，t、宽度、长度、半径、高度和
+    /// zOffset 在起止值之间线性插值；`distance` 为 0 时生成沿道路的连续实体。
+    fn build_repeat(
+        &self,
+        road: &OdrRoad,
+        object: &OdrObject,
+        repeat: &OdrObjectRepeat,
+        items: &mut Vec<ObjectMeshItem>,
+    ) {
+        let lerp = |a: f64, b: f64, f: f64| a + (b - a) * f;
+        let lerp_opt = |a: Option<f64>, b: Option<f64>, fallback: Option<f64>, f: f64| match (a, b)
+        {
+            (Some(a), Some(b)) => Some(lerp(a, b, f)),
+            (a, b) => a.or(b).or(fallback),
+        };
+        let fraction = |s: f64| {
+            if repeat.length > 0.0 {
+                ((s - repeat.s) / repeat.length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        if repeat.distance > 0.0 {
+            let count = (repeat.length / repeat.distance + 1e-9).floor() as usize + 1;
+            for i in 0..count {
+                let s = repeat.s + i as f64 * repeat.distance;
+                let f = fraction(s);
+                let mut instance = object.clone();
+                instance.repeat.clear();
+                instance.s = s;
+                instance.t = lerp(repeat.t_start, repeat.t_end, f);
+                instance.z_offset = lerp(repeat.z_offset_start, repeat.z_offset_end, f);
+                instance.height = Some(lerp(repeat.height_start, repeat.height_end, f));
+                instance.length =
+                    lerp_opt(repeat.length_start, repeat.length_end, object.length, f);
+                instance.width = lerp_opt(repeat.width_start, repeat.width_end, object.width, f);
+                instance.radius =
+                    lerp_opt(repeat.radius_start, repeat.radius_end, object.radius, f);
+                if detached(repeat) {
+                    // 实例位于起止参考点的连线上，航向沿连线方向
+                    let (p0, p1) = repeat_end_points(road, repeat);
+                    let p = p0.add(&p1.sub(&p0).mul(&Vec3::new(f, f, f)));
+                    let sth = road.xyz_to_sth(p.x, p.y, p.z);
+                    instance.s = sth.x;
+                    instance.t = sth.y;
+                    let hdg =
+                        (p1.y - p0.y).atan2(p1.x - p0.x) - road.eval_reference_line(sth.x).hdg;
+                    instance.hdg = Some(object.hdg.unwrap_or(0.0) + hdg);
+                }
+                // cornerRoad 角点是道路坐标下的绝对位置，按实例相对原对象的 s/t 偏移平移
+                let (ds, dt) = (instance.s - object.s, instance.t - object.t);
+                for corner in instance
+                    .outlines
+                    .iter_mut()
+                    .flat_map(|o| o.corner_road.iter_mut())
+                {
+                    corner.s += ds;
+                    corner.t += dt;
+                }
+                self.build_instance(road, &instance, items);
+            }
+            return;
+        }
+
+        // 连续实体：横截面为宽 width、高 height 的矩形
+        let s_end = repeat.s + repeat.length;
+        let samples = uniform_samples(repeat.s, s_end, self.sample_step);
+        let (p0, p1) = repeat_end_points(road, repeat);
+        let mut rings = Vec::with_capacity(samples.len());
+        for s in samples {
+            let f = fraction(s);
+            let t = lerp(repeat.t_start, repeat.t_end, f);
+            let z = lerp(repeat.z_offset_start, repeat.z_offset_end, f);
+            let height = lerp(repeat.height_start, repeat.height_end, f);
+            let width = lerp_opt(repeat.width_start, repeat.width_end, object.width, f)
+                .or(
+                    lerp_opt(repeat.radius_start, repeat.radius_end, object.radius, f)
+                        .map(|r| 2.0 * r),
+                )
+                .unwrap_or(0.0);
+            let hw = width / 2.0;
+
+            let point = |dt: f64, dz: f64| {
+                if detached(repeat) {
+                    let base = p0.add(&p1.sub(&p0).mul(&Vec3::new(f, f, f)));
+                    let dir = p1.sub(&p0);
+                    let len = dir.x.hypot(dir.y).max(f64::EPSILON);
+                    Vec3::new(
+                        base.x - dir.y / len * dt,
+                        base.y + dir.x / len * dt,
+                        base.z + dz,
+                    )
+                } else {
+                    road.sth_to_xyz(s, t + dt, z + dz)
+                }
+            };
+            // 右下、右上、左上、左下，从 s 正方向看逆时针
+            rings.push([
+                point(-hw, 0.0),
+                point(-hw, height),
+                point(hw, height),
+                point(hw, 0.0),
+            ]);
+        }
+
+        let mut geometry = Geometry::default();
+        geometry.sweep(&rings);
+        items.push(self.item(object, geometry, ObjectMeshKind::Proxy, |_| {}));
+    }
+
+    fn item(
+        &self,
+        object: &OdrObject,
+        geometry: Geometry,
+        kind: ObjectMeshKind,
+        apply: impl FnOnce(&mut ObjectMeshItem),
+    ) -> ObjectMeshItem {
+        let mut vertices = Vec::with_capacity(geometry.points.len() * 3);
+        for p in &geometry.points {
+            self.frame.push_point(&mut vertices, *p);
+        }
+        let mut item = ObjectMeshItem {
+            object_id: object.id.clone(),
+            mesh: self.frame.mesh(vertices, geometry.indices),
+            kind,
+            fill_type: None,
+            color: None,
+            border_type: None,
+        };
+        apply(&mut item);
+        item
+    }
+}
+
+/// 轮廓的底面和顶面顶点环
+fn outline_rings(pose: &OdrPose, road: &OdrRoad, outline: &OdrOutline) -> (Vec<Vec3>, Vec<Vec3>) {
+    if !outline.corner_road.is_empty() {
+        outline
+            .corner_road
+            .iter()
+            .map(|c| {
+                (
+                    road.sth_to_xyz(c.s, c.t, c.dz),
+                    road.sth_to_xyz(c.s, c.t, c.dz + c.height),
+                )
+            })
+            .unzip()
+    } else {
+        outline
+            .corner_local
+            .iter()
+            .map(|c| {
+                (
+                    pose.local(c.u, c.v, c.z),
+                    pose.local(c.u, c.v, c.z + c.height),
+                )
+            })
+            .unzip()
+    }
+}
+
+/// 按 ID 查找轮廓角点（底面位置）
+fn corner_by_id(object: &OdrObject, pose: &OdrPose, road: &OdrRoad, id: u32) -> Option<Vec3> {
+    object.outlines.iter().find_map(|outline| {
+        let road_corner = outline
+            .corner_road
+            .iter()
+            .find(|c| c.id == Some(id))
+            .map(|c| road.sth_to_xyz(c.s, c.t, c.dz));
+        road_corner.or_else(|| {
+            outline
+                .corner_local
+                .iter()
+                .find(|c| c.id == Some(id))
+                .map(|c| pose.local(c.u, c.v, c.z))
+        })
+    })
+}
+
+fn corner_path(
+    object: &OdrObject,
+    pose: &OdrPose,
+    road: &OdrRoad,
+    references: &[OdrCornerReference],
+) -> Vec<Vec3> {
+    references
+        .iter()
+        .filter_map(|r| corner_by_id(object, pose, road, r.id))
+        .collect()
+}
+
+/// 标记所在的路径
+///
+/// 有角点引用时沿引用的轮廓角点；否则沿包围盒的 `side` 边。
+/// 包围盒各边按从上方看逆时针的方向排列。
+fn marking_path(
+    object: &OdrObject,
+    pose: &OdrPose,
+    road: &OdrRoad,
+    marking: &OdrMarking,
+) -> Option<Vec<Vec3>> {
+    let lift = marking.z_offset.unwrap_or(DEFAULT_MARKING_LIFT);
+    let lifted = |p: Vec3| Vec3::new(p.x, p.y, p.z + lift);
+
+    if !marking.corner_references.is_empty() {
+        let path = corner_path(object, pose, road, &marking.corner_references);
+        return (path.len() >= 2).then(|| path.into_iter().map(lifted).collect());
+    }
+
+    let (hl, hw) = (object.length? / 2.0, object.width? / 2.0);
+    let (a, b) = match marking.side {
+        OdrSideType::Right => ((-hl, -hw), (hl, -hw)),
+        OdrSideType::Front => ((hl, -hw), (hl, hw)),
+        OdrSideType::Left => ((hl, hw), (-hl, hw)),
+        OdrSideType::Rear => ((-hl, hw), (-hl, -hw)),
+    };
+    Some(vec![pose.local(a.0, a.1, lift), pose.local(b.0, b.1, lift)])
+}
+
+/// 边框所在的路径（闭合轮廓首尾相接）
+fn border_path(
+    object: &OdrObject,
+    pose: &OdrPose,
+    road: &OdrRoad,
+    border: &OdrBorder,
+) -> Option<Vec<Vec3>> {
+    let mut path = if border.use_complete_outline.unwrap_or(true) {
+        let outline = object
+            .outlines
+            .iter()
+            .find(|o| o.id == Some(border.outline_id))?;
+        let (mut base, _) = outline_rings(pose, road, outline);
+        if outline.closed.unwrap_or(true)
+            && let Some(first) = base.first().copied()
+        {
+            base.push(first);
+        }
+        base
+    } else {
+        corner_path(object, pose, road, &border.corner_references)
+    };
+    path.iter_mut().for_each(|p| p.z += DEFAULT_MARKING_LIFT);
+    (path.len() >= 2).then_some(path)
+}
+
+fn detached(repeat: &OdrObjectRepeat) -> bool {
+    repeat.detach_from_reference_line.unwrap_or(false)
+}
+
+/// 重复区域起点和终点的参考点
+fn repeat_end_points(road: &OdrRoad, repeat: &OdrObjectRepeat) -> (Vec3, Vec3) {
+    (
+        road.sth_to_xyz(repeat.s, repeat.t_start, repeat.z_offset_start),
+        road.sth_to_xyz(repeat.s + repeat.length, repeat.t_end, repeat.z_offset_end),
+    )
+}
+
+/// 惯性坐标下的三角形集合，最后统一转换到输出坐标系
+///
+/// 各面使用独立顶点，使法线保持平直。
+#[derive(Default)]
+struct Geometry {
+    points: Vec<Vec3>,
+    indices: Vec<u32>,
+}
+
+impl Geometry {
+    /// 添加四边形，顶点按期望法线方向逆时针排列
+    fn quad(&mut self, corners: [Vec3; 4]) {
+        let base = self.points.len() as u32;
+        self.points.extend_from_slice(&corners);
+        self.indices
+            .extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    /// 添加平面多边形（按 xy 投影三角化，法线朝上）
+    fn polygon(&mut self, points: &[Vec3]) {
+        let base = self.points.len() as u32;
+        self.points.extend_from_slice(points);
+        self.indices.extend(
+            triangulate_polygon(points)
+                .iter()
+                .flat_map(|t| t.iter().map(|&i| base + i as u32)),
+        );
+    }
+
+    /// 由底面和顶面顶点环拉伸侧面，闭合时补上顶面
+    ///
+    /// 侧面法线朝外；高度全为 0 的闭合轮廓只生成顶面，即平面区域。
+    fn extrude(&mut self, base: &[Vec3], top: &[Vec3], closed: bool) {
+        let n = base.len();
+        if n < 2 {
+            return;
+        }
+        let ccw = signed_area(base) >= 0.0;
+        let has_height = base.iter().zip(top).any(|(b, t)| (t.z - b.z).abs() > 1e-9);
+
+        if has_height {
+            let edges = if closed { n } else { n - 1 };
+            for i in 0..edges {
+                let j = (i + 1) % n;
+                let (a, b) = if ccw { (i, j) } else { (j, i) };
+                self.quad([base[a], base[b], top[b], top[a]]);
+            }
+        }
+        if closed && n >= 3 {
+            self.polygon(top);
+        }
+    }
+
+    /// 沿一系列横截面矩形扫掠出连续实体（侧面、顶面和两端）
+    ///
+    /// 每个截面为 [右下, 右上, 左上, 左下]。
+    fn sweep(&mut self, rings: &[[Vec3; 4]]) {
+        for w in rings.windows(2) {
+            let (r0, r1) = (&w[0], &w[1]);
+            // 右侧面、顶面、左侧面
+            for k in 0..3 {
+                self.quad([r0[k], r1[k], r1[k + 1], r0[k + 1]]);
+            }
+        }
+        if let (Some(first), Some(last)) = (rings.first(), rings.last()) {
+            self.quad(*first);
+            self.quad([last[3], last[2], last[1], last[0]]);
+        }
+    }
+
+    /// 沿折线生成宽度为 `width` 的带状面，法线朝上
+    fn ribbon(&mut self, path: &[Vec3], width: f64) {
+        let hw = width / 2.0;
+        for w in path.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let len = (b.x - a.x).hypot(b.y - a.y);
+            if len < 1e-9 {
+                continue;
+            }
+            // 左侧法向
+            let (nx, ny) = (-(b.y - a.y) / len * hw, (b.x - a.x) / len * hw);
+            let offset = |p: Vec3, k: f64| Vec3::new(p.x + nx * k, p.y + ny * k, p.z);
+            self.quad([
+                offset(a, -1.0),
+                offset(b, -1.0),
+                offset(b, 1.0),
+                offset(a, 1.0),
+            ]);
+        }
+    }
+
+    /// 按标记的起止偏移和虚线模式沿折线生成带状面
+    fn dashed_ribbon(&mut self, path: &[Vec3], width: f64, marking: &OdrMarking) {
+        let lengths: Vec<f64> = std::iter::once(0.0)
+            .chain(path.windows(2).scan(0.0, |acc, w| {
+                *acc += (w[1].x - w[0].x).hypot(w[1].y - w[0].y);
+                Some(*acc)
+            }))
+            .collect();
+        let total = *lengths.last().unwrap_or(&0.0);
+        let start = marking.start_offset;
+        let end = total - marking.stop_offset;
+
+        let dashes = dash_intervals(start, end, marking.line_length, marking.space_length);
+        for dash in dashes.chunks_exact(2) {
+            let mut sub = vec![point_at(path, &lengths, dash[0])];
+            sub.extend(
+                lengths
+                    .iter()
+                    .zip(path)
+                    .filter(|(l, _)| **l > dash[0] && **l < dash[1])
+                    .map(|(_, p)| *p),
+            );
+            sub.push(point_at(path, &lengths, dash[1]));
+            self.ribbon(&sub, width);
+        }
+    }
+}
+
+/// 折线上弧长 `l` 处的点
+fn point_at(path: &[Vec3], lengths: &[f64], l: f64) -> Vec3 {
+    let i = lengths
+        .windows(2)
+        .position(|w| l <= w[1])
+        .unwrap_or(lengths.len().saturating_sub(2));
+    let (a, b) = (path[i], path[i + 1]);
+    let span = lengths[i + 1] - lengths[i];
+    let f = if span > 0.0 {
+        ((l - lengths[i]) / span).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    Vec3::new(
+        a.x + (b.x - a.x) * f,
+        a.y + (b.y - a.y) * f,
+        a.z + (b.z - a.z) * f,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::{
+            object::{OdrCornerLocal, OdrCornerRoad},
+            road::{road_elevation::OdrRoadElevation, superelevation::OdrSuperelevation},
+        },
+        test_support::line_road,
+    };
+
+    fn build_test_road() -> OdrRoad {
+        line_road("1", 0.0, 0.0, 100.0, Vec::new())
+    }
+
+    fn enu_builder() -> ObjectMeshBuilder {
+        ObjectMeshBuilder::with_frame(None, &MeshFrame::enu(None))
+    }
+
+    fn points(mesh: &MeshData) -> Vec<[f32; 3]> {
+        mesh.vertices
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2]])
+            .collect()
+    }
+
+    #[test]
+    fn test_box_proxy() {
+        let road = build_test_road();
+        let mut object = OdrObject::new("o1".to_string(), 10.0, -2.0, 0.0);
+        object.length = Some(4.0);
+        object.width = Some(2.0);
+        object.height = Some(1.5);
+
+        let items = enu_builder().object_items(&road, &object);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, ObjectMeshKind::Proxy);
+
+        let mesh = &items[0].mesh;
+        // 4 个侧面 + 顶面
+        assert_eq!(mesh.vertices.len() / 3, 4 * 4 + 4);
+        let pts = points(mesh);
+        let (min_x, max_x) = pts
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(a, b), p| (a.min(p[0]), b.max(p[0])));
+        let max_z = pts.iter().map(|p| p[2]).fold(f32::MIN, f32::max);
+        assert!((min_x - 8.0).abs() < 1e-5 && (max_x - 12.0).abs() < 1e-5);
+        assert!((max_z - 1.5).abs() < 1e-5);
+
+        // 侧面法线朝外：法线与 (顶点 - 中心) 同向
+        for (p, n) in pts.iter().zip(mesh.normals.chunks_exact(3)) {
+            let d = (p[0] - 10.0) * n[0] + (p[1] + 2.0) * n[1];
+            assert!(d >= -1e-5 || n[2] > 0.9);
+        }
+    }
+
+    #[test]
+    fn test_flat_outline() {
+        let road = build_test_road();
+        let mut object = OdrObject::new("o2".to_string(), 20.0, 0.0, 0.0);
+        let mut outline = OdrOutline::new();
+        outline.fill_type = Some(OdrOutlineFillType::Grass);
+        outline.corner_local = vec![
+            OdrCornerLocal::new(-1.0, -1.0, 0.0, 0.0),
+            OdrCornerLocal::new(1.0, -1.0, 0.0, 0.0),
+            OdrCornerLocal::new(1.0, 1.0, 0.0, 0.0),
+            OdrCornerLocal::new(-1.0, 1.0, 0.0, 0.0),
+        ];
+        object.outlines.push(outline);
+
+        let items = enu_builder().object_items(&road, &object);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, ObjectMeshKind::Outline);
+        assert!(matches!(
+            items[0].fill_type,
+            Some(OdrOutlineFillType::Grass)
+        ));
+        // 只有顶面，法线朝上
+        let mesh = &items[0].mesh;
+        assert_eq!(mesh.vertices.len() / 3, 4);
+        assert_eq!(mesh.indices.len(), 6);
+        assert!(mesh.normals.chunks_exact(3).all(|n| n[2] > 0.99));
+    }
+
+    #[test]
+    fn test_repeat() {
+        let road = build_test_road();
+        let mut object = OdrObject::new("o3".to_string(), 0.0, 0.0, 0.0);
+        object.radius = Some(0.1);
+
+        // 每 5 m 一根立柱，共 5 根
+        object.repeat = vec![OdrObjectRepeat::new(
+            10.0, 20.0, 5.0, -3.0, -3.0, 1.0, 1.0, 0.0, 0.0,
+        )];
+        let items = enu_builder().object_items(&road, &object);
+        assert_eq!(items.len(), 5);
+        let cylinder = CYLINDER_SEGMENTS * 4 + CYLINDER_SEGMENTS;
+        assert!(items.iter().all(|i| i.mesh.vertices.len() / 3 == cylinder));
+
+        // distance 为 0：连续护栏
+        let mut repeat = OdrObjectRepeat::new(10.0, 20.0, 0.0, -3.0, -3.0, 0.8, 0.8, 0.0, 0.0);
+        repeat.width_start = Some(0.3);
+        repeat.width_end = Some(0.3);
+        object.repeat = vec![repeat];
+        let items = enu_builder().object_items(&road, &object);
+        assert_eq!(items.len(), 1);
+        let rings = uniform_samples(10.0, 30.0, 1.0).len();
+        assert_eq!(
+            items[0].mesh.vertices.len() / 3,
+            (rings - 1) * 3 * 4 + 2 * 4
+        );
+        let pts = points(&items[0].mesh);
+        assert!(pts.iter().all(|p| (p[1] + 3.0).abs() <= 0.15 + 1e-5));
+    }
+
+    #[test]
+    fn test_repeat_corner_road() {
+        let mut road = build_test_road();
+        let mut object = OdrObject::new("o6".to_string(), 10.0, 2.0, 0.0);
+        let mut outline = OdrOutline::new();
+        outline.corner_road = vec![
+            OdrCornerRoad::new(10.0, 1.0, 0.0, 0.5),
+            OdrCornerRoad::new(12.0, 1.0, 0.0, 0.5),
+            OdrCornerRoad::new(12.0, 3.0, 0.0, 0.5),
+            OdrCornerRoad::new(10.0, 3.0, 0.0, 0.5),
+        ];
+        object.outlines.push(outline);
+
+        // 每 10 m 一个实例，t 从 2 渐变到 4
+        object.repeat = vec![OdrObjectRepeat::new(
+            10.0, 20.0, 10.0, 2.0, 4.0, 0.5, 0.5, 0.0, 0.0,
+        )];
+        road.objects.push(object);
+        let list = enu_builder().build_road_object_meshes(&road);
+        assert_eq!(list.length(), 3);
+        for i in 0..list.length() {
+            let item = list.get(i).unwrap();
+            assert_eq!(item.kind, ObjectMeshKind::Outline);
+            let pts = points(&item.mesh);
+            let (min_x, max_x, min_y, max_y) = pts.iter().fold(
+                (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+                |(a, b, c, d), p| (a.min(p[0]), b.max(p[0]), c.min(p[1]), d.max(p[1])),
+            );
+            let ds = 10.0 * i as f32;
+            let dt = i as f32;
+            assert!((min_x - (10.0 + ds)).abs() < 1e-4, "{min_x}");
+            assert!((max_x - (12.0 + ds)).abs() < 1e-4, "{max_x}");
+            assert!((min_y - (1.0 + dt)).abs() < 1e-4, "{min_y}");
+            assert!((max_y - (3.0 + dt)).abs() < 1e-4, "{max_y}");
+        }
+    }
+
+    #[test]
+    fn test_marking_and_border() {
+        let road = build_test_road();
+        let mut object = OdrObject::new("o4".to_string(), 50.0, 0.0, 0.0);
+        object.length = Some(10.0);
+        object.width = Some(4.0);
+
+        // 前边（u = 5）上 1 m 实线 1 m 间隔，共 2 段
+        let mut marking = OdrMarking::new(
+            OdrRoadMarkColor::White,
+            1.0,
+            1.0,
+            OdrSideType::Front,
+            0.0,
+            0.0,
+        );
+        marking.width = Some(0.2);
+        object.markings.push(marking);
+
+        let mut outline = OdrOutline::new();
+        outline.id = Some(0);
+        outline.corner_road = vec![
+            OdrCornerRoad::new(45.0, -2.0, 0.0, 0.0),
+            OdrCornerRoad::new(55.0, -2.0, 0.0, 0.0),
+            OdrCornerRoad::new(55.0, 2.0, 0.0, 0.0),
+        ];
+        object.outlines.push(outline);
+        object
+            .borders
+            .push(OdrBorder::new(0, OdrBorderType::Curb, 0.3));
+
+        let items = enu_builder().object_items(&road, &object);
+        let marking = items
+            .iter()
+            .find(|i| i.kind == ObjectMeshKind::Marking)
+            .unwrap();
+        assert_eq!(marking.mesh.vertices.len() / 3, 2 * 4);
+        let pts = points(&marking.mesh);
+        assert!(pts.iter().all(|p| (p[0] - 55.0).abs() <= 0.1 + 1e-4));
+        assert!(pts.iter().all(|p| (p[2] - 0.005).abs() < 1e-5));
+
+        // 闭合三角形轮廓的边框有 3 段
+        let border = items
+            .iter()
+            .find(|i| i.kind == ObjectMeshKind::Border)
+            .unwrap();
+        assert!(matches!(border.border_type, Some(OdrBorderType::Curb)));
+        assert_eq!(border.mesh.vertices.len() / 3, 3 * 4);
+    }
+
+    #[test]
+    fn test_perp_to_road_pose() {
+        let mut road = build_test_road();
+        road.elevations = vec![OdrRoadElevation::new(0.0, 0.0, 0.1, 0.0, 0.0)];
+        road.superelevations = vec![OdrSuperelevation::new(0.0, 0.05, 0.0, 0.0, 0.0)];
+
+        let mut object = OdrObject::new("o5".to_string(), 10.0, 0.0, 0.0);
+        object.perp_to_road = Some(true);
+        let pose = object.world_pose(&road);
+
+        assert!((pose.position.z - 1.0).abs() < 1e-9);
+        // 沿对象 u 轴前进 1 m 时升高约 sin(atan(0.1))
+        let p = pose.local(1.0, 0.0, 0.0);
+        assert!((p.z - 1.0 - 0.1_f64.atan().sin()).abs() < 1e-9);
+        // u/v 平面与路面重合，超高为正时左侧升高
+        let p = pose.local(0.0, 1.0, 0.0);
+        assert!(p.z > 1.0);
+        assert!(road.xyz_to_sth(p.x, p.y, p.z).z.abs() < 1e-9);
+    }
+}
@@ -1,23 +1,49 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    math::{mesh::MeshData, vec3::Vec3},
-    odr::models::{
-        enums::OdrRoadMarkColor, lane::{
-            OdrLane,
-            lane_geometry::OdrLaneWidth,
-            lane_road_mark::{OdrRoadMark, OdrRoadMarkSway, OdrRoadMarkType},
-            lane_section::OdrLaneSection,
-        }, road::OdrRoad
+    math::{frame::MeshFrame, mesh::MeshData, vec3::Vec3},
+    odr::{
+        mesh::{
+            attributes::{AttributeChannels, VertexAttribute, VertexSample},
+            road_mark_polyline::{
+                RoadMarkPolyline, RoadMarkPolylineList, RoadMarkSemantics, dash_intervals,
+            },
+            road_mark_profile::RoadMarkProfile,
+            sampling::{adaptive_samples, lane_width_breaks, uniform_samples},
+        },
+        models::{
+            enums::OdrRoadMarkColor, lane::{
+                OdrLane,
+                lane_geometry::OdrLaneWidth,
+                lane_road_mark::{OdrRoadMark, OdrRoadMarkRule, OdrRoadMarkSway, OdrRoadMarkType},
+                lane_section::OdrLaneSection,
+            }, opendrive::OpenDrive, road::OdrRoad
+        },
     },
 };
 
+/// Geometry kind of a road mark mesh, lets the renderer pick a material
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoadMarkKind {
+    /// Painted line lying on the road surface
+    Paint,
+    /// Extruded curb profile
+    Curb,
+    /// Flat grass strip
+    Grass,
+    /// Raised botts dots
+    BottsDots,
+}
+
 /// Single road mark mesh with its color
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct RoadMarkMeshItem {
     mesh: MeshData,
     color: OdrRoadMarkColor,
+    kind: RoadMarkKind,
+    rgb: u32,
 }
 
 #[wasm_bindgen]
@@ -31,6 +57,39 @@ impl RoadMarkMeshItem {
     pub fn color(&self) -> OdrRoadMarkColor {
         self.color.clone()
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> RoadMarkKind {
+        self.kind
+    }
+
+    /// sRGB color as `0xRRGGBB`, resolved with the road mark profile
+    #[wasm_bindgen(getter)]
+    pub fn rgb(&self) -> u32 {
+        self.rgb
+    }
+}
+
+impl RoadMarkMeshItem {
+    /// Create a mesh item; `rgb` is the color resolved with the road mark profile
+    pub(crate) fn new(
+        mesh: MeshData,
+        color: OdrRoadMarkColor,
+        kind: RoadMarkKind,
+        rgb: u32,
+    ) -> Self {
+        Self {
+            mesh,
+            color,
+            kind,
+            rgb,
+        }
+    }
+
+    /// Split into the mesh, the OpenDRIVE color and the resolved sRGB color
+    pub(crate) fn into_parts(self) -> (MeshData, OdrRoadMarkColor, u32) {
+        (self.mesh, self.color, self.rgb)
+    }
 }
 
 /// List of road mark meshes
@@ -54,20 +113,36 @@ impl RoadMarkMeshList {
     }
 }
 
-/// Default line width when not specified (meters)
-const DEFAULT_LINE_WIDTH: f64 = 0.15;
+impl RoadMarkMeshList {
+    pub(crate) fn new(items: Vec<RoadMarkMeshItem>) -> Self {
+        Self { items }
+    }
+
+    pub(crate) fn into_items(self) -> Vec<RoadMarkMeshItem> {
+        self.items
+    }
+}
 
 /// Default line height/thickness above road surface (meters)
 const DEFAULT_LINE_HEIGHT: f64 = 0.005;
 
-/// Default broken line length (meters)
-const DEFAULT_BROKEN_LENGTH: f64 = 3.0;
+/// Default curb width (meters)
+const DEFAULT_CURB_WIDTH: f64 = 0.15;
+
+/// Default curb height (meters)
+const DEFAULT_CURB_HEIGHT: f64 = 0.15;
+
+/// Default grass strip width (meters)
+const DEFAULT_GRASS_WIDTH: f64 = 0.5;
 
-/// Default broken line space (meters)
-const DEFAULT_BROKEN_SPACE: f64 = 6.0;
+/// Default botts dot size, both along and across the lane (meters)
+const DEFAULT_BOTTS_DOT_SIZE: f64 = 0.1;
 
-/// Default gap between double lines (meters)
-const DEFAULT_DOUBLE_LINE_GAP: f64 = 0.1;
+/// Default botts dot height above road surface (meters)
+const DEFAULT_BOTTS_DOT_HEIGHT: f64 = 0.02;
+
+/// Distance between consecutive botts dot centers (meters)
+const BOTTS_DOT_SPACING: f64 = 0.4;
 
 /// Internal struct for default line pattern generation
 struct DefaultLine {
@@ -77,6 +152,29 @@ struct DefaultLine {
     width: f64,
 }
 
+/// A line of a road mark resolved to absolute s ranges
+struct MarkLine {
+    s_start: f64,
+    s_end: f64,
+    t_offset: f64,
+    width: f64,
+    /// Visible intervals, flat `[start, end, ...]`
+    dashes: Vec<f64>,
+    /// Line color superseding the road mark color
+    color: Option<OdrRoadMarkColor>,
+    rule: Option<OdrRoadMarkRule>,
+}
+
+/// Valid s range of the road mark at `index`, ending where the next one starts
+fn road_mark_range(lane: &OdrLane, index: usize, s_start: f64, s_end: f64) -> (f64, f64) {
+    let mark_s_start = s_start + lane.road_marks[index].s_offset;
+    let mark_s_end = match lane.road_marks.get(index + 1) {
+        Some(next) => s_start + next.s_offset,
+        None => s_end,
+    };
+    (mark_s_start, mark_s_end)
+}
+
 /// Road marking mesh builder
 ///
 /// Builds triangle meshes for lane road markings (lines on the road surface)
@@ -84,8 +182,14 @@ struct DefaultLine {
 pub struct RoadMarkMeshBuilder {
     /// Sample step along s direction (meters)
     sample_step: f64,
-    /// Map center point for solving large coordinate precision issues, from OpenDrive.center
-    center: Vec3,
+    /// Output coordinate frame
+    frame: MeshFrame,
+    /// Chordal error tolerance for adaptive sampling (meters), uniform sampling when None
+    tolerance: Option<f64>,
+    /// Per-vertex attributes written to road mark meshes
+    attributes: AttributeChannels,
+    /// Road mark profile overriding the per-road country profile
+    profile: Option<RoadMarkProfile>,
 }
 
 #[wasm_bindgen]
@@ -97,12 +201,56 @@ impl RoadMarkMeshBuilder {
     /// - `center`: Map center point for solving large coordinate precision issues, from OpenDrive.center
     #[wasm_bindgen(constructor)]
     pub fn new(sample_step: Option<f64>, center: Option<Vec3>) -> Self {
+        Self::with_frame(sample_step, &MeshFrame::webgl(center))
+    }
+
+    /// Create a road mark mesh builder with an explicit output frame
+    ///
+    /// # Arguments
+    /// - `sample_step`: Sampling interval along reference line (meters), default 0.2
+    /// - `frame`: Output frame (axis convention, handedness, unit scale and origin)
+    #[wasm_bindgen(js_name = "withFrame")]
+    pub fn with_frame(sample_step: Option<f64>, frame: &MeshFrame) -> Self {
         Self {
             sample_step: sample_step.unwrap_or(0.2),
-            center: center.unwrap_or_default(),
+            frame: *frame,
+            tolerance: None,
+            attributes: AttributeChannels::default(),
+            profile: None,
         }
     }
 
+    /// Set the chordal error tolerance for adaptive sampling (meters)
+    ///
+    /// When set, samples follow reference line curvature, lane width, sway and
+    /// elevation changes, and `sample_step` becomes the minimum sample spacing.
+    /// Pass None to go back to uniform sampling.
+    #[wasm_bindgen(js_name = "setTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: Option<f64>) {
+        self.tolerance = tolerance.filter(|t| *t > 0.0);
+    }
+
+    /// Set the road mark profile used for all roads
+    ///
+    /// By default the profile is picked from the `country` of the road type in
+    /// effect at each road mark. Pass None to go back to that behavior.
+    #[wasm_bindgen(js_name = "setProfile")]
+    pub fn set_profile(&mut self, profile: Option<RoadMarkProfile>) {
+        self.profile = profile;
+    }
+
+    /// Add a built-in per-vertex attribute, read it back with `MeshData.attribute(name)`
+    #[wasm_bindgen(js_name = "addAttribute")]
+    pub fn add_attribute(&mut self, attribute: VertexAttribute) {
+        self.attributes.add(attribute);
+    }
+
+    /// Remove all per-vertex attributes
+    #[wasm_bindgen(js_name = "clearAttributes")]
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
     /// Build road mark meshes for a single lane
     ///
     /// Returns a list of meshes, each with its own color, allowing proper
@@ -133,12 +281,7 @@ impl RoadMarkMeshBuilder {
             }
 
             // Calculate valid s range for this road mark
-            let mark_s_start = s_start + road_mark.s_offset;
-            let mark_s_end = if i + 1 < lane.road_marks.len() {
-                s_start + lane.road_marks[i + 1].s_offset
-            } else {
-                s_end
-            };
+            let (mark_s_start, mark_s_end) = road_mark_range(lane, i, s_start, s_end);
 
             // Skip if range is invalid
             if mark_s_end <= mark_s_start {
@@ -146,42 +289,162 @@ impl RoadMarkMeshBuilder {
             }
 
             // Build mesh based on definition type
-            let mesh = if road_mark.explicit.is_some() {
-                self.build_explicit_lines(road, lane_section, lane, road_mark, mark_s_start)
+            let (mesh, kind) = if road_mark.explicit.is_some() {
+                let mesh =
+                    self.build_explicit_lines(road, lane_section, lane, road_mark, mark_s_start);
+                (mesh, RoadMarkKind::Paint)
             } else if road_mark.type_detail.is_some() {
-                self.build_type_lines(
+                let mesh = self.build_type_lines(
                     road,
                     lane_section,
                     lane,
                     road_mark,
                     mark_s_start,
                     mark_s_end,
-                )
+                );
+                (mesh, RoadMarkKind::Paint)
             } else {
-                self.build_default_lines(
+                let kind = match road_mark.mark_type {
+                    OdrRoadMarkType::Curb => RoadMarkKind::Curb,
+                    OdrRoadMarkType::Grass => RoadMarkKind::Grass,
+                    OdrRoadMarkType::BottsDots => RoadMarkKind::BottsDots,
+                    _ => RoadMarkKind::Paint,
+                };
+                let build = match kind {
+                    RoadMarkKind::Paint => Self::build_default_lines,
+                    RoadMarkKind::Curb => Self::build_curb,
+                    RoadMarkKind::Grass => Self::build_grass,
+                    RoadMarkKind::BottsDots => Self::build_botts_dots,
+                };
+                let mesh = build(
+                    self,
                     road,
                     lane_section,
                     lane,
                     road_mark,
                     mark_s_start,
                     mark_s_end,
-                )
+                );
+                (mesh, kind)
+            };
+
+            // Grass with the standard color is drawn green
+            let color = match (kind, &road_mark.color) {
+                (RoadMarkKind::Grass, OdrRoadMarkColor::Standard) => OdrRoadMarkColor::Green,
+                (_, color) => color.clone(),
             };
 
             // Only add non-empty meshes
             if !mesh.vertices.is_empty() {
-                items.push(RoadMarkMeshItem {
-                    mesh,
-                    color: road_mark.color.clone(),
-                });
+                let rgb = self.profile_at(road, mark_s_start).color_rgb(color.clone());
+                items.push(RoadMarkMeshItem::new(mesh, color, kind, rgb));
             }
         }
 
         RoadMarkMeshList { items }
     }
+
+    /// Build the road marks of a single lane as semantic polylines
+    ///
+    /// Returns one polyline per line of each road mark, running along the line
+    /// center on the road surface. Marks without paint (`none`, or `custom`
+    /// without a `<type>`) still produce a polyline with no dashes, so every
+    /// lane boundary with a road mark is covered.
+    ///
+    /// # Arguments
+    /// - `road`: Road object
+    /// - `section_index`: Index of the lane section within the road
+    /// - `lane`: Lane to build road mark polylines for
+    #[wasm_bindgen(js_name = "buildLaneRoadMarkPolylines")]
+    pub fn build_lane_road_mark_polylines(
+        &self,
+        road: &OdrRoad,
+        section_index: usize,
+        lane: &OdrLane,
+    ) -> RoadMarkPolylineList {
+        let Some(section) = road.lanes.get(section_index) else {
+            return RoadMarkPolylineList::new(Vec::new());
+        };
+        let (s_start, s_end) = road.section_range(section_index);
+
+        let mut polylines = Vec::new();
+        for (i, road_mark) in lane.road_marks.iter().enumerate() {
+            let (mark_s_start, mark_s_end) = road_mark_range(lane, i, s_start, s_end);
+            if mark_s_end <= mark_s_start {
+                continue;
+            }
+
+            let semantics = RoadMarkSemantics::from_road_mark(road_mark);
+            let profile = self.profile_at(road, mark_s_start);
+            for line in self.mark_lines(road_mark, &profile, mark_s_start, mark_s_end) {
+                let edge_t = self.line_edge_t(road, section, lane, road_mark, line.t_offset, 0.0);
+                let point = |s: f64| {
+                    let t = edge_t(s)[0];
+                    [road.sth_to_xyz(s, t, road.eval_shape(s, t))]
+                };
+
+                let s_values =
+                    self.line_samples(road, section, road_mark, line.s_start, line.s_end, point);
+                let mut vertices = Vec::with_capacity(s_values.len() * 3);
+                for &s in &s_values {
+                    let [p] = point(s);
+                    self.frame.push_point(&mut vertices, p);
+                }
+
+                let mut semantics = semantics.clone();
+                if let Some(color) = line.color {
+                    semantics.color = color;
+                }
+                if line.rule.is_some() {
+                    semantics.rule = line.rule;
+                }
+                polylines.push(RoadMarkPolyline {
+                    road_id: road.id.clone(),
+                    section: section_index,
+                    lane_id: lane.id,
+                    semantics,
+                    t_offset: line.t_offset,
+                    width: line.width,
+                    vertices,
+                    s: s_values,
+                    dashes: line.dashes,
+                });
+            }
+        }
+        RoadMarkPolylineList::new(polylines)
+    }
+
+    /// Build semantic polylines for all lane boundaries of a map
+    ///
+    /// See `buildLaneRoadMarkPolylines`, center lanes are included.
+    #[wasm_bindgen(js_name = "buildRoadMarkPolylines")]
+    pub fn build_road_mark_polylines(&self, odr: &OpenDrive) -> RoadMarkPolylineList {
+        let mut polylines = Vec::new();
+        for road in &odr.roads {
+            for (section_index, section) in road.lanes.iter().enumerate() {
+                let all_lanes = section
+                    .left
+                    .iter()
+                    .chain(std::iter::once(&section.center))
+                    .chain(section.right.iter());
+                for lane in all_lanes {
+                    polylines.extend(
+                        self.build_lane_road_mark_polylines(road, section_index, lane)
+                            .into_items(),
+                    );
+                }
+            }
+        }
+        RoadMarkPolylineList::new(polylines)
+    }
 }
 
 impl RoadMarkMeshBuilder {
+    /// Add a per-vertex attribute computed by a callback
+    pub fn add_attribute_fn(&mut self, name: &str, f: impl Fn(&VertexSample) -> f32 + 'static) {
+        self.attributes.add_fn(name, f);
+    }
+
     /// Build mesh for <type> defined road marks (automatically repeated)
     fn build_type_lines(
         &self,
@@ -195,7 +458,8 @@ impl RoadMarkMeshBuilder {
         let type_detail = road_mark.type_detail.as_ref().unwrap();
         let mut result = MeshData::empty();
 
-        let default_width = road_mark.width.unwrap_or(DEFAULT_LINE_WIDTH);
+        let profile = self.profile_at(road, mark_s_start);
+        let default_width = Self::mark_width(road_mark, &profile);
 
         for line in &type_detail.lines {
             let line_width = line.width.unwrap_or(default_width);
@@ -259,7 +523,8 @@ impl RoadMarkMeshBuilder {
         let explicit = road_mark.explicit.as_ref().unwrap();
         let mut result = MeshData::empty();
 
-        let default_width = road_mark.width.unwrap_or(DEFAULT_LINE_WIDTH);
+        let profile = self.profile_at(road, mark_s_start);
+        let default_width = Self::mark_width(road_mark, &profile);
 
         for line in &explicit.lines {
             let s_start = mark_s_start + line.s_offset;
@@ -292,7 +557,8 @@ impl RoadMarkMeshBuilder {
         mark_s_start: f64,
         mark_s_end: f64,
     ) -> MeshData {
-        let default_lines = self.get_default_lines_for_type(road_mark);
+        let profile = self.profile_at(road, mark_s_start);
+        let default_lines = self.get_default_lines_for_type(road_mark, &profile);
 
         if default_lines.is_empty() {
             return MeshData::empty();
@@ -344,11 +610,111 @@ impl RoadMarkMeshBuilder {
         result
     }
 
+    /// Lines composing a road mark with their s range and visible intervals
+    fn mark_lines(
+        &self,
+        road_mark: &OdrRoadMark,
+        profile: &RoadMarkProfile,
+        mark_s_start: f64,
+        mark_s_end: f64,
+    ) -> Vec<MarkLine> {
+        let default_width = Self::mark_width(road_mark, profile);
+
+        if let Some(explicit) = &road_mark.explicit {
+            return explicit
+                .lines
+                .iter()
+                .map(|line| {
+                    let s_start = mark_s_start + line.s_offset;
+                    let s_end = s_start + line.length;
+                    MarkLine {
+                        s_start,
+                        s_end,
+                        t_offset: line.t_offset,
+                        width: line.width.unwrap_or(default_width),
+                        dashes: dash_intervals(s_start, s_end, 0.0, 0.0),
+                        color: None,
+                        rule: line.rule.clone(),
+                    }
+                })
+                .collect();
+        }
+
+        if let Some(type_detail) = &road_mark.type_detail {
+            return type_detail
+                .lines
+                .iter()
+                .map(|line| (mark_s_start + line.s_offset, line))
+                .filter(|(s_start, _)| *s_start < mark_s_end)
+                .map(|(s_start, line)| MarkLine {
+                    s_start,
+                    s_end: mark_s_end,
+                    t_offset: line.t_offset,
+                    width: line.width.unwrap_or(default_width),
+                    dashes: dash_intervals(s_start, mark_s_end, line.length, line.space),
+                    color: line.color.clone(),
+                    rule: line.rule.clone(),
+                })
+                .collect();
+        }
+
+        let solid = |width: f64, dashes: Vec<f64>| {
+            vec![MarkLine {
+                s_start: mark_s_start,
+                s_end: mark_s_end,
+                t_offset: 0.0,
+                width,
+                dashes,
+                color: None,
+                rule: None,
+            }]
+        };
+        let full = dash_intervals(mark_s_start, mark_s_end, 0.0, 0.0);
+        match road_mark.mark_type {
+            OdrRoadMarkType::Curb => solid(road_mark.width.unwrap_or(DEFAULT_CURB_WIDTH), full),
+            OdrRoadMarkType::Grass => solid(road_mark.width.unwrap_or(DEFAULT_GRASS_WIDTH), full),
+            OdrRoadMarkType::BottsDots => {
+                solid(road_mark.width.unwrap_or(DEFAULT_BOTTS_DOT_SIZE), full)
+            }
+            OdrRoadMarkType::None | OdrRoadMarkType::Custom => solid(0.0, Vec::new()),
+            _ => self
+                .get_default_lines_for_type(road_mark, profile)
+                .into_iter()
+                .map(|line| MarkLine {
+                    s_start: mark_s_start,
+                    s_end: mark_s_end,
+                    t_offset: line.t_offset,
+                    width: line.width,
+                    dashes: dash_intervals(mark_s_start, mark_s_end, line.length, line.space),
+                    color: None,
+                    rule: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Road mark profile in effect at s: the override if set, else by country
+    fn profile_at(&self, road: &OdrRoad, s: f64) -> RoadMarkProfile {
+        self.profile
+            .unwrap_or_else(|| RoadMarkProfile::for_road(road, s))
+    }
+
+    /// Road mark width, defaulting to the profile width for its weight
+    fn mark_width(road_mark: &OdrRoadMark, profile: &RoadMarkProfile) -> f64 {
+        road_mark
+            .width
+            .unwrap_or_else(|| profile.line_width(road_mark.weight.clone()))
+    }
+
     /// Get default line patterns based on road mark type
-    fn get_default_lines_for_type(&self, road_mark: &OdrRoadMark) -> Vec<DefaultLine> {
-        let width = road_mark.width.unwrap_or(DEFAULT_LINE_WIDTH);
+    fn get_default_lines_for_type(
+        &self,
+        road_mark: &OdrRoadMark,
+        profile: &RoadMarkProfile,
+    ) -> Vec<DefaultLine> {
+        let width = Self::mark_width(road_mark, profile);
         let half_width = width / 2.0;
-        let gap = DEFAULT_DOUBLE_LINE_GAP;
+        let gap = profile.double_line_gap;
 
         match road_mark.mark_type {
             OdrRoadMarkType::Solid | OdrRoadMarkType::Edge => {
@@ -362,8 +728,8 @@ impl RoadMarkMeshBuilder {
             OdrRoadMarkType::Broken => {
                 vec![DefaultLine {
                     t_offset: 0.0,
-                    length: DEFAULT_BROKEN_LENGTH,
-                    space: DEFAULT_BROKEN_SPACE,
+                    length: profile.broken_length,
+                    space: profile.broken_space,
                     width,
                 }]
             }
@@ -387,14 +753,14 @@ impl RoadMarkMeshBuilder {
                 vec![
                     DefaultLine {
                         t_offset: -(half_width + gap / 2.0),
-                        length: DEFAULT_BROKEN_LENGTH,
-                        space: DEFAULT_BROKEN_SPACE,
+                        length: profile.broken_length,
+                        space: profile.broken_space,
                         width: half_width,
                     },
                     DefaultLine {
                         t_offset: half_width + gap / 2.0,
-                        length: DEFAULT_BROKEN_LENGTH,
-                        space: DEFAULT_BROKEN_SPACE,
+                        length: profile.broken_length,
+                        space: profile.broken_space,
                         width: half_width,
                     },
                 ]
@@ -409,8 +775,8 @@ impl RoadMarkMeshBuilder {
                     },
                     DefaultLine {
                         t_offset: half_width + gap / 2.0,
-                        length: DEFAULT_BROKEN_LENGTH,
-                        space: DEFAULT_BROKEN_SPACE,
+                        length: profile.broken_length,
+                        space: profile.broken_space,
                         width: half_width,
                     },
                 ]
@@ -419,8 +785,8 @@ impl RoadMarkMeshBuilder {
                 vec![
                     DefaultLine {
                         t_offset: -(half_width + gap / 2.0),
-                        length: DEFAULT_BROKEN_LENGTH,
-                        space: DEFAULT_BROKEN_SPACE,
+                        length: profile.broken_length,
+                        space: profile.broken_space,
                         width: half_width,
                     },
                     DefaultLine {
@@ -431,17 +797,10 @@ impl RoadMarkMeshBuilder {
                     },
                 ]
             }
-            OdrRoadMarkType::BottsDots => {
-                // Botts dots: small squares with spacing
-                vec![DefaultLine {
-                    t_offset: 0.0,
-                    length: 0.1,
-                    space: 0.3,
-                    width: 0.1,
-                }]
-            }
-            // Skip types that don't render visible lines
+            // Skip types that don't render painted lines; curb, grass and
+            // botts dots have dedicated geometry builders
             OdrRoadMarkType::None
+            | OdrRoadMarkType::BottsDots
             | OdrRoadMarkType::Grass
             | OdrRoadMarkType::Curb
             | OdrRoadMarkType::Custom => {
@@ -465,20 +824,156 @@ impl RoadMarkMeshBuilder {
         width: f64,
     ) -> MeshData {
         let height = road_mark.height.unwrap_or(DEFAULT_LINE_HEIGHT);
-        let half_width = width / 2.0;
+        let edge_t = self.line_edge_t(road, section, lane, road_mark, t_offset, width);
+
+        self.build_strip(road, section, lane, road_mark, s_start, s_end, |s| {
+            let [t_inner, t_outer] = edge_t(s);
+            [(t_inner, height), (t_outer, height)]
+        })
+    }
+
+    /// Build an extruded curb: inner wall, top face and outer wall
+    fn build_curb(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        road_mark: &OdrRoadMark,
+        s_start: f64,
+        s_end: f64,
+    ) -> MeshData {
+        let width = road_mark.width.unwrap_or(DEFAULT_CURB_WIDTH);
+        let height = road_mark.height.unwrap_or(DEFAULT_CURB_HEIGHT);
+        let edge_t = self.line_edge_t(road, section, lane, road_mark, 0.0, width);
+
+        // (t index, h) pairs of the profile corners for each face
+        let faces = [
+            [(0, 0.0), (0, height)],
+            [(0, height), (1, height)],
+            [(1, height), (1, 0.0)],
+        ];
 
-        let length = s_end - s_start;
-        let num_samples = ((length / self.sample_step).ceil() as usize).max(2);
+        let mut result = MeshData::empty();
+        for [(ia, ha), (ib, hb)] in faces {
+            let mesh = self.build_strip(road, section, lane, road_mark, s_start, s_end, |s| {
+                let t = edge_t(s);
+                [(t[ia], ha), (t[ib], hb)]
+            });
+            result.merge(mesh);
+        }
+        result
+    }
+
+    /// Build a flat grass strip on the road surface
+    fn build_grass(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        road_mark: &OdrRoadMark,
+        s_start: f64,
+        s_end: f64,
+    ) -> MeshData {
+        let width = road_mark.width.unwrap_or(DEFAULT_GRASS_WIDTH);
+        let edge_t = self.line_edge_t(road, section, lane, road_mark, 0.0, width);
+
+        self.build_strip(road, section, lane, road_mark, s_start, s_end, |s| {
+            let [t_inner, t_outer] = edge_t(s);
+            [
+                (t_inner, DEFAULT_LINE_HEIGHT),
+                (t_outer, DEFAULT_LINE_HEIGHT),
+            ]
+        })
+    }
+
+    /// Build raised botts dots as small cubes placed at a regular spacing
+    ///
+    /// Each dot is a cube without the bottom face; faces have their own
+    /// vertices so normals stay flat, and each face is UV-mapped to [0, 1].
+    fn build_botts_dots(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        road_mark: &OdrRoadMark,
+        s_start: f64,
+        s_end: f64,
+    ) -> MeshData {
+        let size = road_mark.width.unwrap_or(DEFAULT_BOTTS_DOT_SIZE);
+        let height = road_mark.height.unwrap_or(DEFAULT_BOTTS_DOT_HEIGHT);
+        let half = size / 2.0;
+        let edge_t = self.line_edge_t(road, section, lane, road_mark, 0.0, size);
 
         let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut uvs = Vec::new();
+        let mut attributes = self.attributes.begin(0);
+
+        let mut s_center = s_start + half;
+        while s_center + half <= s_end {
+            let [t_inner, t_outer] = edge_t(s_center);
+            let t_center = (t_inner + t_outer) / 2.0;
+
+            // Footprint corners (ds, dt), counter-clockwise seen from above
+            let footprint = [(-half, -half), (half, -half), (half, half), (-half, half)];
+            let corner = |(ds, dt): (f64, f64), top: bool| {
+                let (s, t) = (s_center + ds, t_center + dt);
+                let h = road.eval_shape(s, t) + if top { height } else { 0.0 };
+                (s, t, road.sth_to_xyz(s, t, h))
+            };
+
+            let mut faces = vec![footprint.map(|c| corner(c, true))];
+            for i in 0..4 {
+                let (a, b) = (footprint[i], footprint[(i + 1) % 4]);
+                faces.push([
+                    corner(a, false),
+                    corner(b, false),
+                    corner(b, true),
+                    corner(a, true),
+                ]);
+            }
+
+            for face in faces {
+                let base = (vertices.len() / 3) as u32;
+                for (s, t, p) in face {
+                    self.frame.push_point(&mut vertices, p);
+                    if !self.attributes.is_empty() {
+                        let sample = VertexSample { road, lane, s, t };
+                        self.attributes.push(&mut attributes, &sample);
+                    }
+                }
+                uvs.extend_from_slice(&[0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+                indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+            }
+
+            s_center += BOTTS_DOT_SPACING;
+        }
+
+        self.frame
+            .mesh(vertices, indices)
+            .with_uvs(uvs)
+            .with_attributes(attributes)
+    }
+
+    /// Lateral extent `[t_inner, t_outer]` of a line along the lane outer border
+    ///
+    /// Includes the road mark sway and the line `t_offset`, both measured away
+    /// from the reference line.
+    fn line_edge_t<'a>(
+        &'a self,
+        road: &'a OdrRoad,
+        section: &'a OdrLaneSection,
+        lane: &'a OdrLane,
+        road_mark: &'a OdrRoadMark,
+        t_offset: f64,
+        width: f64,
+    ) -> impl Fn(f64) -> [f64; 2] + 'a {
+        let half_width = width / 2.0;
 
         // Direction sign: left lanes (+), right lanes (-)
         let sign = if lane.id >= 0 { 1.0 } else { -1.0 };
 
-        for i in 0..num_samples {
-            let t = i as f64 / (num_samples - 1) as f64;
-            let s = s_start + t * length;
-
+        move |s: f64| {
             // ds relative to road mark start
             let ds = s - (section.s + road_mark.s_offset);
 
@@ -492,32 +987,91 @@ impl RoadMarkMeshBuilder {
             let t_center = t_base + sign * (sway_offset + t_offset);
 
             // Line inner/outer boundaries
-            let t_inner = t_center - sign * half_width;
-            let t_outer = t_center + sign * half_width;
-
-            // Calculate height: road mark height + shape offset
-            let h_inner = height + road.eval_shape(s, t_inner);
-            let h_outer = height + road.eval_shape(s, t_outer);
-
-            // Convert to 3D coordinates (line above road surface)
-            let inner_pt = road.sth_to_xyz(s, t_inner, h_inner);
-            let outer_pt = road.sth_to_xyz(s, t_outer, h_outer);
-
-            // Coordinate system conversion: OpenDRIVE -> WebGL
-            // Subtract center to solve large coordinate precision issues
-            vertices.push((inner_pt.x - self.center.x) as f32);
-            vertices.push((inner_pt.z - self.center.z) as f32);
-            vertices.push(-(inner_pt.y - self.center.y) as f32);
-
-            vertices.push((outer_pt.x - self.center.x) as f32);
-            vertices.push((outer_pt.z - self.center.z) as f32);
-            vertices.push(-(outer_pt.y - self.center.y) as f32);
+            [t_center - sign * half_width, t_center + sign * half_width]
+        }
+    }
+
+    /// Build a triangle strip between two edges running along s
+    ///
+    /// `edge(s)` returns `(t, h)` of both edges, where `h` is the height above
+    /// the road surface (lateral shape included).
+    #[allow(clippy::too_many_arguments)]
+    fn build_strip(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        lane: &OdrLane,
+        road_mark: &OdrRoadMark,
+        s_start: f64,
+        s_end: f64,
+        edge: impl Fn(f64) -> [(f64, f64); 2],
+    ) -> MeshData {
+        let edge_points = |s: f64| {
+            // Convert to 3D coordinates (edge above road surface)
+            edge(s).map(|(t, h)| road.sth_to_xyz(s, t, h + road.eval_shape(s, t)))
+        };
+
+        let samples = self.line_samples(road, section, road_mark, s_start, s_end, edge_points);
+        let num_samples = samples.len();
+
+        let mut vertices = Vec::new();
+        let mut uvs = Vec::with_capacity(num_samples * 4);
+        let mut attributes = self.attributes.begin(num_samples * 2);
+        for s in samples {
+            let [inner_pt, outer_pt] = edge_points(s);
+
+            // Convert to the output frame
+            self.frame.push_point(&mut vertices, inner_pt);
+            self.frame.push_point(&mut vertices, outer_pt);
+
+            // UV: u is the distance along s from the segment start (restarts for
+            // every dash), v goes from 0 at the inner edge to 1 at the outer edge
+            let u = (s - s_start) as f32;
+            uvs.extend_from_slice(&[u, 0.0, u, 1.0]);
+
+            if !self.attributes.is_empty() {
+                for (t, _) in edge(s) {
+                    let sample = VertexSample { road, lane, s, t };
+                    self.attributes.push(&mut attributes, &sample);
+                }
+            }
         }
 
         let indices = MeshData::generate_strip_indices(num_samples);
-        let normals = MeshData::calculate_normals(&vertices, &indices);
+        self.frame
+            .mesh(vertices, indices)
+            .with_uvs(uvs)
+            .with_attributes(attributes)
+    }
 
-        MeshData::new(vertices, indices, normals)
+    /// Sample positions along s for a line, adaptive when a tolerance is set
+    ///
+    /// `eval(s)` returns the points whose chordal error is checked.
+    fn line_samples<const N: usize>(
+        &self,
+        road: &OdrRoad,
+        section: &OdrLaneSection,
+        road_mark: &OdrRoadMark,
+        s_start: f64,
+        s_end: f64,
+        eval: impl Fn(f64) -> [Vec3; N],
+    ) -> Vec<f64> {
+        match self.tolerance {
+            Some(tolerance) => {
+                let mark_start = section.s + road_mark.s_offset;
+                let sway_breaks = road_mark.sways.iter().map(|sw| mark_start + sw.ds);
+                adaptive_samples(
+                    road,
+                    s_start,
+                    s_end,
+                    lane_width_breaks(section).chain(sway_breaks),
+                    tolerance,
+                    self.sample_step,
+                    eval,
+                )
+            }
+            None => uniform_samples(s_start, s_end, self.sample_step),
+        }
     }
 
     /// Get lane outer border t coordinate at given s
@@ -567,25 +1121,7 @@ impl RoadMarkMeshBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::odr::models::lane::lane_link::OdrLaneLink;
-
-    fn build_test_lane(id: i32, width: f64) -> OdrLane {
-        OdrLane {
-            id,
-            lane_type: "driving".into(),
-            level: false,
-            road_works: None,
-            link: OdrLaneLink::new(None, None),
-            width: vec![OdrLaneWidth::new(0.0, width, 0.0, 0.0, 0.0)],
-            border: Vec::new(),
-            height: Vec::new(),
-            speed: Vec::new(),
-            access: Vec::new(),
-            rule: Vec::new(),
-            material: Vec::new(),
-            road_marks: Vec::new(),
-        }
-    }
+    use crate::odr::test_support::{line_road, plain_lane};
 
     fn build_test_road_mark(mark_type: OdrRoadMarkType) -> OdrRoadMark {
         OdrRoadMark {
@@ -649,7 +1185,7 @@ mod tests {
     fn test_get_default_lines_for_solid() {
         let builder = RoadMarkMeshBuilder::new(None, None);
         let road_mark = build_test_road_mark(OdrRoadMarkType::Solid);
-        let lines = builder.get_default_lines_for_type(&road_mark);
+        let lines = builder.get_default_lines_for_type(&road_mark, &RoadMarkProfile::default());
 
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0].space, 0.0);
@@ -659,7 +1195,7 @@ mod tests {
     fn test_get_default_lines_for_broken() {
         let builder = RoadMarkMeshBuilder::new(None, None);
         let road_mark = build_test_road_mark(OdrRoadMarkType::Broken);
-        let lines = builder.get_default_lines_for_type(&road_mark);
+        let lines = builder.get_default_lines_for_type(&road_mark, &RoadMarkProfile::default());
 
         assert_eq!(lines.len(), 1);
         assert!(lines[0].space > 0.0);
@@ -670,7 +1206,7 @@ mod tests {
     fn test_get_default_lines_for_double() {
         let builder = RoadMarkMeshBuilder::new(None, None);
         let road_mark = build_test_road_mark(OdrRoadMarkType::SolidSolid);
-        let lines = builder.get_default_lines_for_type(&road_mark);
+        let lines = builder.get_default_lines_for_type(&road_mark, &RoadMarkProfile::default());
 
         assert_eq!(lines.len(), 2);
         // Two lines should be on opposite sides
@@ -681,7 +1217,7 @@ mod tests {
     fn test_get_default_lines_for_none() {
         let builder = RoadMarkMeshBuilder::new(None, None);
         let road_mark = build_test_road_mark(OdrRoadMarkType::None);
-        let lines = builder.get_default_lines_for_type(&road_mark);
+        let lines = builder.get_default_lines_for_type(&road_mark, &RoadMarkProfile::default());
 
         assert!(lines.is_empty());
     }
@@ -694,4 +1230,200 @@ mod tests {
         assert_eq!(indices.len(), 12);
         assert_eq!(&indices[0..6], &[0, 1, 2, 1, 3, 2]);
     }
+
+    /// Straight 20m road along x with a single right lane carrying `mark`
+    fn build_test_road(mark: OdrRoadMark) -> (OdrRoad, OdrLaneSection, OdrLane) {
+        let mut lane = plain_lane(-1, 3.5);
+        lane.road_marks.push(mark);
+        let section = OdrLaneSection::new(
+            0.0,
+            Vec::new(),
+            vec![lane.clone()],
+            plain_lane(0, 0.0),
+            None,
+        );
+        let road = line_road("1", 0.0, 0.0, 20.0, vec![section.clone()]);
+        (road, section, lane)
+    }
+
+    #[test]
+    fn test_broken_line_uvs_restart_per_dash() {
+        let (road, section, lane) = build_test_road(build_test_road_mark(OdrRoadMarkType::Broken));
+
+        let builder = RoadMarkMeshBuilder::new(Some(0.5), None);
+        let items = builder
+            .build_lane_road_marks(&road, &section, &lane, 0.0, 20.0)
+            .into_items();
+        let (mesh, _, _) = items.into_iter().next().unwrap().into_parts();
+
+        assert_eq!(mesh.uvs.len() / 2, mesh.vertices.len() / 3);
+        // Dashes at s = 0..3, 9..12 and 18..20, u restarts at 0 for each dash
+        let u: Vec<f32> = mesh.uvs.chunks_exact(2).map(|uv| uv[0]).collect();
+        assert_eq!(u.iter().filter(|&&u| u == 0.0).count(), 3 * 2);
+        assert!(u.iter().all(|&u| (0.0..=3.0).contains(&u)));
+        let v: Vec<f32> = mesh.uvs.chunks_exact(2).map(|uv| uv[1]).collect();
+        assert!(v.chunks_exact(2).all(|pair| pair == [0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_road_mark_kinds() {
+        let cases = [
+            (OdrRoadMarkType::Solid, RoadMarkKind::Paint),
+            (OdrRoadMarkType::Curb, RoadMarkKind::Curb),
+            (OdrRoadMarkType::Grass, RoadMarkKind::Grass),
+            (OdrRoadMarkType::BottsDots, RoadMarkKind::BottsDots),
+        ];
+        let builder = RoadMarkMeshBuilder::new(Some(0.5), None);
+        for (mark_type, kind) in cases {
+            let mut mark = build_test_road_mark(mark_type);
+            mark.color = OdrRoadMarkColor::Standard;
+            let (road, section, lane) = build_test_road(mark);
+            let items = builder
+                .build_lane_road_marks(&road, &section, &lane, 0.0, 20.0)
+                .into_items();
+
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].kind(), kind);
+            let green = matches!(items[0].color(), OdrRoadMarkColor::Green);
+            assert_eq!(green, kind == RoadMarkKind::Grass);
+        }
+    }
+
+    #[test]
+    fn test_curb_profile() {
+        let mut mark = build_test_road_mark(OdrRoadMarkType::Curb);
+        mark.width = Some(0.2);
+        mark.height = Some(0.12);
+        let (road, section, lane) = build_test_road(mark);
+
+        let builder = RoadMarkMeshBuilder::with_frame(Some(0.5), &MeshFrame::enu(None));
+        let items = builder
+            .build_lane_road_marks(&road, &section, &lane, 0.0, 20.0)
+            .into_items();
+        let (mesh, _, _) = items.into_iter().next().unwrap().into_parts();
+
+        // Inner wall, top and outer wall, 40 samples each
+        assert_eq!(mesh.vertices.len() / 3, 3 * 40 * 2);
+        let points: Vec<&[f32]> = mesh.vertices.chunks_exact(3).collect();
+        let (min_y, max_y) = points.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+            (lo.min(p[1]), hi.max(p[1]))
+        });
+        assert!((min_y + 3.6).abs() < 1e-5 && (max_y + 3.4).abs() < 1e-5);
+        let max_z = points.iter().map(|p| p[2]).fold(f32::MIN, f32::max);
+        assert!((max_z - 0.12).abs() < 1e-5);
+        assert!(points.iter().any(|p| p[2].abs() < 1e-6));
+        // Top face points up
+        let top = 40 * 2;
+        assert!(mesh.normals[top * 3 + 2].abs() > 0.99);
+    }
+
+    #[test]
+    fn test_botts_dots_spacing() {
+        let mut mark = build_test_road_mark(OdrRoadMarkType::BottsDots);
+        mark.width = None;
+        mark.height = None;
+        let (road, section, lane) = build_test_road(mark);
+
+        let builder = RoadMarkMeshBuilder::with_frame(Some(0.5), &MeshFrame::enu(None));
+        let items = builder
+            .build_lane_road_marks(&road, &section, &lane, 0.0, 2.0)
+            .into_items();
+        let (mesh, _, _) = items.into_iter().next().unwrap().into_parts();
+
+        // Dot centers at s = 0.05, 0.45, ..., 1.65, 20 vertices per dot
+        let dots = 5;
+        assert_eq!(mesh.vertices.len() / 3, dots * 20);
+        assert_eq!(mesh.indices.len(), dots * 30);
+        assert_eq!(mesh.uvs.len(), dots * 40);
+
+        let points: Vec<&[f32]> = mesh.vertices.chunks_exact(3).collect();
+        let max_z = points.iter().map(|p| p[2]).fold(f32::MIN, f32::max);
+        assert!((max_z - DEFAULT_BOTTS_DOT_HEIGHT as f32).abs() < 1e-6);
+        // First dot covers s = 0..0.1, consecutive dots are spaced evenly
+        let first_x: Vec<f32> = points[..4].iter().map(|p| p[0]).collect();
+        assert!(first_x.iter().all(|&x| (-1e-6..=0.1 + 1e-6).contains(&x)));
+        let second_x = points[20][0];
+        assert!((second_x - points[0][0] - BOTTS_DOT_SPACING as f32).abs() < 1e-5);
+        // Top face normals point up, side faces are horizontal
+        assert!(mesh.normals[2] > 0.99);
+        assert!(mesh.normals[4 * 3 + 2].abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_road_mark_polylines() {
+        let (road, _, lane) = build_test_road(build_test_road_mark(OdrRoadMarkType::Broken));
+        let builder = RoadMarkMeshBuilder::with_frame(Some(0.5), &MeshFrame::enu(None));
+
+        let polylines = builder
+            .build_lane_road_mark_polylines(&road, 0, &lane)
+            .into_items();
+        assert_eq!(polylines.len(), 1);
+        let polyline = &polylines[0];
+        assert_eq!((polyline.lane_id, polyline.section), (-1, 0));
+        assert!(matches!(
+            polyline.semantics.mark_type,
+            OdrRoadMarkType::Broken
+        ));
+        assert_eq!(polyline.width, 0.15);
+        assert_eq!(polyline.dashes, vec![0.0, 3.0, 9.0, 12.0, 18.0, 20.0]);
+        // Continuous over the whole mark, along the lane outer border
+        assert_eq!(polyline.vertices.len() / 3, polyline.s.len());
+        assert_eq!((polyline.s[0], *polyline.s.last().unwrap()), (0.0, 20.0));
+        assert!(
+            polyline
+                .vertices
+                .chunks_exact(3)
+                .all(|p| p[1] == -3.5 && p[2] == 0.0)
+        );
+
+        // Double line gives one polyline per line
+        let (road, _, lane) = build_test_road(build_test_road_mark(OdrRoadMarkType::SolidBroken));
+        let polylines = builder
+            .build_lane_road_mark_polylines(&road, 0, &lane)
+            .into_items();
+        assert_eq!(polylines.len(), 2);
+        assert_eq!(polylines[0].dashes, vec![0.0, 20.0]);
+        assert_eq!(polylines[1].dashes.len(), 6);
+
+        // Boundaries without paint keep their semantics
+        let (road, _, lane) = build_test_road(build_test_road_mark(OdrRoadMarkType::None));
+        let polylines = builder
+            .build_lane_road_mark_polylines(&road, 0, &lane)
+            .into_items();
+        assert_eq!(polylines.len(), 1);
+        assert!(polylines[0].dashes.is_empty());
+    }
+
+    #[test]
+    fn test_country_road_mark_profile() {
+        use crate::odr::models::road::road_type::OdrRoadType;
+
+        let mut mark = build_test_road_mark(OdrRoadMarkType::Broken);
+        mark.width = None;
+        let (mut road, _, lane) = build_test_road(mark);
+        road.road_types = vec![OdrRoadType::new(
+            0.0,
+            "motorway".to_string(),
+            Some("DE".to_string()),
+        )];
+
+        // Country of the road type selects the profile
+        let mut builder = RoadMarkMeshBuilder::new(Some(0.5), None);
+        let polylines = builder
+            .build_lane_road_mark_polylines(&road, 0, &lane)
+            .into_items();
+        assert_eq!(polylines[0].dashes, vec![0.0, 6.0, 18.0, 20.0]);
+        assert_eq!(polylines[0].width, 0.12);
+
+        // User override wins over the country
+        builder.set_profile(Some(RoadMarkProfile::china()));
+        let polylines = builder
+            .build_lane_road_mark_polylines(&road, 0, &lane)
+            .into_items();
+        assert_eq!(polylines[0].dashes, vec![0.0, 6.0, 15.0, 20.0]);
+        let items = builder
+            .build_lane_road_marks(&road, &road.lanes[0], &lane, 0.0, 20.0)
+            .into_items();
+        assert_eq!(items[0].rgb(), 0xffffff);
+    }
 }
@@ -0,0 +1,308 @@
+use wasm_bindgen::prelude::*;
+
+use crate::odr::models::{
+    enums::{OdrRoadMarkColor, OdrRoadMarkWeight},
+    lane::{
+        OdrLane,
+        lane_road_mark::{OdrRoadMark, OdrRoadMarkLaneChange, OdrRoadMarkRule, OdrRoadMarkType},
+        lane_section::OdrLaneSection,
+    },
+    road::OdrRoad,
+};
+
+/// Semantic attributes of a road mark (or of one of its lines)
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RoadMarkSemantics {
+    /// Road mark type keyword
+    #[wasm_bindgen(getter_with_clone, js_name = "markType")]
+    pub mark_type: OdrRoadMarkType,
+
+    /// Color, a `<type>` line color supersedes the road mark color
+    #[wasm_bindgen(getter_with_clone)]
+    pub color: OdrRoadMarkColor,
+
+    /// Weight of the road mark (bold/standard)
+    #[wasm_bindgen(getter_with_clone)]
+    pub weight: Option<OdrRoadMarkWeight>,
+
+    /// Width of the road mark (meters), None when not specified
+    pub width: Option<f64>,
+
+    /// Lane change permission across this boundary
+    #[wasm_bindgen(getter_with_clone, js_name = "laneChange")]
+    pub lane_change: Option<OdrRoadMarkLaneChange>,
+
+    /// Rule for passing the line from inside (lower to higher lane ID)
+    #[wasm_bindgen(getter_with_clone)]
+    pub rule: Option<OdrRoadMarkRule>,
+}
+
+impl RoadMarkSemantics {
+    /// Semantics of a whole road mark
+    ///
+    /// The rule is taken from the first `<type>` or `<explicit>` line that
+    /// defines one, since `<roadMark>` itself has no rule attribute.
+    pub fn from_road_mark(road_mark: &OdrRoadMark) -> Self {
+        let type_rules = road_mark
+            .type_detail
+            .iter()
+            .flat_map(|d| d.lines.iter().map(|l| l.rule.clone()));
+        let explicit_rules = road_mark
+            .explicit
+            .iter()
+            .flat_map(|e| e.lines.iter().map(|l| l.rule.clone()));
+
+        Self {
+            mark_type: road_mark.mark_type.clone(),
+            color: road_mark.color.clone(),
+            weight: road_mark.weight.clone(),
+            width: road_mark
+                .width
+                .or(road_mark.type_detail.as_ref().map(|d| d.width)),
+            lane_change: road_mark.lane_change.clone(),
+            rule: type_rules.chain(explicit_rules).flatten().next(),
+        }
+    }
+}
+
+/// A single road mark line as a polyline along its center
+///
+/// Vertices are sampled continuously over the whole line range, gaps included,
+/// and lie on the road surface. The visible parts are given by `dashes`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RoadMarkPolyline {
+    /// Road ID
+    #[wasm_bindgen(getter_with_clone, js_name = "roadId")]
+    pub road_id: String,
+
+    /// Index of the lane section within the road
+    pub section: usize,
+
+    /// ID of the lane owning the road mark (outer border of the lane)
+    #[wasm_bindgen(js_name = "laneId")]
+    pub lane_id: i32,
+
+    /// Semantics of this line
+    #[wasm_bindgen(getter_with_clone)]
+    pub semantics: RoadMarkSemantics,
+
+    /// Lateral offset of the line from the lane border (meters)
+    #[wasm_bindgen(js_name = "tOffset")]
+    pub t_offset: f64,
+
+    /// Width of the line (meters), 0 for boundaries without paint
+    pub width: f64,
+
+    /// Vertex positions in the output frame, flat `[x, y, z, ...]`
+    #[wasm_bindgen(getter_with_clone)]
+    pub vertices: Vec<f32>,
+
+    /// s-coordinate of each vertex
+    #[wasm_bindgen(getter_with_clone)]
+    pub s: Vec<f64>,
+
+    /// Visible intervals as flat `[s_start, s_end, ...]` pairs, empty when
+    /// nothing is painted
+    #[wasm_bindgen(getter_with_clone)]
+    pub dashes: Vec<f64>,
+}
+
+/// List of road mark polylines
+///
+/// Array-like JS access, like `RoadMarkMeshList`.
+#[wasm_bindgen]
+pub struct RoadMarkPolylineList {
+    items: Vec<RoadMarkPolyline>,
+}
+
+#[wasm_bindgen]
+impl RoadMarkPolylineList {
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<RoadMarkPolyline> {
+        self.items.get(index).cloned()
+    }
+}
+
+impl RoadMarkPolylineList {
+    pub(crate) fn new(items: Vec<RoadMarkPolyline>) -> Self {
+        Self { items }
+    }
+
+    pub(crate) fn into_items(self) -> Vec<RoadMarkPolyline> {
+        self.items
+    }
+}
+
+/// Road mark semantics on both sides of a lane at a given s
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct LaneBoundarySemantics {
+    /// Left boundary (towards higher lane IDs)
+    #[wasm_bindgen(getter_with_clone)]
+    pub left: Option<RoadMarkSemantics>,
+
+    /// Right boundary (towards lower lane IDs)
+    #[wasm_bindgen(getter_with_clone)]
+    pub right: Option<RoadMarkSemantics>,
+}
+
+/// Get the road mark semantics of both boundaries of a lane at `s`
+///
+/// A road mark describes the outer border of its lane, so the inner boundary
+/// comes from the neighbouring lane towards the center (or the center lane).
+/// Boundaries without a road mark are undefined. Returns undefined if the lane
+/// does not exist at `s`.
+#[wasm_bindgen(js_name = "laneBoundarySemantics")]
+pub fn lane_boundary_semantics(
+    road: &OdrRoad,
+    lane_id: i32,
+    s: f64,
+) -> Option<LaneBoundarySemantics> {
+    let section = road
+        .lanes
+        .iter()
+        .rev()
+        .find(|l| l.s <= s)
+        .or(road.lanes.first())?;
+
+    let (left_id, right_id) = match lane_id {
+        0 => (0, 0),
+        id if id > 0 => (id, id - 1),
+        id => (id + 1, id),
+    };
+    section.find_lane(lane_id)?;
+
+    let semantics = |id: i32| {
+        let lane = section.find_lane(id)?;
+        active_road_mark(section, lane, s).map(RoadMarkSemantics::from_road_mark)
+    };
+    Some(LaneBoundarySemantics {
+        left: semantics(left_id),
+        right: semantics(right_id),
+    })
+}
+
+/// Road mark of a lane that is in effect at `s`
+///
+/// `None` before the `sOffset` of the first road mark.
+pub(crate) fn active_road_mark<'a>(
+    section: &OdrLaneSection,
+    lane: &'a OdrLane,
+    s: f64,
+) -> Option<&'a OdrRoadMark> {
+    lane.road_marks
+        .iter()
+        .rev()
+        .find(|m| section.s + m.s_offset <= s)
+}
+
+/// Visible intervals of a repeating `length`/`space` pattern over
+/// `[s_start, s_end]`, flattened as `[start, end, ...]`
+///
+/// A non-positive length or space means a solid line.
+pub(crate) fn dash_intervals(s_start: f64, s_end: f64, length: f64, space: f64) -> Vec<f64> {
+    if s_end <= s_start {
+        return Vec::new();
+    }
+    if length <= 0.0 || space <= 0.0 {
+        return vec![s_start, s_end];
+    }
+
+    let mut dashes = Vec::new();
+    let mut s = s_start;
+    while s < s_end {
+        let dash_end = (s + length).min(s_end);
+        dashes.extend_from_slice(&[s, dash_end]);
+        s += length + space;
+    }
+    dashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::test_support::{self, line_road};
+
+    fn lane(id: i32, mark_type: OdrRoadMarkType, lane_change: OdrRoadMarkLaneChange) -> OdrLane {
+        let mut lane = test_support::lane(id, 3.5, mark_type);
+        lane.road_marks[0].width = Some(0.15);
+        lane.road_marks[0].lane_change = Some(lane_change);
+        lane
+    }
+
+    fn road() -> OdrRoad {
+        let mut center = lane(0, OdrRoadMarkType::SolidSolid, OdrRoadMarkLaneChange::None);
+        center.road_marks.push(OdrRoadMark {
+            s_offset: 10.0,
+            mark_type: OdrRoadMarkType::Broken,
+            lane_change: Some(OdrRoadMarkLaneChange::Both),
+            ..center.road_marks[0].clone()
+        });
+        let section = OdrLaneSection::new(
+            0.0,
+            vec![lane(1, OdrRoadMarkType::Solid, OdrRoadMarkLaneChange::None)],
+            vec![
+                lane(-1, OdrRoadMarkType::Broken, OdrRoadMarkLaneChange::Both),
+                lane(-2, OdrRoadMarkType::Solid, OdrRoadMarkLaneChange::None),
+            ],
+            center,
+            None,
+        );
+        line_road("1", 0.0, 0.0, 20.0, vec![section])
+    }
+
+    #[test]
+    fn test_lane_boundary_semantics() {
+        let road = road();
+
+        let b = lane_boundary_semantics(&road, -2, 5.0).unwrap();
+        assert!(matches!(b.left.unwrap().mark_type, OdrRoadMarkType::Broken));
+        assert!(matches!(b.right.unwrap().mark_type, OdrRoadMarkType::Solid));
+
+        // Inner boundary of -1 and 1 is the center lane mark, which changes at s = 10
+        let b = lane_boundary_semantics(&road, -1, 5.0).unwrap();
+        let left = b.left.unwrap();
+        assert!(matches!(left.mark_type, OdrRoadMarkType::SolidSolid));
+        assert!(matches!(
+            left.lane_change,
+            Some(OdrRoadMarkLaneChange::None)
+        ));
+        let b = lane_boundary_semantics(&road, 1, 15.0).unwrap();
+        assert!(matches!(
+            b.right.unwrap().mark_type,
+            OdrRoadMarkType::Broken
+        ));
+        assert!(matches!(b.left.unwrap().mark_type, OdrRoadMarkType::Solid));
+
+        assert!(lane_boundary_semantics(&road, -3, 5.0).is_none());
+
+        // No mark applies before the sOffset of the first road mark
+        let mut road = road;
+        road.lanes[0].right[1].road_marks[0].s_offset = 5.0;
+        let b = lane_boundary_semantics(&road, -2, 2.0).unwrap();
+        assert!(b.left.is_some() && b.right.is_none());
+        assert!(
+            lane_boundary_semantics(&road, -2, 5.0)
+                .unwrap()
+                .right
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_dash_intervals() {
+        assert_eq!(dash_intervals(0.0, 10.0, 0.0, 0.0), vec![0.0, 10.0]);
+        assert_eq!(
+            dash_intervals(0.0, 10.0, 3.0, 3.0),
+            vec![0.0, 3.0, 6.0, 9.0]
+        );
+        assert_eq!(dash_intervals(0.0, 7.0, 3.0, 3.0), vec![0.0, 3.0, 6.0, 7.0]);
+        assert!(dash_intervals(5.0, 5.0, 3.0, 3.0).is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+use wasm_bindgen::prelude::*;
+
+use crate::odr::models::{
+    enums::{OdrRoadMarkColor, OdrRoadMarkWeight},
+    road::OdrRoad,
+};
+
+/// Country-specific defaults for rendering road marks
+///
+/// Used wherever a road mark leaves a value unspecified: keyword types
+/// (`broken`, `solid solid`, ...) take their dash pattern and double line gap
+/// from here, and marks without `width` get the width of their weight.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoadMarkProfile {
+    /// Visible length of broken lines (meters)
+    #[wasm_bindgen(js_name = "brokenLength")]
+    pub broken_length: f64,
+
+    /// Gap between the dashes of broken lines (meters)
+    #[wasm_bindgen(js_name = "brokenSpace")]
+    pub broken_space: f64,
+
+    /// Line width for standard weight (meters)
+    #[wasm_bindgen(js_name = "standardWidth")]
+    pub standard_width: f64,
+
+    /// Line width for bold weight (meters)
+    #[wasm_bindgen(js_name = "boldWidth")]
+    pub bold_width: f64,
+
+    /// Gap between the two lines of double road marks (meters)
+    #[wasm_bindgen(js_name = "doubleLineGap")]
+    pub double_line_gap: f64,
+
+    /// sRGB colors indexed by `color_index`
+    colors: [u32; 9],
+}
+
+impl Default for RoadMarkProfile {
+    fn default() -> Self {
+        Self {
+            broken_length: 3.0,
+            broken_space: 6.0,
+            standard_width: 0.15,
+            bold_width: 0.3,
+            double_line_gap: 0.1,
+            colors: [
+                0xffffff, // standard
+                0xffffff, // white
+                0xffcc00, // yellow
+                0x0066cc, // blue
+                0x00aa00, // green
+                0xcc0000, // red
+                0xff8800, // orange
+                0x8800cc, // violet
+                0x222222, // black
+            ],
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl RoadMarkProfile {
+    /// Generic profile, used for unknown countries
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Germany (RMS): 6 m dashes with 12 m gaps, 0.12 m lines, 0.25 m bold
+    pub fn germany() -> Self {
+        let mut profile = Self {
+            broken_length: 6.0,
+            broken_space: 12.0,
+            standard_width: 0.12,
+            bold_width: 0.25,
+            double_line_gap: 0.12,
+            ..Self::default()
+        };
+        // Temporary markings, RAL 1023 traffic yellow
+        profile.set_color_rgb(OdrRoadMarkColor::Yellow, 0xf7b500);
+        profile
+    }
+
+    /// United States (MUTCD): 10 ft dashes with 30 ft gaps, 4 in lines, 8 in wide lines
+    pub fn usa() -> Self {
+        let mut profile = Self {
+            broken_length: 3.048,
+            broken_space: 9.144,
+            standard_width: 0.1016,
+            bold_width: 0.2032,
+            double_line_gap: 0.1016,
+            ..Self::default()
+        };
+        profile.set_color_rgb(OdrRoadMarkColor::Yellow, 0xfcb514);
+        profile
+    }
+
+    /// China (GB 5768): 6 m dashes with 9 m gaps, 0.15 m lines, 0.45 m bold
+    pub fn china() -> Self {
+        let mut profile = Self {
+            broken_length: 6.0,
+            broken_space: 9.0,
+            standard_width: 0.15,
+            bold_width: 0.45,
+            double_line_gap: 0.15,
+            ..Self::default()
+        };
+        profile.set_color_rgb(OdrRoadMarkColor::Yellow, 0xffb400);
+        profile
+    }
+
+    /// Profile for an OpenDRIVE country code
+    ///
+    /// Accepts ISO 3166-1 alpha-2/alpha-3 codes and the country names of
+    /// OpenDRIVE 1.4 and earlier, case-insensitive. Unknown codes get the
+    /// generic profile.
+    #[wasm_bindgen(js_name = "forCountry")]
+    pub fn for_country(country: &str) -> Self {
+        match country.to_ascii_lowercase().as_str() {
+            "de" | "deu" | "germany" => Self::germany(),
+            "us" | "usa" => Self::usa(),
+            "cn" | "chn" | "china" => Self::china(),
+            _ => Self::default(),
+        }
+    }
+
+    /// Line width for a road mark weight, standard when not specified
+    #[wasm_bindgen(js_name = "lineWidth")]
+    pub fn line_width(&self, weight: Option<OdrRoadMarkWeight>) -> f64 {
+        match weight {
+            Some(OdrRoadMarkWeight::Bold) => self.bold_width,
+            _ => self.standard_width,
+        }
+    }
+
+    /// sRGB color of a road mark color as `0xRRGGBB`
+    #[wasm_bindgen(js_name = "colorRgb")]
+    pub fn color_rgb(&self, color: OdrRoadMarkColor) -> u32 {
+        self.colors[color_index(&color)]
+    }
+
+    /// Override the sRGB color of a road mark color
+    #[wasm_bindgen(js_name = "setColorRgb")]
+    pub fn set_color_rgb(&mut self, color: OdrRoadMarkColor, rgb: u32) {
+        self.colors[color_index(&color)] = rgb;
+    }
+}
+
+impl RoadMarkProfile {
+    /// Profile for the country of the road type in effect at `s`
+    pub fn for_road(road: &OdrRoad, s: f64) -> Self {
+        road.road_types
+            .iter()
+            .rev()
+            .find(|t| t.s <= s)
+            .or(road.road_types.first())
+            .and_then(|t| t.country.as_deref())
+            .map(Self::for_country)
+            .unwrap_or_default()
+    }
+}
+
+fn color_index(color: &OdrRoadMarkColor) -> usize {
+    match color {
+        OdrRoadMarkColor::White => 1,
+        OdrRoadMarkColor::Yellow => 2,
+        OdrRoadMarkColor::Blue => 3,
+        OdrRoadMarkColor::Green => 4,
+        OdrRoadMarkColor::Red => 5,
+        OdrRoadMarkColor::Orange => 6,
+        OdrRoadMarkColor::Violet => 7,
+        OdrRoadMarkColor::Black => 8,
+        // Standard and the wasm-bindgen generated invalid variant
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{models::road::road_type::OdrRoadType, test_support::line_road};
+
+    #[test]
+    fn test_for_country() {
+        assert_eq!(
+            RoadMarkProfile::for_country("DE"),
+            RoadMarkProfile::germany()
+        );
+        assert_eq!(
+            RoadMarkProfile::for_country("Germany"),
+            RoadMarkProfile::germany()
+        );
+        assert_eq!(RoadMarkProfile::for_country("usa"), RoadMarkProfile::usa());
+        assert_eq!(
+            RoadMarkProfile::for_country("CHN"),
+            RoadMarkProfile::china()
+        );
+        assert_eq!(
+            RoadMarkProfile::for_country("OpenDRIVE"),
+            RoadMarkProfile::default()
+        );
+    }
+
+    #[test]
+    fn test_line_width_and_colors() {
+        let mut profile = RoadMarkProfile::usa();
+        assert_eq!(profile.line_width(None), 0.1016);
+        assert_eq!(profile.line_width(Some(OdrRoadMarkWeight::Bold)), 0.2032);
+
+        assert_eq!(profile.color_rgb(OdrRoadMarkColor::Standard), 0xffffff);
+        profile.set_color_rgb(OdrRoadMarkColor::Standard, 0xeeeeee);
+        assert_eq!(profile.color_rgb(OdrRoadMarkColor::Standard), 0xeeeeee);
+        assert_eq!(profile.color_rgb(OdrRoadMarkColor::White), 0xffffff);
+    }
+
+    #[test]
+    fn test_for_road() {
+        let mut road = line_road("1", 0.0, 0.0, 100.0, Vec::new());
+        assert_eq!(
+            RoadMarkProfile::for_road(&road, 10.0),
+            RoadMarkProfile::default()
+        );
+
+        road.road_types = vec![
+            OdrRoadType::new(0.0, "town".to_string(), Some("DE".to_string())),
+            OdrRoadType::new(50.0, "motorway".to_string(), Some("CN".to_string())),
+        ];
+        assert_eq!(
+            RoadMarkProfile::for_road(&road, 10.0),
+            RoadMarkProfile::germany()
+        );
+        assert_eq!(
+            RoadMarkProfile::for_road(&road, 60.0),
+            RoadMarkProfile::china()
+        );
+    }
+}
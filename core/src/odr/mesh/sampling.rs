@@ -0,0 +1,234 @@
+use std::f64::consts::PI;
+
+use crate::{
+    math::vec3::Vec3,
+    odr::models::{lane::lane_section::OdrLaneSection, road::OdrRoad},
+};
+
+/// 自适应采样时相邻采样点的最大间距（米）
+const MAX_STEP: f64 = 50.0;
+
+/// 估计曲率时每个区间内的参考线采样数
+const CURVATURE_PROBES: usize = 8;
+
+/// 沿 s 均匀采样 [s_start, s_end]，采样点数为 `ceil(length / step)`（至少 2 个）
+pub fn uniform_samples(s_start: f64, s_end: f64, step: f64) -> Vec<f64> {
+    let length = s_end - s_start;
+    let num_samples = ((length / step).ceil() as usize).max(2);
+    (0..num_samples)
+        .map(|i| s_start + length * i as f64 / (num_samples - 1) as f64)
+        .collect()
+}
+
+/// 车道段内所有车道宽度分段的起点（全局 s）
+pub fn lane_width_breaks(section: &OdrLaneSection) -> impl Iterator<Item = f64> + '_ {
+    section
+        .left
+        .iter()
+        .chain(section.right.iter())
+        .flat_map(|lane| lane.width.iter())
+        .map(move |w| section.s + w.s_offset)
+}
+
+/// 按弦高误差自适应采样 [s_start, s_end]
+///
+/// `eval` 返回 s 处需要保持精度的若干点（如车道内外边界），任意相邻采样点之间
+/// 这些点的中点偏离弦线的距离不超过 `tolerance`。道路平面线形、高程、超高、
+/// 横断面和车道偏移的分段起点，以及 `breaks` 中的位置（如车道宽度、标线分段起点）
+/// 总会被采样。每个分段先按参考线曲率估计初始间距，再二分细化，
+/// 间距不小于 `min_step`、不大于 50 米。
+pub fn adaptive_samples<const N: usize>(
+    road: &OdrRoad,
+    s_start: f64,
+    s_end: f64,
+    breaks: impl IntoIterator<Item = f64>,
+    tolerance: f64,
+    min_step: f64,
+    eval: impl Fn(f64) -> [Vec3; N],
+) -> Vec<f64> {
+    let mut knots: Vec<f64> = road
+        .plan_view
+        .iter()
+        .map(|g| g.s)
+        .chain(road.elevations.iter().map(|e| e.s))
+        .chain(road.superelevations.iter().map(|e| e.s))
+        .chain(road.shapes.iter().map(|e| e.s))
+        .chain(road.lane_offsets.iter().map(|o| o.s))
+        .chain(breaks)
+        .filter(|&s| s > s_start + min_step && s < s_end - min_step)
+        .collect();
+    knots.push(s_start);
+    knots.push(s_end);
+    knots.sort_by(f64::total_cmp);
+    knots.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+    let mut samples = vec![s_start];
+    let mut prev = eval(s_start);
+    for pair in knots.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let step = curvature_step(road, a, b, tolerance).min(MAX_STEP);
+        let n = ((b - a) / step).ceil().max(1.0) as usize;
+        for i in 1..=n {
+            let s1 = a + (b - a) * i as f64 / n as f64;
+            let s0 = *samples.last().unwrap();
+            let next = eval(s1);
+            refine(
+                s0,
+                &prev,
+                s1,
+                &next,
+                tolerance,
+                min_step,
+                &eval,
+                &mut samples,
+            );
+            samples.push(s1);
+            prev = next;
+        }
+    }
+    samples
+}
+
+/// 在 (s0, s1) 之间按需插入采样点（不含两端）
+#[allow(clippy::too_many_arguments)]
+fn refine<const N: usize>(
+    s0: f64,
+    p0: &[Vec3; N],
+    s1: f64,
+    p1: &[Vec3; N],
+    tolerance: f64,
+    min_step: f64,
+    eval: &impl Fn(f64) -> [Vec3; N],
+    samples: &mut Vec<f64>,
+) {
+    if s1 - s0 < 2.0 * min_step {
+        return;
+    }
+    let sm = (s0 + s1) / 2.0;
+    let pm = eval(sm);
+    let error = (0..N)
+        .map(|i| distance_to_segment(pm[i], p0[i], p1[i]))
+        .fold(0.0, f64::max);
+    if error <= tolerance {
+        return;
+    }
+    refine(s0, p0, sm, &pm, tolerance, min_step, eval, samples);
+    samples.push(sm);
+    refine(sm, &pm, s1, p1, tolerance, min_step, eval, samples);
+}
+
+/// 由参考线最大曲率估计满足弦高误差的间距：弦高 ≈ L²κ/8
+fn curvature_step(road: &OdrRoad, a: f64, b: f64, tolerance: f64) -> f64 {
+    if road.plan_view.is_empty() {
+        return f64::INFINITY;
+    }
+    let ds = (b - a) / CURVATURE_PROBES as f64;
+    let mut max_curvature: f64 = 0.0;
+    let mut prev_hdg = road.eval_reference_line(a).hdg;
+    for i in 1..=CURVATURE_PROBES {
+        let hdg = road.eval_reference_line(a + ds * i as f64).hdg;
+        let mut delta = (hdg - prev_hdg) % (2.0 * PI);
+        if delta > PI {
+            delta -= 2.0 * PI;
+        } else if delta < -PI {
+            delta += 2.0 * PI;
+        }
+        max_curvature = max_curvature.max(delta.abs() / ds);
+        prev_hdg = hdg;
+    }
+
+    if max_curvature < 1e-12 {
+        f64::INFINITY
+    } else {
+        (8.0 * tolerance / max_curvature).sqrt()
+    }
+}
+
+fn distance_to_segment(p: Vec3, a: Vec3, b: Vec3) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.dot(&ab);
+    if len_sq < 1e-18 {
+        return (p - a).length();
+    }
+    let t = ((p - a).dot(&ab) / len_sq).clamp(0.0, 1.0);
+    (p - (a + ab * t)).length()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        models::road::{road_elevation::OdrRoadElevation, road_geometry::OdrRoadGeometry},
+        test_support,
+    };
+
+    fn road(geometry: OdrRoadGeometry, elevations: Vec<OdrRoadElevation>) -> OdrRoad {
+        OdrRoad {
+            elevations,
+            ..test_support::road("1", geometry, Vec::new())
+        }
+    }
+
+    fn edges(road: &OdrRoad) -> impl Fn(f64) -> [Vec3; 2] + '_ {
+        |s| [road.sth_to_xyz(s, 0.0, 0.0), road.sth_to_xyz(s, -3.5, 0.0)]
+    }
+
+    #[test]
+    fn test_uniform_samples() {
+        assert_eq!(uniform_samples(0.0, 10.0, 5.0), vec![0.0, 10.0]);
+        assert_eq!(uniform_samples(2.0, 2.0, 1.0), vec![2.0, 2.0]);
+        assert_eq!(uniform_samples(0.0, 10.0, 2.5).len(), 4);
+    }
+
+    #[test]
+    fn test_straight_road_uses_few_samples() {
+        let road = road(
+            OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 2000.0),
+            Vec::new(),
+        );
+        let samples = adaptive_samples(&road, 0.0, 2000.0, [700.0], 0.01, 0.1, edges(&road));
+
+        assert_eq!(samples.first(), Some(&0.0));
+        assert_eq!(samples.last(), Some(&2000.0));
+        assert!(samples.contains(&700.0));
+        assert!(samples.len() <= 2000 / 50 + 2, "{}", samples.len());
+    }
+
+    #[test]
+    fn test_arc_meets_tolerance() {
+        let tolerance = 0.01;
+        let road = road(
+            OdrRoadGeometry::create_arc(0.0, 0.0, 0.0, 0.0, 100.0, 0.05),
+            Vec::new(),
+        );
+        let eval = edges(&road);
+        let samples = adaptive_samples(&road, 0.0, 100.0, [], tolerance, 0.1, &eval);
+
+        assert!(samples.windows(2).all(|w| w[1] > w[0]));
+        assert!(samples.len() > 20);
+        for w in samples.windows(2) {
+            let (p0, p1) = (eval(w[0]), eval(w[1]));
+            for k in 1..4 {
+                let pm = eval(w[0] + (w[1] - w[0]) * k as f64 / 4.0);
+                for i in 0..2 {
+                    assert!(distance_to_segment(pm[i], p0[i], p1[i]) <= tolerance * 1.01);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_elevation_change_refines() {
+        let flat = road(
+            OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 200.0),
+            Vec::new(),
+        );
+        let hill = road(
+            OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 200.0),
+            vec![OdrRoadElevation::new(0.0, 0.0, 0.0, 0.001, 0.0)],
+        );
+        let flat_samples = adaptive_samples(&flat, 0.0, 200.0, [], 0.01, 0.1, edges(&flat));
+        let hill_samples = adaptive_samples(&hill, 0.0, 200.0, [], 0.01, 0.1, edges(&hill));
+        assert!(hill_samples.len() > flat_samples.len() * 2);
+    }
+}
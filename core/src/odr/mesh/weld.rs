@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use crate::{
+    math::mesh::MeshData,
+    odr::models::{
+        enums::OdrContactPoint,
+        road::{
+            OdrRoad,
+            road_link::{OdrRoadLink, OdrRoadLinkElementType},
+        },
+    },
+};
+
+/// 车道面网格首尾所在的接缝
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Seam {
+    /// 道路起点
+    RoadStart(String),
+    /// 道路终点
+    RoadEnd(String),
+    /// 道路内第 i 个车道段的起点（i > 0）
+    Section(String, usize),
+}
+
+impl Seam {
+    fn contact(road_id: &str, contact_point: &OdrContactPoint) -> Option<Self> {
+        match contact_point {
+            OdrContactPoint::Start => Some(Seam::RoadStart(road_id.to_string())),
+            OdrContactPoint::End => Some(Seam::RoadEnd(road_id.to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// 接缝焊接器
+///
+/// 收集按三角形带生成的车道面网格，将同一接缝（车道段边界、或按 `OdrRoadLink`
+/// 接触点相连的道路首尾）上距离不超过容差的边界顶点合并到同一位置，
+/// 并对这些顶点的法线取平均，消除裂缝和明暗接缝。
+///
+/// 只移动顶点位置、修改法线，不改变顶点数量和索引，UV 和顶点属性保持不变。
+pub struct SurfaceWelder {
+    /// 焊接距离（输出坐标系单位），为 None 时不焊接
+    tolerance: Option<f64>,
+    meshes: Vec<MeshData>,
+    /// 每个网格起点、终点所在的接缝
+    seams: Vec<Option<[Seam; 2]>>,
+    /// 道路连接：两端接缝视为同一接缝
+    links: Vec<(Seam, Seam)>,
+}
+
+impl SurfaceWelder {
+    pub fn new(tolerance: Option<f64>) -> Self {
+        Self {
+            tolerance,
+            meshes: Vec::new(),
+            seams: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    /// 登记道路的前驱、后继连接（仅道路到道路的连接，需有接触点）
+    pub fn link_road(&mut self, road: &OdrRoad) {
+        let ends = [
+            (&road.predecessor, Seam::RoadStart(road.id.clone())),
+            (&road.successor, Seam::RoadEnd(road.id.clone())),
+        ];
+        for (link, seam) in ends {
+            if let Some(OdrRoadLink {
+                element_id,
+                element_type: OdrRoadLinkElementType::Road,
+                contact_point: Some(contact_point),
+                ..
+            }) = link
+                && let Some(other) = Seam::contact(element_id, contact_point)
+            {
+                self.links.push((seam, other));
+            }
+        }
+    }
+
+    /// 添加一个车道面网格（三角形带，首尾各两个顶点为边界）
+    ///
+    /// # 参数
+    /// - `road`: 车道所在道路
+    /// - `section_idx`: 车道段在道路中的序号
+    /// - `mesh`: 车道面网格
+    pub fn push(&mut self, road: &OdrRoad, section_idx: usize, mesh: MeshData) {
+        let seams = (mesh.vertices.len() >= 12).then(|| {
+            let start = if section_idx == 0 {
+                Seam::RoadStart(road.id.clone())
+            } else {
+                Seam::Section(road.id.clone(), section_idx)
+            };
+            let end = if section_idx + 1 >= road.lanes.len() {
+                Seam::RoadEnd(road.id.clone())
+            } else {
+                Seam::Section(road.id.clone(), section_idx + 1)
+            };
+            [start, end]
+        });
+        self.meshes.push(mesh);
+        self.seams.push(seams);
+    }
+
+    /// 焊接所有接缝，按添加顺序返回网格
+    pub fn finish(mut self) -> Vec<MeshData> {
+        let Some(tolerance) = self.tolerance else {
+            return self.meshes;
+        };
+
+        // 按连接关系合并接缝（并查集）
+        let mut ids: HashMap<Seam, usize> = HashMap::new();
+        let mut parent: Vec<usize> = Vec::new();
+        let mut id_of = |seam: &Seam, parent: &mut Vec<usize>| {
+            *ids.entry(seam.clone()).or_insert_with(|| {
+                parent.push(parent.len());
+                parent.len() - 1
+            })
+        };
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        for (a, b) in &self.links {
+            let a = id_of(a, &mut parent);
+            let b = id_of(b, &mut parent);
+            let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+            parent[ra] = rb;
+        }
+
+        // 收集每个接缝上的边界顶点 (网格序号, 顶点序号)
+        let mut groups: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        for (mesh_idx, seams) in self.seams.iter().enumerate() {
+            let Some(seams) = seams else {
+                continue;
+            };
+            let vertex_count = self.meshes[mesh_idx].vertices.len() / 3;
+            let ends = [[0, 1], [vertex_count - 2, vertex_count - 1]];
+            for (seam, vertices) in seams.iter().zip(ends) {
+                let id = id_of(seam, &mut parent);
+                let group = groups.entry(root(&mut parent, id)).or_default();
+                group.extend(vertices.iter().map(|&v| (mesh_idx, v)));
+            }
+        }
+
+        for group in groups.values() {
+            for cluster in self.clusters(group, tolerance) {
+                self.weld_cluster(&cluster);
+            }
+        }
+
+        self.meshes
+    }
+
+    /// 将接缝上的顶点按距离聚类
+    fn clusters(&self, group: &[(usize, usize)], tolerance: f64) -> Vec<Vec<(usize, usize)>> {
+        let mut clusters: Vec<Vec<(usize, usize)>> = Vec::new();
+        for &vertex in group {
+            let p = self.position(vertex);
+            let found = clusters.iter_mut().find(|c| {
+                let q = self.position(c[0]);
+                (0..3).map(|i| (p[i] - q[i]).powi(2)).sum::<f32>().sqrt() as f64 <= tolerance
+            });
+            match found {
+                Some(cluster) => cluster.push(vertex),
+                None => clusters.push(vec![vertex]),
+            }
+        }
+        clusters.retain(|c| c.len() > 1);
+        clusters
+    }
+
+    /// 合并一组顶点：位置取平均，法线按朝向取平均
+    ///
+    /// 左右车道的三角形绕序相反，法线可能朝向相反，因此以第一个顶点的法线为参考
+    /// 统一朝向后再平均，并按各自原有朝向写回。
+    fn weld_cluster(&mut self, cluster: &[(usize, usize)]) {
+        let mut position = [0.0f32; 3];
+        let mut normal = [0.0f32; 3];
+        let reference = self.normal(cluster[0]);
+        let signs: Vec<f32> = cluster
+            .iter()
+            .map(|&v| {
+                let n = self.normal(v);
+                let dot: f32 = (0..3).map(|i| n[i] * reference[i]).sum();
+                if dot < 0.0 { -1.0 } else { 1.0 }
+            })
+            .collect();
+        for (&v, sign) in cluster.iter().zip(&signs) {
+            let (p, n) = (self.position(v), self.normal(v));
+            for i in 0..3 {
+                position[i] += p[i] / cluster.len() as f32;
+                normal[i] += n[i] * sign;
+            }
+        }
+        let length = normal.iter().map(|c| c * c).sum::<f32>().sqrt();
+
+        for (&(mesh_idx, v), sign) in cluster.iter().zip(&signs) {
+            let mesh = &mut self.meshes[mesh_idx];
+            mesh.vertices[v * 3..v * 3 + 3].copy_from_slice(&position);
+            if length > 1e-6 {
+                for (target, c) in mesh.normals[v * 3..v * 3 + 3].iter_mut().zip(normal) {
+                    *target = c / length * sign;
+                }
+            }
+        }
+    }
+
+    fn position(&self, (mesh_idx, v): (usize, usize)) -> [f32; 3] {
+        let p = &self.meshes[mesh_idx].vertices[v * 3..v * 3 + 3];
+        [p[0], p[1], p[2]]
+    }
+
+    fn normal(&self, (mesh_idx, v): (usize, usize)) -> [f32; 3] {
+        let n = &self.meshes[mesh_idx].normals[v * 3..v * 3 + 3];
+        [n[0], n[1], n[2]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odr::{
+        mesh::lane_builder::LaneMeshBuilder,
+        models::{lane::lane_section::OdrLaneSection, road::road_geometry::OdrRoadGeometry},
+        test_support::{self, plain_lane, road_link},
+    };
+
+    fn section(s: f64, width: f64) -> OdrLaneSection {
+        OdrLaneSection::new(
+            s,
+            vec![plain_lane(1, width)],
+            vec![plain_lane(-1, width)],
+            plain_lane(0, 0.0),
+            None,
+        )
+    }
+
+    fn road(
+        id: &str,
+        geometry: OdrRoadGeometry,
+        lanes: Vec<OdrLaneSection>,
+        successor: Option<OdrRoadLink>,
+    ) -> OdrRoad {
+        OdrRoad {
+            successor,
+            ..test_support::road(id, geometry, lanes)
+        }
+    }
+
+    fn build(builder: &LaneMeshBuilder, roads: &[OdrRoad]) -> Vec<MeshData> {
+        let mut welder = builder.welder();
+        for road in roads {
+            welder.link_road(road);
+            for (section_idx, section) in road.lanes.iter().enumerate() {
+                let s_end = road
+                    .lanes
+                    .get(section_idx + 1)
+                    .map_or(road.length, |next| next.s);
+                for lane in section.left.iter().chain(section.right.iter()) {
+                    let mesh = builder.build_lane_mesh(road, section, lane, section.s, s_end);
+                    welder.push(road, section_idx, mesh);
+                }
+            }
+        }
+        welder.finish()
+    }
+
+    fn vertex(mesh: &MeshData, v: usize) -> [f32; 3] {
+        let p = &mesh.vertices[v * 3..v * 3 + 3];
+        [p[0], p[1], p[2]]
+    }
+
+    #[test]
+    fn test_weld_roads_by_contact_point() {
+        // 道路 1 终点与道路 2 终点相接（相向），两者之间有 5mm 缝隙
+        let roads = [
+            road(
+                "1",
+                OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 20.0),
+                vec![section(0.0, 3.5)],
+                road_link("2", OdrContactPoint::End),
+            ),
+            road(
+                "2",
+                OdrRoadGeometry::create_line(0.0, 40.005, 0.0, std::f64::consts::PI, 20.0),
+                vec![section(0.0, 3.5)],
+                None,
+            ),
+        ];
+        let mut builder = LaneMeshBuilder::new(Some(5.0), None);
+
+        let meshes = build(&builder, &roads);
+        let last = meshes[0].vertices.len() / 3 - 1;
+        assert_ne!(vertex(&meshes[0], last), vertex(&meshes[3], last));
+
+        builder.set_weld_tolerance(Some(0.01));
+        let meshes = build(&builder, &roads);
+        // 道路 1 左车道外边界 (t = 3.5) 与道路 2 右车道外边界 (t = -3.5) 重合
+        assert_eq!(vertex(&meshes[0], last), vertex(&meshes[3], last));
+        assert_eq!(vertex(&meshes[1], last), vertex(&meshes[2], last));
+        // 起点不在接缝上，保持不变
+        assert_eq!(vertex(&meshes[0], 0), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_weld_lane_sections() {
+        // 第二个车道段的宽度略大，边界处外侧顶点不重合
+        let roads = [road(
+            "1",
+            OdrRoadGeometry::create_line(0.0, 0.0, 0.0, 0.0, 20.0),
+            vec![section(0.0, 3.5), section(10.0, 3.502)],
+            None,
+        )];
+        let mut builder = LaneMeshBuilder::new(Some(5.0), None);
+        builder.set_weld_tolerance(Some(0.01));
+        let meshes = build(&builder, &roads);
+
+        // 右车道：第一段末尾外侧顶点与第二段起点外侧顶点焊接到中点
+        let end = meshes[1].vertices.len() / 3 - 1;
+        let welded = vertex(&meshes[1], end);
+        assert_eq!(welded, vertex(&meshes[3], 1));
+        assert!((welded[2] - 3.501).abs() < 1e-5);
+        assert!((meshes[1].normals[end * 3 + 1] - 1.0).abs() < 1e-6);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod mesh;
 pub mod models;
 pub mod parser;
+#[cfg(test)]
+pub(crate) mod test_support;
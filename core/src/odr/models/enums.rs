@@ -38,6 +38,13 @@ pub enum OdrOrientation {
     None = "none",
 }
 
+impl OdrOrientation {
+    /// OpenDRIVE 中的属性值
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
 /// 道路标记颜色枚举
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
@@ -54,6 +61,13 @@ pub enum OdrRoadMarkColor {
     Yellow = "yellow",
 }
 
+impl OdrRoadMarkColor {
+    /// OpenDRIVE 中的属性值
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
 /// 道路标记权重枚举
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
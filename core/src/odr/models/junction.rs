@@ -1,9 +1,15 @@
 use wasm_bindgen::prelude::*;
 
+use crate::math::vec3::Vec3;
+use crate::odr::models::junction::boundary::OdrJunctionBoundary;
 use crate::odr::models::junction::connection::OdrConnection;
+use crate::odr::models::junction::elevation_grid::OdrJunctionElevationGrid;
 use crate::odr::models::junction::priority::OdrJunctionPriority;
+use crate::odr::models::road::OdrRoad;
 
+pub mod boundary;
 pub mod connection;
+pub mod elevation_grid;
 pub mod lane_link;
 pub mod priority;
 
@@ -61,6 +67,14 @@ pub struct OdrJunction {
     /// 优先级列表
     #[wasm_bindgen(getter_with_clone)]
     pub priorities: Vec<OdrJunctionPriority>,
+
+    /// 路口边界（OpenDRIVE 1.8）
+    #[wasm_bindgen(getter_with_clone)]
+    pub boundary: Option<OdrJunctionBoundary>,
+
+    /// 路口高程网格（OpenDRIVE 1.8）
+    #[wasm_bindgen(getter_with_clone, js_name = "elevationGrid")]
+    pub elevation_grid: Option<OdrJunctionElevationGrid>,
 }
 
 #[wasm_bindgen]
@@ -77,6 +91,8 @@ impl OdrJunction {
             orientation: None,
             connections: Vec::new(),
             priorities: Vec::new(),
+            boundary: None,
+            elevation_grid: None,
         }
     }
 
@@ -91,3 +107,20 @@ impl OdrJunction {
         self.orientation = Some(orientation);
     }
 }
+
+impl OdrJunction {
+    /// 计算 `<boundary>` 围成的多边形（惯性坐标，不重复首点）
+    ///
+    /// 没有边界或边界不足三个点时返回空数组。
+    ///
+    /// # 参数
+    /// - `roads`: 地图中的所有道路
+    /// - `step`: 沿边界段的采样间隔（米）
+    pub fn boundary_polygon(&self, roads: &[OdrRoad], step: f64) -> Vec<Vec3> {
+        self.boundary
+            .as_ref()
+            .map(|b| b.polygon(roads, step))
+            .filter(|p| p.len() >= 3)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,214 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    math::vec3::Vec3,
+    odr::models::{enums::OdrContactPoint, road::OdrRoad},
+};
+
+/// 边界段类型
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OdrJunctionSegmentType {
+    /// 沿车道外边界
+    Lane = "lane",
+    /// 横跨道路端口
+    Joint = "joint",
+}
+
+/// 路口边界（OpenDRIVE 1.8）
+///
+/// 由首尾相接的边界段组成，按顺序围成路口区域。
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct OdrJunctionBoundary {
+    /// 边界段列表
+    #[wasm_bindgen(getter_with_clone)]
+    pub segments: Vec<OdrJunctionBoundarySegment>,
+}
+
+/// 路口边界段
+///
+/// `lane` 类型沿 `boundaryLane` 的外边界从 `sStart` 到 `sEnd`（可反向）；
+/// `joint` 类型位于道路 `contactPoint` 端，从 `jointLaneStart` 的外边界横跨到
+/// `jointLaneEnd` 的外边界。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OdrJunctionBoundarySegment {
+    /// 边界段类型
+    #[wasm_bindgen(getter_with_clone, js_name = "segmentType")]
+    pub segment_type: OdrJunctionSegmentType,
+
+    /// 边界段所在道路 ID
+    #[wasm_bindgen(getter_with_clone, js_name = "roadId")]
+    pub road_id: String,
+
+    /// 边界车道 ID（lane 类型）
+    #[wasm_bindgen(js_name = "boundaryLane")]
+    pub boundary_lane: Option<i32>,
+
+    /// 起始 s 坐标（lane 类型）
+    #[wasm_bindgen(js_name = "sStart")]
+    pub s_start: Option<f64>,
+
+    /// 结束 s 坐标（lane 类型）
+    #[wasm_bindgen(js_name = "sEnd")]
+    pub s_end: Option<f64>,
+
+    /// 道路端点（joint 类型）
+    #[wasm_bindgen(getter_with_clone, js_name = "contactPoint")]
+    pub contact_point: Option<OdrContactPoint>,
+
+    /// 起始车道 ID（joint 类型）
+    #[wasm_bindgen(js_name = "jointLaneStart")]
+    pub joint_lane_start: Option<i32>,
+
+    /// 结束车道 ID（joint 类型）
+    #[wasm_bindgen(js_name = "jointLaneEnd")]
+    pub joint_lane_end: Option<i32>,
+
+    /// 过渡长度（joint 类型）
+    #[wasm_bindgen(js_name = "transitionLength")]
+    pub transition_length: Option<f64>,
+}
+
+#[wasm_bindgen]
+impl OdrJunctionBoundary {
+    #[wasm_bindgen(constructor)]
+    pub fn new(segments: Vec<OdrJunctionBoundarySegment>) -> Self {
+        Self { segments }
+    }
+}
+
+#[wasm_bindgen]
+impl OdrJunctionBoundarySegment {
+    /// 创建 lane 类型边界段
+    #[wasm_bindgen(js_name = "createLane")]
+    pub fn create_lane(road_id: String, boundary_lane: i32, s_start: f64, s_end: f64) -> Self {
+        Self {
+            segment_type: OdrJunctionSegmentType::Lane,
+            road_id,
+            boundary_lane: Some(boundary_lane),
+            s_start: Some(s_start),
+            s_end: Some(s_end),
+            contact_point: None,
+            joint_lane_start: None,
+            joint_lane_end: None,
+            transition_length: None,
+        }
+    }
+
+    /// 创建 joint 类型边界段
+    #[wasm_bindgen(js_name = "createJoint")]
+    pub fn create_joint(
+        road_id: String,
+        contact_point: OdrContactPoint,
+        joint_lane_start: i32,
+        joint_lane_end: i32,
+        transition_length: Option<f64>,
+    ) -> Self {
+        Self {
+            segment_type: OdrJunctionSegmentType::Joint,
+            road_id,
+            boundary_lane: None,
+            s_start: None,
+            s_end: None,
+            contact_point: Some(contact_point),
+            joint_lane_start: Some(joint_lane_start),
+            joint_lane_end: Some(joint_lane_end),
+            transition_length,
+        }
+    }
+}
+
+impl OdrJunctionBoundary {
+    /// 计算边界多边形（惯性坐标，不重复首点）
+    ///
+    /// 依次连接各边界段的采样点，顶点高程取自所在道路的路面。
+    /// 引用的道路或车道不存在的边界段被跳过。
+    ///
+    /// # 参数
+    /// - `roads`: 地图中的所有道路
+    /// - `step`: 沿边界段的采样间隔（米）
+    pub fn polygon(&self, roads: &[OdrRoad], step: f64) -> Vec<Vec3> {
+        let mut points: Vec<Vec3> = Vec::new();
+        for segment in &self.segments {
+            let Some(road) = roads.iter().find(|r| r.id == segment.road_id) else {
+                continue;
+            };
+            for p in segment.points(road, step) {
+                let duplicate = points
+                    .last()
+                    .is_some_and(|q| (p.x - q.x).hypot(p.y - q.y) < 1e-6);
+                if !duplicate {
+                    points.push(p);
+                }
+            }
+        }
+
+        if points.len() > 1 {
+            let (first, last) = (points[0], points[points.len() - 1]);
+            if (first.x - last.x).hypot(first.y - last.y) < 1e-6 {
+                points.pop();
+            }
+        }
+        points
+    }
+}
+
+impl OdrJunctionBoundarySegment {
+    /// 沿边界段采样的路面点
+    pub fn points(&self, road: &OdrRoad, step: f64) -> Vec<Vec3> {
+        let surface = |s: f64, t: f64| road.sth_to_xyz(s, t, road.eval_shape(s, t));
+        // 车道在 s 处外边界的 t 坐标，中心车道为车道偏移
+        let lane_outer_t = |lane_id: i32, s: f64| {
+            let section = road
+                .lanes
+                .iter()
+                .rev()
+                .find(|l| l.s <= s)
+                .or(road.lanes.first())?;
+            let lane = section.find_lane(lane_id)?;
+            Some(road.lane_t_bounds(section, lane, s).1)
+        };
+
+        match self.segment_type {
+            OdrJunctionSegmentType::Lane => {
+                let (Some(lane_id), Some(s_start), Some(s_end)) =
+                    (self.boundary_lane, self.s_start, self.s_end)
+                else {
+                    return Vec::new();
+                };
+                let n = (((s_end - s_start).abs() / step).ceil() as usize).max(1);
+                (0..=n)
+                    .filter_map(|i| {
+                        let s = s_start + (s_end - s_start) * i as f64 / n as f64;
+                        lane_outer_t(lane_id, s).map(|t| surface(s, t))
+                    })
+                    .collect()
+            }
+            OdrJunctionSegmentType::Joint => {
+                let (Some(contact_point), Some(lane_start), Some(lane_end)) = (
+                    &self.contact_point,
+                    self.joint_lane_start,
+                    self.joint_lane_end,
+                ) else {
+                    return Vec::new();
+                };
+                let s = match contact_point {
+                    OdrContactPoint::End => road.length,
+                    _ => 0.0,
+                };
+                let (Some(t_start), Some(t_end)) =
+                    (lane_outer_t(lane_start, s), lane_outer_t(lane_end, s))
+                else {
+                    return Vec::new();
+                };
+                let n = (((t_end - t_start).abs() / step).ceil() as usize).max(1);
+                (0..=n)
+                    .map(|i| surface(s, t_start + (t_end - t_start) * i as f64 / n as f64))
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
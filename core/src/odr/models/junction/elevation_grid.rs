@@ -0,0 +1,110 @@
+use wasm_bindgen::prelude::*;
+
+/// 路口高程网格（OpenDRIVE 1.8）
+///
+/// 每个 `<elevation>` 为网格的一行，行间距与行内点间距均为 `gridSpacing`。
+/// 网格与惯性坐标轴对齐并以路口区域包围盒中心为原点：各行沿 x 轴由西向东排列，
+/// 行内 `center` 位于中心，`left` 由近及远向 +y 排列，`right` 由近及远向 -y 排列。
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OdrJunctionElevationGrid {
+    /// 网格间距（米）
+    #[wasm_bindgen(js_name = "gridSpacing")]
+    pub grid_spacing: f64,
+
+    /// 网格行列表
+    #[wasm_bindgen(getter_with_clone)]
+    pub elevations: Vec<OdrJunctionGridElevation>,
+}
+
+/// 高程网格的一行
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct OdrJunctionGridElevation {
+    /// 中心点高程
+    #[wasm_bindgen(getter_with_clone)]
+    pub center: Vec<f64>,
+
+    /// 中心左侧各点高程，由近及远
+    #[wasm_bindgen(getter_with_clone)]
+    pub left: Vec<f64>,
+
+    /// 中心右侧各点高程，由近及远
+    #[wasm_bindgen(getter_with_clone)]
+    pub right: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl OdrJunctionElevationGrid {
+    #[wasm_bindgen(constructor)]
+    pub fn new(grid_spacing: f64, elevations: Vec<OdrJunctionGridElevation>) -> Self {
+        Self {
+            grid_spacing,
+            elevations,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl OdrJunctionGridElevation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: Vec<f64>, left: Vec<f64>, right: Vec<f64>) -> Self {
+        Self {
+            center,
+            left,
+            right,
+        }
+    }
+}
+
+impl OdrJunctionGridElevation {
+    /// 该行由 +y 到 -y 排列的高程，以及中心点在其中的序号
+    fn row(&self) -> (Vec<f64>, usize) {
+        let values: Vec<f64> = self
+            .left
+            .iter()
+            .rev()
+            .chain(&self.center)
+            .chain(&self.right)
+            .copied()
+            .collect();
+        (values, self.left.len())
+    }
+}
+
+impl OdrJunctionElevationGrid {
+    /// 双线性插值计算 (x, y) 处的高程，超出网格范围时取边缘值
+    ///
+    /// # 参数
+    /// - `origin_x`, `origin_y`: 网格原点（路口区域包围盒中心）
+    pub fn eval(&self, origin_x: f64, origin_y: f64, x: f64, y: f64) -> Option<f64> {
+        let rows: Vec<(Vec<f64>, usize)> = self
+            .elevations
+            .iter()
+            .map(|e| e.row())
+            .filter(|(values, _)| !values.is_empty())
+            .collect();
+        if rows.is_empty() || self.grid_spacing <= 0.0 {
+            return None;
+        }
+
+        let v = (y - origin_y) / self.grid_spacing;
+        let eval_row = |(values, center): &(Vec<f64>, usize)| {
+            let index = (*center as f64 - v).clamp(0.0, (values.len() - 1) as f64);
+            lerp_at(values, index)
+        };
+
+        let row_values: Vec<f64> = rows.iter().map(eval_row).collect();
+        let index = ((x - origin_x) / self.grid_spacing + (rows.len() - 1) as f64 / 2.0)
+            .clamp(0.0, (rows.len() - 1) as f64);
+        Some(lerp_at(&row_values, index))
+    }
+}
+
+/// 按小数序号线性插值
+fn lerp_at(values: &[f64], index: f64) -> f64 {
+    let i = (index.floor() as usize).min(values.len() - 1);
+    let j = (i + 1).min(values.len() - 1);
+    let f = index - i as f64;
+    values[i] * (1.0 - f) + values[j] * f
+}
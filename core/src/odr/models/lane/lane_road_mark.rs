@@ -19,6 +19,13 @@ pub enum OdrRoadMarkType {
     Solid = "solid",
 }
 
+impl OdrRoadMarkType {
+    /// Keyword as written in the OpenDRIVE `type` attribute
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub enum OdrRoadMarkLaneChange {
@@ -61,6 +61,15 @@ impl OdrLaneSection {
 }
 
 impl OdrLaneSection {
+    /// 按 ID 查找车道，0 为中心车道
+    pub fn find_lane(&self, lane_id: i32) -> Option<&OdrLane> {
+        match lane_id {
+            0 => Some(&self.center),
+            id if id > 0 => self.left.iter().find(|l| l.id == id),
+            id => self.right.iter().find(|l| l.id == id),
+        }
+    }
+
     /// 计算车道内侧边界的累计偏移
     ///
     /// 通过累加从中心线到目标车道之间所有车道的宽度来计算偏移
@@ -35,3 +35,14 @@ impl OdrLaneSpeed {
         }
     }
 }
+
+impl OdrLaneSpeed {
+    /// 最大速度换算为 m/s，未指定单位时按 m/s 处理
+    pub fn max_mps(&self) -> f64 {
+        match self.unit {
+            Some(OdrSpeedUnit::KMH) => self.max / 3.6,
+            Some(OdrSpeedUnit::MPH) => self.max * 0.44704,
+            _ => self.max,
+        }
+    }
+}
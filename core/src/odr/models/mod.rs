@@ -2,6 +2,8 @@ pub mod enums;
 pub mod header;
 pub mod lane;
 pub mod opendrive;
+pub mod pose;
 pub mod road;
 pub mod junction;
-pub mod object;
\ No newline at end of file
+pub mod object;
+pub mod signal;
\ No newline at end of file
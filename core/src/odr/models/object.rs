@@ -1,5 +1,8 @@
 use wasm_bindgen::prelude::*;
 
+use crate::math::vec3::Vec3;
+use crate::odr::models::{pose::OdrPose, road::OdrRoad};
+
 mod border;
 mod enums;
 mod marking;
@@ -144,4 +147,89 @@ impl OdrObject {
             borders: Vec::new(),
         }
     }
+
+    /// 计算对象在惯性坐标系下的位姿
+    ///
+    /// 原点为 (s, t) 处参考线高程加 zOffset。`perpToRoad` 为 true 时 z 轴垂直于
+    /// 路面，hdg/pitch/roll 相对路面；否则 hdg 相对参考线航向，pitch/roll 相对
+    /// x/y 平面。`orientation` 只表示有效方向，不影响位姿。
+    #[wasm_bindgen(js_name = "worldPose")]
+    pub fn world_pose(&self, road: &OdrRoad) -> OdrPose {
+        OdrPose::on_road(
+            road,
+            self.s,
+            self.t,
+            self.z_offset,
+            self.hdg.unwrap_or(0.0),
+            self.pitch.unwrap_or(0.0),
+            self.roll.unwrap_or(0.0),
+            self.perp_to_road.unwrap_or(false),
+        )
+    }
+}
+
+impl OdrObject {
+    /// 计算对象轮廓在地图坐标系下的顶点
+    ///
+    /// 优先使用 outline 中的 cornerRoad / cornerLocal；没有轮廓时，
+    /// 由 length/width 生成矩形，或由 radius 生成圆形近似。
+    /// 顶点位于对象底面，即道路高程加上 zOffset（或角点的 dz/z）。
+    pub fn outline_points(&self, road: &OdrRoad) -> Vec<Vec<Vec3>> {
+        let origin = road.sth_to_xyz(self.s, self.t, self.z_offset);
+        let hdg = road.eval_reference_line(self.s).hdg + self.hdg.unwrap_or(0.0);
+        let (sin, cos) = hdg.sin_cos();
+        let local = |u: f64, v: f64, z: f64| {
+            Vec3::new(
+                origin.x + u * cos - v * sin,
+                origin.y + u * sin + v * cos,
+                origin.z + z,
+            )
+        };
+
+        if !self.outlines.is_empty() {
+            return self
+                .outlines
+                .iter()
+                .map(|outline| {
+                    if !outline.corner_road.is_empty() {
+                        outline
+                            .corner_road
+                            .iter()
+                            .map(|c| road.sth_to_xyz(c.s, c.t, c.dz))
+                            .collect()
+                    } else {
+                        outline
+                            .corner_local
+                            .iter()
+                            .map(|c| local(c.u, c.v, c.z))
+                            .collect()
+                    }
+                })
+                .collect();
+        }
+
+        if let (Some(length), Some(width)) = (self.length, self.width) {
+            let (hl, hw) = (length / 2.0, width / 2.0);
+            return vec![vec![
+                local(-hl, -hw, 0.0),
+                local(hl, -hw, 0.0),
+                local(hl, hw, 0.0),
+                local(-hl, hw, 0.0),
+            ]];
+        }
+
+        if let Some(radius) = self.radius {
+            const SEGMENTS: usize = 16;
+            return vec![
+                (0..SEGMENTS)
+                    .map(|i| {
+                        let a = i as f64 / SEGMENTS as f64 * std::f64::consts::TAU;
+                        local(radius * a.cos(), radius * a.sin(), 0.0)
+                    })
+                    .collect(),
+            ];
+        }
+
+        Vec::new()
+    }
 }
@@ -1,10 +1,20 @@
+use std::collections::{HashMap, HashSet};
+
 use wasm_bindgen::prelude::*;
 
 use crate::{
     math::vec3::Vec3,
-    odr::models::{header::OdrHeader, junction::OdrJunction, road::OdrRoad},
+    odr::models::{
+        enums::OdrContactPoint,
+        header::OdrHeader,
+        junction::OdrJunction,
+        road::{OdrRoad, road_link::OdrRoadLinkElementType},
+    },
 };
 
+/// 车道在 OpenDRIVE 中的位置：(道路索引, 车道段索引, 车道 ID)
+pub type LaneKey = (usize, usize, i32);
+
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct OpenDrive {
@@ -38,6 +48,126 @@ impl OpenDrive {
 }
 
 impl OpenDrive {
+    /// 收集车流方向上的车道连接 (上游车道, 下游车道)
+    ///
+    /// `road_index` 为道路 ID 到道路索引的映射，`lane_index` 为车道位置到导出
+    /// 元素索引的映射，返回的连接以导出元素索引表示。
+    pub fn lane_flows(
+        &self,
+        road_index: &HashMap<&str, usize>,
+        lane_index: &HashMap<LaneKey, usize>,
+    ) -> Vec<(usize, usize)> {
+        let mut flows = Vec::new();
+        let mut seen = HashSet::new();
+        let mut push = |from: Option<&usize>, to: Option<&usize>| {
+            if let (Some(&from), Some(&to)) = (from, to)
+                && seen.insert((from, to))
+            {
+                flows.push((from, to));
+            }
+        };
+
+        // 与连接道路相邻的车道段
+        let contact_section = |road_idx: usize, contact: &Option<OdrContactPoint>| match contact {
+            Some(OdrContactPoint::End) => self.roads[road_idx].lanes.len().saturating_sub(1),
+            _ => 0,
+        };
+
+        for (road_idx, road) in self.roads.iter().enumerate() {
+            let last_section = road.lanes.len().saturating_sub(1);
+
+            for (section_idx, section) in road.lanes.iter().enumerate() {
+                for lane in section.left.iter().chain(section.right.iter()) {
+                    let own = lane_index.get(&(road_idx, section_idx, lane.id));
+                    let forward = lane.id < 0;
+
+                    // (OpenDRIVE 链接的目标车道, 是否位于 s 增大一侧)
+                    let mut targets: Vec<(LaneKey, bool)> = Vec::new();
+
+                    if let Some(successor) = lane.link.successor {
+                        if section_idx < last_section {
+                            targets.push(((road_idx, section_idx + 1, successor), true));
+                        } else if let Some(link) = &road.successor
+                            && matches!(link.element_type, OdrRoadLinkElementType::Road)
+                            && let Some(&target) = road_index.get(link.element_id.as_str())
+                        {
+                            let target_section = contact_section(target, &link.contact_point);
+                            targets.push(((target, target_section, successor), true));
+                        }
+                    }
+
+                    if let Some(predecessor) = lane.link.predecessor {
+                        if section_idx > 0 {
+                            targets.push(((road_idx, section_idx - 1, predecessor), false));
+                        } else if let Some(link) = &road.predecessor
+                            && matches!(link.element_type, OdrRoadLinkElementType::Road)
+                            && let Some(&target) = road_index.get(link.element_id.as_str())
+                        {
+                            let target_section = contact_section(target, &link.contact_point);
+                            targets.push(((target, target_section, predecessor), false));
+                        }
+                    }
+
+                    for (key, towards_end) in targets {
+                        let target = lane_index.get(&key);
+                        // 正向车道沿 s 增大方向行驶
+                        if towards_end == forward {
+                            push(own, target);
+                        } else {
+                            push(target, own);
+                        }
+                    }
+                }
+            }
+        }
+
+        for junction in &self.junctions {
+            for connection in &junction.connections {
+                let (Some(incoming), Some(connecting)) = (
+                    connection
+                        .incoming_road
+                        .as_deref()
+                        .and_then(|id| road_index.get(id)),
+                    connection
+                        .connecting_road
+                        .as_deref()
+                        .and_then(|id| road_index.get(id)),
+                ) else {
+                    continue;
+                };
+
+                let incoming_road = &self.roads[*incoming];
+                let junction_at_end = incoming_road.successor.as_ref().is_some_and(|l| {
+                    matches!(l.element_type, OdrRoadLinkElementType::Junction)
+                        && l.element_id == junction.id
+                });
+                let incoming_section = if junction_at_end {
+                    incoming_road.lanes.len().saturating_sub(1)
+                } else {
+                    0
+                };
+                let connecting_section = if connection.contact_point.as_deref() == Some("end") {
+                    self.roads[*connecting].lanes.len().saturating_sub(1)
+                } else {
+                    0
+                };
+
+                for lane_link in &connection.lane_links {
+                    let from = lane_index.get(&(*incoming, incoming_section, lane_link.from));
+                    let to = lane_index.get(&(*connecting, connecting_section, lane_link.to));
+                    // 驶向路口的车道：终点处的右侧车道或起点处的左侧车道
+                    if (lane_link.from < 0) == junction_at_end {
+                        push(from, to);
+                    } else {
+                        push(to, from);
+                    }
+                }
+            }
+        }
+
+        flows
+    }
+
     /// 计算地图的中心点
     ///
     /// 通过计算所有道路几何起点的边界框中心来获取
@@ -0,0 +1,154 @@
+use wasm_bindgen::prelude::*;
+
+use crate::math::{quat::Quat, vec3::Vec3};
+use crate::odr::models::road::OdrRoad;
+
+/// 惯性坐标系下的位姿
+///
+/// 局部坐标系 x 轴朝前、y 轴朝左、z 轴朝上，与 OpenDRIVE 对象的 u/v/z 一致。
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OdrPose {
+    /// 局部坐标原点
+    pub position: Vec3,
+    /// 局部坐标系相对惯性坐标系的旋转
+    pub rotation: Quat,
+}
+
+#[wasm_bindgen]
+impl OdrPose {
+    #[wasm_bindgen(constructor)]
+    pub fn new(position: Vec3, rotation: Quat) -> Self {
+        Self { position, rotation }
+    }
+
+    /// 将局部坐标转换为惯性坐标
+    #[wasm_bindgen(js_name = "transformPoint")]
+    pub fn transform_point(&self, local: &Vec3) -> Vec3 {
+        self.position.add(&self.rotation.rotate_vector(local))
+    }
+
+    /// 4x4 齐次变换矩阵（列主序，可直接用于 three.js `Matrix4.fromArray`）
+    pub fn matrix(&self) -> Vec<f64> {
+        let Quat { x, y, z, w } = self.rotation.normalize();
+        let p = self.position;
+        vec![
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + z * w),
+            2.0 * (x * z - y * w),
+            0.0,
+            2.0 * (x * y - z * w),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + x * w),
+            0.0,
+            2.0 * (x * z + y * w),
+            2.0 * (y * z - x * w),
+            1.0 - 2.0 * (x * x + y * y),
+            0.0,
+            p.x,
+            p.y,
+            p.z,
+            1.0,
+        ]
+    }
+}
+
+impl OdrPose {
+    /// 道路上 (s, t, zOffset) 处的位姿
+    ///
+    /// `road_aligned` 为 true 时 z 轴垂直于路面：先旋转到 (s, t) 处的路面坐标系
+    /// （见 `OdrRoad::eval_surface_frame`），再叠加 heading/pitch/roll；否则
+    /// heading 相对参考线航向，pitch/roll 相对 x/y 平面。
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_road(
+        road: &OdrRoad,
+        s: f64,
+        t: f64,
+        z_offset: f64,
+        heading: f64,
+        pitch: f64,
+        roll: f64,
+        road_aligned: bool,
+    ) -> Self {
+        let position = road.sth_to_xyz(s, t, z_offset);
+        let rotation = if road_aligned {
+            let surface = road.eval_surface_frame(s, t).rotation;
+            surface.mul(&Quat::from_hpr(heading, pitch, roll))
+        } else {
+            Quat::from_hpr(road.eval_reference_line(s).hdg + heading, pitch, roll)
+        };
+        Self { position, rotation }
+    }
+
+    /// 局部 (u, v, z) 坐标转换到惯性坐标
+    pub fn local(&self, u: f64, v: f64, z: f64) -> Vec3 {
+        self.transform_point(&Vec3::new(u, v, z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::odr::{
+        models::road::{
+            road_elevation::OdrRoadElevation, road_geometry::OdrRoadGeometry,
+            superelevation::OdrSuperelevation,
+        },
+        test_support,
+    };
+
+    fn build_test_road() -> OdrRoad {
+        OdrRoad {
+            elevations: vec![OdrRoadElevation::new(0.0, 0.0, 0.1, 0.0, 0.0)],
+            superelevations: vec![OdrSuperelevation::new(0.0, 0.05, 0.0, 0.0, 0.0)],
+            ..test_support::road(
+                "1",
+                OdrRoadGeometry::create_line(0.0, 0.0, 0.0, FRAC_PI_2, 100.0),
+                Vec::new(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_on_road() {
+        let road = build_test_road();
+
+        // 不贴合路面：只有航向，局部 x 轴沿参考线（北）
+        let pose = OdrPose::on_road(&road, 10.0, 0.0, 2.0, 0.0, 0.0, 0.0, false);
+        assert!((pose.position.z - 3.0).abs() < 1e-9);
+        let p = pose.local(1.0, 0.0, 0.0);
+        assert!(p.x.abs() < 1e-9 && (p.y - 11.0).abs() < 1e-9);
+        assert!((p.z - 3.0).abs() < 1e-9);
+
+        // 贴合路面：局部 z 轴为路面法线
+        let pose = OdrPose::on_road(&road, 10.0, 0.0, 0.0, 0.0, 0.0, 0.0, true);
+        let forward = pose.rotation.rotate_vector(&Vec3::new(1.0, 0.0, 0.0));
+        assert!((forward.z / forward.y - 0.1).abs() < 1e-9);
+        let up = pose.rotation.rotate_vector(&Vec3::new(0.0, 0.0, 1.0));
+        assert!(up.dot(&forward).abs() < 1e-9);
+        // 超高为正时左侧升高，法线向右倾（参考线朝北时右侧为东）
+        assert!(up.x > 0.0);
+
+        // 局部航向叠加在路面坐标系内
+        let pose = OdrPose::on_road(&road, 10.0, 0.0, 0.0, FRAC_PI_2, 0.0, 0.0, true);
+        let left = pose.rotation.rotate_vector(&Vec3::new(1.0, 0.0, 0.0));
+        assert!(left.x < -0.99);
+    }
+
+    #[test]
+    fn test_matrix() {
+        let pose = OdrPose::new(Vec3::new(1.0, 2.0, 3.0), Quat::from_hpr(0.3, -0.2, 0.1));
+        let m = pose.matrix();
+        let local = Vec3::new(0.5, -1.5, 2.0);
+        let expected = pose.transform_point(&local);
+        let x = m[0] * local.x + m[4] * local.y + m[8] * local.z + m[12];
+        let y = m[1] * local.x + m[5] * local.y + m[9] * local.z + m[13];
+        let z = m[2] * local.x + m[6] * local.y + m[10] * local.z + m[14];
+        assert!((x - expected.x).abs() < 1e-9);
+        assert!((y - expected.y).abs() < 1e-9);
+        assert!((z - expected.z).abs() < 1e-9);
+        assert_eq!(m[15], 1.0);
+    }
+}
@@ -1,27 +1,33 @@
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    math::vec3::Vec3,
+    math::{quat::Quat, vec3::Vec3},
     odr::models::{
-        lane::{lane_offset::OdrLaneOffset, lane_section::OdrLaneSection},
+        lane::{OdrLane, lane_offset::OdrLaneOffset, lane_section::OdrLaneSection},
+        object::OdrObject,
         road::{
+            profile::OdrRoadProfile,
             road_elevation::OdrRoadElevation,
             road_geometry::{OdrRoadGeometry, PosHdg},
             road_link::OdrRoadLink,
             road_type::OdrRoadType,
             shape::OdrShape,
             superelevation::OdrSuperelevation,
+            surface_frame::OdrSurfaceFrame,
             traffic_rule::OdrTrafficRule,
         },
+        signal::OdrSignal,
     },
 };
 
+pub mod profile;
 pub mod road_elevation;
 pub mod road_geometry;
 pub mod road_link;
 pub mod road_type;
 pub mod shape;
 pub mod superelevation;
+pub mod surface_frame;
 pub mod traffic_rule;
 
 #[wasm_bindgen]
@@ -67,6 +73,12 @@ pub struct OdrRoad {
 
     #[wasm_bindgen(getter_with_clone)]
     pub lane_offsets: Vec<OdrLaneOffset>,
+
+    #[wasm_bindgen(getter_with_clone)]
+    pub objects: Vec<OdrObject>,
+
+    #[wasm_bindgen(getter_with_clone)]
+    pub signals: Vec<OdrSignal>,
 }
 
 #[wasm_bindgen]
@@ -103,6 +115,8 @@ impl OdrRoad {
             successor: successor,
             lanes: lanes,
             lane_offsets: lane_offsets,
+            objects: Vec::new(),
+            signals: Vec::new(),
         }
     }
 
@@ -230,20 +244,6 @@ impl OdrRoad {
         best_s
     }
 
-    /// 计算参考线上 s 位置的点和切线方向
-    fn eval_reference_line(&self, s: f64) -> PosHdg {
-        // 找到包含该 s 值的几何段
-        let geom = self
-            .plan_view
-            .iter()
-            .filter(|g| g.s <= s)
-            .last()
-            .unwrap_or_else(|| self.plan_view.first().expect("plan_view is empty"));
-
-        let ds = s - geom.s;
-        geom.eval_at(ds)
-    }
-
     /// 计算 s 位置的基础高程
     #[wasm_bindgen(js_name = "evalElevation")]
     pub fn eval_elevation(&self, s: f64) -> f64 {
@@ -258,6 +258,100 @@ impl OdrRoad {
         }
     }
 
+    /// 计算 s 位置的纵坡（高程对 s 的导数）
+    #[wasm_bindgen(js_name = "evalSlope")]
+    pub fn eval_slope(&self, s: f64) -> f64 {
+        let elev = self.elevations.iter().rev().find(|e| e.s <= s);
+
+        match elev {
+            Some(e) => {
+                let ds = s - e.s;
+                e.b + 2.0 * e.c * ds + 3.0 * e.d * ds.powi(2)
+            }
+            None => 0.0,
+        }
+    }
+
+    /// 计算参考线在 s 位置的曲率（1/米，向左弯为正）
+    #[wasm_bindgen(js_name = "evalCurvature")]
+    pub fn eval_curvature(&self, s: f64) -> f64 {
+        let geom = self.plan_view.iter().rev().find(|g| g.s <= s);
+
+        match geom.or(self.plan_view.first()) {
+            Some(g) => g.curvature_at(s - g.s),
+            None => 0.0,
+        }
+    }
+
+    /// 计算参考线在 s 位置的曲率变化率（dκ/ds，1/米²）
+    #[wasm_bindgen(js_name = "evalCurvatureRate")]
+    pub fn eval_curvature_rate(&self, s: f64) -> f64 {
+        let geom = self.plan_view.iter().rev().find(|g| g.s <= s);
+
+        match geom.or(self.plan_view.first()) {
+            Some(g) => g.curvature_rate_at(s - g.s),
+            None => 0.0,
+        }
+    }
+
+    /// 计算参考线在 s 位置的航向角（弧度）
+    #[wasm_bindgen(js_name = "evalHeading")]
+    pub fn eval_heading(&self, s: f64) -> f64 {
+        self.eval_reference_line(s).hdg
+    }
+
+    /// 计算 s 位置纵断面的竖曲率（1/米，凹形为正）
+    ///
+    /// κ_v = z'' / (1 + z'²)^(3/2)，z 为高程。
+    #[wasm_bindgen(js_name = "evalElevationCurvature")]
+    pub fn eval_elevation_curvature(&self, s: f64) -> f64 {
+        let elev = self.elevations.iter().rev().find(|e| e.s <= s);
+
+        match elev {
+            Some(e) => {
+                let ds = s - e.s;
+                let slope = e.b + 2.0 * e.c * ds + 3.0 * e.d * ds.powi(2);
+                (2.0 * e.c + 6.0 * e.d * ds) / (1.0 + slope * slope).powf(1.5)
+            }
+            None => 0.0,
+        }
+    }
+
+    /// 计算 s 位置的超高变化率（弧度/米）
+    #[wasm_bindgen(js_name = "evalSuperelevationRate")]
+    pub fn eval_superelevation_rate(&self, s: f64) -> f64 {
+        let se = self.superelevations.iter().rev().find(|e| e.s <= s);
+
+        match se {
+            Some(e) => {
+                let ds = s - e.s;
+                e.b + 2.0 * e.c * ds + 3.0 * e.d * ds.powi(2)
+            }
+            None => 0.0,
+        }
+    }
+
+    /// 在一组 s 位置上采样参考线和纵横断面参数，用于绘制曲线图
+    ///
+    /// # 参数
+    /// - `s_values`: 采样位置，按给定顺序输出
+    #[wasm_bindgen(js_name = "evalProfile")]
+    pub fn eval_profile(&self, s_values: Vec<f64>) -> OdrRoadProfile {
+        let eval = |f: fn(&Self, f64) -> f64| s_values.iter().map(|&s| f(self, s)).collect();
+        OdrRoadProfile {
+            heading: eval(Self::eval_heading),
+            curvature: eval(Self::eval_curvature),
+            curvature_rate: eval(Self::eval_curvature_rate),
+            elevation: eval(Self::eval_elevation),
+            slope: eval(Self::eval_slope),
+            elevation_curvature: eval(Self::eval_elevation_curvature),
+            superelevation: eval(Self::eval_superelevation),
+            superelevation_rate: eval(Self::eval_superelevation_rate),
+            cross_fall: eval(|road, s| road.eval_superelevation(s).tan()),
+            s: s_values,
+        }
+    }
+
     /// 计算 s 位置的超高角度（弧度）
     #[wasm_bindgen(js_name = "evalSuperelevation")]
     pub fn eval_superelevation(&self, s: f64) -> f64 {
@@ -334,6 +428,96 @@ impl OdrRoad {
     pub fn has_lateral_profile(&self) -> bool {
         !self.superelevations.is_empty() || !self.shapes.is_empty()
     }
+
+    /// 计算 (s, t) 处路面的局部坐标系
+    ///
+    /// 航向取自参考线；纵坡为高程导数加上超高和 shape 沿 s 的变化，横坡为超高
+    /// 加上 shape 沿 t 的斜率，超高和 shape 的导数使用中心差分。
+    ///
+    /// # 参数
+    /// - `s`: 沿参考线的纵向距离
+    /// - `t`: 横向位置
+    #[wasm_bindgen(js_name = "evalSurfaceFrame")]
+    pub fn eval_surface_frame(&self, s: f64, t: f64) -> OdrSurfaceFrame {
+        const H: f64 = 1e-3;
+
+        let pos_hdg = self.eval_reference_line(s);
+        let reference_curvature = self.eval_curvature(s);
+        // t 处沿 s 前进单位长度对应的水平弧长
+        let scale = (1.0 - reference_curvature * t).max(f64::EPSILON);
+
+        let lateral_z =
+            |s: f64, t: f64| t * self.eval_superelevation(s).tan() + self.eval_shape(s, t);
+        let mut slope_s = self.eval_slope(s);
+        let mut slope_t = 0.0;
+        if self.has_lateral_profile() {
+            // 避免在道路起点之前取值（此时没有超高/shape 记录）
+            let s0 = (s - H).clamp(0.0, (self.length - 2.0 * H).max(0.0));
+            let s1 = s0 + 2.0 * H;
+            slope_s += (lateral_z(s1, t) - lateral_z(s0, t)) / (s1 - s0);
+            slope_t = (lateral_z(s, t + H) - lateral_z(s, t - H)) / (2.0 * H);
+        }
+
+        let (sin, cos) = pos_hdg.hdg.sin_cos();
+        let along = Vec3::new(cos, sin, slope_s / scale);
+        let across = Vec3::new(-sin, cos, slope_t);
+        let normal = along.cross(&across).normalize();
+        let tangent = along.normalize();
+        let lateral = normal.cross(&tangent);
+
+        OdrSurfaceFrame {
+            position: self.sth_to_xyz(s, t, self.eval_shape(s, t)),
+            rotation: Quat::from_basis(&tangent, &lateral, &normal),
+            heading: pos_hdg.hdg,
+            pitch: -tangent.z.asin(),
+            roll: lateral.z.atan2(normal.z),
+            tangent,
+            lateral,
+            normal,
+            curvature: reference_curvature / scale,
+        }
+    }
+}
+
+impl OdrRoad {
+    /// 计算参考线上 s 位置的点和切线方向
+    pub fn eval_reference_line(&self, s: f64) -> PosHdg {
+        // 找到包含该 s 值的几何段
+        let geom = self
+            .plan_view
+            .iter()
+            .filter(|g| g.s <= s)
+            .last()
+            .unwrap_or_else(|| self.plan_view.first().expect("plan_view is empty"));
+
+        let ds = s - geom.s;
+        geom.eval_at(ds)
+    }
+
+    /// 车道段的 s 范围
+    pub fn section_range(&self, section_idx: usize) -> (f64, f64) {
+        let s_start = self.lanes[section_idx].s;
+        let s_end = self
+            .lanes
+            .get(section_idx + 1)
+            .map(|next| next.s)
+            .unwrap_or(self.length);
+        (s_start, s_end)
+    }
+
+    /// 计算车道在 s 处的横向边界 (t_inner, t_outer)，含车道偏移
+    ///
+    /// 中心车道没有宽度，两侧边界都位于车道偏移处。
+    pub fn lane_t_bounds(&self, section: &OdrLaneSection, lane: &OdrLane, s: f64) -> (f64, f64) {
+        let t_inner = section.calculate_inner_offset(lane.id, s) + self.eval_lane_offset(s);
+        let width = lane.eval_width(s - section.s);
+        let t_outer = match lane.id {
+            0 => t_inner,
+            id if id > 0 => t_inner + width,
+            _ => t_inner - width,
+        };
+        (t_inner, t_outer)
+    }
 }
 
 #[cfg(test)]
@@ -450,4 +634,49 @@ mod tests {
         assert_eq!(road.eval_lane_offset(50.0), 2.0);
         assert_eq!(road.eval_lane_offset(100.0), 1.0); // 2.0 - 0.04*25
     }
+
+    #[test]
+    fn test_eval_surface_frame() {
+        // 左转圆弧，纵坡 0.1，超高 0.05，t >= 0 一侧有 0.02 的 shape 横坡
+        let road = OdrRoad::new(
+            "road1".to_string(),
+            100.0,
+            "-1".to_string(),
+            None,
+            None,
+            None,
+            Some(vec![OdrRoadGeometry::create_arc(
+                0.0, 0.0, 0.0, 0.0, 100.0, 0.01,
+            )]),
+            Some(vec![OdrRoadElevation::new(0.0, 0.0, 0.1, 0.0, 0.0)]),
+            Some(vec![OdrSuperelevation::new(0.0, 0.05, 0.0, 0.0, 0.0)]),
+            Some(vec![OdrShape::new(0.0, 0.0, 0.0, 0.02, 0.0, 0.0)]),
+            None,
+            None,
+            vec![],
+            vec![],
+        );
+
+        let frame = road.eval_surface_frame(20.0, 0.0);
+        assert!((frame.heading - 0.2).abs() < 1e-9);
+        assert!((frame.pitch + 0.1_f64.atan()).abs() < 1e-9);
+        assert!((frame.curvature - 0.01).abs() < 1e-12);
+
+        // 右手单位正交基，且与 rotation 一致
+        assert!(frame.tangent.dot(&frame.normal).abs() < 1e-12);
+        assert!(frame.lateral.dot(&frame.normal).abs() < 1e-12);
+        assert!((frame.tangent.cross(&frame.lateral).dot(&frame.normal) - 1.0).abs() < 1e-12);
+        let x = frame.rotation.rotate_vector(&Vec3::new(1.0, 0.0, 0.0));
+        assert!(x.sub(&frame.tangent).length() < 1e-9);
+        let q = Quat::from_hpr(frame.heading, frame.pitch, frame.roll);
+        assert!((q.dot(&frame.rotation).abs() - 1.0).abs() < 1e-9);
+
+        // 横坡 = tan(超高) + shape 斜率
+        let frame = road.eval_surface_frame(20.0, 2.0);
+        assert!((frame.curvature - 0.01 / 0.98).abs() < 1e-12);
+        assert!((frame.position.z - (2.0 + 2.0 * 0.05_f64.tan() + 0.04)).abs() < 1e-9);
+        let across = Vec3::new(-0.2_f64.sin(), 0.2_f64.cos(), 0.05_f64.tan() + 0.02);
+        assert!(frame.normal.dot(&across).abs() < 1e-6);
+        assert!(frame.normal.z > 0.99);
+    }
 }
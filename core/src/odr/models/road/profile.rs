@@ -0,0 +1,48 @@
+use wasm_bindgen::prelude::*;
+
+/// 道路沿 s 的采样剖面
+///
+/// 由 `OdrRoad::eval_profile` 生成，各数组与 `s` 一一对应。
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct OdrRoadProfile {
+    /// 采样位置
+    #[wasm_bindgen(getter_with_clone)]
+    pub s: Vec<f64>,
+
+    /// 参考线航向角（弧度）
+    #[wasm_bindgen(getter_with_clone)]
+    pub heading: Vec<f64>,
+
+    /// 参考线曲率（1/米，向左弯为正）
+    #[wasm_bindgen(getter_with_clone)]
+    pub curvature: Vec<f64>,
+
+    /// 参考线曲率变化率（1/米²）
+    #[wasm_bindgen(getter_with_clone, js_name = "curvatureRate")]
+    pub curvature_rate: Vec<f64>,
+
+    /// 参考线高程（米）
+    #[wasm_bindgen(getter_with_clone)]
+    pub elevation: Vec<f64>,
+
+    /// 纵坡（高程对 s 的导数）
+    #[wasm_bindgen(getter_with_clone)]
+    pub slope: Vec<f64>,
+
+    /// 纵断面竖曲率（1/米）
+    #[wasm_bindgen(getter_with_clone, js_name = "elevationCurvature")]
+    pub elevation_curvature: Vec<f64>,
+
+    /// 超高角度（弧度）
+    #[wasm_bindgen(getter_with_clone)]
+    pub superelevation: Vec<f64>,
+
+    /// 超高变化率（弧度/米）
+    #[wasm_bindgen(getter_with_clone, js_name = "superelevationRate")]
+    pub superelevation_rate: Vec<f64>,
+
+    /// 横坡（超高角度的正切，左侧高为正）
+    #[wasm_bindgen(getter_with_clone, js_name = "crossFall")]
+    pub cross_fall: Vec<f64>,
+}
@@ -1,5 +1,7 @@
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use crate::math::vec3::Vec3;
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct OdrRoadGeometry {
@@ -83,6 +85,106 @@ impl OdrRoadGeometry {
         }
     }
 
+    /// 计算几何段上距离起点 ds 处的曲率（1/米，向左弯为正）
+    pub fn curvature_at(&self, ds: f64) -> f64 {
+        match self.kind {
+            OdrRoadGeometryKind::Line => 0.0,
+            OdrRoadGeometryKind::Arc => self.curvature.unwrap_or(0.0),
+            OdrRoadGeometryKind::Spiral => {
+                let curv_start = self.curv_start.unwrap_or(0.0);
+                let curv_end = self.curv_end.unwrap_or(0.0);
+                if self.length > 1e-15 {
+                    curv_start + (curv_end - curv_start) * ds / self.length
+                } else {
+                    curv_start
+                }
+            }
+            OdrRoadGeometryKind::ParamPoly3 => {
+                let p = match self.p_range {
+                    Some(OdrParamPoly3PRange::Normalized) if self.length > 1e-15 => {
+                        ds / self.length
+                    }
+                    Some(OdrParamPoly3PRange::Normalized) => 0.0,
+                    _ => ds,
+                };
+                let (b_u, c_u, d_u) = (
+                    self.b_u.unwrap_or(0.0),
+                    self.c_u.unwrap_or(0.0),
+                    self.d_u.unwrap_or(0.0),
+                );
+                let (b_v, c_v, d_v) = (
+                    self.b_v.unwrap_or(0.0),
+                    self.c_v.unwrap_or(0.0),
+                    self.d_v.unwrap_or(0.0),
+                );
+
+                // κ = (u'v'' - v'u'') / (u'² + v'²)^(3/2)，与参数化方式无关
+                let du = b_u + 2.0 * c_u * p + 3.0 * d_u * p * p;
+                let dv = b_v + 2.0 * c_v * p + 3.0 * d_v * p * p;
+                let ddu = 2.0 * c_u + 6.0 * d_u * p;
+                let ddv = 2.0 * c_v + 6.0 * d_v * p;
+                let speed_sq = du * du + dv * dv;
+                if speed_sq < 1e-24 {
+                    0.0
+                } else {
+                    (du * ddv - dv * ddu) / speed_sq.powf(1.5)
+                }
+            }
+        }
+    }
+
+    /// 计算几何段上距离起点 ds 处的曲率变化率（dκ/ds，1/米²）
+    ///
+    /// 直线和圆弧为 0，回旋曲线为常数；paramPoly3 由多项式导数解析求得。
+    pub fn curvature_rate_at(&self, ds: f64) -> f64 {
+        match self.kind {
+            OdrRoadGeometryKind::Line | OdrRoadGeometryKind::Arc => 0.0,
+            OdrRoadGeometryKind::Spiral => {
+                if self.length > 1e-15 {
+                    (self.curv_end.unwrap_or(0.0) - self.curv_start.unwrap_or(0.0)) / self.length
+                } else {
+                    0.0
+                }
+            }
+            OdrRoadGeometryKind::ParamPoly3 => {
+                // 与 eval_at/curvature_at 一致，p 与 ds 线性对应
+                let (p, dp_ds) = match self.p_range {
+                    Some(OdrParamPoly3PRange::Normalized) if self.length > 1e-15 => {
+                        (ds / self.length, 1.0 / self.length)
+                    }
+                    Some(OdrParamPoly3PRange::Normalized) => return 0.0,
+                    _ => (ds, 1.0),
+                };
+                let (b_u, c_u, d_u) = (
+                    self.b_u.unwrap_or(0.0),
+                    self.c_u.unwrap_or(0.0),
+                    self.d_u.unwrap_or(0.0),
+                );
+                let (b_v, c_v, d_v) = (
+                    self.b_v.unwrap_or(0.0),
+                    self.c_v.unwrap_or(0.0),
+                    self.d_v.unwrap_or(0.0),
+                );
+
+                let du = b_u + 2.0 * c_u * p + 3.0 * d_u * p * p;
+                let dv = b_v + 2.0 * c_v * p + 3.0 * d_v * p * p;
+                let ddu = 2.0 * c_u + 6.0 * d_u * p;
+                let ddv = 2.0 * c_v + 6.0 * d_v * p;
+                let speed_sq = du * du + dv * dv;
+                if speed_sq < 1e-24 {
+                    return 0.0;
+                }
+
+                // κ = N / S^(3/2)，N = u'v'' - v'u''，S = u'² + v'²
+                // dκ/dp = (N' S - 1.5 N S') / S^(5/2)
+                let n = du * ddv - dv * ddu;
+                let dn = du * 6.0 * d_v - dv * 6.0 * d_u;
+                let d_speed_sq = 2.0 * (du * ddu + dv * ddv);
+                (dn * speed_sq - 1.5 * n * d_speed_sq) / speed_sq.powf(2.5) * dp_ds
+            }
+        }
+    }
+
     /// 直线几何
     fn eval_line(&self, ds: f64) -> PosHdg {
         PosHdg {
@@ -220,6 +322,239 @@ impl OdrRoadGeometry {
     }
 }
 
+/// 折线拟合（不暴露给 WASM）
+impl OdrRoadGeometry {
+    /// 用 line/arc/paramPoly3 几何段拟合一条平面折线
+    ///
+    /// 依次尝试直线、圆弧和三次参数多项式，误差超过 `tolerance` 时在中点处
+    /// 递归细分。每段都从上一段的终点位置和终点航向出发，因此分段之间位置和
+    /// 切线方向连续（G1），返回的几何段 s 从 0 开始累计。
+    ///
+    /// # 参数
+    /// - `points`: 折线顶点（仅使用 x/y）
+    /// - `tolerance`: 允许的最大横向误差（米）
+    pub fn fit_polyline(points: &[Vec3], tolerance: f64) -> Vec<OdrRoadGeometry> {
+        if points.len() < 2 {
+            return Vec::new();
+        }
+
+        let headings = Self::estimate_headings(points);
+        let mut cursor = PosHdg {
+            x: points[0].x,
+            y: points[0].y,
+            hdg: headings[0],
+        };
+        let mut geometries = Vec::new();
+        Self::fit_range(
+            points,
+            &headings,
+            0,
+            points.len() - 1,
+            tolerance,
+            &mut cursor,
+            &mut geometries,
+        );
+
+        // 累计 s
+        let mut s = 0.0;
+        for geom in &mut geometries {
+            geom.s = s;
+            s += geom.length;
+        }
+
+        geometries
+    }
+
+    /// 估计折线每个顶点处的切线方向
+    fn estimate_headings(points: &[Vec3]) -> Vec<f64> {
+        let n = points.len();
+        (0..n)
+            .map(|i| {
+                let (i0, i1) = match i {
+                    0 => (0, 1),
+                    _ if i == n - 1 => (n - 2, n - 1),
+                    _ => (i - 1, i + 1),
+                };
+                (points[i1].y - points[i0].y).atan2(points[i1].x - points[i0].x)
+            })
+            .collect()
+    }
+
+    /// 从 `cursor`（上一段的终点和终点航向）出发拟合 `points[start..=end]`
+    fn fit_range(
+        points: &[Vec3],
+        headings: &[f64],
+        start: usize,
+        end: usize,
+        tolerance: f64,
+        cursor: &mut PosHdg,
+        out: &mut Vec<OdrRoadGeometry>,
+    ) {
+        let mut range = points[start..=end].to_vec();
+        range[0] = Vec3::new(cursor.x, cursor.y, range[0].z);
+
+        let fitted = Self::try_fit_line(&range, cursor.hdg, tolerance)
+            .or_else(|| Self::try_fit_arc(&range, cursor.hdg, tolerance))
+            .or_else(|| Self::try_fit_param_poly3(&range, cursor.hdg, headings[end], tolerance));
+
+        let mut push = |geom: OdrRoadGeometry, cursor: &mut PosHdg| {
+            *cursor = geom.eval_at(geom.length);
+            out.push(geom);
+        };
+        match fitted {
+            Some(geom) => push(geom, cursor),
+            None if end - start <= 2 => {
+                // 点数过少无法继续细分，退化为逐段 Hermite 曲线
+                for i in start + 1..=end {
+                    let seg = [Vec3::new(cursor.x, cursor.y, 0.0), points[i]];
+                    if let Some(geom) =
+                        Self::try_fit_param_poly3(&seg, cursor.hdg, headings[i], f64::MAX)
+                    {
+                        push(geom, cursor);
+                    }
+                }
+            }
+            None => {
+                let mid = (start + end) / 2;
+                Self::fit_range(points, headings, start, mid, tolerance, cursor, out);
+                Self::fit_range(points, headings, mid, end, tolerance, cursor, out);
+            }
+        }
+    }
+
+    /// 尝试用从首点出发、航向为 `hdg` 的直线拟合
+    ///
+    /// 尾点到直线的横向误差同样不超过 `tolerance`，直线在尾点的投影处结束。
+    fn try_fit_line(points: &[Vec3], hdg: f64, tolerance: f64) -> Option<OdrRoadGeometry> {
+        let p0 = points.first()?;
+        let p1 = points.last()?;
+        let (sin_h, cos_h) = hdg.sin_cos();
+        let length = (p1.x - p0.x) * cos_h + (p1.y - p0.y) * sin_h;
+        if length < 1e-9 {
+            return None;
+        }
+
+        let fits = points.iter().all(|p| {
+            let u = (p.x - p0.x) * cos_h + (p.y - p0.y) * sin_h;
+            let v = -(p.x - p0.x) * sin_h + (p.y - p0.y) * cos_h;
+            v.abs() <= tolerance && u >= -tolerance && u <= length + tolerance
+        });
+
+        fits.then(|| Self::create_line(0.0, p0.x, p0.y, hdg, length))
+    }
+
+    /// 尝试用在首点与航向 `hdg` 相切、并经过尾点的圆弧拟合
+    fn try_fit_arc(points: &[Vec3], hdg: f64, tolerance: f64) -> Option<OdrRoadGeometry> {
+        if points.len() < 3 {
+            return None;
+        }
+
+        // 尾点在起点局部坐标系 (u, v) 下的位置决定唯一的相切圆
+        let p0 = points[0];
+        let p1 = points[points.len() - 1];
+        let (sin_h, cos_h) = hdg.sin_cos();
+        let u1 = (p1.x - p0.x) * cos_h + (p1.y - p0.y) * sin_h;
+        let v1 = -(p1.x - p0.x) * sin_h + (p1.y - p0.y) * cos_h;
+        let chord_sq = u1 * u1 + v1 * v1;
+        if chord_sq < 1e-18 {
+            return None;
+        }
+        let curvature = 2.0 * v1 / chord_sq;
+        if curvature.abs() < 1e-12 {
+            return None;
+        }
+
+        // 弦切角的两倍为圆心角，正值为左转（逆时针）
+        let sweep = 2.0 * v1.atan2(u1);
+        let arc = Self::create_arc(0.0, p0.x, p0.y, hdg, sweep / curvature, curvature);
+
+        let num_samples = (points.len() * 8).max(32);
+        let samples: Vec<Vec3> = (0..=num_samples)
+            .map(|i| {
+                let pos = arc.eval_at(arc.length * i as f64 / num_samples as f64);
+                Vec3::new(pos.x, pos.y, 0.0)
+            })
+            .collect();
+        let fits = points.iter().all(|p| {
+            crate::math::polyline::closest_point(&samples, p.x, p.y)
+                .is_some_and(|(_, _, dist)| dist <= tolerance)
+        });
+
+        fits.then_some(arc)
+    }
+
+    /// 尝试用首尾点位置和切线确定的 paramPoly3（pRange = normalized）拟合
+    fn try_fit_param_poly3(
+        points: &[Vec3],
+        hdg_start: f64,
+        hdg_end: f64,
+        tolerance: f64,
+    ) -> Option<OdrRoadGeometry> {
+        let p0 = points.first()?;
+        let p1 = points.last()?;
+        let chord = ((p1.x - p0.x).powi(2) + (p1.y - p0.y).powi(2)).sqrt();
+        if chord < 1e-9 {
+            return None;
+        }
+
+        // 终点和终点切线在起点局部坐标系 (u, v) 下的表示
+        let (sin_h, cos_h) = hdg_start.sin_cos();
+        let u1 = (p1.x - p0.x) * cos_h + (p1.y - p0.y) * sin_h;
+        let v1 = -(p1.x - p0.x) * sin_h + (p1.y - p0.y) * cos_h;
+        let dh = hdg_end - hdg_start;
+
+        // Hermite 插值，p ∈ [0, 1]，切线长度取弦长
+        let (mu0, mv0) = (chord, 0.0);
+        let (mu1, mv1) = (chord * dh.cos(), chord * dh.sin());
+        let c_u = 3.0 * u1 - 2.0 * mu0 - mu1;
+        let d_u = -2.0 * u1 + mu0 + mu1;
+        let c_v = 3.0 * v1 - 2.0 * mv0 - mv1;
+        let d_v = -2.0 * v1 + mv0 + mv1;
+
+        let eval = |p: f64| -> (f64, f64) {
+            let u = mu0 * p + c_u * p * p + d_u * p * p * p;
+            let v = mv0 * p + c_v * p * p + d_v * p * p * p;
+            (p0.x + u * cos_h - v * sin_h, p0.y + u * sin_h + v * cos_h)
+        };
+
+        // 采样曲线并计算弧长
+        let num_samples = (points.len() * 8).max(32);
+        let samples: Vec<Vec3> = (0..=num_samples)
+            .map(|i| {
+                let (x, y) = eval(i as f64 / num_samples as f64);
+                Vec3::new(x, y, 0.0)
+            })
+            .collect();
+
+        let fits = points.iter().all(|p| {
+            crate::math::polyline::closest_point(&samples, p.x, p.y)
+                .is_some_and(|(_, _, dist)| dist <= tolerance)
+        });
+        if !fits {
+            return None;
+        }
+
+        let length = crate::math::polyline::length(&samples);
+
+        Some(Self::create_param_poly3(
+            0.0,
+            p0.x,
+            p0.y,
+            hdg_start,
+            length,
+            0.0,
+            0.0,
+            mu0,
+            mv0,
+            c_u,
+            c_v,
+            d_u,
+            d_v,
+            OdrParamPoly3PRange::Normalized,
+        ))
+    }
+}
+
 #[wasm_bindgen]
 impl OdrRoadGeometry {
     #[wasm_bindgen(js_name = "createLine")]
@@ -341,3 +676,69 @@ impl OdrRoadGeometry {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    /// 20 m 直线、半径 30 m 左转 90° 圆弧、20 m 直线，以及之后的正弦 S 弯
+    fn test_polyline() -> Vec<Vec3> {
+        let mut points: Vec<Vec3> = (0..20).map(|i| Vec3::new(i as f64, 0.0, 0.0)).collect();
+        for i in 0..47 {
+            let a = i as f64 / 47.0 * FRAC_PI_2;
+            points.push(Vec3::new(20.0 + 30.0 * a.sin(), 30.0 - 30.0 * a.cos(), 0.0));
+        }
+        for i in 0..=20 {
+            points.push(Vec3::new(50.0, 30.0 + i as f64, 0.0));
+        }
+        for i in 1..=60 {
+            let y = 50.0 + i as f64;
+            points.push(Vec3::new(
+                50.0 + 4.0 * (y - 50.0) / 60.0 * (PI * (y - 50.0) / 20.0).sin(),
+                y,
+                0.0,
+            ));
+        }
+        points
+    }
+
+    #[test]
+    fn test_fit_polyline_heading_continuity() {
+        let points = test_polyline();
+        let tolerance = 0.05;
+        let geometries = OdrRoadGeometry::fit_polyline(&points, tolerance);
+        assert!(geometries.len() > 3);
+        assert!(
+            geometries
+                .iter()
+                .any(|g| matches!(g.kind, OdrRoadGeometryKind::Arc))
+        );
+
+        // 相邻几何段首尾位置和航向连续
+        for pair in geometries.windows(2) {
+            let end = pair[0].eval_at(pair[0].length);
+            let start = pair[1].eval_at(0.0);
+            assert!((end.x - start.x).hypot(end.y - start.y) < 1e-6);
+            let dh = (end.hdg - start.hdg + PI).rem_euclid(2.0 * PI) - PI;
+            assert!(dh.abs() < 1e-6, "heading jump {dh}");
+            assert!((pair[1].s - pair[0].s - pair[0].length).abs() < 1e-9);
+        }
+
+        // 拟合曲线与折线顶点的误差不超过容差
+        let samples: Vec<Vec3> = geometries
+            .iter()
+            .flat_map(|g| {
+                (0..=100).map(move |i| {
+                    let pos = g.eval_at(g.length * i as f64 / 100.0);
+                    Vec3::new(pos.x, pos.y, 0.0)
+                })
+            })
+            .collect();
+        for p in &points {
+            let (_, _, dist) = crate::math::polyline::closest_point(&samples, p.x, p.y).unwrap();
+            assert!(dist <= tolerance + 1e-3, "{p:?} {dist}");
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    math::{quat::Quat, vec3::Vec3},
+    odr::models::pose::OdrPose,
+};
+
+/// 路面上某点的局部坐标系
+///
+/// 由 `OdrRoad::eval_surface_frame` 计算。`tangent` 沿 s 正方向与路面相切，
+/// `lateral` 指向左侧与路面相切，`normal` 为路面法线（朝上），三者构成右手
+/// 单位正交基，`rotation` 将局部 x/y/z 轴旋转到这三个方向。
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OdrSurfaceFrame {
+    /// 路面点的惯性坐标（含 shape 高程偏移）
+    pub position: Vec3,
+    /// 局部坐标系相对惯性坐标系的旋转
+    pub rotation: Quat,
+    /// 航向角（弧度），即参考线切线方向
+    pub heading: f64,
+    /// 俯仰角（弧度），上坡为负，与 `Quat::from_hpr` 一致
+    pub pitch: f64,
+    /// 横滚角（弧度），左侧高于右侧为正
+    pub roll: f64,
+    /// 沿 s 方向的路面切线
+    pub tangent: Vec3,
+    /// 指向左侧的路面切线
+    pub lateral: Vec3,
+    /// 路面法线
+    pub normal: Vec3,
+    /// 该 t 处平行于参考线的曲线的水平曲率（1/米，向左弯为正）
+    pub curvature: f64,
+}
+
+#[wasm_bindgen]
+impl OdrSurfaceFrame {
+    /// 以路面点为原点、与路面贴合的位姿
+    pub fn pose(&self) -> OdrPose {
+        OdrPose::new(self.position, self.rotation)
+    }
+}
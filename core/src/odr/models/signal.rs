@@ -0,0 +1,133 @@
+use wasm_bindgen::prelude::*;
+
+use std::f64::consts::PI;
+
+use crate::odr::models::enums::OdrOrientation;
+use crate::odr::models::object::OdrLaneValidity;
+use crate::odr::models::pose::OdrPose;
+use crate::odr::models::road::OdrRoad;
+
+/// OpenDRIVE Signal
+///
+/// Signals 描述道路上的交通信号灯和交通标志，通过 country/type/subtype
+/// 组合来标识具体的信号种类。
+///
+/// ```xml
+/// <signal s="50.0" t="-4.0" id="1" dynamic="yes" orientation="+"
+///         zOffset="5.0" country="OpenDRIVE" type="1000001" subtype="-1" />
+/// ```
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct OdrSignal {
+    /// 数据库内的唯一 ID
+    #[wasm_bindgen(getter_with_clone)]
+    pub id: String,
+    /// 信号名称(可自由选择)
+    #[wasm_bindgen(getter_with_clone)]
+    pub name: Option<String>,
+    /// 信号原点的 s 坐标
+    pub s: f64,
+    /// 信号原点的 t 坐标
+    pub t: f64,
+    /// 相对于道路参考线高程的 z 偏移
+    #[wasm_bindgen(js_name = "zOffset")]
+    pub z_offset: f64,
+    /// 是否为动态信号(如信号灯)
+    pub dynamic: bool,
+    /// 信号有效的方向
+    #[wasm_bindgen(getter_with_clone)]
+    pub orientation: OdrOrientation,
+    /// 国家代码(ISO 3166-1)或 "OpenDRIVE"
+    #[wasm_bindgen(getter_with_clone)]
+    pub country: Option<String>,
+    /// 国家代码版本
+    #[wasm_bindgen(getter_with_clone, js_name = "countryRevision")]
+    pub country_revision: Option<String>,
+    /// 信号类型
+    #[wasm_bindgen(getter_with_clone, js_name = "signalType")]
+    pub signal_type: String,
+    /// 信号子类型
+    #[wasm_bindgen(getter_with_clone)]
+    pub subtype: String,
+    /// 信号数值(如限速值)
+    pub value: Option<f64>,
+    /// 数值单位
+    #[wasm_bindgen(getter_with_clone)]
+    pub unit: Option<String>,
+    /// 信号高度
+    pub height: Option<f64>,
+    /// 信号宽度
+    pub width: Option<f64>,
+    /// 信号上的附加文本
+    #[wasm_bindgen(getter_with_clone)]
+    pub text: Option<String>,
+    /// 相对于 orientation 的航向偏移
+    #[wasm_bindgen(js_name = "hOffset")]
+    pub h_offset: Option<f64>,
+    /// 相对于 x/y 平面的俯仰角
+    pub pitch: Option<f64>,
+    /// 相对于 x/y 平面的横滚角
+    pub roll: Option<f64>,
+    /// 车道有效性列表
+    #[wasm_bindgen(getter_with_clone)]
+    pub validity: Vec<OdrLaneValidity>,
+}
+
+#[wasm_bindgen]
+impl OdrSignal {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        id: String,
+        s: f64,
+        t: f64,
+        dynamic: bool,
+        orientation: OdrOrientation,
+        signal_type: String,
+        subtype: String,
+    ) -> Self {
+        Self {
+            id,
+            name: None,
+            s,
+            t,
+            z_offset: 0.0,
+            dynamic,
+            orientation,
+            country: None,
+            country_revision: None,
+            signal_type,
+            subtype,
+            value: None,
+            unit: None,
+            height: None,
+            width: None,
+            text: None,
+            h_offset: None,
+            pitch: None,
+            roll: None,
+            validity: Vec::new(),
+        }
+    }
+
+    /// 计算信号在惯性坐标系下的位姿
+    ///
+    /// 局部 x 轴指向有效行驶方向：`orientation` 为 "+" 时沿参考线，"-" 时
+    /// 反向，"none" 时沿参考线；再叠加 hOffset。pitch/roll 相对 x/y 平面。
+    #[wasm_bindgen(js_name = "worldPose")]
+    pub fn world_pose(&self, road: &OdrRoad) -> OdrPose {
+        let base = match self.orientation {
+            OdrOrientation::Negative => PI,
+            _ => 0.0,
+        };
+        OdrPose::on_road(
+            road,
+            self.s,
+            self.t,
+            self.z_offset,
+            base + self.h_offset.unwrap_or(0.0),
+            self.pitch.unwrap_or(0.0),
+            self.roll.unwrap_or(0.0),
+            false,
+        )
+    }
+}
@@ -2,9 +2,12 @@ use anyhow::{Context, Result};
 use quick_xml::Reader;
 use quick_xml::events::Event;
 
+use crate::odr::models::enums::OdrContactPoint;
 use crate::odr::models::junction::{
     OdrJunction, OdrJunctionType,
+    boundary::{OdrJunctionBoundary, OdrJunctionBoundarySegment},
     connection::OdrConnection,
+    elevation_grid::{OdrJunctionElevationGrid, OdrJunctionGridElevation},
     lane_link::OdrJunctionLaneLink,
     priority::OdrJunctionPriority,
 };
@@ -87,6 +90,12 @@ pub fn parse_junction(
                             let priority = parse_priority(reader, e, false)?;
                             junction.priorities.push(priority);
                         }
+                        b"boundary" => {
+                            junction.boundary = Some(parse_boundary(reader, false)?);
+                        }
+                        b"elevationGrid" => {
+                            junction.elevation_grid = Some(parse_elevation_grid(reader, e, false)?);
+                        }
                         _ => {
                             // 跳过其他子元素（如 controller 等）
                             reader
@@ -105,6 +114,12 @@ pub fn parse_junction(
                             let priority = parse_priority(reader, e, true)?;
                             junction.priorities.push(priority);
                         }
+                        b"boundary" => {
+                            junction.boundary = Some(parse_boundary(reader, true)?);
+                        }
+                        b"elevationGrid" => {
+                            junction.elevation_grid = Some(parse_elevation_grid(reader, e, true)?);
+                        }
                         _ => {}
                     }
                 }
@@ -300,6 +315,203 @@ fn parse_priority(
     Ok(OdrJunctionPriority::new(high, low))
 }
 
+/// 从 XML 元素解析 Boundary（OpenDRIVE 1.8）
+fn parse_boundary(reader: &mut Reader<&[u8]>, is_empty: bool) -> Result<OdrJunctionBoundary> {
+    let mut boundary = OdrJunctionBoundary::default();
+    if is_empty {
+        return Ok(boundary);
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if e.name().as_ref() == b"segment" {
+                    boundary.segments.push(parse_segment(e)?);
+                }
+                reader
+                    .read_to_end(e.name())
+                    .context("跳过 boundary 子元素错误")?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"segment" => {
+                boundary.segments.push(parse_segment(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"boundary" => {
+                break;
+            }
+            Ok(Event::Eof) => {
+                return Err(anyhow::anyhow!("在 boundary 中遇到意外的 EOF"));
+            }
+            Err(e) => {
+                return Err(anyhow::anyhow!("解析 boundary 子元素错误: {:?}", e));
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(boundary)
+}
+
+/// 从 XML 元素解析边界段 Segment
+fn parse_segment(element: &quick_xml::events::BytesStart) -> Result<OdrJunctionBoundarySegment> {
+    let mut segment_type: Option<String> = None;
+    let mut road_id: Option<String> = None;
+    let mut boundary_lane: Option<i32> = None;
+    let mut s_start: Option<f64> = None;
+    let mut s_end: Option<f64> = None;
+    let mut contact_point: Option<OdrContactPoint> = None;
+    let mut joint_lane_start: Option<i32> = None;
+    let mut joint_lane_end: Option<i32> = None;
+    let mut transition_length: Option<f64> = None;
+
+    // 解析属性
+    for attr in element.attributes() {
+        let attr = attr.context("读取 segment 属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析 segment 属性值错误")?;
+
+        match key {
+            b"type" => {
+                segment_type = Some(value.to_string());
+            }
+            b"roadId" => {
+                road_id = Some(value.to_string());
+            }
+            b"boundaryLane" => {
+                boundary_lane = value.parse().ok();
+            }
+            b"sStart" => {
+                s_start = value.parse().ok();
+            }
+            b"sEnd" => {
+                s_end = value.parse().ok();
+            }
+            b"contactPoint" => {
+                contact_point = match value.as_ref() {
+                    "start" => Some(OdrContactPoint::Start),
+                    "end" => Some(OdrContactPoint::End),
+                    _ => None,
+                };
+            }
+            b"jointLaneStart" => {
+                joint_lane_start = value.parse().ok();
+            }
+            b"jointLaneEnd" => {
+                joint_lane_end = value.parse().ok();
+            }
+            b"transitionLength" => {
+                transition_length = value.parse().ok();
+            }
+            _ => {}
+        }
+    }
+
+    let road_id = road_id.context("segment 缺少 roadId 属性")?;
+
+    match segment_type.as_deref() {
+        Some("lane") => Ok(OdrJunctionBoundarySegment::create_lane(
+            road_id,
+            boundary_lane.context("segment 缺少 boundaryLane 属性")?,
+            s_start.context("segment 缺少 sStart 属性")?,
+            s_end.context("segment 缺少 sEnd 属性")?,
+        )),
+        Some("joint") => Ok(OdrJunctionBoundarySegment::create_joint(
+            road_id,
+            contact_point.context("segment 缺少 contactPoint 属性")?,
+            joint_lane_start.context("segment 缺少 jointLaneStart 属性")?,
+            joint_lane_end.context("segment 缺少 jointLaneEnd 属性")?,
+            transition_length,
+        )),
+        Some(other) => Err(anyhow::anyhow!("未知的 segment 类型: {}", other)),
+        None => Err(anyhow::anyhow!("segment 缺少 type 属性")),
+    }
+}
+
+/// 从 XML 元素解析 ElevationGrid（OpenDRIVE 1.8）
+fn parse_elevation_grid(
+    reader: &mut Reader<&[u8]>,
+    element: &quick_xml::events::BytesStart,
+    is_empty: bool,
+) -> Result<OdrJunctionElevationGrid> {
+    let mut grid_spacing: Option<f64> = None;
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取 elevationGrid 属性错误")?;
+        let value = attr
+            .unescape_value()
+            .context("解析 elevationGrid 属性值错误")?;
+        if attr.key.as_ref() == b"gridSpacing" {
+            grid_spacing = value.parse().ok();
+        }
+    }
+
+    let grid_spacing = grid_spacing.context("elevationGrid 缺少 gridSpacing 属性")?;
+    let mut grid = OdrJunctionElevationGrid::new(grid_spacing, Vec::new());
+    if is_empty {
+        return Ok(grid);
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if e.name().as_ref() == b"elevation" {
+                    grid.elevations.push(parse_grid_elevation(e)?);
+                }
+                reader
+                    .read_to_end(e.name())
+                    .context("跳过 elevationGrid 子元素错误")?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"elevation" => {
+                grid.elevations.push(parse_grid_elevation(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"elevationGrid" => {
+                break;
+            }
+            Ok(Event::Eof) => {
+                return Err(anyhow::anyhow!("在 elevationGrid 中遇到意外的 EOF"));
+            }
+            Err(e) => {
+                return Err(anyhow::anyhow!("解析 elevationGrid 子元素错误: {:?}", e));
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(grid)
+}
+
+/// 从 XML 元素解析高程网格的一行
+fn parse_grid_elevation(
+    element: &quick_xml::events::BytesStart,
+) -> Result<OdrJunctionGridElevation> {
+    let mut elevation = OdrJunctionGridElevation::default();
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取 elevation 属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析 elevation 属性值错误")?;
+        let values = || {
+            value
+                .split_whitespace()
+                .map(|v| v.parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .context("解析 elevation 高程列表错误")
+        };
+
+        match key {
+            b"center" => elevation.center = values()?,
+            b"left" => elevation.left = values()?,
+            b"right" => elevation.right = values()?,
+            _ => {}
+        }
+    }
+
+    Ok(elevation)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -595,4 +807,87 @@ mod tests {
             buf.clear();
         }
     }
+
+    #[test]
+    fn test_parse_junction_boundary_and_elevation_grid() {
+        let xml = r#"
+        <junction id="8" type="default">
+            <connection id="0" incomingRoad="1" connectingRoad="3" contactPoint="start"/>
+            <boundary>
+                <segment type="lane" roadId="3" boundaryLane="-1" sStart="0.0" sEnd="12.5"/>
+                <segment type="joint" roadId="2" contactPoint="end" jointLaneStart="-2" jointLaneEnd="2" transitionLength="1.5"/>
+            </boundary>
+            <elevationGrid gridSpacing="2.0">
+                <elevation center="1.0" left="1.1 1.2" right="0.9"/>
+                <elevation center="2.0"/>
+            </elevationGrid>
+        </junction>
+        "#;
+        let mut reader = quick_xml::Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) if e.name().as_ref() == b"junction" => {
+                    let junction = parse_junction(&mut reader, &e, false).unwrap();
+                    assert_eq!(junction.connections.len(), 1);
+
+                    // 验证 boundary
+                    let segments = &junction.boundary.as_ref().unwrap().segments;
+                    assert_eq!(segments.len(), 2);
+                    assert_eq!(segments[0].road_id, "3");
+                    assert_eq!(segments[0].boundary_lane, Some(-1));
+                    assert_eq!(segments[0].s_end, Some(12.5));
+                    assert!(matches!(
+                        segments[1].contact_point,
+                        Some(OdrContactPoint::End)
+                    ));
+                    assert_eq!(segments[1].joint_lane_start, Some(-2));
+                    assert_eq!(segments[1].joint_lane_end, Some(2));
+                    assert_eq!(segments[1].transition_length, Some(1.5));
+
+                    // 验证 elevationGrid
+                    let grid = junction.elevation_grid.as_ref().unwrap();
+                    assert_eq!(grid.grid_spacing, 2.0);
+                    assert_eq!(grid.elevations.len(), 2);
+                    assert_eq!(grid.elevations[0].left, vec![1.1, 1.2]);
+                    assert_eq!(grid.elevations[0].right, vec![0.9]);
+                    assert_eq!(grid.elevations[1].center, vec![2.0]);
+                    assert!(grid.elevations[1].left.is_empty());
+
+                    break;
+                }
+                Ok(Event::Eof) => panic!("未找到 junction"),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    #[test]
+    fn test_parse_boundary_segment_missing_attribute() {
+        let xml = r#"
+        <junction id="9">
+            <boundary>
+                <segment type="lane" roadId="3" sStart="0.0" sEnd="1.0"/>
+            </boundary>
+        </junction>
+        "#;
+        let mut reader = quick_xml::Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) if e.name().as_ref() == b"junction" => {
+                    assert!(parse_junction(&mut reader, &e, false).is_err());
+                    break;
+                }
+                Ok(Event::Eof) => panic!("未找到 junction"),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
 }
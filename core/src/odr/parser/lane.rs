@@ -659,18 +659,7 @@ fn parse_road_mark(
                 };
             }
             b"color" => {
-                color = match value.as_ref() {
-                    "standard" => OdrRoadMarkColor::Standard,
-                    "white" => OdrRoadMarkColor::White,
-                    "yellow" => OdrRoadMarkColor::Yellow,
-                    "red" => OdrRoadMarkColor::Red,
-                    "blue" => OdrRoadMarkColor::Blue,
-                    "green" => OdrRoadMarkColor::Green,
-                    "black" => OdrRoadMarkColor::Black,
-                    "orange" => OdrRoadMarkColor::Orange,
-                    "violet" => OdrRoadMarkColor::Violet,
-                    _ => OdrRoadMarkColor::Standard,
-                };
+                color = parse_road_mark_color(&value);
             }
             b"width" => {
                 width = Some(value.parse().context("解析 width 错误")?);
@@ -756,6 +745,21 @@ fn parse_road_mark(
     })
 }
 
+/// 解析 roadMark 的 color 属性，未知取值按 standard 处理
+pub(crate) fn parse_road_mark_color(value: &str) -> OdrRoadMarkColor {
+    match value {
+        "white" => OdrRoadMarkColor::White,
+        "yellow" => OdrRoadMarkColor::Yellow,
+        "red" => OdrRoadMarkColor::Red,
+        "blue" => OdrRoadMarkColor::Blue,
+        "green" => OdrRoadMarkColor::Green,
+        "black" => OdrRoadMarkColor::Black,
+        "orange" => OdrRoadMarkColor::Orange,
+        "violet" => OdrRoadMarkColor::Violet,
+        _ => OdrRoadMarkColor::Standard,
+    }
+}
+
 /// 解析 roadMark > type 元素
 fn parse_road_mark_type(
     reader: &mut Reader<&[u8]>,
@@ -835,18 +839,7 @@ fn parse_road_mark_type_line(
                 t_offset = value.parse().context("解析 tOffset 错误")?;
             }
             b"color" => {
-                color = Some(match value.as_ref() {
-                    "standard" => OdrRoadMarkColor::Standard,
-                    "white" => OdrRoadMarkColor::White,
-                    "yellow" => OdrRoadMarkColor::Yellow,
-                    "red" => OdrRoadMarkColor::Red,
-                    "blue" => OdrRoadMarkColor::Blue,
-                    "green" => OdrRoadMarkColor::Green,
-                    "black" => OdrRoadMarkColor::Black,
-                    "orange" => OdrRoadMarkColor::Orange,
-                    "violet" => OdrRoadMarkColor::Violet,
-                    _ => OdrRoadMarkColor::Standard,
-                });
+                color = Some(parse_road_mark_color(&value));
             }
             b"rule" => {
                 rule = Some(match value.as_ref() {
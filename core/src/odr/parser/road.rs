@@ -2,7 +2,12 @@ use anyhow::{Context, Result};
 use quick_xml::Reader;
 use quick_xml::events::Event;
 
-use crate::odr::models::enums::{OdrContactPoint, OdrElementDir};
+use crate::odr::models::enums::{OdrContactPoint, OdrElementDir, OdrOrientation};
+use crate::odr::models::object::{
+    OdrBorder, OdrBorderType, OdrCornerLocal, OdrCornerReference, OdrCornerRoad, OdrLaneValidity,
+    OdrMarking, OdrObject, OdrObjectRepeat, OdrOutline, OdrOutlineFillType, OdrParkingSpace,
+    OdrParkingSpaceAccess, OdrRoadMarkColor, OdrRoadMarkWeight, OdrSideType,
+};
 use crate::odr::models::road::OdrRoad;
 use crate::odr::models::road::road_elevation::OdrRoadElevation;
 use crate::odr::models::road::road_geometry::{OdrParamPoly3PRange, OdrRoadGeometry};
@@ -11,6 +16,9 @@ use crate::odr::models::road::road_type::OdrRoadType;
 use crate::odr::models::road::shape::OdrShape;
 use crate::odr::models::road::superelevation::OdrSuperelevation;
 use crate::odr::models::road::traffic_rule::OdrTrafficRule;
+use crate::odr::models::signal::OdrSignal;
+
+use super::lane::parse_road_mark_color;
 
 /// 解析 Road 元素
 pub fn parse_road(
@@ -63,6 +71,8 @@ pub fn parse_road(
     let mut shapes: Vec<OdrShape> = Vec::new();
     let mut lane_sections = Vec::new();
     let mut lane_offsets = Vec::new();
+    let mut objects = Vec::new();
+    let mut signals = Vec::new();
 
     if !is_empty {
         let mut buf = Vec::new();
@@ -94,6 +104,12 @@ pub fn parse_road(
                         lane_sections = sections;
                         lane_offsets = offsets;
                     }
+                    b"objects" => {
+                        objects = parse_objects(reader)?;
+                    }
+                    b"signals" => {
+                        signals = parse_signals(reader)?;
+                    }
                     _ => {
                         // 忽略其他子元素
                         reader
@@ -119,7 +135,7 @@ pub fn parse_road(
         }
     }
 
-    Ok(OdrRoad::new(
+    let mut road = OdrRoad::new(
         id,
         length,
         junction,
@@ -134,7 +150,11 @@ pub fn parse_road(
         successor,
         lane_sections,
         lane_offsets,
-    ))
+    );
+    road.objects = objects;
+    road.signals = signals;
+
+    Ok(road)
 }
 
 /// 解析 link 元素，返回 (predecessor, successor)
@@ -667,3 +687,953 @@ fn parse_shape(element: &quick_xml::events::BytesStart) -> Result<OdrShape> {
 
     Ok(OdrShape::new(s, t, a, b, c, d))
 }
+
+/// 解析 objects 元素
+fn parse_objects(reader: &mut Reader<&[u8]>) -> Result<Vec<OdrObject>> {
+    let mut objects = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                b"object" => {
+                    objects.push(parse_object(reader, e, false)?);
+                }
+                _ => {
+                    // 忽略 objectReference、tunnel、bridge 等
+                    reader
+                        .read_to_end(e.name())
+                        .map_err(|e| anyhow::anyhow!("Error skipping tag: {:?}", e))?;
+                }
+            },
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"object" => {
+                objects.push(parse_object(reader, e, true)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"objects" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in objects")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing objects: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(objects)
+}
+
+/// 解析 object 元素
+fn parse_object(
+    reader: &mut Reader<&[u8]>,
+    element: &quick_xml::events::BytesStart,
+    is_empty: bool,
+) -> Result<OdrObject> {
+    let mut object = OdrObject::new(String::new(), 0.0, 0.0, 0.0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"id" => {
+                object.id = value.to_string();
+            }
+            b"name" => {
+                object.name = Some(value.to_string());
+            }
+            b"type" => {
+                object.object_type = Some(value.to_string());
+            }
+            b"subtype" => {
+                object.subtype = Some(value.to_string());
+            }
+            b"s" => {
+                object.s = value.parse().context("解析 s 错误")?;
+            }
+            b"t" => {
+                object.t = value.parse().context("解析 t 错误")?;
+            }
+            b"zOffset" => {
+                object.z_offset = value.parse().context("解析 zOffset 错误")?;
+            }
+            b"validLength" => {
+                object.valid_length = Some(value.parse().context("解析 validLength 错误")?);
+            }
+            b"orientation" => {
+                object.orientation = Some(parse_orientation(&value));
+            }
+            b"length" => {
+                object.length = Some(value.parse().context("解析 length 错误")?);
+            }
+            b"width" => {
+                object.width = Some(value.parse().context("解析 width 错误")?);
+            }
+            b"radius" => {
+                object.radius = Some(value.parse().context("解析 radius 错误")?);
+            }
+            b"height" => {
+                object.height = Some(value.parse().context("解析 height 错误")?);
+            }
+            b"hdg" => {
+                object.hdg = Some(value.parse().context("解析 hdg 错误")?);
+            }
+            b"pitch" => {
+                object.pitch = Some(value.parse().context("解析 pitch 错误")?);
+            }
+            b"roll" => {
+                object.roll = Some(value.parse().context("解析 roll 错误")?);
+            }
+            b"dynamic" => {
+                object.dynamic = Some(parse_bool(&value));
+            }
+            b"perpToRoad" => {
+                object.perp_to_road = Some(parse_bool(&value));
+            }
+            _ => {}
+        }
+    }
+
+    if is_empty {
+        return Ok(object);
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                b"outline" => {
+                    object.outlines.push(parse_outline(reader, e, false)?);
+                }
+                b"outlines" => {
+                    object.outlines.extend(parse_outlines(reader)?);
+                }
+                b"markings" => {
+                    object.markings = parse_markings(reader)?;
+                }
+                b"borders" => {
+                    object.borders = parse_borders(reader)?;
+                }
+                b"repeat" => {
+                    object.repeat.push(parse_object_repeat(e)?);
+                    reader
+                        .read_to_end(e.name())
+                        .map_err(|e| anyhow::anyhow!("Error skipping tag: {:?}", e))?;
+                }
+                _ => {
+                    reader
+                        .read_to_end(e.name())
+                        .map_err(|e| anyhow::anyhow!("Error skipping tag: {:?}", e))?;
+                }
+            },
+            Ok(Event::Empty(ref e)) => match e.name().as_ref() {
+                b"repeat" => {
+                    object.repeat.push(parse_object_repeat(e)?);
+                }
+                b"outline" => {
+                    object.outlines.push(parse_outline(reader, e, true)?);
+                }
+                b"validity" => {
+                    object.validity.push(parse_validity(e)?);
+                }
+                b"parkingSpace" => {
+                    object.parking_space = Some(parse_parking_space(e)?);
+                }
+                _ => {}
+            },
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"object" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in object")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing object: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(object)
+}
+
+/// 解析 repeat 元素
+fn parse_object_repeat(element: &quick_xml::events::BytesStart) -> Result<OdrObjectRepeat> {
+    let mut repeat = OdrObjectRepeat::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"s" => {
+                repeat.s = value.parse().context("解析 s 错误")?;
+            }
+            b"length" => {
+                repeat.length = value.parse().context("解析 length 错误")?;
+            }
+            b"distance" => {
+                repeat.distance = value.parse().context("解析 distance 错误")?;
+            }
+            b"tStart" => {
+                repeat.t_start = value.parse().context("解析 tStart 错误")?;
+            }
+            b"tEnd" => {
+                repeat.t_end = value.parse().context("解析 tEnd 错误")?;
+            }
+            b"heightStart" => {
+                repeat.height_start = value.parse().context("解析 heightStart 错误")?;
+            }
+            b"heightEnd" => {
+                repeat.height_end = value.parse().context("解析 heightEnd 错误")?;
+            }
+            b"zOffsetStart" => {
+                repeat.z_offset_start = value.parse().context("解析 zOffsetStart 错误")?;
+            }
+            b"zOffsetEnd" => {
+                repeat.z_offset_end = value.parse().context("解析 zOffsetEnd 错误")?;
+            }
+            b"lengthStart" => {
+                repeat.length_start = Some(value.parse().context("解析 lengthStart 错误")?);
+            }
+            b"lengthEnd" => {
+                repeat.length_end = Some(value.parse().context("解析 lengthEnd 错误")?);
+            }
+            b"widthStart" => {
+                repeat.width_start = Some(value.parse().context("解析 widthStart 错误")?);
+            }
+            b"widthEnd" => {
+                repeat.width_end = Some(value.parse().context("解析 widthEnd 错误")?);
+            }
+            b"radiusStart" => {
+                repeat.radius_start = Some(value.parse().context("解析 radiusStart 错误")?);
+            }
+            b"radiusEnd" => {
+                repeat.radius_end = Some(value.parse().context("解析 radiusEnd 错误")?);
+            }
+            b"detachFromReferenceLine" => {
+                repeat.detach_from_reference_line = Some(parse_bool(&value));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(repeat)
+}
+
+/// 解析 outlines 元素
+fn parse_outlines(reader: &mut Reader<&[u8]>) -> Result<Vec<OdrOutline>> {
+    let mut outlines = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"outline" => {
+                outlines.push(parse_outline(reader, e, false)?);
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"outline" => {
+                outlines.push(parse_outline(reader, e, true)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"outlines" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in outlines")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing outlines: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(outlines)
+}
+
+/// 解析 outline 元素
+fn parse_outline(
+    reader: &mut Reader<&[u8]>,
+    element: &quick_xml::events::BytesStart,
+    is_empty: bool,
+) -> Result<OdrOutline> {
+    let mut outline = OdrOutline::new();
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"id" => {
+                outline.id = Some(value.parse().context("解析 id 错误")?);
+            }
+            b"closed" => {
+                outline.closed = Some(parse_bool(&value));
+            }
+            b"outer" => {
+                outline.outer = Some(parse_bool(&value));
+            }
+            b"fillType" => {
+                outline.fill_type = match value.as_ref() {
+                    "grass" => Some(OdrOutlineFillType::Grass),
+                    "concrete" => Some(OdrOutlineFillType::Concrete),
+                    "cobble" => Some(OdrOutlineFillType::Cobble),
+                    "asphalt" => Some(OdrOutlineFillType::Asphalt),
+                    "pavement" => Some(OdrOutlineFillType::Pavement),
+                    "gravel" => Some(OdrOutlineFillType::Gravel),
+                    "soil" => Some(OdrOutlineFillType::Soil),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    if is_empty {
+        return Ok(outline);
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                b"cornerRoad" => {
+                    outline.corner_road.push(parse_corner_road(e)?);
+                }
+                b"cornerLocal" => {
+                    outline.corner_local.push(parse_corner_local(e)?);
+                }
+                _ => {}
+            },
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"outline" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in outline")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing outline: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(outline)
+}
+
+/// 解析 cornerRoad 元素
+fn parse_corner_road(element: &quick_xml::events::BytesStart) -> Result<OdrCornerRoad> {
+    let mut corner = OdrCornerRoad::new(0.0, 0.0, 0.0, 0.0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"id" => {
+                corner.id = Some(value.parse().context("解析 id 错误")?);
+            }
+            b"s" => {
+                corner.s = value.parse().context("解析 s 错误")?;
+            }
+            b"t" => {
+                corner.t = value.parse().context("解析 t 错误")?;
+            }
+            b"dz" => {
+                corner.dz = value.parse().context("解析 dz 错误")?;
+            }
+            b"height" => {
+                corner.height = value.parse().context("解析 height 错误")?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(corner)
+}
+
+/// 解析 cornerLocal 元素
+fn parse_corner_local(element: &quick_xml::events::BytesStart) -> Result<OdrCornerLocal> {
+    let mut corner = OdrCornerLocal::new(0.0, 0.0, 0.0, 0.0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"id" => {
+                corner.id = Some(value.parse().context("解析 id 错误")?);
+            }
+            b"u" => {
+                corner.u = value.parse().context("解析 u 错误")?;
+            }
+            b"v" => {
+                corner.v = value.parse().context("解析 v 错误")?;
+            }
+            b"z" => {
+                corner.z = value.parse().context("解析 z 错误")?;
+            }
+            b"height" => {
+                corner.height = value.parse().context("解析 height 错误")?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(corner)
+}
+
+/// 解析 markings 元素
+fn parse_markings(reader: &mut Reader<&[u8]>) -> Result<Vec<OdrMarking>> {
+    let mut markings = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"marking" => {
+                let mut marking = parse_marking(e)?;
+                marking.corner_references = parse_corner_references(reader, b"marking")?;
+                markings.push(marking);
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"marking" => {
+                markings.push(parse_marking(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"markings" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in markings")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing markings: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(markings)
+}
+
+/// 解析 marking 元素属性
+fn parse_marking(element: &quick_xml::events::BytesStart) -> Result<OdrMarking> {
+    let mut marking = OdrMarking::new(
+        OdrRoadMarkColor::Standard,
+        0.0,
+        0.0,
+        OdrSideType::Front,
+        0.0,
+        0.0,
+    );
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"color" => {
+                marking.color = parse_road_mark_color(&value);
+            }
+            b"lineLength" => {
+                marking.line_length = value.parse().context("解析 lineLength 错误")?;
+            }
+            b"spaceLength" => {
+                marking.space_length = value.parse().context("解析 spaceLength 错误")?;
+            }
+            b"width" => {
+                marking.width = Some(value.parse().context("解析 width 错误")?);
+            }
+            b"weight" => {
+                marking.weight = Some(match value.as_ref() {
+                    "bold" => OdrRoadMarkWeight::Bold,
+                    _ => OdrRoadMarkWeight::Standard,
+                });
+            }
+            b"zOffset" => {
+                marking.z_offset = Some(value.parse().context("解析 zOffset 错误")?);
+            }
+            b"side" => {
+                marking.side = match value.as_ref() {
+                    "left" => OdrSideType::Left,
+                    "right" => OdrSideType::Right,
+                    "rear" => OdrSideType::Rear,
+                    _ => OdrSideType::Front,
+                };
+            }
+            b"startOffset" => {
+                marking.start_offset = value.parse().context("解析 startOffset 错误")?;
+            }
+            b"stopOffset" => {
+                marking.stop_offset = value.parse().context("解析 stopOffset 错误")?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(marking)
+}
+
+/// 解析 borders 元素
+fn parse_borders(reader: &mut Reader<&[u8]>) -> Result<Vec<OdrBorder>> {
+    let mut borders = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"border" => {
+                let mut border = parse_border(e)?;
+                border.corner_references = parse_corner_references(reader, b"border")?;
+                borders.push(border);
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"border" => {
+                borders.push(parse_border(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"borders" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in borders")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing borders: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(borders)
+}
+
+/// 解析 border 元素属性
+fn parse_border(element: &quick_xml::events::BytesStart) -> Result<OdrBorder> {
+    let mut border = OdrBorder::new(0, OdrBorderType::Concrete, 0.0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"outlineId" => {
+                border.outline_id = value.parse().context("解析 outlineId 错误")?;
+            }
+            b"type" => {
+                border.border_type = match value.as_ref() {
+                    "curb" => OdrBorderType::Curb,
+                    _ => OdrBorderType::Concrete,
+                };
+            }
+            b"width" => {
+                border.width = value.parse().context("解析 width 错误")?;
+            }
+            b"useCompleteOutline" => {
+                border.use_complete_outline = Some(parse_bool(&value));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(border)
+}
+
+/// 解析 marking/border 下的 cornerReference 列表，直到遇到 `end_tag` 结束标签
+fn parse_corner_references(
+    reader: &mut Reader<&[u8]>,
+    end_tag: &[u8],
+) -> Result<Vec<OdrCornerReference>> {
+    let mut references = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e))
+                if e.name().as_ref() == b"cornerReference" =>
+            {
+                for attr in e.attributes() {
+                    let attr = attr.context("读取属性错误")?;
+                    if attr.key.as_ref() == b"id" {
+                        let value = attr.unescape_value().context("解析属性值错误")?;
+                        references.push(OdrCornerReference::new(
+                            value.parse().context("解析 cornerReference id 错误")?,
+                        ));
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == end_tag => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in cornerReference")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing cornerReference: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(references)
+}
+
+/// 解析 validity 元素
+fn parse_validity(element: &quick_xml::events::BytesStart) -> Result<OdrLaneValidity> {
+    let mut validity = OdrLaneValidity::new(0, 0);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"fromLane" => {
+                validity.from_lane = value.parse().context("解析 fromLane 错误")?;
+            }
+            b"toLane" => {
+                validity.to_lane = value.parse().context("解析 toLane 错误")?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(validity)
+}
+
+/// 解析 parkingSpace 元素
+fn parse_parking_space(element: &quick_xml::events::BytesStart) -> Result<OdrParkingSpace> {
+    let mut parking_space = OdrParkingSpace::new(OdrParkingSpaceAccess::All);
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"access" => {
+                parking_space.access = match value.as_ref() {
+                    "car" => OdrParkingSpaceAccess::Car,
+                    "women" => OdrParkingSpaceAccess::Women,
+                    "handicapped" => OdrParkingSpaceAccess::Handicapped,
+                    "bus" => OdrParkingSpaceAccess::Bus,
+                    "truck" => OdrParkingSpaceAccess::Truck,
+                    "electric" => OdrParkingSpaceAccess::Electric,
+                    "residents" => OdrParkingSpaceAccess::Residents,
+                    _ => OdrParkingSpaceAccess::All,
+                };
+            }
+            b"restrictions" => {
+                parking_space.restrictions = Some(value.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(parking_space)
+}
+
+/// 解析 signals 元素
+fn parse_signals(reader: &mut Reader<&[u8]>) -> Result<Vec<OdrSignal>> {
+    let mut signals = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                b"signal" => {
+                    signals.push(parse_signal(reader, e, false)?);
+                }
+                _ => {
+                    // 忽略 signalReference 等
+                    reader
+                        .read_to_end(e.name())
+                        .map_err(|e| anyhow::anyhow!("Error skipping tag: {:?}", e))?;
+                }
+            },
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"signal" => {
+                signals.push(parse_signal(reader, e, true)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"signals" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in signals")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing signals: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(signals)
+}
+
+/// 解析 signal 元素
+fn parse_signal(
+    reader: &mut Reader<&[u8]>,
+    element: &quick_xml::events::BytesStart,
+    is_empty: bool,
+) -> Result<OdrSignal> {
+    let mut signal = OdrSignal::new(
+        String::new(),
+        0.0,
+        0.0,
+        false,
+        OdrOrientation::None,
+        String::new(),
+        String::new(),
+    );
+
+    for attr in element.attributes() {
+        let attr = attr.context("读取属性错误")?;
+        let key = attr.key.as_ref();
+        let value = attr.unescape_value().context("解析属性值错误")?;
+
+        match key {
+            b"id" => {
+                signal.id = value.to_string();
+            }
+            b"name" => {
+                signal.name = Some(value.to_string());
+            }
+            b"s" => {
+                signal.s = value.parse().context("解析 s 错误")?;
+            }
+            b"t" => {
+                signal.t = value.parse().context("解析 t 错误")?;
+            }
+            b"zOffset" => {
+                signal.z_offset = value.parse().context("解析 zOffset 错误")?;
+            }
+            b"dynamic" => {
+                signal.dynamic = parse_bool(&value);
+            }
+            b"orientation" => {
+                signal.orientation = parse_orientation(&value);
+            }
+            b"country" => {
+                signal.country = Some(value.to_string());
+            }
+            b"countryRevision" => {
+                signal.country_revision = Some(value.to_string());
+            }
+            b"type" => {
+                signal.signal_type = value.to_string();
+            }
+            b"subtype" => {
+                signal.subtype = value.to_string();
+            }
+            b"value" => {
+                signal.value = Some(value.parse().context("解析 value 错误")?);
+            }
+            b"unit" => {
+                signal.unit = Some(value.to_string());
+            }
+            b"height" => {
+                signal.height = Some(value.parse().context("解析 height 错误")?);
+            }
+            b"width" => {
+                signal.width = Some(value.parse().context("解析 width 错误")?);
+            }
+            b"text" => {
+                signal.text = Some(value.to_string());
+            }
+            b"hOffset" => {
+                signal.h_offset = Some(value.parse().context("解析 hOffset 错误")?);
+            }
+            b"pitch" => {
+                signal.pitch = Some(value.parse().context("解析 pitch 错误")?);
+            }
+            b"roll" => {
+                signal.roll = Some(value.parse().context("解析 roll 错误")?);
+            }
+            _ => {}
+        }
+    }
+
+    if is_empty {
+        return Ok(signal);
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if e.name().as_ref() == b"validity" {
+                    signal.validity.push(parse_validity(e)?);
+                }
+                reader
+                    .read_to_end(e.name())
+                    .map_err(|e| anyhow::anyhow!("Error skipping tag: {:?}", e))?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"validity" => {
+                signal.validity.push(parse_validity(e)?);
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"signal" => {
+                break;
+            }
+            Ok(Event::Eof) => return Err(anyhow::anyhow!("Unexpected EOF in signal")),
+            Err(e) => return Err(anyhow::anyhow!("Error parsing signal: {:?}", e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(signal)
+}
+
+/// 解析 orientation 属性（"+"、"-"、"none"）
+fn parse_orientation(value: &str) -> OdrOrientation {
+    match value {
+        "+" => OdrOrientation::Positive,
+        "-" => OdrOrientation::Negative,
+        _ => OdrOrientation::None,
+    }
+}
+
+/// 解析布尔属性，兼容 OpenDRIVE 1.4 的 "yes"/"no" 写法
+fn parse_bool(value: &str) -> bool {
+    matches!(value, "true" | "yes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROAD_XML: &str = r#"
+    <road id="1" length="100" junction="-1">
+        <planView>
+            <geometry s="0" x="0" y="0" hdg="0" length="100"><line/></geometry>
+        </planView>
+        <lanes>
+            <laneSection s="0">
+                <center><lane id="0" type="none"><link/></lane></center>
+                <right><lane id="-1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane></right>
+            </laneSection>
+        </lanes>
+        <objects>
+            <object id="10" type="crosswalk" name="cw" s="20" t="-1.75" zOffset="0" hdg="0.1" orientation="none" length="4" width="3.5" height="0" perpToRoad="true">
+                <repeat s="20" length="10" distance="5" tStart="-1" tEnd="-2" heightStart="0" heightEnd="0" zOffsetStart="0" zOffsetEnd="0" widthStart="1" detachFromReferenceLine="false"/>
+                <outlines>
+                    <outline id="0" fillType="pavement" outer="true" closed="true">
+                        <cornerRoad id="0" s="20" t="0" dz="0" height="0"/>
+                        <cornerRoad id="1" s="24" t="0" dz="0" height="0"/>
+                        <cornerRoad id="2" s="24" t="-3.5" dz="0" height="0"/>
+                    </outline>
+                </outlines>
+                <outline id="1" closed="false">
+                    <cornerLocal id="0" u="0" v="0" z="0" height="1.2"/>
+                    <cornerLocal id="1" u="1" v="0" z="0.1" height="1.2"/>
+                </outline>
+                <markings>
+                    <marking side="left" color="white" width="0.3" zOffset="0.005" spaceLength="0.5" lineLength="0.5" startOffset="0" stopOffset="0">
+                        <cornerReference id="0"/>
+                        <cornerReference id="1"/>
+                    </marking>
+                </markings>
+                <borders>
+                    <border outlineId="0" type="curb" width="0.2" useCompleteOutline="true"/>
+                </borders>
+                <validity fromLane="-1" toLane="-1"/>
+                <parkingSpace access="handicapped" restrictions="none"/>
+            </object>
+            <object id="11" type="pole" s="50" t="5" zOffset="0" radius="0.1" height="3" dynamic="no"/>
+            <objectReference id="10" s="30" t="0" orientation="+"/>
+        </objects>
+        <signals>
+            <signal id="20" name="stop" s="60" t="-4" zOffset="2" dynamic="no" orientation="-" country="DE" type="206" subtype="-1" value="50" unit="km/h" height="0.8" width="0.8" hOffset="0.2">
+                <validity fromLane="-2" toLane="-1"/>
+                <dependency id="21" type="light"/>
+            </signal>
+            <signal id="21" s="70" t="4" dynamic="yes" orientation="+" type="1000001" subtype="-1"/>
+            <signalReference id="20" s="80" t="0" orientation="+"/>
+        </signals>
+    </road>
+    "#;
+
+    fn parse_test_road() -> OdrRoad {
+        let mut reader = Reader::from_str(ROAD_XML);
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) => {
+                    let e = e.into_owned();
+                    return parse_road(&mut reader, &e, false).unwrap();
+                }
+                Ok(Event::Eof) => panic!("无法解析 XML 事件"),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    #[test]
+    fn test_parse_objects() {
+        let road = parse_test_road();
+        assert_eq!(road.lanes.len(), 1);
+        assert_eq!(road.objects.len(), 2);
+
+        let object = &road.objects[0];
+        assert_eq!(object.id, "10");
+        assert_eq!(object.object_type.as_deref(), Some("crosswalk"));
+        assert_eq!(object.s, 20.0);
+        assert_eq!(object.t, -1.75);
+        assert_eq!(object.hdg, Some(0.1));
+        assert_eq!(object.perp_to_road, Some(true));
+        assert!(matches!(object.orientation, Some(OdrOrientation::None)));
+
+        assert_eq!(object.repeat.len(), 1);
+        assert_eq!(object.repeat[0].distance, 5.0);
+        assert_eq!(object.repeat[0].width_start, Some(1.0));
+        assert_eq!(object.repeat[0].detach_from_reference_line, Some(false));
+
+        // <outlines> 下的与直接位于 <object> 下的 outline 都会被收集
+        assert_eq!(object.outlines.len(), 2);
+        let outline = &object.outlines[0];
+        assert_eq!(outline.id, Some(0));
+        assert!(matches!(
+            outline.fill_type,
+            Some(OdrOutlineFillType::Pavement)
+        ));
+        assert_eq!(outline.corner_road.len(), 3);
+        assert_eq!(outline.corner_road[2].s, 24.0);
+        assert_eq!(outline.corner_road[2].t, -3.5);
+        let outline = &object.outlines[1];
+        assert_eq!(outline.closed, Some(false));
+        assert_eq!(outline.corner_local.len(), 2);
+        assert_eq!(outline.corner_local[1].z, 0.1);
+        assert_eq!(outline.corner_local[1].height, 1.2);
+
+        assert_eq!(object.markings.len(), 1);
+        let marking = &object.markings[0];
+        assert!(matches!(marking.side, OdrSideType::Left));
+        assert!(matches!(marking.color, OdrRoadMarkColor::White));
+        assert_eq!(marking.width, Some(0.3));
+        assert_eq!(marking.corner_references.len(), 2);
+        assert_eq!(marking.corner_references[1].id, 1);
+
+        assert_eq!(object.borders.len(), 1);
+        assert!(matches!(object.borders[0].border_type, OdrBorderType::Curb));
+        assert_eq!(object.borders[0].use_complete_outline, Some(true));
+
+        assert_eq!(object.validity.len(), 1);
+        assert_eq!(object.validity[0].from_lane, -1);
+        assert!(matches!(
+            object.parking_space.as_ref().map(|p| &p.access),
+            Some(OdrParkingSpaceAccess::Handicapped)
+        ));
+
+        let pole = &road.objects[1];
+        assert_eq!(pole.radius, Some(0.1));
+        assert_eq!(pole.height, Some(3.0));
+        assert_eq!(pole.dynamic, Some(false));
+    }
+
+    #[test]
+    fn test_parse_signals() {
+        let road = parse_test_road();
+        assert_eq!(road.signals.len(), 2);
+
+        let signal = &road.signals[0];
+        assert_eq!(signal.id, "20");
+        assert_eq!(signal.name.as_deref(), Some("stop"));
+        assert_eq!(signal.s, 60.0);
+        assert_eq!(signal.z_offset, 2.0);
+        assert!(!signal.dynamic);
+        assert!(matches!(signal.orientation, OdrOrientation::Negative));
+        assert_eq!(signal.country.as_deref(), Some("DE"));
+        assert_eq!(signal.signal_type, "206");
+        assert_eq!(signal.value, Some(50.0));
+        assert_eq!(signal.unit.as_deref(), Some("km/h"));
+        assert_eq!(signal.h_offset, Some(0.2));
+        assert_eq!(signal.validity.len(), 1);
+        assert_eq!(signal.validity[0].from_lane, -2);
+
+        let signal = &road.signals[1];
+        assert!(signal.dynamic);
+        assert!(matches!(signal.orientation, OdrOrientation::Positive));
+    }
+}
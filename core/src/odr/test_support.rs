@@ -0,0 +1,115 @@
+//! 单元测试共用的 OpenDRIVE 构造函数
+
+use crate::odr::models::{
+    enums::{OdrContactPoint, OdrRoadMarkColor},
+    header::OdrHeader,
+    lane::{
+        OdrLane,
+        lane_geometry::OdrLaneWidth,
+        lane_link::OdrLaneLink,
+        lane_road_mark::{OdrRoadMark, OdrRoadMarkType},
+        lane_section::OdrLaneSection,
+    },
+    road::{
+        OdrRoad,
+        road_geometry::OdrRoadGeometry,
+        road_link::{OdrRoadLink, OdrRoadLinkElementType},
+    },
+};
+
+/// 等宽行车道，带一条从车道段起点开始的白色标线
+pub(crate) fn lane(id: i32, width: f64, mark_type: OdrRoadMarkType) -> OdrLane {
+    OdrLane {
+        road_marks: vec![road_mark(mark_type)],
+        ..plain_lane(id, width)
+    }
+}
+
+/// 等宽行车道，不带标线
+pub(crate) fn plain_lane(id: i32, width: f64) -> OdrLane {
+    OdrLane::new(
+        id,
+        "driving".to_string(),
+        None,
+        None,
+        OdrLaneLink::new(None, None),
+        vec![OdrLaneWidth::new(0.0, width, 0.0, 0.0, 0.0)],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// sOffset 为 0 的白色标线，宽度等属性未指定
+pub(crate) fn road_mark(mark_type: OdrRoadMarkType) -> OdrRoadMark {
+    OdrRoadMark {
+        s_offset: 0.0,
+        mark_type,
+        color: OdrRoadMarkColor::White,
+        width: None,
+        height: None,
+        material: None,
+        weight: None,
+        lane_change: None,
+        type_detail: None,
+        explicit: None,
+        sways: Vec::new(),
+    }
+}
+
+/// 单段参考线的普通道路（不属于路口），长度取几何长度
+pub(crate) fn road(id: &str, geometry: OdrRoadGeometry, lanes: Vec<OdrLaneSection>) -> OdrRoad {
+    OdrRoad::new(
+        id.to_string(),
+        geometry.length,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![geometry]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        lanes,
+        Vec::new(),
+    )
+}
+
+/// 沿 x 轴方向、起点为 (x, y) 的直线道路
+pub(crate) fn line_road(
+    id: &str,
+    x: f64,
+    y: f64,
+    length: f64,
+    lanes: Vec<OdrLaneSection>,
+) -> OdrRoad {
+    road(
+        id,
+        OdrRoadGeometry::create_line(0.0, x, y, 0.0, length),
+        lanes,
+    )
+}
+
+/// 指向道路的连接
+pub(crate) fn road_link(id: &str, contact_point: OdrContactPoint) -> Option<OdrRoadLink> {
+    Some(OdrRoadLink {
+        element_id: id.to_string(),
+        element_type: OdrRoadLinkElementType::Road,
+        contact_point: Some(contact_point),
+        element_dir: None,
+        element_s: None,
+    })
+}
+
+/// OpenDRIVE 1.6 文件头，其余属性均未指定
+pub(crate) fn header() -> OdrHeader {
+    OdrHeader::new(
+        1, 6, None, None, None, None, None, None, None, None, None, None,
+    )
+}
@@ -1,7 +1,9 @@
 use maphub_core::odr::models::{
     lane::{OdrLane, lane_link::OdrLaneLink, lane_section::OdrLaneSection},
     road::{
-        OdrRoad, road_elevation::OdrRoadElevation, road_geometry::OdrRoadGeometry,
+        OdrRoad,
+        road_elevation::OdrRoadElevation,
+        road_geometry::{OdrParamPoly3PRange, OdrRoadGeometry},
         superelevation::OdrSuperelevation,
     },
 };
@@ -639,3 +641,167 @@ fn test_sth_xyz_roundtrip_multiple_points() {
         );
     }
 }
+
+#[test]
+fn test_eval_slope_and_curvature() {
+    let road = create_test_road_with_elevation_and_superelevation();
+    // 直线道路曲率为 0，线性高程的坡度恒为 0.1
+    assert!(road.eval_curvature(30.0).abs() < 1e-12);
+    assert!((road.eval_slope(30.0) - 0.1).abs() < 1e-12);
+
+    // u = p, v = 0.05 * p²，p = 0 处曲率为 2 * 0.05
+    let road = OdrRoad::new(
+        "poly".to_string(),
+        10.0,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![OdrRoadGeometry::create_param_poly3(
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            10.0,
+            0.0,
+            0.0,
+            1.0,
+            0.0,
+            0.0,
+            0.05,
+            0.0,
+            0.0,
+            OdrParamPoly3PRange::ArcLength,
+        )]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        vec![create_test_lane_section(0.0)],
+        vec![],
+    );
+    assert!((road.eval_curvature(0.0) - 0.1).abs() < 1e-9);
+
+    // 螺旋线曲率线性变化
+    let road = OdrRoad::new(
+        "spiral".to_string(),
+        100.0,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![OdrRoadGeometry::create_spiral(
+            0.0, 0.0, 0.0, 0.0, 100.0, 0.0, 0.02,
+        )]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        vec![create_test_lane_section(0.0)],
+        vec![],
+    );
+    assert!((road.eval_curvature(50.0) - 0.01).abs() < 1e-12);
+}
+
+#[test]
+fn test_eval_curvature_rate() {
+    // 螺旋线曲率变化率为常数
+    let road = OdrRoad::new(
+        "spiral".to_string(),
+        100.0,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![OdrRoadGeometry::create_spiral(
+            0.0, 0.0, 0.0, 0.0, 100.0, 0.0, 0.02,
+        )]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        vec![create_test_lane_section(0.0)],
+        vec![],
+    );
+    assert!((road.eval_curvature_rate(50.0) - 0.0002).abs() < 1e-12);
+    assert!((road.eval_heading(100.0) - 1.0).abs() < 1e-9);
+
+    // paramPoly3 的解析导数与曲率的差分一致
+    let road = OdrRoad::new(
+        "poly".to_string(),
+        20.0,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![OdrRoadGeometry::create_param_poly3(
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            20.0,
+            0.0,
+            0.0,
+            20.0,
+            0.0,
+            -2.0,
+            3.0,
+            1.0,
+            -1.5,
+            OdrParamPoly3PRange::Normalized,
+        )]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        vec![create_test_lane_section(0.0)],
+        vec![],
+    );
+    for s in [2.0, 10.0, 17.0] {
+        let h = 1e-4;
+        let numeric = (road.eval_curvature(s + h) - road.eval_curvature(s - h)) / (2.0 * h);
+        assert!((road.eval_curvature_rate(s) - numeric).abs() < 1e-7);
+    }
+}
+
+#[test]
+fn test_eval_profile() {
+    // 高程 z = 0.001 * s²，超高 0.01 + 0.001 * s
+    let road = OdrRoad::new(
+        "profile".to_string(),
+        100.0,
+        "-1".to_string(),
+        None,
+        None,
+        None,
+        Some(vec![OdrRoadGeometry::create_line(
+            0.0, 0.0, 0.0, 0.5, 100.0,
+        )]),
+        Some(vec![OdrRoadElevation::new(0.0, 0.0, 0.0, 0.001, 0.0)]),
+        Some(vec![OdrSuperelevation::new(0.0, 0.01, 0.001, 0.0, 0.0)]),
+        None,
+        None,
+        None,
+        vec![create_test_lane_section(0.0)],
+        vec![],
+    );
+
+    assert!((road.eval_elevation_curvature(0.0) - 0.002).abs() < 1e-12);
+    assert!((road.eval_superelevation_rate(40.0) - 0.001).abs() < 1e-12);
+
+    let profile = road.eval_profile(vec![0.0, 50.0, 100.0]);
+    assert_eq!(profile.s, vec![0.0, 50.0, 100.0]);
+    assert_eq!(profile.heading, vec![0.5, 0.5, 0.5]);
+    assert_eq!(profile.curvature, vec![0.0, 0.0, 0.0]);
+    assert!((profile.elevation[1] - 2.5).abs() < 1e-12);
+    assert!((profile.slope[2] - 0.2).abs() < 1e-12);
+    // 坡度 0.1 处的竖曲率为 0.002 / (1 + 0.01)^(3/2)
+    assert!((profile.elevation_curvature[1] - 0.002 / 1.01_f64.powf(1.5)).abs() < 1e-12);
+    assert!((profile.superelevation[1] - 0.06).abs() < 1e-12);
+    assert!((profile.cross_fall[1] - 0.06_f64.tan()).abs() < 1e-12);
+    assert_eq!(profile.superelevation_rate.len(), 3);
+}
@@ -47,7 +47,7 @@ export function MenuBar() {
       <input
         ref={fileInputRef}
         type="file"
-        accept=".xodr,.xml,.bin"
+        accept=".xodr,.xml,.osm,.bin"
         multiple
         className="hidden"
         onChange={handleFileChange}
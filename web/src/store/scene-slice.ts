@@ -5,7 +5,7 @@ import { MapRenderer } from "@/viewer/types/renderer";
 import type { MapNode } from "@/viewer/types/map-node";
 import type { HoverInfo } from "@/viewer/types/format";
 import { Files, File } from "@maphub/core";
-import { formatRegistry, type MapFormatType } from "@/viewer/format";
+import { loadMap, type MapFormatType } from "@/viewer/format";
 import { exportGLB as exportToGLB } from "@/utils/three/export-glb";
 
 export interface SceneSlice {
@@ -73,22 +73,18 @@ export const createSceneSlice: StateCreator<SceneSlice, [], [], SceneSlice> = (s
     // 检测格式
     const format: MapFormatType = files.detectFormat() as MapFormatType;
 
-    // 获取格式处理器
-    const formatHandler = formatRegistry[format];
-    if (!formatHandler) {
+    // 解析文件并为每个根节点创建渲染器
+    const roots = loadMap(format, files);
+    if (!roots) {
       console.error(`Unsupported format: ${format}`);
       return;
     }
 
-    // 解析文件
-    const nodes = formatHandler.parse(files);
-
-    // 为每个根节点创建渲染器并添加到场景
+    // 将根渲染器添加到场景
     const newNodes: MapNode[] = [];
     const newRenderers: MapRenderer[] = [];
 
-    for (const node of nodes) {
-      const renderer = formatHandler.provideRenderer(node);
+    for (const [node, renderer] of roots) {
       viewportRenderer.scene.add(renderer);
       newNodes.push(node);
       newRenderers.push(renderer);
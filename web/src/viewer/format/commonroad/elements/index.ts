@@ -0,0 +1,34 @@
+import type { MapNode } from "@/viewer/types/map-node";
+import type {
+  CommonRoadLanelet,
+  CommonRoadMap,
+  Lanelet2Lanelet,
+  Lanelet2Map,
+} from "@maphub/core";
+
+export interface CommonRoadMapElement extends MapNode<"commonroad", "map"> {
+  commonroad: CommonRoadMap;
+  lanelet2: Lanelet2Map; // 转换后的 Lanelet2 模型，用于网格构建
+  children: CommonRoadLaneletsElement[];
+}
+
+export interface CommonRoadLaneletsElement extends MapNode<"commonroad", "lanelets"> {
+  commonroad: CommonRoadMap;
+  lanelet2: Lanelet2Map;
+  lanelets: CommonRoadLanelet[];
+  children: CommonRoadLaneletElement[];
+}
+
+export interface CommonRoadLaneletElement extends MapNode<"commonroad", "lanelet"> {
+  commonroad: CommonRoadMap;
+  lanelet2: Lanelet2Map;
+  lanelet: CommonRoadLanelet;
+  converted: Lanelet2Lanelet; // 同 ID 的 Lanelet2 车道单元
+  children: [];
+}
+
+// CommonRoad 所有节点类型的 discriminated union
+export type CommonRoadElement =
+  | CommonRoadMapElement
+  | CommonRoadLaneletsElement
+  | CommonRoadLaneletElement;
@@ -0,0 +1,149 @@
+import type { MapFormat } from "@/viewer/types/format";
+import type { MapNode } from "@/viewer/types/map-node";
+import type { MapRenderer } from "@/viewer/types/renderer";
+import {
+  CommonRoadMap,
+  parseCommonRoadFromFiles,
+  type CommonRoadLanelet,
+  type Files,
+  type Lanelet2Lanelet,
+  type Lanelet2Map,
+} from "@maphub/core";
+import type { Vector3 } from "three";
+import type {
+  CommonRoadElement,
+  CommonRoadMapElement,
+  CommonRoadLaneletsElement,
+  CommonRoadLaneletElement,
+} from "./elements";
+import { CommonRoadMapRenderer } from "./renderer";
+import { generateId, type Id } from "@/utils/id";
+import { provideHoverInfo, provideProperties, provideTreeInfo } from "./providers";
+/**
+ * CommonRoad 地图格式实现
+ *
+ * 渲染时先转换为 Lanelet2 模型，复用 Lanelet2 的网格构建器。
+ */
+export const CommonRoadFormat: MapFormat<"commonroad", CommonRoadElement, "map"> = {
+  format: "commonroad",
+  rootNodeType: "map",
+
+  /**
+   * 解析 CommonRoad 文件并生成节点树
+   */
+  parse(files: Files): CommonRoadMapElement[] {
+    console.time("parseCommonRoadFromFiles");
+    const maps: CommonRoadMap[] = parseCommonRoadFromFiles(files);
+    console.timeEnd("parseCommonRoadFromFiles");
+    return maps.map(buildMapElement);
+  },
+
+  provideRenderer(node: CommonRoadMapElement): MapRenderer<"commonroad", "map"> {
+    return new CommonRoadMapRenderer(node);
+  },
+
+  /**
+   * 提供鼠标悬停信息
+   */
+  provideHoverInfo(node: MapNode<"commonroad">, pos: Vector3) {
+    return provideHoverInfo(node as CommonRoadElement, pos);
+  },
+
+  /**
+   * 提供属性面板信息
+   */
+  provideProperties(node: MapNode<"commonroad">) {
+    return provideProperties(node as CommonRoadElement);
+  },
+
+  /**
+   * 提供场景树信息
+   */
+  provideTreeInfo(node: MapNode<"commonroad">) {
+    return provideTreeInfo(node as CommonRoadElement);
+  },
+};
+
+/**
+ * 构建单个车道单元的 Element
+ */
+function buildLaneletElement(
+  commonroad: CommonRoadMap,
+  lanelet2: Lanelet2Map,
+  parentId: Id,
+  lanelet: CommonRoadLanelet,
+  converted: Lanelet2Lanelet,
+): CommonRoadLaneletElement {
+  return {
+    id: generateId(),
+    parentId,
+    children: [],
+    name: `Lanelet ${lanelet.id}`,
+    visible: true,
+    format: "commonroad",
+    type: "lanelet",
+    commonroad,
+    lanelet2,
+    lanelet,
+    converted,
+  };
+}
+
+/**
+ * 构建 Lanelets 容器 Element
+ */
+function buildLaneletsElement(
+  commonroad: CommonRoadMap,
+  lanelet2: Lanelet2Map,
+  parentId: Id,
+): CommonRoadLaneletsElement {
+  const laneletsId = generateId();
+  const lanelets = commonroad.lanelets;
+
+  // 转换时保留 lanelet ID，按 ID 关联转换后的车道单元
+  const converted = new Map<bigint, Lanelet2Lanelet>();
+  for (const lanelet of lanelet2.lanelets) {
+    converted.set(lanelet.id, lanelet);
+  }
+
+  const laneletElements: CommonRoadLaneletElement[] = [];
+  for (const lanelet of lanelets) {
+    const target = converted.get(lanelet.id);
+    if (target) {
+      laneletElements.push(buildLaneletElement(commonroad, lanelet2, laneletsId, lanelet, target));
+    }
+  }
+
+  return {
+    id: laneletsId,
+    parentId,
+    children: laneletElements,
+    name: "Lanelets",
+    visible: true,
+    format: "commonroad",
+    type: "lanelets",
+    commonroad,
+    lanelet2,
+    lanelets,
+  };
+}
+
+/**
+ * 构建完整的 Map Element 树
+ */
+function buildMapElement(commonroad: CommonRoadMap): CommonRoadMapElement {
+  const mapId = generateId();
+  const lanelet2 = commonroad.toLanelet2();
+
+  return {
+    id: mapId,
+    parentId: null,
+    children: [buildLaneletsElement(commonroad, lanelet2, mapId)],
+    commonroad,
+    lanelet2,
+    name: commonroad.benchmarkId || "CommonRoad Map",
+    visible: true,
+    format: "commonroad",
+    type: "map",
+  };
+}
@@ -0,0 +1,50 @@
+import type { HoverInfo } from "@/viewer/types/format";
+import type { CommonRoadElement, CommonRoadLaneletElement } from "../elements";
+import type { Vector3 } from "three";
+import { threePositionToOdr } from "../../odr/math";
+
+/**
+ * 提供车道单元的 hover 信息
+ */
+export function provideLaneletHoverInfo(
+  element: CommonRoadLaneletElement,
+  pos: Vector3,
+): HoverInfo {
+  const { lanelet2, lanelet, converted } = element;
+
+  // 将世界坐标还原为地图局部坐标
+  const xyz = threePositionToOdr(pos, lanelet2.center);
+
+  return {
+    title: `Lanelet ${lanelet.id}`,
+    icon: null,
+    description: [],
+    items: [
+      {
+        label: "Types",
+        value: lanelet.laneletTypes.length > 0 ? lanelet.laneletTypes.join(", ") : "-",
+      },
+      ...(converted.speedLimit !== undefined
+        ? [{ label: "Speed Limit", value: `${converted.speedLimit.toFixed(0)} km/h` }]
+        : []),
+      { label: "Left Marking", value: lanelet.leftBound.lineMarking ?? "-" },
+      { label: "Right Marking", value: lanelet.rightBound.lineMarking ?? "-" },
+      {
+        label: "XYZ Coords",
+        value: `x: ${xyz.x.toFixed(2)}, y: ${xyz.y.toFixed(2)}, z: ${xyz.z.toFixed(2)}`,
+      },
+    ],
+  };
+}
+
+/**
+ * 提供 hover 信息的统一入口
+ */
+export function provideHoverInfo(element: CommonRoadElement, pos: Vector3): HoverInfo | null {
+  switch (element.type) {
+    case "lanelet":
+      return provideLaneletHoverInfo(element, pos);
+    default:
+      return null;
+  }
+}
@@ -0,0 +1,140 @@
+import type { PropertiyGroup, PropertyItem } from "@/viewer/types/format";
+import type {
+  CommonRoadElement,
+  CommonRoadLaneletElement,
+  CommonRoadLaneletsElement,
+  CommonRoadMapElement,
+} from "../elements";
+
+/**
+ * 将 ID 列表格式化为文本
+ */
+function formatIds(ids: BigInt64Array | bigint[]): string {
+  return ids.length > 0 ? Array.from(ids).join(", ") : "-";
+}
+
+/**
+ * 提供地图的属性面板信息
+ */
+export function provideMapProperties(element: CommonRoadMapElement): PropertiyGroup[] {
+  const { commonroad } = element;
+
+  const basicItems: PropertyItem[] = [];
+  if (commonroad.benchmarkId) {
+    basicItems.push({ label: "场景 ID", value: commonroad.benchmarkId });
+  }
+  if (commonroad.version) {
+    basicItems.push({ label: "版本", value: commonroad.version });
+  }
+  basicItems.push(
+    { label: "车道单元数量", value: String(commonroad.lanelets.length) },
+    { label: "交通标志数量", value: String(commonroad.trafficSigns.length) },
+    { label: "信号灯数量", value: String(commonroad.trafficLights.length) },
+    { label: "路口数量", value: String(commonroad.intersections.length) },
+  );
+
+  const groups: PropertiyGroup[] = [{ label: "基本信息", items: basicItems }];
+
+  // 地理位置
+  if (commonroad.latitude !== undefined && commonroad.longitude !== undefined) {
+    groups.push({
+      label: "地理位置",
+      items: [
+        { label: "纬度", value: commonroad.latitude.toFixed(6) },
+        { label: "经度", value: commonroad.longitude.toFixed(6) },
+      ],
+    });
+  }
+
+  return groups;
+}
+
+/**
+ * 提供车道单元集合的属性面板信息
+ */
+export function provideLaneletsProperties(element: CommonRoadLaneletsElement): PropertiyGroup[] {
+  return [
+    {
+      label: "基本信息",
+      items: [{ label: "车道单元数量", value: String(element.lanelets.length) }],
+    },
+  ];
+}
+
+/**
+ * 提供车道单元的属性面板信息
+ */
+export function provideLaneletProperties(element: CommonRoadLaneletElement): PropertiyGroup[] {
+  const { lanelet, converted } = element;
+
+  const basicItems: PropertyItem[] = [
+    { label: "ID", value: String(lanelet.id) },
+    {
+      label: "车道类型",
+      value: lanelet.laneletTypes.length > 0 ? lanelet.laneletTypes.join(", ") : "-",
+    },
+  ];
+  if (lanelet.userOneWay.length > 0) {
+    basicItems.push({ label: "单向通行", value: lanelet.userOneWay.join(", ") });
+  }
+  if (lanelet.userBidirectional.length > 0) {
+    basicItems.push({ label: "双向通行", value: lanelet.userBidirectional.join(", ") });
+  }
+  if (converted.speedLimit !== undefined) {
+    basicItems.push({ label: "限速", value: `${converted.speedLimit.toFixed(0)} km/h` });
+  }
+
+  const topologyItems: PropertyItem[] = [
+    { label: "前驱", value: formatIds(lanelet.predecessors) },
+    { label: "后继", value: formatIds(lanelet.successors) },
+  ];
+  if (lanelet.adjacentLeft) {
+    const { reference, sameDirection } = lanelet.adjacentLeft;
+    topologyItems.push({
+      label: "左侧相邻",
+      value: `${reference} (${sameDirection ? "同向" : "反向"})`,
+    });
+  }
+  if (lanelet.adjacentRight) {
+    const { reference, sameDirection } = lanelet.adjacentRight;
+    topologyItems.push({
+      label: "右侧相邻",
+      value: `${reference} (${sameDirection ? "同向" : "反向"})`,
+    });
+  }
+
+  return [
+    { label: "基本信息", items: basicItems },
+    {
+      label: "边界",
+      items: [
+        { label: "左边界标线", value: lanelet.leftBound.lineMarking ?? "-" },
+        { label: "右边界标线", value: lanelet.rightBound.lineMarking ?? "-" },
+      ],
+    },
+    { label: "拓扑", items: topologyItems },
+    {
+      label: "交通规则",
+      items: [
+        { label: "交通标志", value: formatIds(lanelet.trafficSignRefs) },
+        { label: "信号灯", value: formatIds(lanelet.trafficLightRefs) },
+      ],
+    },
+  ];
+}
+
+/**
+ * 提供属性面板信息的统一入口
+ */
+export function provideProperties(element: CommonRoadElement): PropertiyGroup[] | null {
+  switch (element.type) {
+    case "map":
+      return provideMapProperties(element);
+    case "lanelets":
+      return provideLaneletsProperties(element);
+    case "lanelet":
+      return provideLaneletProperties(element);
+    default:
+      return null;
+  }
+}
@@ -0,0 +1,2 @@
+export { CommonRoadMapRenderer } from "./map-renderer";
+export { CommonRoadLaneletRenderer } from "./lanelet-renderer";
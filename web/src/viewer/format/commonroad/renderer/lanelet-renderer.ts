@@ -0,0 +1,37 @@
+import { Lanelet2MeshBuilder } from "@maphub/core";
+import { MapRenderer } from "@/viewer/types/renderer";
+import type { CommonRoadLaneletElement } from "../elements";
+import { createSurfaceMesh, laneletColor } from "../../lanelet2/renderer/mesh";
+
+export class CommonRoadLaneletRenderer extends MapRenderer<"commonroad", "lanelet"> {
+  readonly node: CommonRoadLaneletElement;
+
+  constructor(node: CommonRoadLaneletElement) {
+    super();
+
+    this.node = node;
+    this.name = node.name;
+    this.visible = node.visible;
+
+    this.createMesh();
+  }
+
+  private createMesh() {
+    const { lanelet2, converted } = this.node;
+    const builder = new Lanelet2MeshBuilder(1.0, lanelet2.center);
+    const meshData = builder.buildLaneletMesh(converted);
+
+    const mesh = createSurfaceMesh(
+      meshData,
+      laneletColor(converted.subtype),
+      `LaneletMesh-${converted.id}`,
+    );
+    if (mesh) {
+      this.add(mesh);
+    }
+
+    // 清理 WASM 对象
+    meshData.free();
+    builder.free();
+  }
+}
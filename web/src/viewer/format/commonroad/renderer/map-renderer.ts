@@ -0,0 +1,55 @@
+import { Lanelet2MeshBuilder } from "@maphub/core";
+import { MapRenderer } from "@/viewer/types/renderer";
+import { AdaptiveGrid } from "@/utils/three/adaptive-grid";
+import { scheduleIdleTask } from "@/utils/scheduler";
+import type { CommonRoadMapElement } from "../elements";
+import { CommonRoadLaneletRenderer } from "./lanelet-renderer";
+import { createLineStringMarks } from "../../lanelet2/renderer/mesh";
+
+export class CommonRoadMapRenderer extends MapRenderer<"commonroad", "map"> {
+  readonly node: CommonRoadMapElement;
+  private readonly grid: AdaptiveGrid;
+
+  constructor(node: CommonRoadMapElement) {
+    super();
+
+    this.node = node;
+    this.name = node.name;
+    this.visible = node.visible;
+
+    this.createChildren();
+    // 在浏览器空闲时按边界的 lineMarking 创建标线网格
+    scheduleIdleTask(() => {
+      this.createMarks();
+    });
+
+    // 创建自适应网格
+    this.grid = new AdaptiveGrid({
+      primaryColor: 0x444444,
+      secondaryColor: 0x222222,
+      opacity: 0.5,
+      offsetY: 0.5,
+    });
+    this.add(this.grid);
+
+    // 在所有车道创建完成后更新网格
+    this.grid.updateFromTarget(this);
+  }
+
+  private createChildren() {
+    this.node.children.forEach((lanelets) => {
+      lanelets.children.forEach((lanelet) => {
+        this.add(new CommonRoadLaneletRenderer(lanelet));
+      });
+    });
+  }
+
+  private createMarks() {
+    const { lanelet2 } = this.node;
+    const builder = new Lanelet2MeshBuilder(0.1, lanelet2.center);
+    for (const mesh of createLineStringMarks(builder, lanelet2.lineStrings)) {
+      this.add(mesh);
+    }
+    builder.free();
+  }
+}
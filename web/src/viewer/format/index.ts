@@ -1,11 +1,19 @@
 import { OpenDriveFormat } from "./odr";
+import { Lanelet2Format } from "./lanelet2";
+import { CommonRoadFormat } from "./commonroad";
+import type { Files } from "@maphub/core";
+import type { MapFormat } from "../types/format";
+import type { MapNode } from "../types/map-node";
+import type { MapRenderer } from "../types/renderer";
 
 export const formatRegistry = {
   opendrive: OpenDriveFormat,
+  lanelet2: Lanelet2Format,
+  commonroad: CommonRoadFormat,
 };
 
 // 地图格式类型
-export type MapFormatType = "opendrive";
+export type MapFormatType = "opendrive" | "lanelet2" | "commonroad";
 
 // 地图格式节点类型
 export interface MapFormatNodeType {
@@ -19,4 +27,34 @@ export interface MapFormatNodeType {
     | "junctions"
     | "junction"
     | "junction-connection";
+  lanelet2: "map" | "lanelets" | "lanelet" | "areas" | "area";
+  commonroad: "map" | "lanelets" | "lanelet";
+}
+
+/**
+ * 用指定格式解析文件，并为每个根节点创建渲染器
+ *
+ * 按格式分派到具体的处理器，使根节点与 provideRenderer 的参数类型一致。
+ * 格式不受支持时返回 null。
+ */
+export function loadMap(format: MapFormatType, files: Files): [MapNode, MapRenderer][] | null {
+  switch (format) {
+    case "opendrive":
+      return loadRoots(formatRegistry.opendrive, files);
+    case "lanelet2":
+      return loadRoots(formatRegistry.lanelet2, files);
+    case "commonroad":
+      return loadRoots(formatRegistry.commonroad, files);
+    default:
+      return null;
+  }
+}
+
+function loadRoots<F extends MapFormatType, E extends MapNode<F>, R extends MapFormatNodeType[F]>(
+  handler: MapFormat<F, E, R>,
+  files: Files,
+): [MapNode<F, R>, MapRenderer<F, R>][] {
+  return handler
+    .parse(files)
+    .map((node): [MapNode<F, R>, MapRenderer<F, R>] => [node, handler.provideRenderer(node)]);
 }
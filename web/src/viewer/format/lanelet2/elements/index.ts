@@ -0,0 +1,39 @@
+import type { MapNode } from "@/viewer/types/map-node";
+import type { Lanelet2Area, Lanelet2Lanelet, Lanelet2Map } from "@maphub/core";
+
+export interface Lanelet2MapElement extends MapNode<"lanelet2", "map"> {
+  map: Lanelet2Map;
+  children: (Lanelet2LaneletsElement | Lanelet2AreasElement)[];
+}
+
+export interface Lanelet2LaneletsElement extends MapNode<"lanelet2", "lanelets"> {
+  map: Lanelet2Map;
+  lanelets: Lanelet2Lanelet[];
+  children: Lanelet2LaneletElement[];
+}
+
+export interface Lanelet2LaneletElement extends MapNode<"lanelet2", "lanelet"> {
+  map: Lanelet2Map;
+  lanelet: Lanelet2Lanelet;
+  children: [];
+}
+
+export interface Lanelet2AreasElement extends MapNode<"lanelet2", "areas"> {
+  map: Lanelet2Map;
+  areas: Lanelet2Area[];
+  children: Lanelet2AreaElement[];
+}
+
+export interface Lanelet2AreaElement extends MapNode<"lanelet2", "area"> {
+  map: Lanelet2Map;
+  area: Lanelet2Area;
+  children: [];
+}
+
+// Lanelet2 所有节点类型的 discriminated union
+export type Lanelet2Element =
+  | Lanelet2MapElement
+  | Lanelet2LaneletsElement
+  | Lanelet2LaneletElement
+  | Lanelet2AreasElement
+  | Lanelet2AreaElement;
@@ -0,0 +1,176 @@
+import type { MapFormat } from "@/viewer/types/format";
+import type { MapNode } from "@/viewer/types/map-node";
+import type { MapRenderer } from "@/viewer/types/renderer";
+import {
+  Lanelet2Map,
+  parseLanelet2FromFiles,
+  type Files,
+  type Lanelet2Area,
+  type Lanelet2Lanelet,
+} from "@maphub/core";
+import type { Vector3 } from "three";
+import type {
+  Lanelet2Element,
+  Lanelet2MapElement,
+  Lanelet2LaneletsElement,
+  Lanelet2LaneletElement,
+  Lanelet2AreasElement,
+  Lanelet2AreaElement,
+} from "./elements";
+import { Lanelet2MapRenderer } from "./renderer";
+import { generateId, type Id } from "@/utils/id";
+import { provideHoverInfo, provideProperties, provideTreeInfo } from "./providers";
+/**
+ * Lanelet2 地图格式实现
+ */
+export const Lanelet2Format: MapFormat<"lanelet2", Lanelet2Element, "map"> = {
+  format: "lanelet2",
+  rootNodeType: "map",
+
+  /**
+   * 解析 Lanelet2 (.osm) 文件并生成节点树
+   */
+  parse(files: Files): Lanelet2MapElement[] {
+    console.time("parseLanelet2FromFiles");
+    const maps: Lanelet2Map[] = parseLanelet2FromFiles(files);
+    console.timeEnd("parseLanelet2FromFiles");
+    return maps.map(buildMapElement);
+  },
+
+  provideRenderer(node: Lanelet2MapElement): MapRenderer<"lanelet2", "map"> {
+    return new Lanelet2MapRenderer(node);
+  },
+
+  /**
+   * 提供鼠标悬停信息
+   */
+  provideHoverInfo(node: MapNode<"lanelet2">, pos: Vector3) {
+    return provideHoverInfo(node as Lanelet2Element, pos);
+  },
+
+  /**
+   * 提供属性面板信息
+   */
+  provideProperties(node: MapNode<"lanelet2">) {
+    return provideProperties(node as Lanelet2Element);
+  },
+
+  /**
+   * 提供场景树信息
+   */
+  provideTreeInfo(node: MapNode<"lanelet2">) {
+    return provideTreeInfo(node as Lanelet2Element);
+  },
+};
+
+/**
+ * 构建单个车道单元的 Element
+ */
+function buildLaneletElement(
+  map: Lanelet2Map,
+  parentId: Id,
+  lanelet: Lanelet2Lanelet,
+): Lanelet2LaneletElement {
+  return {
+    id: generateId(),
+    parentId,
+    children: [],
+    name: `Lanelet ${lanelet.id}${lanelet.subtype ? ` (${lanelet.subtype})` : ""}`,
+    visible: true,
+    format: "lanelet2",
+    type: "lanelet",
+    map,
+    lanelet,
+  };
+}
+
+/**
+ * 构建 Lanelets 容器 Element
+ */
+function buildLaneletsElement(
+  map: Lanelet2Map,
+  parentId: Id,
+  lanelets: Lanelet2Lanelet[],
+): Lanelet2LaneletsElement {
+  const laneletsId = generateId();
+
+  return {
+    id: laneletsId,
+    parentId,
+    children: lanelets.map((lanelet) => buildLaneletElement(map, laneletsId, lanelet)),
+    name: "Lanelets",
+    visible: true,
+    format: "lanelet2",
+    type: "lanelets",
+    map,
+    lanelets,
+  };
+}
+
+/**
+ * 构建单个区域的 Element
+ */
+function buildAreaElement(map: Lanelet2Map, parentId: Id, area: Lanelet2Area): Lanelet2AreaElement {
+  return {
+    id: generateId(),
+    parentId,
+    children: [],
+    name: `Area ${area.id}${area.subtype ? ` (${area.subtype})` : ""}`,
+    visible: true,
+    format: "lanelet2",
+    type: "area",
+    map,
+    area,
+  };
+}
+
+/**
+ * 构建 Areas 容器 Element
+ */
+function buildAreasElement(
+  map: Lanelet2Map,
+  parentId: Id,
+  areas: Lanelet2Area[],
+): Lanelet2AreasElement {
+  const areasId = generateId();
+
+  return {
+    id: areasId,
+    parentId,
+    children: areas.map((area) => buildAreaElement(map, areasId, area)),
+    name: "Areas",
+    visible: true,
+    format: "lanelet2",
+    type: "areas",
+    map,
+    areas,
+  };
+}
+
+/**
+ * 构建完整的 Map Element 树
+ */
+function buildMapElement(map: Lanelet2Map): Lanelet2MapElement {
+  const mapId = generateId();
+
+  // 构建 lanelets 容器节点
+  const children: (Lanelet2LaneletsElement | Lanelet2AreasElement)[] = [
+    buildLaneletsElement(map, mapId, map.lanelets),
+  ];
+
+  // 如果有 areas，构建 areas 容器节点
+  if (map.areas.length > 0) {
+    children.push(buildAreasElement(map, mapId, map.areas));
+  }
+
+  return {
+    id: mapId,
+    parentId: null,
+    children,
+    map,
+    name: "Lanelet2 Map",
+    visible: true,
+    format: "lanelet2",
+    type: "map",
+  };
+}
@@ -0,0 +1,62 @@
+import type { HoverInfo } from "@/viewer/types/format";
+import type { Lanelet2AreaElement, Lanelet2Element, Lanelet2LaneletElement } from "../elements";
+import type { Vector3 } from "three";
+import { threePositionToOdr } from "../../odr/math";
+
+/**
+ * 提供 lanelet 的 hover 信息
+ */
+export function provideLaneletHoverInfo(element: Lanelet2LaneletElement, pos: Vector3): HoverInfo {
+  const { map, lanelet } = element;
+
+  // 将世界坐标还原为地图局部坐标
+  const xyz = threePositionToOdr(pos, map.center);
+
+  return {
+    title: `Lanelet ${lanelet.id}`,
+    icon: null,
+    description: [],
+    items: [
+      { label: "Subtype", value: lanelet.subtype ?? "-" },
+      ...(lanelet.speedLimit !== undefined
+        ? [{ label: "Speed Limit", value: `${lanelet.speedLimit.toFixed(0)} km/h` }]
+        : []),
+      { label: "One Way", value: lanelet.oneWay ? "Yes" : "No" },
+      {
+        label: "XYZ Coords",
+        value: `x: ${xyz.x.toFixed(2)}, y: ${xyz.y.toFixed(2)}, z: ${xyz.z.toFixed(2)}`,
+      },
+    ],
+  };
+}
+
+/**
+ * 提供 area 的 hover 信息
+ */
+export function provideAreaHoverInfo(element: Lanelet2AreaElement): HoverInfo {
+  const { area } = element;
+
+  return {
+    title: `Area ${area.id}`,
+    icon: null,
+    description: [],
+    items: [
+      { label: "Subtype", value: area.subtype ?? "-" },
+      { label: "Vertices", value: String(area.outer.length) },
+    ],
+  };
+}
+
+/**
+ * 提供 hover 信息的统一入口
+ */
+export function provideHoverInfo(element: Lanelet2Element, pos: Vector3): HoverInfo | null {
+  switch (element.type) {
+    case "lanelet":
+      return provideLaneletHoverInfo(element, pos);
+    case "area":
+      return provideAreaHoverInfo(element);
+    default:
+      return null;
+  }
+}
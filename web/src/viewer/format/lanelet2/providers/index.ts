@@ -0,0 +1,3 @@
+export * from "./hover";
+export * from "./properties";
+export * from "./tree";
@@ -0,0 +1,176 @@
+import type { PropertiyGroup, PropertyItem } from "@/viewer/types/format";
+import type { Lanelet2Tag } from "@maphub/core";
+import type {
+  Lanelet2AreaElement,
+  Lanelet2AreasElement,
+  Lanelet2Element,
+  Lanelet2LaneletElement,
+  Lanelet2LaneletsElement,
+  Lanelet2MapElement,
+} from "../elements";
+
+/**
+ * 将 OSM 标签转换为属性项
+ */
+function tagItems(tags: Lanelet2Tag[]): PropertyItem[] {
+  return tags.map((tag) => ({ label: tag.key, value: tag.value }));
+}
+
+/**
+ * 将 ID 列表格式化为文本
+ */
+function formatIds(ids: BigInt64Array | bigint[]): string {
+  return ids.length > 0 ? Array.from(ids).join(", ") : "-";
+}
+
+/**
+ * 提供地图的属性面板信息
+ */
+export function provideMapProperties(element: Lanelet2MapElement): PropertiyGroup[] {
+  const { map } = element;
+
+  const groups: PropertiyGroup[] = [
+    {
+      label: "基本信息",
+      items: [
+        { label: "车道单元数量", value: String(map.lanelets.length) },
+        { label: "区域数量", value: String(map.areas.length) },
+        { label: "线串数量", value: String(map.lineStrings.length) },
+        { label: "交通规则数量", value: String(map.regulatoryElements.length) },
+      ],
+    },
+  ];
+
+  // 地理原点
+  if (map.originLat !== undefined && map.originLon !== undefined) {
+    groups.push({
+      label: "地理原点",
+      items: [
+        { label: "纬度", value: map.originLat.toFixed(6) },
+        { label: "经度", value: map.originLon.toFixed(6) },
+      ],
+    });
+  }
+
+  return groups;
+}
+
+/**
+ * 提供车道单元集合的属性面板信息
+ */
+export function provideLaneletsProperties(element: Lanelet2LaneletsElement): PropertiyGroup[] {
+  return [
+    {
+      label: "基本信息",
+      items: [{ label: "车道单元数量", value: String(element.lanelets.length) }],
+    },
+  ];
+}
+
+/**
+ * 提供车道单元的属性面板信息
+ */
+export function provideLaneletProperties(element: Lanelet2LaneletElement): PropertiyGroup[] {
+  const { lanelet } = element;
+
+  const basicItems: PropertyItem[] = [
+    { label: "ID", value: String(lanelet.id) },
+    { label: "子类型", value: lanelet.subtype ?? "-" },
+    { label: "单向", value: lanelet.oneWay ? "是" : "否" },
+  ];
+  if (lanelet.speedLimit !== undefined) {
+    basicItems.push({ label: "限速", value: `${lanelet.speedLimit.toFixed(0)} km/h` });
+  }
+  if (lanelet.location) {
+    basicItems.push({ label: "位置", value: lanelet.location });
+  }
+  if (lanelet.turnDirection) {
+    basicItems.push({ label: "转向", value: lanelet.turnDirection });
+  }
+
+  const groups: PropertiyGroup[] = [
+    { label: "基本信息", items: basicItems },
+    {
+      label: "边界",
+      items: [
+        { label: "左边界", value: `${lanelet.left.id} (${lanelet.left.lineType})` },
+        { label: "右边界", value: `${lanelet.right.id} (${lanelet.right.lineType})` },
+      ],
+    },
+    {
+      label: "拓扑",
+      items: [
+        { label: "前驱", value: formatIds(lanelet.predecessors) },
+        { label: "后继", value: formatIds(lanelet.successors) },
+        { label: "左侧相邻", value: formatIds(lanelet.leftNeighbors) },
+        { label: "右侧相邻", value: formatIds(lanelet.rightNeighbors) },
+        { label: "交通规则", value: formatIds(lanelet.regulatoryElements) },
+      ],
+    },
+  ];
+
+  const tags = tagItems(lanelet.tags);
+  if (tags.length > 0) {
+    groups.push({ label: "标签", items: tags });
+  }
+
+  return groups;
+}
+
+/**
+ * 提供区域集合的属性面板信息
+ */
+export function provideAreasProperties(element: Lanelet2AreasElement): PropertiyGroup[] {
+  return [
+    {
+      label: "基本信息",
+      items: [{ label: "区域数量", value: String(element.areas.length) }],
+    },
+  ];
+}
+
+/**
+ * 提供区域的属性面板信息
+ */
+export function provideAreaProperties(element: Lanelet2AreaElement): PropertiyGroup[] {
+  const { area } = element;
+
+  const groups: PropertiyGroup[] = [
+    {
+      label: "基本信息",
+      items: [
+        { label: "ID", value: String(area.id) },
+        { label: "子类型", value: area.subtype ?? "-" },
+        { label: "顶点数量", value: String(area.outer.length) },
+        { label: "交通规则", value: formatIds(area.regulatoryElements) },
+      ],
+    },
+  ];
+
+  const tags = tagItems(area.tags);
+  if (tags.length > 0) {
+    groups.push({ label: "标签", items: tags });
+  }
+
+  return groups;
+}
+
+/**
+ * 提供属性面板信息的统一入口
+ */
+export function provideProperties(element: Lanelet2Element): PropertiyGroup[] | null {
+  switch (element.type) {
+    case "map":
+      return provideMapProperties(element);
+    case "lanelets":
+      return provideLaneletsProperties(element);
+    case "lanelet":
+      return provideLaneletProperties(element);
+    case "areas":
+      return provideAreasProperties(element);
+    case "area":
+      return provideAreaProperties(element);
+    default:
+      return null;
+  }
+}
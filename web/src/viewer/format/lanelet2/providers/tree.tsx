@@ -0,0 +1,25 @@
+import type { TreeInfo } from "@/viewer/types/format";
+import type { Lanelet2Element } from "../elements";
+import { FolderOpen, MapIcon, RectangleHorizontal, Square } from "lucide-react";
+
+/**
+ * 提供场景树信息
+ */
+export function provideTreeInfo(element: Lanelet2Element): TreeInfo {
+  const iconClass = "w-4 h-4 shrink-0 text-muted-foreground";
+
+  switch (element.type) {
+    case "map":
+      return { icon: <MapIcon className={iconClass} />, menus: [] };
+    case "lanelets":
+      return { icon: <FolderOpen className={iconClass} />, menus: [], virtual: true };
+    case "areas":
+      return { icon: <FolderOpen className={iconClass} />, menus: [], virtual: true };
+    case "lanelet":
+      return { icon: <RectangleHorizontal className={iconClass} />, menus: [] };
+    case "area":
+      return { icon: <Square className={iconClass} />, menus: [] };
+    default:
+      return { icon: null, menus: [] };
+  }
+}
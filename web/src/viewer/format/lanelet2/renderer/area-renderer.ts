@@ -0,0 +1,34 @@
+import { Lanelet2MeshBuilder } from "@maphub/core";
+import { MapRenderer } from "@/viewer/types/renderer";
+import type { Lanelet2AreaElement } from "../elements";
+import { createSurfaceMesh } from "./mesh";
+
+export class Lanelet2AreaRenderer extends MapRenderer<"lanelet2", "area"> {
+  readonly node: Lanelet2AreaElement;
+
+  constructor(node: Lanelet2AreaElement) {
+    super();
+
+    this.node = node;
+    this.name = node.name;
+    this.visible = node.visible;
+
+    this.createMesh();
+  }
+
+  private createMesh() {
+    const { map, area } = this.node;
+    const builder = new Lanelet2MeshBuilder(1.0, map.center);
+    const meshData = builder.buildAreaMesh(area);
+
+    const color = area.subtype === "vegetation" ? 0x557744 : 0x777777;
+    const mesh = createSurfaceMesh(meshData, color, `AreaMesh-${area.id}`);
+    if (mesh) {
+      this.add(mesh);
+    }
+
+    // 清理 WASM 对象
+    meshData.free();
+    builder.free();
+  }
+}
@@ -0,0 +1,3 @@
+export { Lanelet2MapRenderer } from "./map-renderer";
+export { Lanelet2LaneletRenderer } from "./lanelet-renderer";
+export { Lanelet2AreaRenderer } from "./area-renderer";
@@ -0,0 +1,37 @@
+import { Lanelet2MeshBuilder } from "@maphub/core";
+import { MapRenderer } from "@/viewer/types/renderer";
+import type { Lanelet2LaneletElement } from "../elements";
+import { createSurfaceMesh, laneletColor } from "./mesh";
+
+export class Lanelet2LaneletRenderer extends MapRenderer<"lanelet2", "lanelet"> {
+  readonly node: Lanelet2LaneletElement;
+
+  constructor(node: Lanelet2LaneletElement) {
+    super();
+
+    this.node = node;
+    this.name = node.name;
+    this.visible = node.visible;
+
+    this.createMesh();
+  }
+
+  private createMesh() {
+    const { map, lanelet } = this.node;
+    const builder = new Lanelet2MeshBuilder(1.0, map.center);
+    const meshData = builder.buildLaneletMesh(lanelet);
+
+    const mesh = createSurfaceMesh(
+      meshData,
+      laneletColor(lanelet.subtype),
+      `LaneletMesh-${lanelet.id}`,
+    );
+    if (mesh) {
+      this.add(mesh);
+    }
+
+    // 清理 WASM 对象
+    meshData.free();
+    builder.free();
+  }
+}
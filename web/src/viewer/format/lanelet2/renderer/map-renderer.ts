@@ -0,0 +1,62 @@
+import { Lanelet2MeshBuilder } from "@maphub/core";
+import { MapRenderer } from "@/viewer/types/renderer";
+import { AdaptiveGrid } from "@/utils/three/adaptive-grid";
+import { scheduleIdleTask } from "@/utils/scheduler";
+import type { Lanelet2MapElement } from "../elements";
+import { Lanelet2LaneletRenderer } from "./lanelet-renderer";
+import { Lanelet2AreaRenderer } from "./area-renderer";
+import { createLineStringMarks } from "./mesh";
+
+export class Lanelet2MapRenderer extends MapRenderer<"lanelet2", "map"> {
+  readonly node: Lanelet2MapElement;
+  private readonly grid: AdaptiveGrid;
+
+  constructor(node: Lanelet2MapElement) {
+    super();
+
+    this.node = node;
+    this.name = node.name;
+    this.visible = node.visible;
+
+    this.createChildren();
+    // 线串可被多个 lanelet 共用，在地图层统一构建标线，避免重复
+    scheduleIdleTask(() => {
+      this.createMarks();
+    });
+
+    // 创建自适应网格
+    this.grid = new AdaptiveGrid({
+      primaryColor: 0x444444,
+      secondaryColor: 0x222222,
+      opacity: 0.5,
+      offsetY: 0.5,
+    });
+    this.add(this.grid);
+
+    // 在所有车道创建完成后更新网格
+    this.grid.updateFromTarget(this);
+  }
+
+  private createChildren() {
+    this.node.children.forEach((child) => {
+      if (child.type === "lanelets") {
+        child.children.forEach((lanelet) => {
+          this.add(new Lanelet2LaneletRenderer(lanelet));
+        });
+      } else {
+        child.children.forEach((area) => {
+          this.add(new Lanelet2AreaRenderer(area));
+        });
+      }
+    });
+  }
+
+  private createMarks() {
+    const { map } = this.node;
+    const builder = new Lanelet2MeshBuilder(0.1, map.center);
+    for (const mesh of createLineStringMarks(builder, map.lineStrings)) {
+      this.add(mesh);
+    }
+    builder.free();
+  }
+}
@@ -0,0 +1,100 @@
+import {
+  BufferAttribute,
+  BufferGeometry,
+  DoubleSide,
+  Mesh,
+  MeshStandardMaterial,
+} from "three";
+import type { Lanelet2LineString, Lanelet2MeshBuilder, MeshData } from "@maphub/core";
+
+/**
+ * 根据 lanelet 的 subtype 选择车道面颜色
+ */
+export function laneletColor(subtype?: string): number {
+  switch (subtype) {
+    case "crosswalk":
+      return 0xbbbbbb; // 人行横道
+    case "walkway":
+      return 0xcccccc; // 人行道
+    case "bicycle_lane":
+      return 0x88aa77; // 自行车道
+    case "bus_lane":
+      return 0x996655; // 公交车道
+    default:
+      return 0x555555; // 行车道
+  }
+}
+
+/**
+ * 由 MeshData 创建面网格，网格数据为空时返回 null
+ *
+ * 调用方负责释放 meshData。
+ */
+export function createSurfaceMesh(meshData: MeshData, color: number, name: string): Mesh | null {
+  if (meshData.vertices.length === 0) return null;
+
+  const geometry = new BufferGeometry();
+  geometry.setAttribute("position", new BufferAttribute(meshData.vertices, 3));
+  geometry.setAttribute("normal", new BufferAttribute(meshData.normals, 3));
+  geometry.setIndex(new BufferAttribute(meshData.indices, 1));
+
+  const material = new MeshStandardMaterial({
+    color,
+    roughness: 0.6,
+    metalness: 0.2,
+    side: DoubleSide,
+  });
+
+  const mesh = new Mesh(geometry, material);
+  mesh.name = name;
+  return mesh;
+}
+
+/**
+ * 按线串的 type/subtype 标签构建标线网格
+ */
+export function createLineStringMarks(
+  builder: Lanelet2MeshBuilder,
+  lineStrings: Lanelet2LineString[],
+): Mesh[] {
+  const meshes: Mesh[] = [];
+
+  for (const lineString of lineStrings) {
+    const meshList = builder.buildLineStringMarks(lineString);
+
+    for (let i = 0; i < meshList.length; i++) {
+      const item = meshList.get(i);
+      if (!item) continue;
+
+      const meshData = item.mesh;
+      if (meshData.vertices.length > 0) {
+        const geometry = new BufferGeometry();
+        geometry.setAttribute("position", new BufferAttribute(meshData.vertices, 3));
+        geometry.setAttribute("normal", new BufferAttribute(meshData.normals, 3));
+        geometry.setIndex(new BufferAttribute(meshData.indices, 1));
+
+        // 颜色已按标线配置解析为 sRGB
+        const material = new MeshStandardMaterial({
+          color: item.rgb,
+          roughness: 0.4,
+          metalness: 0.1,
+          side: DoubleSide,
+          polygonOffset: true,
+          polygonOffsetFactor: -2,
+          polygonOffsetUnits: -2,
+        });
+
+        const mesh = new Mesh(geometry, material);
+        mesh.name = `LineStringMarks-${lineString.id}-${i}`;
+        meshes.push(mesh);
+      }
+
+      // 清理 WASM 对象
+      meshData.free();
+    }
+
+    meshList.free();
+  }
+
+  return meshes;
+}